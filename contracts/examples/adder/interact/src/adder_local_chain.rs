@@ -0,0 +1,52 @@
+use clap::Parser;
+use multiversx_sc_snippets::{
+    env_logger,
+    local_chain::{LocalChain, LocalChainServer},
+    multiversx_sc_scenario::{
+        scenario_model::{Account, SetStateStep},
+        test_wallets,
+    },
+    tokio,
+};
+
+const ADDER_PATH_EXPR: &str = "file:../output/adder.wasm";
+
+/// Local chain serving the adder contract, for running the interactor without a real network.
+#[derive(Debug, Parser)]
+#[command(about)]
+struct LocalChainCli {
+    /// The port to listen on
+    #[arg(short = 'p', long = "port", default_value_t = 8085)]
+    port: u16,
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let cli = LocalChainCli::parse();
+
+    let mut chain = LocalChain::default();
+    chain
+        .set_current_dir(env!("CARGO_MANIFEST_DIR").into())
+        .register_contract(ADDER_PATH_EXPR, adder::ContractBuilder)
+        .set_state_step(
+            SetStateStep::new().put_account(
+                format!(
+                    "bech32:{}",
+                    test_wallets::mike().address().to_bech32_string().unwrap()
+                )
+                .as_str(),
+                Account::new().nonce(0).balance("1,000,000,000,000,000,000"),
+            ),
+        );
+
+    let server = LocalChainServer::new(chain)
+        .start(([127, 0, 0, 1], cli.port).into())
+        .expect("failed to start local chain server");
+    println!("local chain listening on {}", server.url());
+
+    tokio::signal::ctrl_c()
+        .await
+        .expect("failed to listen for ctrl-c");
+}
@@ -0,0 +1,66 @@
+use adder::ProxyTrait;
+use multiversx_sc_snippets::{
+    local_chain::{LocalChain, LocalChainTransport},
+    multiversx_sc::{
+        storage::mappers::SingleValue,
+        types::{Address, BigUint},
+    },
+    multiversx_sc_scenario::{
+        api::StaticApi,
+        scenario_model::{Account, ScCallStep, ScDeployStep, SetStateStep},
+        test_wallets, ContractInfo,
+    },
+    sdk::blockchain::CommunicationProxy,
+    tokio, Interactor, InteractorConfig,
+};
+use std::time::Duration;
+
+const ADDER_PATH_EXPR: &str = "file:../output/adder.wasm";
+const GAS_LIMIT: u64 = 10_000_000;
+
+type AdderContract = ContractInfo<adder::Proxy<StaticApi>>;
+
+/// Deploys the adder, adds to it and reads the sum, through the interactor, on a local chain.
+#[tokio::test]
+async fn adder_local_chain_test() {
+    let owner = test_wallets::mike();
+    let owner_expr = format!("bech32:{}", owner.address().to_bech32_string().unwrap());
+    let mut chain = LocalChain::default();
+    chain
+        .register_contract(ADDER_PATH_EXPR, adder::ContractBuilder)
+        .set_state_step(SetStateStep::new().put_account(
+            owner_expr.as_str(),
+            Account::new().nonce(0).balance("1,000,000,000,000,000,000"),
+        ));
+    let code = chain.code_expression(ADDER_PATH_EXPR);
+    let proxy = CommunicationProxy::with_transport(LocalChainTransport::new(chain));
+    let mut interactor = Interactor::with_proxy(proxy)
+        .await
+        .with_config(InteractorConfig::default().polling_interval(Duration::from_millis(10)));
+    let owner_address = interactor.register_wallet(owner);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(code)
+        .argument("5")
+        .gas_limit(GAS_LIMIT);
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address: Address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("missing deployed address");
+    let mut adder_contract = AdderContract::new(&adder_address);
+
+    interactor
+        .sc_call(
+            ScCallStep::new()
+                .call(adder_contract.add(3u32))
+                .from(&owner_address)
+                .gas_limit(GAS_LIMIT),
+        )
+        .await;
+
+    let sum: SingleValue<BigUint<StaticApi>> = interactor.quick_query(adder_contract.sum()).await;
+    assert_eq!(sum.into(), BigUint::<StaticApi>::from(8u32));
+}
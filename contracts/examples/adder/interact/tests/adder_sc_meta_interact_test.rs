@@ -0,0 +1,89 @@
+use multiversx_sc_meta::{
+    abi_json::ContractAbiJson,
+    cmd::standalone::interact::{
+        parse_interact_command, InteractAbi, InteractCommand, InteractError, InteractPayment,
+        InteractRunner,
+    },
+};
+use multiversx_sc_snippets::{
+    local_chain::{LocalChain, LocalChainTransport},
+    multiversx_sc::contract_base::ContractAbiProvider,
+    multiversx_sc_scenario::{
+        scenario_model::{Account, SetStateStep},
+        test_wallets,
+    },
+    sdk::{blockchain::CommunicationProxy, data::address::Address},
+    tokio,
+};
+use std::time::Duration;
+
+const ADDER_PATH_EXPR: &str = "file:../output/adder.wasm";
+
+/// Chain with the adder contract registered, and a funded owner, plus the adder code.
+fn adder_chain() -> (CommunicationProxy, Vec<u8>) {
+    let owner_expr = format!(
+        "bech32:{}",
+        test_wallets::mike().address().to_bech32_string().unwrap()
+    );
+
+    let mut chain = LocalChain::default();
+    chain
+        .register_contract(ADDER_PATH_EXPR, adder::ContractBuilder)
+        .set_state_step(SetStateStep::new().put_account(
+            owner_expr.as_str(),
+            Account::new().nonce(0).balance("1,000,000,000,000,000,000"),
+        ));
+    let code = chain.code_expression(ADDER_PATH_EXPR).value;
+    let proxy = CommunicationProxy::with_transport(LocalChainTransport::new(chain));
+    (proxy, code)
+}
+
+fn adder_abi() -> InteractAbi {
+    InteractAbi::from_abi_json(&ContractAbiJson::from(&adder::AbiProvider::abi())).unwrap()
+}
+
+fn command(abi: &InteractAbi, raw_args: &[&str]) -> InteractCommand {
+    let raw_args: Vec<String> = raw_args.iter().map(|arg| arg.to_string()).collect();
+    parse_interact_command(abi, &raw_args).unwrap()
+}
+
+#[tokio::test]
+async fn interact_local_chain_test() {
+    let owner = test_wallets::mike();
+    let (proxy, code) = adder_chain();
+    let abi = adder_abi();
+    let command = |raw_args: &[&str]| command(&abi, raw_args);
+
+    let deployer = InteractRunner::new(abi.clone(), proxy.clone())
+        .with_wallet(owner)
+        .with_code(code)
+        .with_poll_interval(Duration::from_millis(10));
+    let report = deployer.run(&command(&["deploy", "5"])).await.unwrap();
+    let adder_address = Address::from_bech32_string(report["address"].as_str().unwrap()).unwrap();
+
+    let runner = InteractRunner::new(abi.clone(), proxy)
+        .with_wallet(owner)
+        .with_contract(adder_address)
+        .with_poll_interval(Duration::from_millis(10));
+    let report = runner.run(&command(&["add", "3"])).await.unwrap();
+    assert!(report["txHash"].is_string());
+
+    let report = runner.run(&command(&["getSum"])).await.unwrap();
+    assert_eq!(report["results"][0].as_str(), Some("8"));
+
+    let err = runner.run(&command(&["add", "three"])).await.unwrap_err();
+    assert!(err.to_string().contains("cannot encode argument `value`"));
+}
+
+#[tokio::test]
+async fn interact_value_with_payments_test() {
+    let (proxy, _) = adder_chain();
+    let abi = adder_abi();
+    let runner = InteractRunner::new(abi.clone(), proxy)
+        .with_wallet(test_wallets::mike())
+        .with_contract(Address::from_bytes([5u8; 32]))
+        .with_value(1u32.into())
+        .with_payment(InteractPayment::parse("TOKEN-123456:5").unwrap());
+    let err = runner.run(&command(&abi, &["add", "3"])).await.unwrap_err();
+    assert!(matches!(err, InteractError::Command(_)));
+}
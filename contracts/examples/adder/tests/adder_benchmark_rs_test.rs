@@ -0,0 +1,46 @@
+use multiversx_sc_scenario::{benchmark::ScenarioBenchmark, scenario_model::*, *};
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract("file:output/adder.wasm", adder::ContractBuilder);
+    blockchain
+}
+
+fn add_step(value: &str) -> ScCallStep {
+    ScCallStep::new()
+        .from("address:owner")
+        .to("sc:adder")
+        .function("add")
+        .argument(value)
+        .expect(TxExpect::ok().no_result())
+}
+
+#[test]
+fn adder_benchmark_rs() {
+    let benchmark = ScenarioBenchmark::new("adder")
+        .setup_scenario("scenarios/adder.scen.json")
+        .call("add_small", add_step("1"))
+        .call("add_large", add_step("1000000000000000000000000"));
+
+    let report = benchmark.run_debugger(&mut world());
+    assert_eq!(report.entries.len(), 2);
+    for entry in &report.entries {
+        assert_eq!(entry.endpoint, "add");
+        assert!(entry.vm_hook_count.unwrap() > 0);
+        assert_eq!(entry.gas_used, None);
+    }
+    assert!(!report.compare(&report, 0.0).has_regressions());
+}
+
+#[test]
+fn adder_benchmark_go_cleans_up() {
+    let benchmark = ScenarioBenchmark::new("adder_cleanup")
+        .setup_scenario("scenarios/adder.scen.json")
+        .call("add_small", add_step("1"));
+
+    let report = benchmark.run_vm_go(std::path::Path::new(env!("CARGO_MANIFEST_DIR")));
+    assert_eq!(report.entries.len(), 1);
+    assert!(!std::path::Path::new("scenarios/adder_cleanup.bench.scen.json").exists());
+}
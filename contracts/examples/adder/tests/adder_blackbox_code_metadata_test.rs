@@ -0,0 +1,224 @@
+use multiversx_sc::types::CodeMetadata;
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract("file:output/adder.wasm", adder::ContractBuilder);
+    blockchain
+}
+
+fn deploy_adder(world: &mut ScenarioWorld, code_metadata: CodeMetadata) {
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account(
+                    "address:owner",
+                    Account::new()
+                        .nonce(1)
+                        .balance("1000")
+                        .esdt_balance("str:TOK-123456", "1000"),
+                )
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(&adder_code)
+                .code_metadata(code_metadata)
+                .argument("5")
+                .expect(TxExpect::ok().no_result()),
+        );
+}
+
+#[test]
+fn adder_blackbox_upgrade_not_upgradeable() {
+    let mut world = world();
+    deploy_adder(&mut world, CodeMetadata::READABLE);
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .function("upgradeContract")
+                .argument(&adder_code)
+                .argument("0x0100") // codeMetadata
+                .argument("8") // contract argument
+                .expect(TxExpect::err(10, "str:upgrade not allowed")),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account("address:owner", CheckAccount::new())
+                .put_account(
+                    "sc:adder",
+                    CheckAccount::new().check_storage("str:sum", "5"),
+                ),
+        );
+}
+
+#[test]
+fn adder_blackbox_upgrade_replaces_code_metadata() {
+    let mut world = world();
+    deploy_adder(&mut world, CodeMetadata::UPGRADEABLE);
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .function("upgradeContract")
+                .argument(&adder_code)
+                .argument("0x0000") // codeMetadata, no longer upgradeable
+                .argument("8")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .function("upgradeContract")
+                .argument(&adder_code)
+                .argument("0x0100")
+                .argument("9")
+                .expect(TxExpect::err(10, "str:upgrade not allowed")),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account("address:owner", CheckAccount::new())
+                .put_account(
+                    "sc:adder",
+                    CheckAccount::new().check_storage("str:sum", "8"),
+                ),
+        );
+}
+
+#[test]
+fn adder_blackbox_transfer_to_non_payable() {
+    let mut world = world();
+    deploy_adder(&mut world, CodeMetadata::UPGRADEABLE);
+
+    world
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .egld_value("100")
+                .expect(TxExpect::err(
+                    10,
+                    "str:sending value to non payable contract",
+                )),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .esdt_transfer("str:TOK-123456", 0, "100")
+                .expect(TxExpect::err(
+                    10,
+                    "str:sending value to non payable contract",
+                )),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    "address:owner",
+                    CheckAccount::new()
+                        .balance("1000")
+                        .esdt_balance("str:TOK-123456", "1000"),
+                )
+                .put_account("sc:adder", CheckAccount::new().balance("0")),
+        );
+}
+
+#[test]
+fn adder_blackbox_call_non_payable_with_value() {
+    let mut world = world();
+    deploy_adder(&mut world, CodeMetadata::UPGRADEABLE);
+
+    // calls are not stopped by the code metadata, the non-payable endpoint rejects the value
+    world
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .egld_value("100")
+                .function("add")
+                .argument("1")
+                .expect(TxExpect::err(
+                    10,
+                    "str:function does not accept EGLD payment",
+                )),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .esdt_transfer("str:TOK-123456", 0, "100")
+                .function("add")
+                .argument("1")
+                .expect(TxExpect::err(
+                    10,
+                    "str:function does not accept ESDT payment",
+                )),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    "address:owner",
+                    CheckAccount::new()
+                        .balance("1000")
+                        .esdt_balance("str:TOK-123456", "1000"),
+                )
+                .put_account(
+                    "sc:adder",
+                    CheckAccount::new()
+                        .balance("0")
+                        .check_storage("str:sum", "5"),
+                ),
+        );
+}
+
+#[test]
+fn adder_blackbox_transfer_to_payable() {
+    let mut world = world();
+    deploy_adder(&mut world, CodeMetadata::PAYABLE);
+
+    world
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .egld_value("100")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .sc_call(
+            ScCallStep::new()
+                .from("address:owner")
+                .to("sc:adder")
+                .esdt_transfer("str:TOK-123456", 0, "100")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    "address:owner",
+                    CheckAccount::new()
+                        .balance("900")
+                        .esdt_balance("str:TOK-123456", "900"),
+                )
+                .put_account(
+                    "sc:adder",
+                    CheckAccount::new()
+                        .balance("100")
+                        .esdt_balance("str:TOK-123456", "100"),
+                ),
+        );
+}
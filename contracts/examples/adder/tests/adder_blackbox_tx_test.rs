@@ -0,0 +1,104 @@
+use adder::*;
+use multiversx_sc::{
+    storage::mappers::SingleValue,
+    types::{BigUint, ReturnsRawResult, ReturnsResult, ReturnsResultAs},
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    num_bigint,
+    scenario_model::*,
+    world_tx::{ReturnsNewAddress, ScenarioTxExpect, ScenarioTxRun},
+    *,
+};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+const OWNER: &str = "address:owner";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn adder_blackbox_tx() {
+    let mut world = world();
+    let mut adder_contract = ContractInfo::<adder::Proxy<StaticApi>>::new("sc:adder");
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, Account::new().nonce(1).balance(100u32))
+            .new_address(OWNER, 1, "sc:adder"),
+    );
+
+    let new_address = world
+        .tx()
+        .from(OWNER)
+        .deploy(adder_contract.init(5u32))
+        .code(ADDER_PATH_EXPR)
+        .returns(ReturnsNewAddress)
+        .run();
+    assert_eq!(new_address, adder_contract.to_address());
+
+    let sum: SingleValue<BigUint<StaticApi>> = world
+        .query()
+        .call(adder_contract.sum())
+        .returns(ReturnsResultAs::new())
+        .run();
+    assert_eq!(sum.into(), BigUint::from(5u32));
+
+    world.tx().from(OWNER).call(adder_contract.add(3u32)).run();
+
+    // same call, built by hand
+    world
+        .tx()
+        .from(OWNER)
+        .to(&adder_contract)
+        .raw_call("add")
+        .argument(&2u32)
+        .run();
+
+    let (sum, raw_sum) = world
+        .query()
+        .call(adder_contract.sum())
+        .returns((
+            ReturnsResultAs::<SingleValue<num_bigint::BigUint>>::new(),
+            ReturnsRawResult,
+        ))
+        .run();
+    assert_eq!(sum.into(), num_bigint::BigUint::from(10u32));
+    assert_eq!(raw_sum, vec![vec![10u8]]);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(&adder_contract)
+        .egld(BigUint::from(1u32))
+        .raw_call("add")
+        .argument(&1u32)
+        .expect(TxExpect::err(
+            10,
+            "str:function does not accept EGLD payment",
+        ))
+        .run();
+
+    let sum = world
+        .query()
+        .to(&adder_contract)
+        .raw_call("getSum")
+        .original_result::<BigUint<StaticApi>>()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(sum, BigUint::from(10u32));
+
+    world.check_state_step(
+        CheckStateStep::new()
+            .put_account(OWNER, CheckAccount::new())
+            .put_account(
+                "sc:adder",
+                CheckAccount::new().check_storage("str:sum", "10"),
+            ),
+    );
+}
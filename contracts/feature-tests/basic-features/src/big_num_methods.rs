@@ -42,6 +42,41 @@ pub trait BigIntMethods {
         a.pow(b)
     }
 
+    #[endpoint]
+    fn checked_sub_big_uint(&self, a: &BigUint, b: &BigUint) -> OptionalValue<BigUint> {
+        a.checked_sub(b).into()
+    }
+
+    #[endpoint]
+    fn checked_div_big_uint(&self, a: &BigUint, b: &BigUint) -> OptionalValue<BigUint> {
+        a.checked_div(b).into()
+    }
+
+    #[endpoint]
+    fn mul_div_big_uint(&self, a: &BigUint, b: &BigUint, c: &BigUint, round_up: bool) -> BigUint {
+        let rounding = if round_up {
+            RoundingMode::Up
+        } else {
+            RoundingMode::Down
+        };
+        a.mul_div(b, c, rounding)
+    }
+
+    #[endpoint]
+    fn mod_pow_big_uint(&self, a: &BigUint, exp: &BigUint, modulus: &BigUint) -> BigUint {
+        a.mod_pow(exp, modulus)
+    }
+
+    #[endpoint]
+    fn gcd_big_uint(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a.gcd(b)
+    }
+
+    #[endpoint]
+    fn clamp_big_uint(&self, a: BigUint, min: BigUint, max: BigUint) -> BigUint {
+        a.clamp(min, max)
+    }
+
     #[endpoint]
     fn big_uint_to_u64(&self, bu: &BigUint) -> OptionalValue<u64> {
         bu.to_u64().into()
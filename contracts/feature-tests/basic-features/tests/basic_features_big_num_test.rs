@@ -68,3 +68,61 @@ fn test_big_uint_bitwise_shift() {
     check_big_uint_shift(1023, 5);
     check_big_uint_shift(0, 10);
 }
+
+#[test]
+fn test_big_uint_checked_ops() {
+    let bf = basic_features::contract_obj::<StaticApi>();
+    let result = bf.checked_sub_big_uint(&BigUint::from(10u32), &BigUint::from(3u32));
+    assert_eq!(Some(BigUint::from(7u32)), result.into_option());
+    let result = bf.checked_sub_big_uint(&BigUint::from(3u32), &BigUint::from(10u32));
+    assert_eq!(None, result.into_option());
+    let result = bf.checked_div_big_uint(&BigUint::from(10u32), &BigUint::zero());
+    assert_eq!(None, result.into_option());
+}
+
+fn check_big_uint_mod_pow(base: u64, exp: u64, modulus: u64) {
+    let bf = basic_features::contract_obj::<StaticApi>();
+    let result = bf.mod_pow_big_uint(
+        &BigUint::from(base),
+        &BigUint::from(exp),
+        &BigUint::from(modulus),
+    );
+    let expected = (0..exp).fold(1 % modulus, |acc, _| acc * base % modulus);
+    assert_eq!(BigUint::from(expected), result);
+}
+
+#[test]
+fn test_big_uint_mod_pow() {
+    check_big_uint_mod_pow(4, 13, 497);
+    check_big_uint_mod_pow(2, 0, 7);
+    check_big_uint_mod_pow(0, 5, 7);
+    check_big_uint_mod_pow(123, 45, 1);
+    check_big_uint_mod_pow(1000, 17, 65537);
+}
+
+#[test]
+fn test_big_uint_mul_div_gcd_clamp() {
+    let bf = basic_features::contract_obj::<StaticApi>();
+    let ten = BigUint::from(10u32);
+    let three = BigUint::from(3u32);
+    assert_eq!(
+        BigUint::from(33u32),
+        bf.mul_div_big_uint(&ten, &ten, &three, false)
+    );
+    assert_eq!(
+        BigUint::from(34u32),
+        bf.mul_div_big_uint(&ten, &ten, &three, true)
+    );
+    assert_eq!(
+        BigUint::from(6u32),
+        bf.gcd_big_uint(&BigUint::from(48u32), &BigUint::from(18u32))
+    );
+    assert_eq!(
+        BigUint::from(10u32),
+        bf.clamp_big_uint(BigUint::from(5u32), ten.clone(), BigUint::from(20u32))
+    );
+    assert_eq!(
+        BigUint::from(20u32),
+        bf.clamp_big_uint(BigUint::from(25u32), ten, BigUint::from(20u32))
+    );
+}
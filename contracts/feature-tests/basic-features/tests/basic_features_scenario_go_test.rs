@@ -34,6 +34,11 @@ fn big_uint_sqrt_go() {
     world().run("scenarios/big_uint_sqrt.scen.json");
 }
 
+#[test]
+fn big_uint_math_go() {
+    world().run("scenarios/big_uint_math.scen.json");
+}
+
 #[test]
 fn big_uint_pow_go() {
     world().run("scenarios/big_uint_pow.scen.json");
@@ -51,6 +51,11 @@ fn big_uint_sqrt_rs() {
     world().run("scenarios/big_uint_sqrt.scen.json");
 }
 
+#[test]
+fn big_uint_math_rs() {
+    world().run("scenarios/big_uint_math.scen.json");
+}
+
 #[test]
 fn big_uint_pow_rs() {
     world().run("scenarios/big_uint_pow.scen.json");
@@ -5,12 +5,11 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          368
+// Endpoints:                          374
 // Async Callback:                       1
-// Total number of exported functions: 370
+// Total number of exported functions: 376
 
 #![no_std]
-
 // Configuration that works with rustc < 1.73.0.
 // TODO: Recommended rustc version: 1.73.0 or newer.
 #![feature(lang_items)]
@@ -33,6 +32,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         pow_big_int_ref => pow_big_int_ref
         pow_big_uint => pow_big_uint
         pow_big_uint_ref => pow_big_uint_ref
+        checked_sub_big_uint => checked_sub_big_uint
+        checked_div_big_uint => checked_div_big_uint
+        mul_div_big_uint => mul_div_big_uint
+        mod_pow_big_uint => mod_pow_big_uint
+        gcd_big_uint => gcd_big_uint
+        clamp_big_uint => clamp_big_uint
         big_uint_to_u64 => big_uint_to_u64
         biguint_overwrite_u64 => biguint_overwrite_u64
         big_uint_zero => big_uint_zero
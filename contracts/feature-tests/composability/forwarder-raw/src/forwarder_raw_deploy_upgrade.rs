@@ -65,7 +65,7 @@ pub trait ForwarderRawDeployUpgrade {
             self.blockchain().get_gas_left(),
             &BigUint::zero(),
             &source_contract_address,
-            CodeMetadata::DEFAULT,
+            CodeMetadata::UPGRADEABLE,
             &arguments.to_arg_buffer(),
         )
     }
@@ -18,6 +18,17 @@ pub trait ForwarderTransferExecuteModule {
             .transfer_execute();
     }
 
+    /// Same as `forward_transf_exec_accept_funds`, but built with the unified transaction syntax.
+    #[endpoint]
+    #[payable("*")]
+    fn forward_transf_exec_accept_funds_tx(&self, to: ManagedAddress) {
+        let payment = self.call_value().egld_or_single_esdt();
+        self.tx()
+            .egld_or_single_esdt(payment)
+            .call(self.vault_proxy().contract(to).accept_funds())
+            .transfer_execute();
+    }
+
     #[endpoint]
     #[payable("*")]
     fn forward_transf_execu_accept_funds_with_fees(
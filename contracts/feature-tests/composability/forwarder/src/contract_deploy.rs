@@ -14,6 +14,21 @@ pub trait DeployContractModule {
         self.perform_deploy_vault(&code, opt_arg).into()
     }
 
+    /// Same as `deploy_contract`, but built with the unified transaction syntax.
+    #[endpoint]
+    fn deploy_contract_tx(
+        &self,
+        code: ManagedBuffer,
+        opt_arg: OptionalValue<ManagedBuffer>,
+    ) -> MultiValue2<ManagedAddress, OptionalValue<ManagedBuffer>> {
+        self.tx()
+            .deploy(self.vault_proxy().init(opt_arg))
+            .code(code)
+            .returns((ReturnsNewManagedAddress, ReturnsResult))
+            .sync_call()
+            .into()
+    }
+
     #[endpoint]
     fn deploy_two_contracts(
         &self,
@@ -154,6 +154,52 @@ pub trait ForwarderNftModule: storage::ForwarderStorageModule {
             .nft_update_attributes(&token_identifier, nonce, &new_attributes);
     }
 
+    #[endpoint]
+    fn nft_modify_royalties(&self, token_identifier: TokenIdentifier, nonce: u64, royalties: u64) {
+        self.send()
+            .esdt_modify_royalties(&token_identifier, nonce, royalties);
+    }
+
+    #[endpoint]
+    fn nft_set_new_uris(
+        &self,
+        token_identifier: TokenIdentifier,
+        nonce: u64,
+        uris: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.send()
+            .esdt_nft_set_new_uris(&token_identifier, nonce, &uris.to_vec());
+    }
+
+    #[endpoint]
+    fn nft_modify_creator(&self, token_identifier: TokenIdentifier, nonce: u64) {
+        self.send()
+            .esdt_nft_modify_creator(&token_identifier, nonce);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[endpoint]
+    fn nft_recreate(
+        &self,
+        token_identifier: TokenIdentifier,
+        nonce: u64,
+        name: ManagedBuffer,
+        royalties: u64,
+        hash: ManagedBuffer,
+        new_attributes: Color,
+        uris: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.send().esdt_metadata_recreate(
+            &token_identifier,
+            nonce,
+            &name,
+            royalties,
+            &hash,
+            &new_attributes,
+            &uris.to_vec(),
+        );
+    }
+
     #[endpoint]
     fn nft_decode_complex_attributes(
         &self,
@@ -5,12 +5,11 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           66
+// Endpoints:                           72
 // Async Callback:                       1
-// Total number of exported functions:  68
+// Total number of exported functions:  74
 
 #![no_std]
-
 // Configuration that works with rustc < 1.73.0.
 // TODO: Recommended rustc version: 1.73.0 or newer.
 #![feature(lang_items)]
@@ -43,6 +42,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         callback_data_at_index => callback_data_at_index
         clear_callback_data => clear_callback_data
         forward_transf_exec_accept_funds => forward_transf_exec_accept_funds
+        forward_transf_exec_accept_funds_tx => forward_transf_exec_accept_funds_tx
         forward_transf_execu_accept_funds_with_fees => forward_transf_execu_accept_funds_with_fees
         forward_transf_exec_accept_funds_twice => forward_transf_exec_accept_funds_twice
         forward_transf_exec_accept_funds_return_values => forward_transf_exec_accept_funds_return_values
@@ -51,6 +51,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         transf_exec_multi_reject_funds => transf_exec_multi_reject_funds
         changeOwnerAddress => change_owner
         deploy_contract => deploy_contract
+        deploy_contract_tx => deploy_contract_tx
         deploy_two_contracts => deploy_two_contracts
         deploy_vault_from_source => deploy_vault_from_source
         upgradeVault => upgrade_vault
@@ -78,6 +79,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         nft_create_compact => nft_create_compact
         nft_add_uris => nft_add_uris
         nft_update_attributes => nft_update_attributes
+        nft_modify_royalties => nft_modify_royalties
+        nft_set_new_uris => nft_set_new_uris
+        nft_modify_creator => nft_modify_creator
+        nft_recreate => nft_recreate
         nft_decode_complex_attributes => nft_decode_complex_attributes
         nft_add_quantity => nft_add_quantity
         nft_burn => nft_burn
@@ -29,6 +29,12 @@ fn forw_raw_async_accept_esdt_go() {
     world().run("scenarios/forw_raw_async_accept_esdt.scen.json");
 }
 
+#[test]
+#[ignore = "delegation system SC mocks are only available in the Rust VM"]
+fn forw_raw_async_delegation_go() {
+    world().run("scenarios/forw_raw_async_delegation.scen.json");
+}
+
 #[test]
 fn forw_raw_async_echo_go() {
     world().run("scenarios/forw_raw_async_echo.scen.json");
@@ -269,6 +275,11 @@ fn forwarder_call_transf_exec_accept_egld_go() {
     world().run("scenarios/forwarder_call_transf_exec_accept_egld.scen.json");
 }
 
+#[test]
+fn forwarder_call_transf_exec_accept_egld_tx_go() {
+    world().run("scenarios/forwarder_call_transf_exec_accept_egld_tx.scen.json");
+}
+
 #[test]
 fn forwarder_call_transf_exec_accept_egld_twice_go() {
     world().run("scenarios/forwarder_call_transf_exec_accept_egld_twice.scen.json");
@@ -324,6 +335,11 @@ fn forwarder_contract_deploy_go() {
     world().run("scenarios/forwarder_contract_deploy.scen.json");
 }
 
+#[test]
+fn forwarder_contract_deploy_tx_go() {
+    world().run("scenarios/forwarder_contract_deploy_tx.scen.json");
+}
+
 #[test]
 fn forwarder_contract_upgrade_go() {
     world().run("scenarios/forwarder_contract_upgrade.scen.json");
@@ -364,6 +380,12 @@ fn forwarder_nft_decode_complex_attributes_go() {
     world().run("scenarios/forwarder_nft_decode_complex_attributes.scen.json");
 }
 
+#[test]
+#[ignore = "dynamic NFT metadata builtin functions are only mocked in the Rust VM"]
+fn forwarder_nft_modify_metadata_go() {
+    world().run("scenarios/forwarder_nft_modify_metadata.scen.json");
+}
+
 #[test]
 fn forwarder_nft_transfer_async_go() {
     world().run("scenarios/forwarder_nft_transfer_async.scen.json");
@@ -77,6 +77,11 @@ fn forw_raw_async_accept_esdt_rs() {
     world().run("scenarios/forw_raw_async_accept_esdt.scen.json");
 }
 
+#[test]
+fn forw_raw_async_delegation_rs() {
+    world().run("scenarios/forw_raw_async_delegation.scen.json");
+}
+
 #[test]
 fn forw_raw_async_echo_rs() {
     world().run("scenarios/forw_raw_async_echo.scen.json");
@@ -166,7 +171,6 @@ fn forw_raw_sync_egld_rs() {
 }
 
 #[test]
-#[ignore]
 fn forw_raw_sync_readonly_rs() {
     world().run("scenarios/forw_raw_sync_readonly.scen.json");
 }
@@ -323,6 +327,11 @@ fn forwarder_call_transf_exec_accept_egld_rs() {
     world().run("scenarios/forwarder_call_transf_exec_accept_egld.scen.json");
 }
 
+#[test]
+fn forwarder_call_transf_exec_accept_egld_tx_rs() {
+    world().run("scenarios/forwarder_call_transf_exec_accept_egld_tx.scen.json");
+}
+
 #[test]
 fn forwarder_call_transf_exec_accept_egld_twice_rs() {
     world().run("scenarios/forwarder_call_transf_exec_accept_egld_twice.scen.json");
@@ -378,6 +387,11 @@ fn forwarder_contract_deploy_rs() {
     world().run("scenarios/forwarder_contract_deploy.scen.json");
 }
 
+#[test]
+fn forwarder_contract_deploy_tx_rs() {
+    world().run("scenarios/forwarder_contract_deploy_tx.scen.json");
+}
+
 #[test]
 fn forwarder_contract_upgrade_rs() {
     world().run("scenarios/forwarder_contract_upgrade.scen.json");
@@ -418,6 +432,11 @@ fn forwarder_nft_decode_complex_attributes_rs() {
     world().run("scenarios/forwarder_nft_decode_complex_attributes.scen.json");
 }
 
+#[test]
+fn forwarder_nft_modify_metadata_rs() {
+    world().run("scenarios/forwarder_nft_modify_metadata.scen.json");
+}
+
 #[test]
 fn forwarder_nft_transfer_async_rs() {
     world().run("scenarios/forwarder_nft_transfer_async.scen.json");
@@ -0,0 +1,98 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const USER_ADDRESS_EXPR: &str = "address:user";
+const FORWARDER_RAW_ADDRESS_EXPR: &str = "sc:forwarder-raw";
+const FORWARDER_RAW_PATH_EXPR: &str = "file:forwarder-raw/output/forwarder-raw.wasm";
+const VAULT_ADDRESS_EXPR: &str = "sc:vault";
+const VAULT_PATH_EXPR: &str = "file:vault/output/vault.wasm";
+
+const TOKEN_ID_EXPR: &str = "str:TOKEN-123456";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_RAW_PATH_EXPR, forwarder_raw::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+
+    let forwarder_raw_code = blockchain.code_expression(FORWARDER_RAW_PATH_EXPR);
+    let vault_code = blockchain.code_expression(VAULT_PATH_EXPR);
+
+    blockchain.set_state_step(
+        SetStateStep::new()
+            .put_account(USER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(
+                FORWARDER_RAW_ADDRESS_EXPR,
+                Account::new().nonce(1).code(forwarder_raw_code),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                Account::new()
+                    .nonce(1)
+                    .code(vault_code)
+                    .esdt_balance(TOKEN_ID_EXPR, "1000"),
+            ),
+    );
+
+    blockchain
+}
+
+fn call_readonly(endpoint_name: &str) -> ScCallStep {
+    ScCallStep::new()
+        .from(USER_ADDRESS_EXPR)
+        .to(FORWARDER_RAW_ADDRESS_EXPR)
+        .function("call_execute_on_dest_context_readonly")
+        .argument(VAULT_ADDRESS_EXPR)
+        .argument(format!("str:{endpoint_name}").as_str())
+}
+
+#[test]
+fn forwarder_raw_readonly_without_storage() {
+    let mut world = world();
+
+    world.sc_call(
+        call_readonly("echo_arguments_without_storage")
+            .argument("1")
+            .argument("2")
+            .expect(TxExpect::ok().result("1").result("2")),
+    );
+}
+
+#[test]
+fn forwarder_raw_readonly_storage_write() {
+    let mut world = world();
+
+    // the vault counts the calls in storage
+    world
+        .sc_call(
+            call_readonly("echo_arguments")
+                .argument("1")
+                .argument("2")
+                .expect(TxExpect::err(10, "str:cannot write on read only mode")),
+        )
+        .check_state_step(CheckStateStep::new().put_account(
+            VAULT_ADDRESS_EXPR,
+            CheckAccount::new().check_storage("str:call_counts|nested:str:echo_arguments", ""),
+        ));
+}
+
+#[test]
+fn forwarder_raw_readonly_transfer() {
+    let mut world = world();
+
+    world
+        .sc_call(
+            call_readonly("retrieve_funds")
+                .argument(TOKEN_ID_EXPR)
+                .argument("0")
+                .argument("100")
+                .expect(TxExpect::err(
+                    10,
+                    "str:operation not permitted in read only mode",
+                )),
+        )
+        .check_state_step(CheckStateStep::new().put_account(
+            VAULT_ADDRESS_EXPR,
+            CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "1000"),
+        ));
+}
@@ -20,19 +20,23 @@ pub trait ManagedMapFeatures {
         let mut map = ManagedMap::new();
         let num_entries = self.get_num_entries();
         for index in 0..num_entries {
-            map.put(&self.get_key(index), &self.get_value(index));
+            // empty values are treated as missing, same as in storage
+            let value = self.get_value(index);
+            if !value.is_empty() {
+                map.insert(self.get_key(index), value);
+            }
         }
         map
     }
 
     #[view]
     fn mm_get(&self, key: &ManagedBuffer) -> ManagedBuffer {
-        self.create_map().get(key)
+        self.create_map().get(key).unwrap_or_default()
     }
 
     #[view]
     fn mm_contains(&self, key: &ManagedBuffer) -> bool {
-        self.create_map().contains(key)
+        self.create_map().contains_key(key)
     }
 
     #[view]
@@ -42,8 +46,37 @@ pub trait ManagedMapFeatures {
         get_key: &ManagedBuffer,
     ) -> MultiValue2<ManagedBuffer, ManagedBuffer> {
         let mut map = self.create_map();
-        let removed_value = map.remove(remove_key);
-        let get_value = map.get(get_key);
+        let removed_value = map.remove(remove_key).unwrap_or_default();
+        let get_value = map.get(get_key).unwrap_or_default();
         (removed_value, get_value).into()
     }
+
+    /// Counts the occurrences of each item, in order of first appearance.
+    #[view]
+    fn mm_count_occurrences(
+        &self,
+        items: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, u32>> {
+        let mut counts: ManagedMap<ManagedBuffer, u32> = ManagedMap::new();
+        for item in items {
+            counts.entry(item).or_default().update(|count| *count += 1);
+        }
+        counts.iter().map(MultiValue2::from).collect()
+    }
+
+    /// Maps each key to its double, then removes one of them.
+    #[view]
+    fn mm_remove_len(
+        &self,
+        remove_key: u64,
+        keys: MultiValueEncoded<u64>,
+    ) -> MultiValue3<usize, Option<u64>, ManagedVec<u64>> {
+        let mut map: ManagedMap<u64, u64> = ManagedMap::new();
+        for key in keys {
+            map.insert(key, key * 2);
+        }
+        let removed = map.remove(&remove_key);
+        let remaining_keys = map.keys().collect();
+        (map.len(), removed, remaining_keys).into()
+    }
 }
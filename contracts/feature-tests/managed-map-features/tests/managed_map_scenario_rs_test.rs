@@ -10,6 +10,11 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+#[test]
+fn mmap_typed_rs() {
+    world().run("scenarios/mmap_typed.scen.json");
+}
+
 #[test]
 fn mmap_get_rs() {
     world().run("scenarios/mmap_get.scen.json");
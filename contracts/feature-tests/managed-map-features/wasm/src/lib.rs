@@ -5,12 +5,11 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            3
+// Endpoints:                            5
 // Async Callback (empty):               1
-// Total number of exported functions:   5
+// Total number of exported functions:   7
 
 #![no_std]
-
 // Configuration that works with rustc < 1.73.0.
 // TODO: Recommended rustc version: 1.73.0 or newer.
 #![feature(lang_items)]
@@ -25,6 +24,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         mm_get => mm_get
         mm_contains => mm_contains
         mm_remove_get => mm_remove_get
+        mm_count_occurrences => mm_count_occurrences
+        mm_remove_len => mm_remove_len
     )
 }
 
@@ -4,10 +4,12 @@ mod user_builtin {
     #[multiversx_sc::proxy]
     pub trait UserBuiltin {
         #[endpoint(SetUserName)]
-        fn set_user_name(&self, name: &BoxedBytes) -> BigUint;
+        fn set_user_name(&self, name: &ManagedBuffer) -> BigUint;
     }
 }
 
+/// Follows the flow of the DNS contracts: charges the registration cost,
+/// then asks the protocol to set the username of the caller.
 mod dns_mock {
     multiversx_sc::imports!();
 
@@ -18,14 +20,22 @@ mod dns_mock {
 
         #[payable("EGLD")]
         #[endpoint]
-        fn register(&self, name: BoxedBytes) {
-            let _payment = self.call_value().egld_value();
+        fn register(&self, name: ManagedBuffer) {
+            let payment = self.call_value().egld_value();
+            require!(
+                *payment == self.registration_cost().get(),
+                "should pay exactly the registration cost"
+            );
+
             let address = self.blockchain().get_caller();
             self.user_builtin_proxy(address)
                 .set_user_name(&name)
                 .async_call()
                 .call_and_exit()
         }
+
+        #[storage_mapper("registration_cost")]
+        fn registration_cost(&self) -> SingleValueMapper<BigUint>;
     }
 }
 
@@ -3,6 +3,7 @@ mod contract_abi;
 mod endpoint_abi;
 mod esdt_attribute_abi;
 mod event_abi;
+mod storage_key_abi;
 mod type_abi;
 mod type_abi_impl_basic;
 mod type_abi_impl_codec_multi;
@@ -14,6 +15,7 @@ pub use contract_abi::*;
 pub use endpoint_abi::*;
 pub use esdt_attribute_abi::EsdtAttributeAbi;
 pub use event_abi::*;
+pub use storage_key_abi::{StorageKeyAbi, StorageKeyCollision, StorageSubKeyAbi};
 pub use type_abi::*;
 pub use type_description::*;
 pub use type_description_container::*;
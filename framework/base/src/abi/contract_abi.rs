@@ -14,6 +14,7 @@ pub struct ContractAbi {
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
     pub esdt_attributes: Vec<EsdtAttributeAbi>,
+    pub storage_keys: Vec<StorageKeyAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -30,6 +31,7 @@ impl ContractAbi {
             promise_callbacks: Vec::new(),
             events: Vec::new(),
             esdt_attributes: Vec::new(),
+            storage_keys: Vec::new(),
             has_callback,
             type_descriptions: TypeDescriptionContainerImpl::new(),
         }
@@ -46,6 +48,8 @@ impl ContractAbi {
         self.type_descriptions.insert_all(&other.type_descriptions);
         self.esdt_attributes
             .extend_from_slice(other.esdt_attributes.as_slice());
+        self.storage_keys
+            .extend_from_slice(other.storage_keys.as_slice());
     }
 
     /// A type can provide more than 1 type descripions.
@@ -71,6 +75,24 @@ impl ContractAbi {
         }
     }
 
+    /// All pairs of storage keys declared in different modules, that can address the same storage entry.
+    pub fn storage_key_collisions(&self) -> Vec<StorageKeyCollision<'_>> {
+        let mut result = Vec::new();
+        for (i, storage_key) in self.storage_keys.iter().enumerate() {
+            for other in &self.storage_keys[i + 1..] {
+                if let Some((first_key, second_key)) = storage_key.collision_with(other) {
+                    result.push(StorageKeyCollision {
+                        first: storage_key,
+                        first_key,
+                        second: other,
+                        second_key,
+                    });
+                }
+            }
+        }
+        result
+    }
+
     /// All exported functions: init, endpoints, promises callbacks.
     pub fn iter_all_exports(&self) -> impl Iterator<Item = &EndpointAbi> {
         self.constructors
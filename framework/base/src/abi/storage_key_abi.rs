@@ -0,0 +1,104 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Storage key declared by a contract or module, via any of the storage annotations.
+///
+/// Not part of the ABI JSON, only used for checking the contract at build time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyAbi {
+    /// Name of the contract or module trait that declares it.
+    pub module_name: String,
+    pub rust_method_name: String,
+    pub key: String,
+    /// The storage entries behind the key, relative to it.
+    pub sub_keys: Vec<StorageSubKeyAbi>,
+}
+
+/// One of the storage entries behind a storage key.
+///
+/// Storage mappers usually manage several entries, e.g. a `VecMapper` stores
+/// its length under `<key>.len` and its items under `<key>.item<index>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StorageSubKeyAbi {
+    /// Appended to the storage key.
+    pub suffix: &'static str,
+
+    /// More bytes follow the suffix, e.g. an index, an encoded item or the storage key arguments.
+    pub is_prefix: bool,
+}
+
+impl StorageSubKeyAbi {
+    pub const fn exact(suffix: &'static str) -> Self {
+        StorageSubKeyAbi {
+            suffix,
+            is_prefix: false,
+        }
+    }
+
+    pub const fn prefix(suffix: &'static str) -> Self {
+        StorageSubKeyAbi {
+            suffix,
+            is_prefix: true,
+        }
+    }
+}
+
+/// Two storage keys from different modules that can address the same storage entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyCollision<'a> {
+    pub first: &'a StorageKeyAbi,
+    /// The expanded key of `first` involved in the collision.
+    pub first_key: String,
+    pub second: &'a StorageKeyAbi,
+    /// The expanded key of `second` involved in the collision.
+    pub second_key: String,
+}
+
+impl StorageKeyAbi {
+    /// Used in code generation.
+    pub fn new(
+        module_name: &str,
+        rust_method_name: &str,
+        key: &str,
+        sub_keys: &[StorageSubKeyAbi],
+    ) -> Self {
+        StorageKeyAbi {
+            module_name: module_name.to_string(),
+            rust_method_name: rust_method_name.to_string(),
+            key: key.to_string(),
+            sub_keys: sub_keys.to_vec(),
+        }
+    }
+
+    /// The key of each storage entry, with the flag that tells whether more bytes follow.
+    pub fn expanded_keys(&self) -> impl Iterator<Item = (String, bool)> + '_ {
+        self.sub_keys
+            .iter()
+            .map(|sub_key| (format!("{}{}", self.key, sub_key.suffix), sub_key.is_prefix))
+    }
+
+    /// Two keys collide if they are declared in different modules and any of their expanded keys
+    /// are equal, or one of them is a prefix and the other one starts with it.
+    ///
+    /// Returns the first pair of expanded keys that collide.
+    pub fn collision_with(&self, other: &StorageKeyAbi) -> Option<(String, String)> {
+        if self.module_name == other.module_name {
+            return None;
+        }
+
+        for (key, is_prefix) in self.expanded_keys() {
+            for (other_key, other_is_prefix) in other.expanded_keys() {
+                if key == other_key
+                    || (is_prefix && other_key.starts_with(key.as_str()))
+                    || (other_is_prefix && key.starts_with(other_key.as_str()))
+                {
+                    return Some((key, other_key));
+                }
+            }
+        }
+        None
+    }
+}
@@ -14,3 +14,21 @@ pub const SET_USERNAME_FUNC_NAME: &str = "SetUserName";
 pub const MIGRATE_USERNAME_FUNC_NAME: &str = "migrateUserName";
 pub const DELETE_USERNAME_FUNC_NAME: &str = "DeleteUserName";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &str = "upgradeContract";
+pub const ESDT_SET_ROLE_FUNC_NAME: &str = "ESDTSetRole";
+pub const ESDT_UNSET_ROLE_FUNC_NAME: &str = "ESDTUnSetRole";
+pub const ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME: &str = "ESDTNFTCreateRoleTransfer";
+pub const ESDT_FREEZE_FUNC_NAME: &str = "ESDTFreeze";
+pub const ESDT_UNFREEZE_FUNC_NAME: &str = "ESDTUnFreeze";
+pub const ESDT_WIPE_FUNC_NAME: &str = "ESDTWipe";
+pub const ESDT_SET_LIMITED_TRANSFER_FUNC_NAME: &str = "ESDTSetLimitedTransfer";
+pub const ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME: &str = "ESDTUnSetLimitedTransfer";
+pub const ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME: &str = "ESDTSetBurnRoleForAll";
+pub const ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME: &str = "ESDTUnSetBurnRoleForAll";
+pub const ESDT_MODIFY_ROYALTIES_FUNC_NAME: &str = "ESDTModifyRoyalties";
+pub const ESDT_MODIFY_CREATOR_FUNC_NAME: &str = "ESDTModifyCreator";
+pub const ESDT_SET_NEW_URIS_FUNC_NAME: &str = "ESDTSetNewURIs";
+pub const ESDT_METADATA_RECREATE_FUNC_NAME: &str = "ESDTMetaDataRecreate";
+pub const SAVE_KEY_VALUE_FUNC_NAME: &str = "SaveKeyValue";
+pub const SET_GUARDIAN_FUNC_NAME: &str = "SetGuardian";
+pub const GUARD_ACCOUNT_FUNC_NAME: &str = "GuardAccount";
+pub const UN_GUARD_ACCOUNT_FUNC_NAME: &str = "UnGuardAccount";
@@ -54,6 +54,63 @@ pub trait BigIntApiImpl: HandleTypeInfo + ErrorApi {
     fn bi_pow(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle);
     fn bi_log2(&self, x: Self::BigIntHandle) -> u32;
 
+    /// Computes `base ^ exp mod modulus` by repeated squaring, the full power is never computed.
+    fn bi_mod_pow(
+        &self,
+        dest: Self::BigIntHandle,
+        base: Self::BigIntHandle,
+        exp: Self::BigIntHandle,
+        modulus: Self::BigIntHandle,
+    ) {
+        if self.bi_sign(modulus.clone()) != Sign::Plus {
+            Self::error_api_impl().signal_error(err_msg::BIG_INT_MODULUS_NOT_POSITIVE);
+        }
+        if self.bi_sign(exp.clone()) == Sign::Minus {
+            Self::error_api_impl().signal_error(err_msg::BIG_INT_NEGATIVE_EXPONENT);
+        }
+
+        let one = self.bi_new(1);
+        let result = self.bi_new_zero();
+        self.bi_t_mod(result.clone(), one.clone(), modulus.clone());
+        let power = self.bi_new_zero();
+        self.bi_t_mod(power.clone(), base, modulus.clone());
+        let remaining_exp = self.bi_new_zero();
+        self.bi_abs(remaining_exp.clone(), exp);
+        let lowest_bit = self.bi_new_zero();
+
+        while self.bi_sign(remaining_exp.clone()) == Sign::Plus {
+            self.bi_and(lowest_bit.clone(), remaining_exp.clone(), one.clone());
+            if self.bi_sign(lowest_bit.clone()) == Sign::Plus {
+                self.bi_mul(result.clone(), result.clone(), power.clone());
+                self.bi_t_mod(result.clone(), result.clone(), modulus.clone());
+            }
+            self.bi_mul(power.clone(), power.clone(), power.clone());
+            self.bi_t_mod(power.clone(), power.clone(), modulus.clone());
+            self.bi_shr(remaining_exp.clone(), remaining_exp.clone(), 1);
+        }
+
+        self.bi_abs(dest, result);
+    }
+
+    /// Greatest common divisor of the absolute values, by the Euclidean algorithm.
+    ///
+    /// The result is never negative, and `gcd(0, 0)` is 0.
+    fn bi_gcd(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle) {
+        let mut a = self.bi_new_zero();
+        self.bi_abs(a.clone(), x);
+        let mut b = self.bi_new_zero();
+        self.bi_abs(b.clone(), y);
+        let mut remainder = self.bi_new_zero();
+
+        while self.bi_sign(b.clone()) != Sign::NoSign {
+            self.bi_t_mod(remainder.clone(), a.clone(), b.clone());
+            core::mem::swap(&mut a, &mut b);
+            core::mem::swap(&mut b, &mut remainder);
+        }
+
+        self.bi_abs(dest, a);
+    }
+
     fn bi_and(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle);
     fn bi_or(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle);
     fn bi_xor(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle);
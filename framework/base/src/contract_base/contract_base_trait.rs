@@ -2,7 +2,10 @@ use super::{
     BlockchainWrapper, CallValueWrapper, CryptoWrapper, ErrorHelper, ManagedSerializer,
     SendRawWrapper, SendWrapper, StorageRawWrapper,
 };
-use crate::api::VMApi;
+use crate::{
+    api::VMApi,
+    types::{Tx, TxBaseWithEnv, TxScEnv},
+};
 
 /// Interface to be used by the actual smart contract code.
 ///
@@ -26,6 +29,14 @@ pub trait ContractBase: Sized {
         SendWrapper::new()
     }
 
+    /// Starts building a transaction sent from the current contract.
+    ///
+    /// The same transaction builder is also used in tests and interactors.
+    #[inline]
+    fn tx(&self) -> TxBaseWithEnv<TxScEnv<Self::Api>> {
+        Tx::new_with_env(TxScEnv::default())
+    }
+
     /// Low-level functionality related to sending transactions from the current contract.
     ///
     /// For almost all cases contracts should instead use `self.send()` and `ContractCall`.
@@ -6,9 +6,11 @@ use crate::{
     api::{
         BlockchainApi, BlockchainApiImpl, CallTypeApi, StorageReadApi,
         CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
-        ESDT_LOCAL_BURN_FUNC_NAME, ESDT_LOCAL_MINT_FUNC_NAME, ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
-        ESDT_NFT_ADD_URI_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME, ESDT_NFT_CREATE_FUNC_NAME,
-        ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+        ESDT_LOCAL_BURN_FUNC_NAME, ESDT_LOCAL_MINT_FUNC_NAME, ESDT_METADATA_RECREATE_FUNC_NAME,
+        ESDT_MODIFY_CREATOR_FUNC_NAME, ESDT_MODIFY_ROYALTIES_FUNC_NAME,
+        ESDT_NFT_ADD_QUANTITY_FUNC_NAME, ESDT_NFT_ADD_URI_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME,
+        ESDT_NFT_CREATE_FUNC_NAME, ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+        ESDT_SET_NEW_URIS_FUNC_NAME,
     },
     codec,
     esdt::ESDTSystemSmartContractProxy,
@@ -800,4 +802,107 @@ where
             &arg_buffer,
         );
     }
+
+    /// Changes the royalties of an NFT, via a synchronous builtin function call.
+    ///
+    /// Must have the ESDTRoleModifyRoyalties role set.
+    pub fn esdt_modify_royalties(
+        &self,
+        token_id: &TokenIdentifier<A>,
+        nft_nonce: u64,
+        new_royalties: u64,
+    ) {
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(token_id);
+        arg_buffer.push_arg(nft_nonce);
+        arg_buffer.push_arg(new_royalties);
+
+        let _ = self.call_local_esdt_built_in_function(
+            A::blockchain_api_impl().get_gas_left(),
+            &ManagedBuffer::from(ESDT_MODIFY_ROYALTIES_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
+    /// Replaces all URIs of an NFT, via a synchronous builtin function call.
+    ///
+    /// Must have the ESDTRoleSetNewURI role set.
+    pub fn esdt_nft_set_new_uris(
+        &self,
+        token_id: &TokenIdentifier<A>,
+        nft_nonce: u64,
+        new_uris: &ManagedVec<A, ManagedBuffer<A>>,
+    ) {
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(token_id);
+        arg_buffer.push_arg(nft_nonce);
+
+        if new_uris.is_empty() {
+            // at least one URI is required, so we push an empty one
+            arg_buffer.push_arg(codec::Empty);
+        } else {
+            for uri in new_uris {
+                arg_buffer.push_arg(uri);
+            }
+        }
+
+        let _ = self.call_local_esdt_built_in_function(
+            A::blockchain_api_impl().get_gas_left(),
+            &ManagedBuffer::from(ESDT_SET_NEW_URIS_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
+    /// Makes the current contract the creator of an NFT, via a synchronous builtin function call.
+    ///
+    /// Must have the ESDTRoleModifyCreator role set.
+    pub fn esdt_nft_modify_creator(&self, token_id: &TokenIdentifier<A>, nft_nonce: u64) {
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(token_id);
+        arg_buffer.push_arg(nft_nonce);
+
+        let _ = self.call_local_esdt_built_in_function(
+            A::blockchain_api_impl().get_gas_left(),
+            &ManagedBuffer::from(ESDT_MODIFY_CREATOR_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
+    /// Overwrites all the metadata of an NFT, via a synchronous builtin function call.
+    ///
+    /// Must have the ESDTRoleNFTRecreate role set.
+    #[allow(clippy::too_many_arguments)]
+    pub fn esdt_metadata_recreate<T: codec::TopEncode>(
+        &self,
+        token_id: &TokenIdentifier<A>,
+        nft_nonce: u64,
+        name: &ManagedBuffer<A>,
+        royalties: u64,
+        hash: &ManagedBuffer<A>,
+        new_attributes: &T,
+        uris: &ManagedVec<A, ManagedBuffer<A>>,
+    ) {
+        let mut arg_buffer = ManagedArgBuffer::new();
+        arg_buffer.push_arg(token_id);
+        arg_buffer.push_arg(nft_nonce);
+        arg_buffer.push_arg(name);
+        arg_buffer.push_arg(royalties);
+        arg_buffer.push_arg(hash);
+        arg_buffer.push_arg(new_attributes);
+
+        if uris.is_empty() {
+            // at least one URI is required, so we push an empty one
+            arg_buffer.push_arg(codec::Empty);
+        } else {
+            for uri in uris {
+                arg_buffer.push_arg(uri);
+            }
+        }
+
+        let _ = self.call_local_esdt_built_in_function(
+            A::blockchain_api_impl().get_gas_left(),
+            &ManagedBuffer::from(ESDT_METADATA_RECREATE_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
 }
@@ -39,6 +39,14 @@ pub const VALUE_EXCEEDS_SLICE: &[u8] = b"value exceeds target slice";
 pub const CAST_TO_I64_ERROR: &[u8] = b"cast to i64 error";
 pub const BIG_UINT_EXCEEDS_SLICE: &[u8] = b"big uint as_bytes exceed target slice";
 pub const BIG_UINT_SUB_NEGATIVE: &[u8] = b"cannot subtract because result would be negative";
+pub const BIG_INT_DIVISION_BY_ZERO: &[u8] = b"division by zero";
+pub const BIG_INT_NEGATIVE_EXPONENT: &[u8] = b"exponent cannot be negative";
+pub const BIG_INT_MODULUS_NOT_POSITIVE: &[u8] = b"modulus must be positive";
+pub const BIG_INT_CLAMP_INVALID_BOUNDS: &[u8] = b"clamp lower bound exceeds upper bound";
+pub const MANAGED_MAP_KEY_NOT_FOUND: &[u8] = b"managed map entry not found for key";
+pub const MANAGED_DECIMAL_NEGATIVE: &[u8] = b"managed decimal cannot be negative";
+pub const MANAGED_DECIMAL_SCALE_TOO_LARGE: &[u8] =
+    b"managed decimal scale too large for the result";
 
 pub const DESERIALIZATION_INVALID_BYTE: &str = "call data deserialization error: not a valid byte";
 pub const DESERIALIZATION_NOT_32_BYTES: &str =
@@ -2,6 +2,7 @@ use core::marker::PhantomData;
 
 use super::StorageMapper;
 use crate::{
+    abi::StorageSubKeyAbi,
     api::{ErrorApiImpl, StorageMapperApi},
     storage::{
         storage_clear, storage_get, storage_get_from_address, storage_get_len, storage_set,
@@ -32,6 +33,12 @@ impl<SA> StorageMapper<SA> for AddressToIdMapper<SA>
 where
     SA: StorageMapperApi,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::prefix("addrId"),
+        StorageSubKeyAbi::prefix("addr"),
+        StorageSubKeyAbi::exact("lastId"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         AddressToIdMapper {
             _phantom_api: PhantomData,
@@ -7,7 +7,7 @@ use crate::codec::{
 
 use super::{unordered_set_mapper, StorageMapper, UnorderedSetMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     storage::{storage_get, storage_set, StorageKey},
     storage_clear,
@@ -41,6 +41,17 @@ where
     K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static + Default + PartialEq,
     V: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static + Default + PartialEq,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact("_id.len"),
+        StorageSubKeyAbi::prefix("_id.item"),
+        StorageSubKeyAbi::prefix("_id.index"),
+        StorageSubKeyAbi::exact("_value.len"),
+        StorageSubKeyAbi::prefix("_value.item"),
+        StorageSubKeyAbi::prefix("_value.index"),
+        StorageSubKeyAbi::prefix("_value_to_id"),
+        StorageSubKeyAbi::prefix("_id_to_value"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         let mut id_key = base_key.clone();
         id_key.append_bytes(ID_SUFIX);
@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use super::{StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         self,
@@ -104,6 +104,11 @@ where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode + Clone,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".info"),
+        StorageSubKeyAbi::prefix(".node"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         LinkedListMapper {
             _phantom_api: PhantomData,
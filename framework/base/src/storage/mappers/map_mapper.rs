@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
@@ -33,6 +33,14 @@ where
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
     V: TopEncode + TopDecode,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".info"),
+        StorageSubKeyAbi::prefix(".node_links"),
+        StorageSubKeyAbi::prefix(".value"),
+        StorageSubKeyAbi::prefix(".node_id"),
+        StorageSubKeyAbi::prefix(".mapped"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         MapMapper {
             _phantom_api: PhantomData,
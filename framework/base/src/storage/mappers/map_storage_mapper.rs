@@ -2,6 +2,7 @@ use core::marker::PhantomData;
 
 use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
 use crate::{
+    abi::StorageSubKeyAbi,
     api::StorageMapperApi,
     codec::{NestedDecode, NestedEncode, TopDecode, TopEncode},
     storage::{self, StorageKey},
@@ -28,6 +29,14 @@ where
     K: TopEncode + TopDecode + NestedEncode + NestedDecode,
     V: StorageMapper<SA> + StorageClearable,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".info"),
+        StorageSubKeyAbi::prefix(".node_links"),
+        StorageSubKeyAbi::prefix(".value"),
+        StorageSubKeyAbi::prefix(".node_id"),
+        StorageSubKeyAbi::prefix(".storage"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         Self {
             _phantom_api: PhantomData,
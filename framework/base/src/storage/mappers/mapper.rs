@@ -1,4 +1,4 @@
-use crate::{api::StorageMapperApi, storage::StorageKey};
+use crate::{abi::StorageSubKeyAbi, api::StorageMapperApi, storage::StorageKey};
 
 pub trait StorageMapper<SA>: 'static
 where
@@ -6,6 +6,12 @@ where
 {
     /// Will be called automatically by the `#[storage_mapper]` annotation generated code.
     fn new(base_key: StorageKey<SA>) -> Self;
+
+    /// The storage entries the mapper manages, relative to its base key.
+    ///
+    /// Only used for checking storage key collisions at build time.
+    /// The default assumes the mapper can write anything starting with its base key.
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[StorageSubKeyAbi::prefix("")];
 }
 
 pub trait StorageClearable {
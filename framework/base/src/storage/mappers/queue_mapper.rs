@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use super::{StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         self,
@@ -77,6 +77,12 @@ where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".info"),
+        StorageSubKeyAbi::prefix(".node_links"),
+        StorageSubKeyAbi::prefix(".value"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         QueueMapper {
             _phantom_api: PhantomData,
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 pub use super::queue_mapper::Iter;
 use super::{QueueMapper, StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         self, multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode,
@@ -31,6 +31,13 @@ where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".info"),
+        StorageSubKeyAbi::prefix(".node_links"),
+        StorageSubKeyAbi::prefix(".value"),
+        StorageSubKeyAbi::prefix(".node_id"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         SetMapper {
             _phantom_api: PhantomData,
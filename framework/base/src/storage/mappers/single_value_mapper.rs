@@ -2,7 +2,7 @@ use core::{borrow::Borrow, marker::PhantomData};
 
 use super::StorageMapper;
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         multi_types::PlaceholderOutput, CodecFrom, CodecFromSelf, DecodeErrorHandler,
@@ -33,6 +33,8 @@ where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[StorageSubKeyAbi::exact("")];
+
     #[inline]
     fn new(base_key: StorageKey<SA>) -> Self {
         SingleValueMapper {
@@ -1,5 +1,5 @@
 use crate::{
-    abi::TypeAbi,
+    abi::{StorageSubKeyAbi, TypeAbi},
     api::ErrorApiImpl,
     codec::{CodecFrom, EncodeErrorHandler, TopEncodeMulti, TopEncodeMultiOutput},
     storage_clear, storage_get, storage_set,
@@ -38,6 +38,8 @@ impl<SA> StorageMapper<SA> for FungibleTokenMapper<SA>
 where
     SA: StorageMapperApi + CallTypeApi,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[StorageSubKeyAbi::exact("")];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         Self {
             token_state: storage_get(base_key.as_ref()),
@@ -1,4 +1,5 @@
 use crate::{
+    abi::StorageSubKeyAbi,
     codec::{
         CodecFrom, EncodeErrorHandler, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
     },
@@ -41,6 +42,8 @@ impl<SA> StorageMapper<SA> for NonFungibleTokenMapper<SA>
 where
     SA: StorageMapperApi + CallTypeApi,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[StorageSubKeyAbi::exact("")];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         Self {
             token_state: storage_get(base_key.as_ref()),
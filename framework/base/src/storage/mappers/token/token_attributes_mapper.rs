@@ -4,6 +4,7 @@ use crate::codec::{NestedDecode, NestedEncode, TopDecode, TopEncode};
 
 use super::super::StorageMapper;
 use crate::{
+    abi::StorageSubKeyAbi,
     api::{ErrorApiImpl, ManagedTypeApi, StorageMapperApi},
     storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
     types::{ManagedType, TokenIdentifier},
@@ -35,6 +36,13 @@ impl<SA> StorageMapper<SA> for TokenAttributesMapper<SA>
 where
     SA: StorageMapperApi,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".counter"),
+        StorageSubKeyAbi::prefix(".mapping"),
+        StorageSubKeyAbi::prefix(".attr"),
+        StorageSubKeyAbi::prefix(".nonce"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         TokenAttributesMapper {
             _phantom_api: PhantomData,
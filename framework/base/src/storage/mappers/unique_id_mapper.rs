@@ -5,7 +5,7 @@ use crate::codec::{
 
 use super::{StorageMapper, VecMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
     storage::StorageKey,
     storage_set,
@@ -30,6 +30,11 @@ impl<SA> StorageMapper<SA> for UniqueIdMapper<SA>
 where
     SA: StorageMapperApi,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".len"),
+        StorageSubKeyAbi::prefix(".item"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         Self {
             base_key: base_key.clone(),
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 pub use super::vec_mapper::Iter;
 use super::{StorageClearable, StorageMapper, VecMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
     codec::{
         multi_encode_iter_or_handle_err, CodecFrom, EncodeErrorHandler, NestedDecode, NestedEncode,
@@ -32,6 +32,12 @@ where
     SA: StorageMapperApi,
     T: TopEncode + TopDecode + NestedEncode + NestedDecode,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::exact(".len"),
+        StorageSubKeyAbi::prefix(".item"),
+        StorageSubKeyAbi::prefix(".index"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         UnorderedSetMapper {
             _phantom_api: PhantomData,
@@ -7,7 +7,7 @@ use crate::codec::{
 
 use super::StorageMapper;
 use crate::{
-    abi::{TypeAbi, TypeName},
+    abi::{StorageSubKeyAbi, TypeAbi, TypeName},
     api::StorageMapperApi,
     storage::{storage_get, storage_get_len, storage_set, StorageKey},
     types::{ManagedAddress, ManagedType, ManagedVec, MultiValueEncoded},
@@ -38,6 +38,12 @@ impl<SA> StorageMapper<SA> for UserMapper<SA>
 where
     SA: StorageMapperApi,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[
+        StorageSubKeyAbi::prefix("_address_to_id"),
+        StorageSubKeyAbi::prefix("_id_to_address"),
+        StorageSubKeyAbi::exact("_count"),
+    ];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         UserMapper {
             _phantom_api: PhantomData,
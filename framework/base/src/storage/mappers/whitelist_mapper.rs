@@ -2,6 +2,7 @@ use core::marker::PhantomData;
 
 use super::{SingleValueMapper, StorageMapper};
 use crate::{
+    abi::StorageSubKeyAbi,
     api::{ErrorApiImpl, StorageMapperApi},
     codec::NestedEncode,
     storage::StorageKey,
@@ -29,6 +30,8 @@ where
     SA: StorageMapperApi,
     T: NestedEncode + 'static,
 {
+    const SUB_KEYS: &'static [StorageSubKeyAbi] = &[StorageSubKeyAbi::prefix("")];
+
     fn new(base_key: StorageKey<SA>) -> Self {
         Self {
             base_key,
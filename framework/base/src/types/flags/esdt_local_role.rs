@@ -16,6 +16,10 @@ static ESDT_ROLE_NFT_BURN: &[u8] = b"ESDTRoleNFTBurn";
 static ESDT_ROLE_NFT_ADD_URI: &[u8] = b"ESDTRoleNFTAddURI";
 static ESDT_ROLE_NFT_UPDATE_ATTRIBUTES: &[u8] = b"ESDTRoleNFTUpdateAttributes";
 static ESDT_ROLE_TRANSFER: &[u8] = b"ESDTTransferRole";
+static ESDT_ROLE_MODIFY_ROYALTIES: &[u8] = b"ESDTRoleModifyRoyalties";
+static ESDT_ROLE_MODIFY_CREATOR: &[u8] = b"ESDTRoleModifyCreator";
+static ESDT_ROLE_SET_NEW_URI: &[u8] = b"ESDTRoleSetNewURI";
+static ESDT_ROLE_NFT_RECREATE: &[u8] = b"ESDTRoleNFTRecreate";
 
 #[derive(
     TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi, Clone, PartialEq, Eq, Debug, Copy,
@@ -30,6 +34,10 @@ pub enum EsdtLocalRole {
     NftAddUri,
     NftUpdateAttributes,
     Transfer,
+    ModifyRoyalties,
+    ModifyCreator,
+    SetNewUri,
+    NftRecreate,
 }
 
 impl EsdtLocalRole {
@@ -44,6 +52,10 @@ impl EsdtLocalRole {
             Self::NftAddUri => 6,
             Self::NftUpdateAttributes => 7,
             Self::Transfer => 8,
+            Self::ModifyRoyalties => 9,
+            Self::ModifyCreator => 10,
+            Self::SetNewUri => 11,
+            Self::NftRecreate => 12,
         }
     }
 
@@ -58,6 +70,10 @@ impl EsdtLocalRole {
             Self::NftAddUri => ESDT_ROLE_NFT_ADD_URI,
             Self::NftUpdateAttributes => ESDT_ROLE_NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => ESDT_ROLE_TRANSFER,
+            Self::ModifyRoyalties => ESDT_ROLE_MODIFY_ROYALTIES,
+            Self::ModifyCreator => ESDT_ROLE_MODIFY_CREATOR,
+            Self::SetNewUri => ESDT_ROLE_SET_NEW_URI,
+            Self::NftRecreate => ESDT_ROLE_NFT_RECREATE,
         }
     }
 
@@ -72,13 +88,17 @@ impl EsdtLocalRole {
             Self::NftAddUri => EsdtLocalRoleFlags::NFT_ADD_URI,
             Self::NftUpdateAttributes => EsdtLocalRoleFlags::NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => EsdtLocalRoleFlags::TRANSFER,
+            Self::ModifyRoyalties => EsdtLocalRoleFlags::MODIFY_ROYALTIES,
+            Self::ModifyCreator => EsdtLocalRoleFlags::MODIFY_CREATOR,
+            Self::SetNewUri => EsdtLocalRoleFlags::SET_NEW_URI,
+            Self::NftRecreate => EsdtLocalRoleFlags::NFT_RECREATE,
         }
     }
 }
 
 // TODO: can be done with macros, but I didn't find a public library that does it and is no_std
 // we can implement it, it's easy
-const ALL_ROLES: [EsdtLocalRole; 8] = [
+const ALL_ROLES: [EsdtLocalRole; 12] = [
     EsdtLocalRole::Mint,
     EsdtLocalRole::Burn,
     EsdtLocalRole::NftCreate,
@@ -87,6 +107,10 @@ const ALL_ROLES: [EsdtLocalRole; 8] = [
     EsdtLocalRole::NftAddUri,
     EsdtLocalRole::NftUpdateAttributes,
     EsdtLocalRole::Transfer,
+    EsdtLocalRole::ModifyRoyalties,
+    EsdtLocalRole::ModifyCreator,
+    EsdtLocalRole::SetNewUri,
+    EsdtLocalRole::NftRecreate,
 ];
 
 impl EsdtLocalRole {
@@ -107,6 +131,10 @@ impl From<u16> for EsdtLocalRole {
             6 => Self::NftAddUri,
             7 => Self::NftUpdateAttributes,
             8 => Self::Transfer,
+            9 => Self::ModifyRoyalties,
+            10 => Self::ModifyCreator,
+            11 => Self::SetNewUri,
+            12 => Self::NftRecreate,
             _ => Self::None,
         }
     }
@@ -131,6 +159,14 @@ impl<'a> From<&'a [u8]> for EsdtLocalRole {
             Self::NftUpdateAttributes
         } else if byte_slice == ESDT_ROLE_TRANSFER {
             Self::Transfer
+        } else if byte_slice == ESDT_ROLE_MODIFY_ROYALTIES {
+            Self::ModifyRoyalties
+        } else if byte_slice == ESDT_ROLE_MODIFY_CREATOR {
+            Self::ModifyCreator
+        } else if byte_slice == ESDT_ROLE_SET_NEW_URI {
+            Self::SetNewUri
+        } else if byte_slice == ESDT_ROLE_NFT_RECREATE {
+            Self::NftRecreate
         } else {
             Self::None
         }
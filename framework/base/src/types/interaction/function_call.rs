@@ -74,6 +74,15 @@ where
     }
 }
 
+impl<Api> From<()> for FunctionCall<Api>
+where
+    Api: ManagedTypeApi,
+{
+    fn from(_: ()) -> Self {
+        FunctionCall::empty()
+    }
+}
+
 impl<Api> TopEncodeMulti for FunctionCall<Api>
 where
     Api: ManagedTypeApi,
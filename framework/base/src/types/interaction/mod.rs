@@ -14,6 +14,7 @@ mod contract_call_with_multi_esdt;
 mod contract_deploy;
 mod function_call;
 mod managed_arg_buffer;
+mod tx;
 
 pub use async_call::AsyncCall;
 pub use async_call_promises::AsyncCallPromises;
@@ -31,3 +32,4 @@ pub use contract_call_with_multi_esdt::ContractCallWithMultiEsdt;
 pub use contract_deploy::{new_contract_deploy, ContractDeploy};
 pub use function_call::FunctionCall;
 pub use managed_arg_buffer::ManagedArgBuffer;
+pub use tx::*;
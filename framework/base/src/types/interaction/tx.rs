@@ -0,0 +1,597 @@
+mod tx_data;
+mod tx_env;
+mod tx_env_sc;
+mod tx_from;
+mod tx_gas;
+mod tx_payment;
+mod tx_result_handler;
+mod tx_to;
+
+pub use tx_data::{Code, DeployCall, FromSource, TxData, TxDataFunctionCall};
+pub use tx_env::{TxBaseWithEnv, TxEnv};
+pub use tx_env_sc::{DeployRawResult, SyncCallRawResult, TxScEnv};
+pub use tx_from::{TxFrom, TxFromSpecified};
+pub use tx_gas::{ExplicitGas, TxGas};
+pub use tx_payment::{
+    EgldPayment, FullPaymentData, PaymentConversionResult, TxPayment, TxPaymentEgldOnly,
+};
+pub use tx_result_handler::{
+    OriginalResultMarker, RHListItem, ReturnsHandler, ReturnsNewManagedAddress, ReturnsRawResult,
+    ReturnsResult, ReturnsResultAs, TxResultHandler, TxRunnableResultHandler,
+};
+pub use tx_to::{TxTo, TxToSpecified};
+
+use core::marker::PhantomData;
+
+use crate::{
+    codec::TopEncodeMulti,
+    types::{
+        BigUint, CodeMetadata, EgldOrEsdtTokenPayment, EgldOrMultiEsdtPayment, EsdtTokenPayment,
+        FunctionCall, ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedVec,
+    },
+};
+
+use super::{ContractCallNoPayment, ContractDeploy};
+
+/// Generic transaction builder, used in contracts, in tests and in interactors alike.
+///
+/// Each of the type arguments starts out as `()` (unspecified) and gets replaced as the transaction is built:
+/// - `Env` - the environment where the transaction is executed, it decides how the transaction is run;
+/// - `From` - the sender, in contracts it is always the contract itself;
+/// - `To` - the recipient;
+/// - `Payment` - EGLD or ESDT transfers;
+/// - `Gas` - the gas limit, if left unspecified each environment has its own default;
+/// - `Data` - the function call or the deploy data;
+/// - `RH` - the result handler, it keeps the type of the original endpoint result and decides what the transaction returns.
+///
+/// Each environment then provides methods to execute the transaction, e.g. `sync_call` in contracts, or `run` in tests.
+#[must_use]
+pub struct Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    pub env: Env,
+    pub from: From,
+    pub to: To,
+    pub payment: Payment,
+    pub gas: Gas,
+    pub data: Data,
+    pub result_handler: RH,
+}
+
+impl<Env> Tx<Env, (), (), (), (), (), ()>
+where
+    Env: TxEnv,
+{
+    /// Creates an empty transaction in the given environment.
+    pub fn new_with_env(env: Env) -> Self {
+        Tx {
+            env,
+            from: (),
+            to: (),
+            payment: (),
+            gas: (),
+            data: (),
+            result_handler: (),
+        }
+    }
+}
+
+impl<Env, To, Payment, Gas, Data, RH> Tx<Env, (), To, Payment, Gas, Data, RH>
+where
+    Env: TxEnv,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Specifies the sender of the transaction.
+    pub fn from<From>(self, from: From) -> Tx<Env, From, To, Payment, Gas, Data, RH>
+    where
+        From: TxFromSpecified<Env>,
+    {
+        Tx {
+            env: self.env,
+            from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: self.data,
+            result_handler: self.result_handler,
+        }
+    }
+}
+
+impl<Env, From, Payment, Gas, Data, RH> Tx<Env, From, (), Payment, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Specifies the recipient of the transaction.
+    pub fn to<To>(self, to: To) -> Tx<Env, From, To, Payment, Gas, Data, RH>
+    where
+        To: TxToSpecified<Env>,
+    {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to,
+            payment: self.payment,
+            gas: self.gas,
+            data: self.data,
+            result_handler: self.result_handler,
+        }
+    }
+}
+
+impl<Env, From, To, Gas, Data, RH> Tx<Env, From, To, (), Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Sets any supported payment type.
+    pub fn payment<Payment>(self, payment: Payment) -> Tx<Env, From, To, Payment, Gas, Data, RH>
+    where
+        Payment: TxPayment<Env>,
+    {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment,
+            gas: self.gas,
+            data: self.data,
+            result_handler: self.result_handler,
+        }
+    }
+
+    /// Adds an EGLD payment.
+    pub fn egld(
+        self,
+        egld_amount: BigUint<Env::Api>,
+    ) -> Tx<Env, From, To, EgldPayment<Env::Api>, Gas, Data, RH> {
+        self.payment(EgldPayment { value: egld_amount })
+    }
+
+    /// Adds a single ESDT payment. Further ESDT payments can be added afterwards.
+    pub fn esdt<P: Into<EsdtTokenPayment<Env::Api>>>(
+        self,
+        payment: P,
+    ) -> Tx<Env, From, To, EsdtTokenPayment<Env::Api>, Gas, Data, RH> {
+        self.payment(payment.into())
+    }
+
+    /// Sets a (potentially) multi-token transfer.
+    pub fn multi_esdt(
+        self,
+        payments: ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>,
+    ) -> Tx<Env, From, To, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>, Gas, Data, RH> {
+        self.payment(payments)
+    }
+
+    /// Sets a payment that can be either EGLD or a single ESDT, as determined at runtime.
+    pub fn egld_or_single_esdt<P: Into<EgldOrEsdtTokenPayment<Env::Api>>>(
+        self,
+        payment: P,
+    ) -> Tx<Env, From, To, EgldOrEsdtTokenPayment<Env::Api>, Gas, Data, RH> {
+        self.payment(payment.into())
+    }
+
+    /// Sets a payment that can be either EGLD or several ESDT, as determined at runtime.
+    pub fn egld_or_multi_esdt(
+        self,
+        payment: EgldOrMultiEsdtPayment<Env::Api>,
+    ) -> Tx<Env, From, To, EgldOrMultiEsdtPayment<Env::Api>, Gas, Data, RH> {
+        self.payment(payment)
+    }
+}
+
+impl<Env, From, To, Gas, Data, RH> Tx<Env, From, To, EsdtTokenPayment<Env::Api>, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Adds another ESDT payment, turning the single payment into a multi-transfer.
+    pub fn esdt<P: Into<EsdtTokenPayment<Env::Api>>>(
+        self,
+        payment: P,
+    ) -> Tx<Env, From, To, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>, Gas, Data, RH> {
+        let mut payments = ManagedVec::new();
+        payments.push(self.payment);
+        payments.push(payment.into());
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: payments,
+            gas: self.gas,
+            data: self.data,
+            result_handler: self.result_handler,
+        }
+    }
+}
+
+impl<Env, From, To, Gas, Data, RH>
+    Tx<Env, From, To, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Adds another ESDT payment to the multi-transfer.
+    pub fn esdt<P: Into<EsdtTokenPayment<Env::Api>>>(mut self, payment: P) -> Self {
+        self.payment.push(payment.into());
+        self
+    }
+}
+
+impl<Env, From, To, Payment, Data, RH> Tx<Env, From, To, Payment, (), Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Sets an explicit gas limit to the transaction.
+    pub fn gas(self, gas_limit: u64) -> Tx<Env, From, To, Payment, ExplicitGas, Data, RH> {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: ExplicitGas(gas_limit),
+            data: self.data,
+            result_handler: self.result_handler,
+        }
+    }
+}
+
+impl<Env, From, To, Payment, Gas> Tx<Env, From, To, Payment, Gas, (), ()>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Starts a function call built by hand, without a proxy.
+    ///
+    /// Arguments can be added afterwards, there is no type checking against the endpoint ABI.
+    pub fn raw_call<N: Into<ManagedBuffer<Env::Api>>>(
+        self,
+        function_name: N,
+    ) -> Tx<Env, From, To, Payment, Gas, FunctionCall<Env::Api>, ()> {
+        self.function_call(FunctionCall::new(function_name))
+    }
+
+    /// Sets the function call directly.
+    pub fn function_call(
+        self,
+        function_call: FunctionCall<Env::Api>,
+    ) -> Tx<Env, From, To, Payment, Gas, FunctionCall<Env::Api>, ()> {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: function_call,
+            result_handler: self.result_handler,
+        }
+    }
+
+    /// Starts a deploy built by hand, without a proxy.
+    ///
+    /// The code (or the source contract) needs to be specified afterwards.
+    pub fn raw_deploy(self) -> Tx<Env, From, To, Payment, Gas, DeployCall<Env, ()>, ()> {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: DeployCall::default(),
+            result_handler: self.result_handler,
+        }
+    }
+}
+
+impl<Env, From, Payment, Gas> Tx<Env, From, (), Payment, Gas, (), ()>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Takes the recipient, the function call and the result type from a contract call produced by a proxy.
+    ///
+    /// This way the same proxies can be used in all environments.
+    pub fn call<OriginalResult>(
+        self,
+        contract_call: ContractCallNoPayment<Env::Api, OriginalResult>,
+    ) -> Tx<
+        Env,
+        From,
+        ManagedAddress<Env::Api>,
+        Payment,
+        Gas,
+        FunctionCall<Env::Api>,
+        OriginalResultMarker<OriginalResult>,
+    > {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: contract_call.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: contract_call.function_call,
+            result_handler: OriginalResultMarker::new(),
+        }
+    }
+}
+
+impl<Env, From, Payment, Gas> Tx<Env, From, (), Payment, Gas, (), ()>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Takes the constructor arguments and the result type from a deploy produced by a proxy.
+    ///
+    /// The code (or the source contract) needs to be specified afterwards.
+    pub fn deploy<OriginalResult>(
+        self,
+        contract_deploy: ContractDeploy<Env::Api, OriginalResult>,
+    ) -> Tx<Env, From, (), Payment, Gas, DeployCall<Env, ()>, OriginalResultMarker<OriginalResult>>
+    {
+        let data = DeployCall {
+            arg_buffer: contract_deploy.arg_buffer,
+            ..Default::default()
+        };
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data,
+            result_handler: OriginalResultMarker::new(),
+        }
+    }
+}
+
+impl<Env, From, To, Payment, Gas, RH> Tx<Env, From, To, Payment, Gas, FunctionCall<Env::Api>, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Serializes and adds an argument to the function call.
+    ///
+    /// Accepts multi-values, so it might effectively be adding more than one raw argument.
+    pub fn argument<T: TopEncodeMulti>(mut self, arg: &T) -> Self {
+        self.data = self.data.argument(arg);
+        self
+    }
+
+    /// Adds an argument that is already serialized.
+    pub fn raw_argument<A: Into<ManagedBuffer<Env::Api>>>(mut self, raw_arg: A) -> Self {
+        self.data.arg_buffer.push_arg_raw(raw_arg.into());
+        self
+    }
+
+    /// Replaces all arguments with an already serialized argument buffer.
+    pub fn arguments_raw(mut self, raw_argument_buffer: ManagedArgBuffer<Env::Api>) -> Self {
+        self.data.arg_buffer = raw_argument_buffer;
+        self
+    }
+}
+
+impl<Env, From, To, Payment, Gas, CodeSource, RH>
+    Tx<Env, From, To, Payment, Gas, DeployCall<Env, CodeSource>, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Serializes and adds an argument to the deploy (i.e. to the constructor).
+    pub fn argument<T: TopEncodeMulti>(mut self, arg: &T) -> Self {
+        self.data.arg_buffer.push_multi_arg(arg);
+        self
+    }
+
+    /// Sets the code metadata of the new contract.
+    pub fn code_metadata(mut self, code_metadata: CodeMetadata) -> Self {
+        self.data.code_metadata = code_metadata;
+        self
+    }
+}
+
+impl<Env, From, To, Payment, Gas, RH> Tx<Env, From, To, Payment, Gas, DeployCall<Env, ()>, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Sets the code of the new contract.
+    ///
+    /// The accepted code types depend on the environment: managed buffers in contracts, file paths in tests.
+    pub fn code<C>(self, code: C) -> Tx<Env, From, To, Payment, Gas, DeployCall<Env, Code<C>>, RH> {
+        self.code_source(Code(code))
+    }
+
+    /// The new contract will have the same code as an existing contract.
+    pub fn from_source<A>(
+        self,
+        source_address: A,
+    ) -> Tx<Env, From, To, Payment, Gas, DeployCall<Env, FromSource<A>>, RH> {
+        self.code_source(FromSource(source_address))
+    }
+
+    fn code_source<CodeSource>(
+        self,
+        code_source: CodeSource,
+    ) -> Tx<Env, From, To, Payment, Gas, DeployCall<Env, CodeSource>, RH> {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: DeployCall {
+                code_source,
+                code_metadata: self.data.code_metadata,
+                arg_buffer: self.data.arg_buffer,
+                _phantom: PhantomData,
+            },
+            result_handler: self.result_handler,
+        }
+    }
+}
+
+impl<Env, From, To, Payment, Gas, Data> Tx<Env, From, To, Payment, Gas, Data, ()>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+{
+    /// Declares the type of the result produced by the endpoint, for calls built by hand.
+    ///
+    /// Proxies do this automatically.
+    pub fn original_result<OriginalResult>(
+        self,
+    ) -> Tx<Env, From, To, Payment, Gas, Data, OriginalResultMarker<OriginalResult>> {
+        self.with_result_handler(OriginalResultMarker::new())
+    }
+
+    /// Specifies what the transaction should return, when the result type is not known.
+    pub fn returns<Item>(
+        self,
+        item: Item,
+    ) -> Tx<Env, From, To, Payment, Gas, Data, ReturnsHandler<(), Item>> {
+        self.with_result_handler(ReturnsHandler::new(item))
+    }
+}
+
+impl<Env, From, To, Payment, Gas, Data, OriginalResult>
+    Tx<Env, From, To, Payment, Gas, Data, OriginalResultMarker<OriginalResult>>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+{
+    /// Specifies what the transaction should return, e.g. `ReturnsResult` for the decoded endpoint result.
+    ///
+    /// Several items can be requested at once, as a tuple.
+    pub fn returns<Item>(
+        self,
+        item: Item,
+    ) -> Tx<Env, From, To, Payment, Gas, Data, ReturnsHandler<OriginalResult, Item>> {
+        self.with_result_handler(ReturnsHandler::new(item))
+    }
+}
+
+impl<Env, From, To, Payment, Gas, Data, RH> Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    fn with_result_handler<NewRH>(
+        self,
+        result_handler: NewRH,
+    ) -> Tx<Env, From, To, Payment, Gas, Data, NewRH>
+    where
+        NewRH: TxResultHandler<Env>,
+    {
+        Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: self.data,
+            result_handler,
+        }
+    }
+
+    /// Resolves the sender address, in the current environment.
+    pub fn from_address(&self) -> ManagedAddress<Env::Api> {
+        self.from.resolve_address(&self.env)
+    }
+
+    /// Resolves the gas limit, in the current environment.
+    pub fn gas_limit(&self) -> u64 {
+        self.gas.resolve_gas(&self.env)
+    }
+}
+
+impl<Env, From, To, Payment, Gas, Data, RH> Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxToSpecified<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxDataFunctionCall<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Converts the payment and the function call into a transaction with EGLD value only,
+    /// ESDT transfers become builtin function calls.
+    pub fn to_normalized(self) -> PaymentConversionResult<Env::Api> {
+        let from = self.from.resolve_address(&self.env);
+        let to = self.to.resolve_address(&self.env);
+        self.payment
+            .into_full_payment_data()
+            .convert_tx_data(&from, to, self.data.into())
+    }
+
+    /// The transaction data, as it would appear on the blockchain.
+    pub fn to_call_data_string(self) -> ManagedBuffer<Env::Api> {
+        self.to_normalized().function_call.to_call_data_string()
+    }
+}
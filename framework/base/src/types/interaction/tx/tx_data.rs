@@ -0,0 +1,84 @@
+use core::marker::PhantomData;
+
+use crate::types::{CodeMetadata, FunctionCall, ManagedArgBuffer};
+
+use super::TxEnv;
+
+/// Marks the data field of a transaction: nothing (simple transfer), a function call, or a deploy.
+pub trait TxData<Env>
+where
+    Env: TxEnv,
+{
+    fn is_no_call(&self) -> bool;
+}
+
+/// Data that can be expressed as a function call, i.e. anything except deploys.
+pub trait TxDataFunctionCall<Env>: TxData<Env> + Into<FunctionCall<Env::Api>>
+where
+    Env: TxEnv,
+{
+}
+
+impl<Env> TxData<Env> for ()
+where
+    Env: TxEnv,
+{
+    fn is_no_call(&self) -> bool {
+        true
+    }
+}
+
+impl<Env> TxDataFunctionCall<Env> for () where Env: TxEnv {}
+
+impl<Env> TxData<Env> for FunctionCall<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn is_no_call(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<Env> TxDataFunctionCall<Env> for FunctionCall<Env::Api> where Env: TxEnv {}
+
+/// The code of a new contract, in whatever form the environment accepts.
+pub struct Code<C>(pub C);
+
+/// The new contract gets the code of an already deployed contract.
+pub struct FromSource<A>(pub A);
+
+/// Deploy data: code (or source contract), code metadata and constructor arguments.
+///
+/// The code source starts out as `()` and must be specified before deploying.
+pub struct DeployCall<Env, CodeSource>
+where
+    Env: TxEnv,
+{
+    pub code_source: CodeSource,
+    pub code_metadata: CodeMetadata,
+    pub arg_buffer: ManagedArgBuffer<Env::Api>,
+    pub(super) _phantom: PhantomData<Env>,
+}
+
+impl<Env> Default for DeployCall<Env, ()>
+where
+    Env: TxEnv,
+{
+    fn default() -> Self {
+        DeployCall {
+            code_source: (),
+            code_metadata: CodeMetadata::DEFAULT,
+            arg_buffer: ManagedArgBuffer::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Env, CodeSource> TxData<Env> for DeployCall<Env, CodeSource>
+where
+    Env: TxEnv,
+{
+    fn is_no_call(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{api::CallTypeApi, types::ManagedAddress};
+
+use super::Tx;
+
+/// The environment in which a transaction is built and executed: a contract, a test, or an interactor.
+///
+/// Each environment decides what happens to the unspecified fields of a transaction.
+pub trait TxEnv: Sized {
+    type Api: CallTypeApi + 'static;
+
+    /// The sender, when none was specified.
+    fn resolve_sender_address(&self) -> ManagedAddress<Self::Api>;
+
+    /// The gas limit, when none was specified.
+    fn default_gas(&self) -> u64;
+}
+
+/// A transaction in which nothing was specified yet, except the environment.
+pub type TxBaseWithEnv<Env> = Tx<Env, (), (), (), (), (), ()>;
@@ -0,0 +1,384 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::{BlockchainApiImpl, CallTypeApi, StorageWriteApi},
+    codec::{CodecFrom, TopDecodeMulti, TopEncodeMulti},
+    contract_base::{BlockchainWrapper, SendRawWrapper},
+    types::{AsyncCall, FunctionCall, ManagedAddress, ManagedBuffer, ManagedVec},
+};
+
+use super::{
+    super::contract_call_exec::{decode_result, TRANSFER_EXECUTE_DEFAULT_LEFTOVER},
+    Code, DeployCall, FromSource, RHListItem, ReturnsNewManagedAddress, ReturnsRawResult,
+    ReturnsResult, ReturnsResultAs, Tx, TxData, TxDataFunctionCall, TxEnv, TxFrom, TxGas,
+    TxPayment, TxPaymentEgldOnly, TxResultHandler, TxRunnableResultHandler, TxTo, TxToSpecified,
+};
+
+/// Environment for transactions launched from within a contract.
+///
+/// The sender is always the contract itself, the default gas limit is all the gas left.
+pub struct TxScEnv<Api>
+where
+    Api: CallTypeApi + 'static,
+{
+    _phantom: PhantomData<Api>,
+}
+
+impl<Api> Default for TxScEnv<Api>
+where
+    Api: CallTypeApi + 'static,
+{
+    fn default() -> Self {
+        TxScEnv {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Api> TxEnv for TxScEnv<Api>
+where
+    Api: CallTypeApi + 'static,
+{
+    type Api = Api;
+
+    fn resolve_sender_address(&self) -> ManagedAddress<Api> {
+        BlockchainWrapper::<Api>::new().get_sc_address()
+    }
+
+    fn default_gas(&self) -> u64 {
+        Api::blockchain_api_impl().get_gas_left()
+    }
+}
+
+/// The results of a synchronous call, as returned by the VM.
+pub struct SyncCallRawResult<Api>(pub ManagedVec<Api, ManagedBuffer<Api>>)
+where
+    Api: CallTypeApi + 'static;
+
+/// The results of a synchronous deploy, as returned by the VM.
+pub struct DeployRawResult<Api>
+where
+    Api: CallTypeApi + 'static,
+{
+    pub new_address: ManagedAddress<Api>,
+    pub raw_results: ManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+impl<Env, From, To, Payment, Gas, Data, RH> Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// In case of `transfer_execute`, by default a little gas is left for the calling transaction to finish.
+    fn gas_limit_with_leftover(&self) -> u64 {
+        let gas_limit = self.gas.resolve_gas(&self.env);
+        if self.gas.is_explicit() || gas_limit <= TRANSFER_EXECUTE_DEFAULT_LEFTOVER {
+            gas_limit
+        } else {
+            gas_limit - TRANSFER_EXECUTE_DEFAULT_LEFTOVER
+        }
+    }
+}
+
+impl<Api, To, Payment, Gas, Data, RH> Tx<TxScEnv<Api>, (), To, Payment, Gas, Data, RH>
+where
+    Api: CallTypeApi + 'static,
+    To: TxToSpecified<TxScEnv<Api>>,
+    Payment: TxPayment<TxScEnv<Api>>,
+    Gas: TxGas<TxScEnv<Api>>,
+    Data: TxDataFunctionCall<TxScEnv<Api>>,
+    RH: TxResultHandler<TxScEnv<Api>>,
+{
+    /// Immediately launches a transfer-execute call.
+    ///
+    /// This is similar to an async call, but there is no callback
+    /// and there can be more than one such call per transaction.
+    pub fn transfer_execute(self) {
+        let gas_limit = self.gas_limit_with_leftover();
+        let to = self.to.resolve_address(&self.env);
+        let function_call: FunctionCall<Api> = self.data.into();
+        let payment = self.payment.into_full_payment_data();
+        let send_raw = SendRawWrapper::<Api>::new();
+
+        let _ = match payment.multi_esdt.len() {
+            0 => send_raw.direct_egld_execute(
+                &to,
+                &payment.egld_value(),
+                gas_limit,
+                &function_call.function_name,
+                &function_call.arg_buffer,
+            ),
+            1 => {
+                let esdt = payment.multi_esdt.get(0);
+                if esdt.token_nonce == 0 {
+                    send_raw.transfer_esdt_execute(
+                        &to,
+                        &esdt.token_identifier,
+                        &esdt.amount,
+                        gas_limit,
+                        &function_call.function_name,
+                        &function_call.arg_buffer,
+                    )
+                } else {
+                    send_raw.transfer_esdt_nft_execute(
+                        &to,
+                        &esdt.token_identifier,
+                        esdt.token_nonce,
+                        &esdt.amount,
+                        gas_limit,
+                        &function_call.function_name,
+                        &function_call.arg_buffer,
+                    )
+                }
+            },
+            _ => send_raw.multi_esdt_transfer_execute(
+                &to,
+                &payment.multi_esdt,
+                gas_limit,
+                &function_call.function_name,
+                &function_call.arg_buffer,
+            ),
+        };
+    }
+
+    /// Converts to a legacy async call.
+    pub fn async_call(self) -> AsyncCall<Api> {
+        let normalized = self.to_normalized();
+        AsyncCall {
+            to: normalized.to,
+            egld_payment: normalized.egld_payment,
+            function_call: normalized.function_call,
+            callback_call: None,
+        }
+    }
+
+    /// Sends an async call, which terminates the current execution. The result of the call is ignored.
+    pub fn async_call_and_exit(self) -> !
+    where
+        Api: StorageWriteApi,
+    {
+        self.async_call().call_and_exit()
+    }
+}
+
+impl<Api, To, Payment> Tx<TxScEnv<Api>, (), To, Payment, (), (), ()>
+where
+    Api: CallTypeApi + 'static,
+    To: TxToSpecified<TxScEnv<Api>>,
+    Payment: TxPayment<TxScEnv<Api>>,
+{
+    /// Sends the payment directly, no function is called.
+    pub fn transfer(self) {
+        self.gas(0).transfer_execute();
+    }
+}
+
+impl<Api, To, Payment, Gas, Data, RH> Tx<TxScEnv<Api>, (), To, Payment, Gas, Data, RH>
+where
+    Api: CallTypeApi + 'static,
+    To: TxToSpecified<TxScEnv<Api>>,
+    Payment: TxPayment<TxScEnv<Api>>,
+    Gas: TxGas<TxScEnv<Api>>,
+    Data: TxDataFunctionCall<TxScEnv<Api>>,
+    RH: TxRunnableResultHandler<SyncCallRawResult<Api>, TxScEnv<Api>>,
+{
+    /// Executes immediately, synchronously, and returns what the result handler requested.
+    ///
+    /// Only works if the target contract is in the same shard.
+    pub fn sync_call(self) -> RH::Returns {
+        let gas_limit = self.gas.resolve_gas(&self.env);
+        let result_handler = self.result_handler;
+        let normalized = Tx {
+            env: self.env,
+            from: self.from,
+            to: self.to,
+            payment: self.payment,
+            gas: self.gas,
+            data: self.data,
+            result_handler: (),
+        }
+        .to_normalized();
+
+        let raw_result = SendRawWrapper::<Api>::new().execute_on_dest_context_raw(
+            gas_limit,
+            &normalized.to,
+            &normalized.egld_payment,
+            &normalized.function_call.function_name,
+            &normalized.function_call.arg_buffer,
+        );
+        SendRawWrapper::<Api>::new().clean_return_data();
+
+        result_handler.process_result(&SyncCallRawResult(raw_result))
+    }
+}
+
+impl<Api, To, Gas, Data, RH> Tx<TxScEnv<Api>, (), To, (), Gas, Data, RH>
+where
+    Api: CallTypeApi + 'static,
+    To: TxToSpecified<TxScEnv<Api>>,
+    Gas: TxGas<TxScEnv<Api>>,
+    Data: TxDataFunctionCall<TxScEnv<Api>>,
+    RH: TxRunnableResultHandler<SyncCallRawResult<Api>, TxScEnv<Api>>,
+{
+    /// Executes immediately, synchronously, without allowing the target contract to modify the state.
+    pub fn sync_call_readonly(self) -> RH::Returns {
+        let gas_limit = self.gas.resolve_gas(&self.env);
+        let to = self.to.resolve_address(&self.env);
+        let function_call: FunctionCall<Api> = self.data.into();
+
+        let raw_result = SendRawWrapper::<Api>::new().execute_on_dest_context_readonly_raw(
+            gas_limit,
+            &to,
+            &function_call.function_name,
+            &function_call.arg_buffer,
+        );
+        SendRawWrapper::<Api>::new().clean_return_data();
+
+        self.result_handler
+            .process_result(&SyncCallRawResult(raw_result))
+    }
+}
+
+impl<Api, Payment, Gas, RH>
+    Tx<TxScEnv<Api>, (), (), Payment, Gas, DeployCall<TxScEnv<Api>, Code<ManagedBuffer<Api>>>, RH>
+where
+    Api: CallTypeApi + 'static,
+    Payment: TxPaymentEgldOnly<TxScEnv<Api>>,
+    Gas: TxGas<TxScEnv<Api>>,
+    RH: TxRunnableResultHandler<DeployRawResult<Api>, TxScEnv<Api>>,
+{
+    /// Deploys a new contract in the same shard, synchronously.
+    pub fn sync_call(self) -> RH::Returns {
+        let gas_limit = self.gas.resolve_gas(&self.env);
+        let egld_value = self.payment.into_full_payment_data().egld_value();
+        let (new_address, raw_results) = SendRawWrapper::<Api>::new().deploy_contract(
+            gas_limit,
+            &egld_value,
+            &self.data.code_source.0,
+            self.data.code_metadata,
+            &self.data.arg_buffer,
+        );
+        SendRawWrapper::<Api>::new().clean_return_data();
+
+        self.result_handler.process_result(&DeployRawResult {
+            new_address,
+            raw_results,
+        })
+    }
+}
+
+impl<Api, Payment, Gas, RH>
+    Tx<
+        TxScEnv<Api>,
+        (),
+        (),
+        Payment,
+        Gas,
+        DeployCall<TxScEnv<Api>, FromSource<ManagedAddress<Api>>>,
+        RH,
+    >
+where
+    Api: CallTypeApi + 'static,
+    Payment: TxPaymentEgldOnly<TxScEnv<Api>>,
+    Gas: TxGas<TxScEnv<Api>>,
+    RH: TxRunnableResultHandler<DeployRawResult<Api>, TxScEnv<Api>>,
+{
+    /// Deploys a new contract in the same shard, synchronously, copying the code of an existing contract.
+    pub fn sync_call(self) -> RH::Returns {
+        let gas_limit = self.gas.resolve_gas(&self.env);
+        let egld_value = self.payment.into_full_payment_data().egld_value();
+        let (new_address, raw_results) = SendRawWrapper::<Api>::new().deploy_from_source_contract(
+            gas_limit,
+            &egld_value,
+            &self.data.code_source.0,
+            self.data.code_metadata,
+            &self.data.arg_buffer,
+        );
+        SendRawWrapper::<Api>::new().clean_return_data();
+
+        self.result_handler.process_result(&DeployRawResult {
+            new_address,
+            raw_results,
+        })
+    }
+}
+
+impl<Api, Original> RHListItem<SyncCallRawResult<Api>, TxScEnv<Api>, Original> for ReturnsResult
+where
+    Api: CallTypeApi + 'static,
+    Original: TopDecodeMulti,
+{
+    type Returns = Original;
+
+    fn item_process_result(self, raw_result: &SyncCallRawResult<Api>) -> Original {
+        decode_result(raw_result.0.clone())
+    }
+}
+
+impl<Api, Original, T> RHListItem<SyncCallRawResult<Api>, TxScEnv<Api>, Original>
+    for ReturnsResultAs<T>
+where
+    Api: CallTypeApi + 'static,
+    Original: TopEncodeMulti,
+    T: CodecFrom<Original>,
+{
+    type Returns = T;
+
+    fn item_process_result(self, raw_result: &SyncCallRawResult<Api>) -> T {
+        decode_result(raw_result.0.clone())
+    }
+}
+
+impl<Api, Original> RHListItem<SyncCallRawResult<Api>, TxScEnv<Api>, Original> for ReturnsRawResult
+where
+    Api: CallTypeApi + 'static,
+{
+    type Returns = ManagedVec<Api, ManagedBuffer<Api>>;
+
+    fn item_process_result(self, raw_result: &SyncCallRawResult<Api>) -> Self::Returns {
+        raw_result.0.clone()
+    }
+}
+
+impl<Api, Original> RHListItem<DeployRawResult<Api>, TxScEnv<Api>, Original> for ReturnsResult
+where
+    Api: CallTypeApi + 'static,
+    Original: TopDecodeMulti,
+{
+    type Returns = Original;
+
+    fn item_process_result(self, raw_result: &DeployRawResult<Api>) -> Original {
+        decode_result(raw_result.raw_results.clone())
+    }
+}
+
+impl<Api, Original, T> RHListItem<DeployRawResult<Api>, TxScEnv<Api>, Original>
+    for ReturnsResultAs<T>
+where
+    Api: CallTypeApi + 'static,
+    Original: TopEncodeMulti,
+    T: CodecFrom<Original>,
+{
+    type Returns = T;
+
+    fn item_process_result(self, raw_result: &DeployRawResult<Api>) -> T {
+        decode_result(raw_result.raw_results.clone())
+    }
+}
+
+impl<Api, Original> RHListItem<DeployRawResult<Api>, TxScEnv<Api>, Original>
+    for ReturnsNewManagedAddress
+where
+    Api: CallTypeApi + 'static,
+{
+    type Returns = ManagedAddress<Api>;
+
+    fn item_process_result(self, raw_result: &DeployRawResult<Api>) -> Self::Returns {
+        raw_result.new_address.clone()
+    }
+}
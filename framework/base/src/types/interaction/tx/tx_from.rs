@@ -0,0 +1,67 @@
+use crate::types::{heap::Address, ManagedAddress};
+
+use super::TxEnv;
+
+/// Marks the sender of a transaction.
+pub trait TxFrom<Env>
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, env: &Env) -> ManagedAddress<Env::Api>;
+}
+
+/// Marks an explicitly specified sender, as opposed to the default one provided by the environment.
+pub trait TxFromSpecified<Env>: TxFrom<Env>
+where
+    Env: TxEnv,
+{
+}
+
+impl<Env> TxFrom<Env> for ()
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, env: &Env) -> ManagedAddress<Env::Api> {
+        env.resolve_sender_address()
+    }
+}
+
+impl<Env> TxFrom<Env> for ManagedAddress<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        self.clone()
+    }
+}
+impl<Env> TxFromSpecified<Env> for ManagedAddress<Env::Api> where Env: TxEnv {}
+
+impl<Env> TxFrom<Env> for &ManagedAddress<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        (*self).clone()
+    }
+}
+impl<Env> TxFromSpecified<Env> for &ManagedAddress<Env::Api> where Env: TxEnv {}
+
+impl<Env> TxFrom<Env> for Address
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        self.into()
+    }
+}
+impl<Env> TxFromSpecified<Env> for Address where Env: TxEnv {}
+
+impl<Env> TxFrom<Env> for &Address
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        (*self).into()
+    }
+}
+impl<Env> TxFromSpecified<Env> for &Address where Env: TxEnv {}
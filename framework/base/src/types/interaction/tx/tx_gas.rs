@@ -0,0 +1,41 @@
+use super::TxEnv;
+
+/// Marks the gas limit of a transaction. The unit type means that the environment default is used.
+pub trait TxGas<Env>
+where
+    Env: TxEnv,
+{
+    fn is_explicit(&self) -> bool;
+
+    fn resolve_gas(&self, env: &Env) -> u64;
+}
+
+impl<Env> TxGas<Env> for ()
+where
+    Env: TxEnv,
+{
+    fn is_explicit(&self) -> bool {
+        false
+    }
+
+    fn resolve_gas(&self, env: &Env) -> u64 {
+        env.default_gas()
+    }
+}
+
+/// Gas limit set explicitly on the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExplicitGas(pub u64);
+
+impl<Env> TxGas<Env> for ExplicitGas
+where
+    Env: TxEnv,
+{
+    fn is_explicit(&self) -> bool {
+        true
+    }
+
+    fn resolve_gas(&self, _env: &Env) -> u64 {
+        self.0
+    }
+}
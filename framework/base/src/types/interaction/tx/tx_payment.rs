@@ -0,0 +1,240 @@
+use crate::{
+    api::ManagedTypeApi,
+    types::{
+        BigUint, EgldOrEsdtTokenPayment, EgldOrMultiEsdtPayment, EsdtTokenPayment, FunctionCall,
+        ManagedAddress, ManagedVec,
+    },
+};
+
+use super::TxEnv;
+
+/// Marks the payment of a transaction. The unit type means no payment.
+pub trait TxPayment<Env>
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool;
+
+    /// Brings any payment to the same form, which every environment knows how to handle.
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api>;
+}
+
+/// Payments allowed when deploying contracts.
+pub trait TxPaymentEgldOnly<Env>: TxPayment<Env>
+where
+    Env: TxEnv,
+{
+}
+
+impl<Env> TxPaymentEgldOnly<Env> for () where Env: TxEnv {}
+impl<Env> TxPaymentEgldOnly<Env> for EgldPayment<Env::Api> where Env: TxEnv {}
+
+/// Simple EGLD payment.
+pub struct EgldPayment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub value: BigUint<Api>,
+}
+
+/// Any possible payment: EGLD, ESDT, or both.
+///
+/// The blockchain does not allow both at once, but keeping them separate makes conversions simple.
+pub struct FullPaymentData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub egld: Option<EgldPayment<Api>>,
+    pub multi_esdt: ManagedVec<Api, EsdtTokenPayment<Api>>,
+}
+
+/// A transaction with EGLD value only, any ESDT transfers having been converted to builtin function calls.
+pub struct PaymentConversionResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub to: ManagedAddress<Api>,
+    pub egld_payment: BigUint<Api>,
+    pub function_call: FunctionCall<Api>,
+}
+
+impl<Api> Default for FullPaymentData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    fn default() -> Self {
+        FullPaymentData {
+            egld: None,
+            multi_esdt: ManagedVec::new(),
+        }
+    }
+}
+
+impl<Api> FullPaymentData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub fn egld_value(&self) -> BigUint<Api> {
+        match &self.egld {
+            Some(egld) => egld.value.clone(),
+            None => BigUint::zero(),
+        }
+    }
+
+    /// ESDT transfers are converted to the corresponding builtin function calls.
+    ///
+    /// Single fungible transfers are sent directly to the recipient,
+    /// NFT and multi-transfers are sent to the sender, with the real recipient as argument.
+    pub fn convert_tx_data(
+        self,
+        from: &ManagedAddress<Api>,
+        to: ManagedAddress<Api>,
+        function_call: FunctionCall<Api>,
+    ) -> PaymentConversionResult<Api> {
+        let egld_payment = self.egld_value();
+        match self.multi_esdt.len() {
+            0 => PaymentConversionResult {
+                to,
+                egld_payment,
+                function_call,
+            },
+            1 => {
+                let payment = self.multi_esdt.get(0);
+                if payment.token_nonce == 0 {
+                    PaymentConversionResult {
+                        to,
+                        egld_payment: BigUint::zero(),
+                        function_call: function_call
+                            .convert_to_single_transfer_fungible_call(payment),
+                    }
+                } else {
+                    PaymentConversionResult {
+                        to: from.clone(),
+                        egld_payment: BigUint::zero(),
+                        function_call: function_call
+                            .convert_to_single_transfer_nft_call(&to, payment),
+                    }
+                }
+            },
+            _ => PaymentConversionResult {
+                to: from.clone(),
+                egld_payment: BigUint::zero(),
+                function_call: function_call
+                    .convert_to_multi_transfer_esdt_call(&to, self.multi_esdt),
+            },
+        }
+    }
+}
+
+impl<Env> TxPayment<Env> for ()
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool {
+        true
+    }
+
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api> {
+        FullPaymentData::default()
+    }
+}
+
+impl<Env> TxPayment<Env> for EgldPayment<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool {
+        self.value == 0u32
+    }
+
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api> {
+        FullPaymentData {
+            egld: Some(self),
+            multi_esdt: ManagedVec::new(),
+        }
+    }
+}
+
+impl<Env> TxPayment<Env> for EsdtTokenPayment<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool {
+        self.amount == 0u32
+    }
+
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api> {
+        FullPaymentData {
+            egld: None,
+            multi_esdt: ManagedVec::from_single_item(self),
+        }
+    }
+}
+
+impl<Env> TxPayment<Env> for ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api> {
+        FullPaymentData {
+            egld: None,
+            multi_esdt: self,
+        }
+    }
+}
+
+impl<Env> TxPayment<Env> for EgldOrEsdtTokenPayment<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool {
+        self.amount == 0u32
+    }
+
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api> {
+        if self.token_identifier.is_egld() {
+            FullPaymentData {
+                egld: Some(EgldPayment { value: self.amount }),
+                multi_esdt: ManagedVec::new(),
+            }
+        } else {
+            FullPaymentData {
+                egld: None,
+                multi_esdt: ManagedVec::from_single_item(EsdtTokenPayment::new(
+                    self.token_identifier.unwrap_esdt(),
+                    self.token_nonce,
+                    self.amount,
+                )),
+            }
+        }
+    }
+}
+
+impl<Env> TxPayment<Env> for EgldOrMultiEsdtPayment<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn is_no_payment(&self) -> bool {
+        match self {
+            EgldOrMultiEsdtPayment::Egld(egld_amount) => *egld_amount == 0u32,
+            EgldOrMultiEsdtPayment::MultiEsdt(payments) => payments.is_empty(),
+        }
+    }
+
+    fn into_full_payment_data(self) -> FullPaymentData<Env::Api> {
+        match self {
+            EgldOrMultiEsdtPayment::Egld(egld_amount) => FullPaymentData {
+                egld: Some(EgldPayment { value: egld_amount }),
+                multi_esdt: ManagedVec::new(),
+            },
+            EgldOrMultiEsdtPayment::MultiEsdt(payments) => FullPaymentData {
+                egld: None,
+                multi_esdt: payments,
+            },
+        }
+    }
+}
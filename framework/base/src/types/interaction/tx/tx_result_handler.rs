@@ -0,0 +1,184 @@
+use core::marker::PhantomData;
+
+use super::TxEnv;
+
+/// Decides what a transaction returns after it is executed.
+///
+/// It also keeps the type of the result produced by the endpoint, as declared in the proxy,
+/// so that the requested results can be checked against it at compile time.
+pub trait TxResultHandler<Env>
+where
+    Env: TxEnv,
+{
+    type OriginalResult;
+}
+
+impl<Env> TxResultHandler<Env> for ()
+where
+    Env: TxEnv,
+{
+    type OriginalResult = ();
+}
+
+/// Only holds the type of the endpoint result, nothing is returned.
+pub struct OriginalResultMarker<O> {
+    _phantom: PhantomData<O>,
+}
+
+impl<O> OriginalResultMarker<O> {
+    pub fn new() -> Self {
+        OriginalResultMarker {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<O> Default for OriginalResultMarker<O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Env, O> TxResultHandler<Env> for OriginalResultMarker<O>
+where
+    Env: TxEnv,
+{
+    type OriginalResult = O;
+}
+
+/// Holds the type of the endpoint result, and the item (or tuple of items) that decide what gets returned.
+pub struct ReturnsHandler<O, Item> {
+    pub item: Item,
+    _phantom: PhantomData<O>,
+}
+
+impl<O, Item> ReturnsHandler<O, Item> {
+    pub fn new(item: Item) -> Self {
+        ReturnsHandler {
+            item,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Env, O, Item> TxResultHandler<Env> for ReturnsHandler<O, Item>
+where
+    Env: TxEnv,
+{
+    type OriginalResult = O;
+}
+
+/// Extracts one returned value out of the raw result of a transaction.
+///
+/// The raw result type is specific to each environment and to each kind of transaction,
+/// e.g. in contracts a synchronous call only produces the raw results, while a deploy also produces a new address.
+pub trait RHListItem<RawResult, Env, Original>
+where
+    Env: TxEnv,
+{
+    type Returns;
+
+    fn item_process_result(self, raw_result: &RawResult) -> Self::Returns;
+}
+
+impl<RawResult, Env, Original, A, B> RHListItem<RawResult, Env, Original> for (A, B)
+where
+    Env: TxEnv,
+    A: RHListItem<RawResult, Env, Original>,
+    B: RHListItem<RawResult, Env, Original>,
+{
+    type Returns = (A::Returns, B::Returns);
+
+    fn item_process_result(self, raw_result: &RawResult) -> Self::Returns {
+        (
+            self.0.item_process_result(raw_result),
+            self.1.item_process_result(raw_result),
+        )
+    }
+}
+
+impl<RawResult, Env, Original, A, B, C> RHListItem<RawResult, Env, Original> for (A, B, C)
+where
+    Env: TxEnv,
+    A: RHListItem<RawResult, Env, Original>,
+    B: RHListItem<RawResult, Env, Original>,
+    C: RHListItem<RawResult, Env, Original>,
+{
+    type Returns = (A::Returns, B::Returns, C::Returns);
+
+    fn item_process_result(self, raw_result: &RawResult) -> Self::Returns {
+        (
+            self.0.item_process_result(raw_result),
+            self.1.item_process_result(raw_result),
+            self.2.item_process_result(raw_result),
+        )
+    }
+}
+
+/// Result handlers that can process the raw result of a given type.
+pub trait TxRunnableResultHandler<RawResult, Env>: TxResultHandler<Env>
+where
+    Env: TxEnv,
+{
+    type Returns;
+
+    fn process_result(self, raw_result: &RawResult) -> Self::Returns;
+}
+
+impl<RawResult, Env> TxRunnableResultHandler<RawResult, Env> for ()
+where
+    Env: TxEnv,
+{
+    type Returns = ();
+
+    fn process_result(self, _raw_result: &RawResult) -> Self::Returns {}
+}
+
+impl<RawResult, Env, O> TxRunnableResultHandler<RawResult, Env> for OriginalResultMarker<O>
+where
+    Env: TxEnv,
+{
+    type Returns = ();
+
+    fn process_result(self, _raw_result: &RawResult) -> Self::Returns {}
+}
+
+impl<RawResult, Env, O, Item> TxRunnableResultHandler<RawResult, Env> for ReturnsHandler<O, Item>
+where
+    Env: TxEnv,
+    Item: RHListItem<RawResult, Env, O>,
+{
+    type Returns = Item::Returns;
+
+    fn process_result(self, raw_result: &RawResult) -> Self::Returns {
+        self.item.item_process_result(raw_result)
+    }
+}
+
+/// Returns the endpoint result, decoded as the type declared in the proxy.
+pub struct ReturnsResult;
+
+/// Returns the endpoint result, decoded as any type compatible with the one declared in the proxy.
+pub struct ReturnsResultAs<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T> ReturnsResultAs<T> {
+    pub fn new() -> Self {
+        ReturnsResultAs {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ReturnsResultAs<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the raw endpoint results, without decoding them.
+pub struct ReturnsRawResult;
+
+/// Returns the address of the newly deployed contract, as a managed address.
+pub struct ReturnsNewManagedAddress;
@@ -0,0 +1,60 @@
+use crate::types::{heap::Address, ManagedAddress};
+
+use super::TxEnv;
+
+/// Marks the recipient of a transaction. The unit type stands for "no recipient", e.g. for deploys.
+pub trait TxTo<Env>
+where
+    Env: TxEnv,
+{
+}
+
+impl<Env> TxTo<Env> for () where Env: TxEnv {}
+
+/// Marks an explicitly specified recipient.
+pub trait TxToSpecified<Env>: TxTo<Env>
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, env: &Env) -> ManagedAddress<Env::Api>;
+}
+
+impl<Env> TxTo<Env> for ManagedAddress<Env::Api> where Env: TxEnv {}
+impl<Env> TxToSpecified<Env> for ManagedAddress<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        self.clone()
+    }
+}
+
+impl<Env> TxTo<Env> for &ManagedAddress<Env::Api> where Env: TxEnv {}
+impl<Env> TxToSpecified<Env> for &ManagedAddress<Env::Api>
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        (*self).clone()
+    }
+}
+
+impl<Env> TxTo<Env> for Address where Env: TxEnv {}
+impl<Env> TxToSpecified<Env> for Address
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        self.into()
+    }
+}
+
+impl<Env> TxTo<Env> for &Address where Env: TxEnv {}
+impl<Env> TxToSpecified<Env> for &Address
+where
+    Env: TxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<Env::Api> {
+        (*self).into()
+    }
+}
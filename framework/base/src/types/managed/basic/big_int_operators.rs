@@ -3,7 +3,8 @@ use core::ops::{
 };
 
 use crate::{
-    api::{use_raw_handle, BigIntApiImpl, ManagedTypeApi, StaticVarApiImpl},
+    api::{use_raw_handle, BigIntApiImpl, ErrorApiImpl, ManagedTypeApi, StaticVarApiImpl},
+    err_msg,
     types::{BigInt, BigUint, ManagedType, Sign},
 };
 
@@ -123,3 +124,37 @@ impl<M: ManagedTypeApi> Neg for BigInt<M> {
         BigInt::from_handle(result_handle)
     }
 }
+
+impl<M: ManagedTypeApi> BigInt<M> {
+    /// Division that returns `None` instead of signalling an error when dividing by zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if *other == 0i64 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+
+    /// Greatest common divisor of the absolute values. `gcd(0, 0)` is 0.
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> BigUint<M> {
+        let result_handle: M::BigIntHandle = use_raw_handle(M::static_var_api_impl().next_handle());
+        M::managed_type_impl().bi_gcd(
+            result_handle.clone(),
+            self.handle.clone(),
+            other.handle.clone(),
+        );
+        BigUint::from_handle(result_handle)
+    }
+
+    /// Restricts the value to the `[min, max]` interval.
+    ///
+    /// Unlike `Ord::clamp`, signals a proper error if `min > max`.
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        if min > max {
+            M::error_api_impl().signal_error(err_msg::BIG_INT_CLAMP_INVALID_BOUNDS);
+        }
+        self.max(min).min(max)
+    }
+}
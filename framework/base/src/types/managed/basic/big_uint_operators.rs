@@ -1,10 +1,17 @@
 use crate::{
-    api::{const_handles, use_raw_handle, BigIntApiImpl, ManagedTypeApi, StaticVarApiImpl},
-    types::{BigUint, ManagedType},
+    api::{
+        const_handles, use_raw_handle, BigIntApiImpl, ErrorApiImpl, ManagedTypeApi,
+        StaticVarApiImpl,
+    },
+    err_msg,
+    types::{BigUint, ManagedType, RoundingMode},
 };
-use core::ops::{
-    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
-    Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+use core::{
+    cmp::Ordering,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
 };
 
 macro_rules! binary_operator {
@@ -221,3 +228,96 @@ macro_rules! shift_assign_traits {
 
 shift_assign_traits! {ShrAssign, shr_assign, bi_shr}
 shift_assign_traits! {ShlAssign, shl_assign, bi_shl}
+
+impl<M: ManagedTypeApi> BigUint<M> {
+    /// Subtraction that returns `None` instead of signalling an error when the result would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self < other {
+            None
+        } else {
+            Some(self - other)
+        }
+    }
+
+    /// Division that returns `None` instead of signalling an error when dividing by zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+
+    /// Division, with an explicit rounding of the result.
+    pub fn div_with_rounding(&self, other: &Self, rounding: RoundingMode) -> Self {
+        if *other == 0u32 {
+            M::error_api_impl().signal_error(err_msg::BIG_INT_DIVISION_BY_ZERO);
+        }
+
+        let quotient = self / other;
+        let remainder = self % other;
+        if remainder == 0u32 {
+            return quotient;
+        }
+
+        let round_up = match rounding {
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            RoundingMode::HalfUp => &(remainder * 2u32) >= other,
+            RoundingMode::HalfEven => match (remainder * 2u32).cmp(other) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => &quotient % 2u32 == 1u32,
+            },
+        };
+
+        if round_up {
+            quotient + 1u32
+        } else {
+            quotient
+        }
+    }
+
+    /// Computes `self * mul / div`, with an explicit rounding of the result.
+    ///
+    /// The intermediate product is not truncated, so no precision is lost before the division.
+    pub fn mul_div(&self, mul: &Self, div: &Self, rounding: RoundingMode) -> Self {
+        (self * mul).div_with_rounding(div, rounding)
+    }
+
+    /// Computes `self ^ exp mod modulus`, without computing the full power.
+    #[must_use]
+    pub fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
+        let result_handle: M::BigIntHandle = use_raw_handle(M::static_var_api_impl().next_handle());
+        M::managed_type_impl().bi_mod_pow(
+            result_handle.clone(),
+            self.handle.clone(),
+            exp.handle.clone(),
+            modulus.handle.clone(),
+        );
+        BigUint::from_handle(result_handle)
+    }
+
+    /// Greatest common divisor. `gcd(0, 0)` is 0.
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        let result_handle: M::BigIntHandle = use_raw_handle(M::static_var_api_impl().next_handle());
+        M::managed_type_impl().bi_gcd(
+            result_handle.clone(),
+            self.handle.clone(),
+            other.handle.clone(),
+        );
+        BigUint::from_handle(result_handle)
+    }
+
+    /// Restricts the value to the `[min, max]` interval.
+    ///
+    /// Unlike `Ord::clamp`, signals a proper error if `min > max`.
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        if min > max {
+            M::error_api_impl().signal_error(err_msg::BIG_INT_CLAMP_INVALID_BOUNDS);
+        }
+        self.max(min).min(max)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    api::{BigIntApiImpl, ManagedBufferApiImpl, ManagedTypeApi},
+    formatter::hex_util::hex_digits_to_byte,
+    types::{BigUint, ManagedVec},
+};
+
+use super::ManagedBuffer;
+
+/// Number of bytes loaded at a time when scanning or transforming a buffer.
+const SCAN_BATCH_SIZE: usize = 64;
+
+/// Multiple of 3, so that only the last batch can produce padding.
+const BASE64_ENCODE_BATCH_SIZE: usize = 48;
+
+/// Multiple of 4, one encoded group never spans two batches.
+const BASE64_DECODE_BATCH_SIZE: usize = 64;
+
+/// 10^9 still fits in a `u32`.
+const DECIMAL_BATCH_SIZE: usize = 9;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_PADDING: u8 = b'=';
+
+fn base64_digit_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'A'..=b'Z' => Some(digit - b'A'),
+        b'a'..=b'z' => Some(digit - b'a' + 26),
+        b'0'..=b'9' => Some(digit - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Encodes up to 3 bytes as a group of 4 base64 digits, padding if needed.
+fn encode_base64_group(group: &[u8]) -> [u8; 4] {
+    let b0 = group[0];
+    let b1 = group.get(1).copied().unwrap_or_default();
+    let b2 = group.get(2).copied().unwrap_or_default();
+    let mut result = [
+        BASE64_ALPHABET[(b0 >> 2) as usize],
+        BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize],
+        BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize],
+        BASE64_ALPHABET[(b2 & 0x3f) as usize],
+    ];
+    if group.len() < 3 {
+        result[3] = BASE64_PADDING;
+    }
+    if group.len() < 2 {
+        result[2] = BASE64_PADDING;
+    }
+    result
+}
+
+/// Decodes a group of 4 base64 digits. Padding is only accepted in the last group.
+///
+/// Returns the decoded bytes and how many of them are meaningful.
+fn decode_base64_group(group: &[u8], is_last: bool) -> Option<([u8; 3], usize)> {
+    if group.len() != 4 {
+        return None;
+    }
+
+    let num_padding = group
+        .iter()
+        .rev()
+        .take_while(|&&d| d == BASE64_PADDING)
+        .count();
+    if num_padding > 2 || (num_padding > 0 && !is_last) {
+        return None;
+    }
+
+    let mut sextets = [0u8; 4];
+    for (sextet, &digit) in sextets.iter_mut().zip(&group[..4 - num_padding]) {
+        *sextet = base64_digit_value(digit)?;
+    }
+
+    let decoded = [
+        (sextets[0] << 2) | (sextets[1] >> 4),
+        (sextets[1] << 4) | (sextets[2] >> 2),
+        (sextets[2] << 6) | sextets[3],
+    ];
+    Some((decoded, 3 - num_padding))
+}
+
+/// Text and byte utilities.
+///
+/// Hex encoding and decimal formatting are delegated to the VM,
+/// everything else works on the managed buffer in small batches,
+/// so that contracts never need to copy whole buffers into static arrays.
+impl<M: ManagedTypeApi> ManagedBuffer<M> {
+    /// Checks whether the bytes starting at `starting_position` are equal to `other`.
+    ///
+    /// Returns `false` if the range falls outside the buffer.
+    pub fn eq_range(&self, starting_position: usize, other: &ManagedBuffer<M>) -> bool {
+        match self.copy_slice(starting_position, other.len()) {
+            Some(slice) => &slice == other,
+            None => false,
+        }
+    }
+
+    pub fn starts_with(&self, prefix: &ManagedBuffer<M>) -> bool {
+        self.eq_range(0, prefix)
+    }
+
+    pub fn ends_with(&self, suffix: &ManagedBuffer<M>) -> bool {
+        let len = self.len();
+        let suffix_len = suffix.len();
+        suffix_len <= len && self.eq_range(len - suffix_len, suffix)
+    }
+
+    /// Position of the first occurrence of `needle`, if any.
+    ///
+    /// An empty needle is found at position 0.
+    pub fn find(&self, needle: &ManagedBuffer<M>) -> Option<usize> {
+        let len = self.len();
+        let needle_len = needle.len();
+        if needle_len > len {
+            return None;
+        }
+        if needle_len == 0 {
+            return Some(0);
+        }
+
+        let mut first_byte = [0u8; 1];
+        let _ = needle.load_slice(0, &mut first_byte);
+
+        let last_start = len - needle_len;
+        let mut batch = [0u8; SCAN_BATCH_SIZE];
+        let mut batch_start = 0;
+        while batch_start <= last_start {
+            let batch_len = core::cmp::min(SCAN_BATCH_SIZE, last_start + 1 - batch_start);
+            let loaded = &mut batch[..batch_len];
+            let _ = self.load_slice(batch_start, loaded);
+            for (offset, &byte) in loaded.iter().enumerate() {
+                let position = batch_start + offset;
+                if byte == first_byte[0] && self.eq_range(position, needle) {
+                    return Some(position);
+                }
+            }
+            batch_start += batch_len;
+        }
+        None
+    }
+
+    #[inline]
+    pub fn contains(&self, needle: &ManagedBuffer<M>) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Splits the buffer around each occurrence of the delimiter byte.
+    ///
+    /// Behaves like `slice::split`: an empty buffer yields one empty part,
+    /// and consecutive delimiters yield empty parts between them.
+    pub fn split(&self, delimiter: u8) -> ManagedVec<M, ManagedBuffer<M>> {
+        let mut parts = ManagedVec::new();
+        let mut part_start = 0;
+        let mut batch_start = 0;
+        self.for_each_batch::<SCAN_BATCH_SIZE, _>(|batch| {
+            for (offset, &byte) in batch.iter().enumerate() {
+                if byte == delimiter {
+                    let position = batch_start + offset;
+                    parts.push(self.copy_range(part_start, position));
+                    part_start = position + 1;
+                }
+            }
+            batch_start += batch.len();
+        });
+        parts.push(self.copy_range(part_start, self.len()));
+        parts
+    }
+
+    fn copy_range(&self, start: usize, end: usize) -> ManagedBuffer<M> {
+        self.copy_slice(start, end - start).unwrap_or_default()
+    }
+
+    pub fn to_ascii_lowercase(&self) -> ManagedBuffer<M> {
+        self.map_bytes(|byte| byte.to_ascii_lowercase())
+    }
+
+    pub fn to_ascii_uppercase(&self) -> ManagedBuffer<M> {
+        self.map_bytes(|byte| byte.to_ascii_uppercase())
+    }
+
+    fn map_bytes<F: Fn(u8) -> u8>(&self, f: F) -> ManagedBuffer<M> {
+        let mut result = ManagedBuffer::new();
+        self.for_each_batch::<SCAN_BATCH_SIZE, _>(|batch| {
+            let mut mapped = [0u8; SCAN_BATCH_SIZE];
+            for (dest, &byte) in mapped.iter_mut().zip(batch) {
+                *dest = f(byte);
+            }
+            result.append_bytes(&mapped[..batch.len()]);
+        });
+        result
+    }
+
+    /// Lowercase hex representation, computed by the VM.
+    pub fn to_hex(&self) -> ManagedBuffer<M> {
+        let result: ManagedBuffer<M> = ManagedBuffer::new();
+        M::managed_type_impl().mb_to_hex(self.handle.clone(), result.handle.clone());
+        result
+    }
+
+    /// Decodes a hex string, accepting both lowercase and uppercase digits.
+    ///
+    /// Returns `None` on odd length or invalid digits.
+    pub fn from_hex(hex: &ManagedBuffer<M>) -> Option<ManagedBuffer<M>> {
+        if hex.len() % 2 == 1 {
+            return None;
+        }
+
+        let mut result = ManagedBuffer::new();
+        let mut valid = true;
+        hex.for_each_batch::<SCAN_BATCH_SIZE, _>(|batch| {
+            if !valid {
+                return;
+            }
+            let mut decoded = [0u8; SCAN_BATCH_SIZE / 2];
+            for (dest, digits) in decoded.iter_mut().zip(batch.chunks(2)) {
+                match hex_digits_to_byte(
+                    digits[0].to_ascii_lowercase(),
+                    digits[1].to_ascii_lowercase(),
+                ) {
+                    Some(byte) => *dest = byte,
+                    None => {
+                        valid = false;
+                        return;
+                    },
+                }
+            }
+            result.append_bytes(&decoded[..batch.len() / 2]);
+        });
+
+        if valid {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Standard (RFC 4648) base64 encoding, with padding.
+    pub fn to_base64(&self) -> ManagedBuffer<M> {
+        let mut result = ManagedBuffer::new();
+        self.for_each_batch::<BASE64_ENCODE_BATCH_SIZE, _>(|batch| {
+            let mut encoded = [0u8; BASE64_ENCODE_BATCH_SIZE / 3 * 4];
+            let mut encoded_len = 0;
+            for group in batch.chunks(3) {
+                encoded[encoded_len..encoded_len + 4].copy_from_slice(&encode_base64_group(group));
+                encoded_len += 4;
+            }
+            result.append_bytes(&encoded[..encoded_len]);
+        });
+        result
+    }
+
+    /// Decodes standard (RFC 4648) base64, padding required.
+    ///
+    /// Returns `None` if the input is not valid base64.
+    pub fn from_base64(encoded: &ManagedBuffer<M>) -> Option<ManagedBuffer<M>> {
+        let encoded_len = encoded.len();
+        let mut result = ManagedBuffer::new();
+        let mut valid = true;
+        let mut batch_start = 0;
+        encoded.for_each_batch::<BASE64_DECODE_BATCH_SIZE, _>(|batch| {
+            if !valid {
+                return;
+            }
+            let mut decoded = [0u8; BASE64_DECODE_BATCH_SIZE / 4 * 3];
+            let mut decoded_len = 0;
+            for (group_index, group) in batch.chunks(4).enumerate() {
+                let is_last = batch_start + (group_index + 1) * 4 == encoded_len;
+                match decode_base64_group(group, is_last) {
+                    Some((bytes, num_bytes)) => {
+                        decoded[decoded_len..decoded_len + num_bytes]
+                            .copy_from_slice(&bytes[..num_bytes]);
+                        decoded_len += num_bytes;
+                    },
+                    None => {
+                        valid = false;
+                        return;
+                    },
+                }
+            }
+            result.append_bytes(&decoded[..decoded_len]);
+            batch_start += batch.len();
+        });
+
+        if valid {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Decimal representation of a `BigUint`, computed by the VM.
+    pub fn from_big_uint_decimal(value: &BigUint<M>) -> ManagedBuffer<M> {
+        let result: ManagedBuffer<M> = ManagedBuffer::new();
+        M::managed_type_impl().bi_to_string(value.handle.clone(), result.handle.clone());
+        result
+    }
+
+    /// Parses the buffer as an unsigned decimal number.
+    ///
+    /// Returns `None` if the buffer is empty or contains anything other than ASCII digits.
+    pub fn parse_as_big_uint_decimal(&self) -> Option<BigUint<M>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut result = BigUint::zero();
+        let mut valid = true;
+        self.for_each_batch::<DECIMAL_BATCH_SIZE, _>(|batch| {
+            if !valid {
+                return;
+            }
+            let mut chunk = 0u32;
+            for &digit in batch {
+                if !digit.is_ascii_digit() {
+                    valid = false;
+                    return;
+                }
+                chunk = chunk * 10 + (digit - b'0') as u32;
+            }
+            result *= 10u32.pow(batch.len() as u32);
+            result += chunk;
+        });
+
+        if valid {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
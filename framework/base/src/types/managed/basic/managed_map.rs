@@ -1,22 +1,57 @@
+use core::marker::PhantomData;
+
 use crate::{
-    api::{use_raw_handle, ManagedMapApiImpl, ManagedTypeApi, StaticVarApiImpl},
+    api::{use_raw_handle, ErrorApiImpl, ManagedMapApiImpl, ManagedTypeApi, StaticVarApiImpl},
+    codec::{TopDecode, TopEncode},
+    contract_base::ManagedSerializer,
+    err_msg,
     types::ManagedType,
 };
 
 use super::ManagedBuffer;
 
-/// A byte buffer managed by an external API.
+/// Size of the key indexes and of the length, as stored in the underlying VM map.
+const INDEX_LEN: usize = 4;
+
+/// Prefix of the entries holding the key index and the encoded value, by encoded key.
+const VALUE_PREFIX: u8 = b'v';
+
+/// Prefix of the entries holding the encoded keys, by key index.
+const KEY_PREFIX: u8 = b'k';
+
+/// Key of the entry holding the number of elements.
+const LEN_KEY: &[u8] = b"len";
+
+/// A map with typed keys and values, held by the VM.
+///
+/// Keys and values are top-encoded. Lookups, insertions and removals are all performed by the VM,
+/// so they do not depend on the number of entries.
+///
+/// Besides the values, the VM map also holds the encoded keys, by position, and the number of entries,
+/// to provide `len` and iteration. Each value is stored together with the position of its key,
+/// so that removing an entry can move the last key into the freed position, instead of searching for it.
+/// Because of this, the iteration order is the insertion order, as long as nothing gets removed.
 #[repr(transparent)]
-pub struct ManagedMap<M: ManagedTypeApi> {
+pub struct ManagedMap<M, K = ManagedBuffer<M>, V = ManagedBuffer<M>>
+where
+    M: ManagedTypeApi,
+{
     pub(crate) handle: M::ManagedMapHandle,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl<M: ManagedTypeApi> ManagedType<M> for ManagedMap<M> {
+impl<M, K, V> ManagedType<M> for ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+{
     type OwnHandle = M::ManagedMapHandle;
 
     #[inline]
     fn from_handle(handle: M::ManagedMapHandle) -> Self {
-        ManagedMap { handle }
+        ManagedMap {
+            handle,
+            _phantom: PhantomData,
+        }
     }
 
     fn get_handle(&self) -> M::ManagedMapHandle {
@@ -28,48 +63,494 @@ impl<M: ManagedTypeApi> ManagedType<M> for ManagedMap<M> {
     }
 }
 
-impl<M: ManagedTypeApi> ManagedMap<M> {
+impl<M, K, V> ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+{
     pub fn new() -> Self {
         let new_handle = M::managed_type_impl().mm_new();
         ManagedMap::from_handle(new_handle)
     }
 }
 
-impl<M: ManagedTypeApi> Default for ManagedMap<M> {
+impl<M, K, V> Default for ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+{
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<M: ManagedTypeApi> ManagedMap<M> {
-    pub fn get(&self, key: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+impl<M, K, V> ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    fn raw_get(&self, raw_key: &ManagedBuffer<M>) -> ManagedBuffer<M> {
         let new_handle: M::ManagedBufferHandle =
             use_raw_handle(M::static_var_api_impl().next_handle());
-        M::managed_type_impl().mm_get(self.handle.clone(), key.handle.clone(), new_handle.clone());
+        M::managed_type_impl().mm_get(
+            self.handle.clone(),
+            raw_key.handle.clone(),
+            new_handle.clone(),
+        );
         ManagedBuffer::from_handle(new_handle)
     }
 
-    pub fn put(&mut self, key: &ManagedBuffer<M>, value: &ManagedBuffer<M>) {
+    fn raw_put(&mut self, raw_key: &ManagedBuffer<M>, raw_value: &ManagedBuffer<M>) {
         M::managed_type_impl().mm_put(
             self.handle.clone(),
-            key.handle.clone(),
-            value.handle.clone(),
+            raw_key.handle.clone(),
+            raw_value.handle.clone(),
         );
     }
 
-    pub fn remove(&mut self, key: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    fn raw_remove(&mut self, raw_key: &ManagedBuffer<M>) {
         let new_handle: M::ManagedBufferHandle =
             use_raw_handle(M::static_var_api_impl().next_handle());
-        M::managed_type_impl().mm_remove(
-            self.handle.clone(),
-            key.handle.clone(),
-            new_handle.clone(),
-        );
-        ManagedBuffer::from_handle(new_handle)
+        M::managed_type_impl().mm_remove(self.handle.clone(), raw_key.handle.clone(), new_handle);
+    }
+
+    fn raw_contains(&self, raw_key: &ManagedBuffer<M>) -> bool {
+        M::managed_type_impl().mm_contains(self.handle.clone(), raw_key.handle.clone())
+    }
+
+    fn value_key(encoded_key: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+        let mut raw_key = ManagedBuffer::new_from_bytes(&[VALUE_PREFIX]);
+        raw_key.append(encoded_key);
+        raw_key
+    }
+
+    fn key_key(index: usize) -> ManagedBuffer<M> {
+        let mut raw_key = [KEY_PREFIX; 1 + INDEX_LEN];
+        raw_key[1..].copy_from_slice(&(index as u32).to_be_bytes()[..]);
+        ManagedBuffer::new_from_bytes(&raw_key[..])
+    }
+
+    fn encode_key(key: &K) -> ManagedBuffer<M> {
+        ManagedSerializer::<M>::new().top_encode_to_managed_buffer(key)
+    }
+
+    fn decode_key(encoded_key: &ManagedBuffer<M>) -> K {
+        ManagedSerializer::<M>::new().top_decode_from_managed_buffer(encoded_key)
+    }
+
+    fn decode_value(encoded_value: &ManagedBuffer<M>) -> V {
+        ManagedSerializer::<M>::new().top_decode_from_managed_buffer(encoded_value)
+    }
+
+    fn set_len(&mut self, len: usize) {
+        let raw_len = ManagedBuffer::new_from_bytes(&(len as u32).to_be_bytes()[..]);
+        self.raw_put(&ManagedBuffer::new_from_bytes(LEN_KEY), &raw_len);
+    }
+
+    /// Returns the key index and the encoded value, if the key is present.
+    fn get_entry(&self, encoded_key: &ManagedBuffer<M>) -> Option<(usize, ManagedBuffer<M>)> {
+        let raw_value = self.raw_get(&Self::value_key(encoded_key));
+        let encoded_value_len = raw_value.len().checked_sub(INDEX_LEN)?;
+
+        let mut index_bytes = [0u8; INDEX_LEN];
+        let _ = raw_value.load_slice(0, &mut index_bytes);
+        let encoded_value = raw_value
+            .copy_slice(INDEX_LEN, encoded_value_len)
+            .unwrap_or_default();
+        Some((u32::from_be_bytes(index_bytes) as usize, encoded_value))
+    }
+
+    /// Same as `get_entry`, for keys that are known to be present.
+    fn get_existing_entry(&self, encoded_key: &ManagedBuffer<M>) -> (usize, ManagedBuffer<M>) {
+        self.get_entry(encoded_key)
+            .unwrap_or_else(|| M::error_api_impl().signal_error(err_msg::MANAGED_MAP_KEY_NOT_FOUND))
+    }
+
+    fn put_entry(
+        &mut self,
+        encoded_key: &ManagedBuffer<M>,
+        index: usize,
+        encoded_value: &ManagedBuffer<M>,
+    ) {
+        let mut raw_value = ManagedBuffer::new_from_bytes(&(index as u32).to_be_bytes()[..]);
+        raw_value.append(encoded_value);
+        self.raw_put(&Self::value_key(encoded_key), &raw_value);
+    }
+
+    fn encoded_key_at(&self, index: usize) -> ManagedBuffer<M> {
+        self.raw_get(&Self::key_key(index))
+    }
+
+    fn entry_at(&self, index: usize) -> Option<(ManagedBuffer<M>, ManagedBuffer<M>)> {
+        if index >= self.len() {
+            return None;
+        }
+        let encoded_key = self.encoded_key_at(index);
+        let (_, encoded_value) = self.get_existing_entry(&encoded_key);
+        Some((encoded_key, encoded_value))
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        let raw_len = self.raw_get(&ManagedBuffer::new_from_bytes(LEN_KEY));
+        let mut len_bytes = [0u8; INDEX_LEN];
+        if raw_len.load_slice(0, &mut len_bytes).is_err() {
+            return 0;
+        }
+        u32::from_be_bytes(len_bytes) as usize
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.raw_contains(&Self::value_key(&Self::encode_key(k)))
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, M, K, V> {
+        if self.contains_key(&key) {
+            Entry::Occupied(OccupiedEntry {
+                key,
+                map: self,
+                _marker: PhantomData,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                key,
+                map: self,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    /// Gets the value associated with the key, if any.
+    pub fn get(&self, k: &K) -> Option<V> {
+        self.get_entry(&Self::encode_key(k))
+            .map(|(_, encoded_value)| Self::decode_value(&encoded_value))
+    }
+
+    /// Adds a key-value pair to the map.
+    ///
+    /// If the map did not have this key present, `None` is returned.
+    /// Otherwise, the value is updated, and the old value is returned.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        let encoded_key = Self::encode_key(&k);
+        let encoded_value = ManagedSerializer::<M>::new().top_encode_to_managed_buffer(&v);
+        match self.get_entry(&encoded_key) {
+            Some((index, old_encoded_value)) => {
+                self.put_entry(&encoded_key, index, &encoded_value);
+                Some(Self::decode_value(&old_encoded_value))
+            },
+            None => {
+                let index = self.len();
+                self.put_entry(&encoded_key, index, &encoded_value);
+                self.raw_put(&Self::key_key(index), &encoded_key);
+                self.set_len(index + 1);
+                None
+            },
+        }
+    }
+
+    /// Takes the value out of the map, if present.
+    ///
+    /// The last key takes the position of the removed one.
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        let encoded_key = Self::encode_key(k);
+        let (index, encoded_value) = self.get_entry(&encoded_key)?;
+        self.raw_remove(&Self::value_key(&encoded_key));
+
+        let last_index = self.len() - 1;
+        if index != last_index {
+            let moved_key = self.encoded_key_at(last_index);
+            let (_, moved_encoded_value) = self.get_existing_entry(&moved_key);
+            self.put_entry(&moved_key, index, &moved_encoded_value);
+            self.raw_put(&Self::key_key(index), &moved_key);
+        }
+        self.raw_remove(&Self::key_key(last_index));
+        self.set_len(last_index);
+
+        Some(Self::decode_value(&encoded_value))
+    }
+
+    /// An iterator visiting all keys.
+    pub fn keys(&self) -> Keys<'_, M, K, V> {
+        Keys {
+            map: self,
+            index: 0,
+        }
+    }
+
+    /// An iterator visiting all values, in the same order as the keys.
+    pub fn values(&self) -> Values<'_, M, K, V> {
+        Values {
+            map: self,
+            index: 0,
+        }
+    }
+
+    /// An iterator visiting all key-value pairs.
+    pub fn iter(&self) -> Iter<'_, M, K, V> {
+        Iter {
+            map: self,
+            index: 0,
+        }
+    }
+}
+
+pub struct Keys<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    map: &'a ManagedMap<M, K, V>,
+    index: usize,
+}
+
+impl<'a, M, K, V> Iterator for Keys<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        if self.index >= self.map.len() {
+            return None;
+        }
+        let encoded_key = self.map.encoded_key_at(self.index);
+        self.index += 1;
+        Some(ManagedMap::<M, K, V>::decode_key(&encoded_key))
+    }
+}
+
+pub struct Values<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    map: &'a ManagedMap<M, K, V>,
+    index: usize,
+}
+
+impl<'a, M, K, V> Iterator for Values<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<V> {
+        let (_, encoded_value) = self.map.entry_at(self.index)?;
+        self.index += 1;
+        Some(ManagedMap::<M, K, V>::decode_value(&encoded_value))
+    }
+}
+
+pub struct Iter<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    map: &'a ManagedMap<M, K, V>,
+    index: usize,
+}
+
+impl<'a, M, K, V> Iterator for Iter<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        let (encoded_key, encoded_value) = self.map.entry_at(self.index)?;
+        self.index += 1;
+        let key = ManagedMap::<M, K, V>::decode_key(&encoded_key);
+        let value = ManagedMap::<M, K, V>::decode_value(&encoded_value);
+        Some((key, value))
+    }
+}
+
+pub enum Entry<'a, M, K: 'a, V: 'a>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, M, K, V>),
+
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, M, K, V>),
+}
+
+/// A view into a vacant entry in a `ManagedMap`.
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, M, K: 'a, V: 'a>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    key: K,
+    map: &'a mut ManagedMap<M, K, V>,
+
+    // Be invariant in `K` and `V`
+    _marker: PhantomData<&'a mut (K, V)>,
+}
+
+/// A view into an occupied entry in a `ManagedMap`.
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, M, K: 'a, V: 'a>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode,
+    V: TopEncode + TopDecode,
+{
+    key: K,
+    map: &'a mut ManagedMap<M, K, V>,
+
+    // Be invariant in `K` and `V`
+    _marker: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, M, K, V> Entry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode + Clone,
+    V: TopEncode + TopDecode,
+{
+    /// Ensures a value is in the entry by inserting the default if empty, and returns
+    /// an `OccupiedEntry`.
+    pub fn or_insert(self, default: V) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns an `OccupiedEntry`.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match *self {
+            Entry::Occupied(ref entry) => entry.key(),
+            Entry::Vacant(ref entry) => entry.key(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.update(f);
+                Entry::Occupied(entry)
+            },
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, M, K, V: Default> Entry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode + Clone,
+    V: TopEncode + TopDecode,
+{
+    /// Ensures a value is in the entry by inserting the default value if empty,
+    /// and returns an `OccupiedEntry`.
+    pub fn or_default(self) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+impl<'a, M, K, V> VacantEntry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode + Clone,
+    V: TopEncode + TopDecode,
+{
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the VacantEntry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key,
+    /// and returns an `OccupiedEntry`.
+    pub fn insert(self, value: V) -> OccupiedEntry<'a, M, K, V> {
+        self.map.insert(self.key.clone(), value);
+        OccupiedEntry {
+            key: self.key,
+            map: self.map,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, M, K, V> OccupiedEntry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: TopEncode + TopDecode + Clone,
+    V: TopEncode + TopDecode,
+{
+    /// Gets a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take ownership of the key and value from the map.
+    pub fn remove_entry(self) -> (K, V) {
+        let value = self.map.remove(&self.key).unwrap();
+        (self.key, value)
+    }
+
+    /// Gets the value in the entry.
+    pub fn get(&self) -> V {
+        self.map.get(&self.key).unwrap()
+    }
+
+    /// Syntactic sugar, to more compactly express a get, update and set in one line.
+    /// Propagates the return value of the given function.
+    pub fn update<R, F: FnOnce(&mut V) -> R>(&mut self, f: F) -> R {
+        let mut value = self.get();
+        let result = f(&mut value);
+        self.map.insert(self.key.clone(), value);
+        result
+    }
+
+    /// Sets the value of the entry with the `OccupiedEntry`'s key,
+    /// and returns the entry's old value.
+    pub fn insert(self, value: V) -> V {
+        self.map.insert(self.key, value).unwrap()
     }
 
-    pub fn contains(&self, key: &ManagedBuffer<M>) -> bool {
-        M::managed_type_impl().mm_contains(self.handle.clone(), key.handle.clone())
+    /// Takes the value of the entry out of the map, and returns it.
+    pub fn remove(self) -> V {
+        self.map.remove(&self.key).unwrap()
     }
 }
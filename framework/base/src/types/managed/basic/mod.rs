@@ -12,6 +12,7 @@ mod big_uint_operators;
 mod cast_to_i64;
 mod elliptic_curve;
 mod managed_buffer;
+mod managed_buffer_text;
 
 pub use big_float::BigFloat;
 pub use big_int::BigInt;
@@ -21,6 +22,6 @@ pub use elliptic_curve::{EllipticCurve, EllipticCurveComponents};
 pub use managed_buffer::ManagedBuffer;
 
 #[cfg(feature = "managed-map")]
-mod managed_map;
+pub mod managed_map;
 #[cfg(feature = "managed-map")]
 pub use managed_map::ManagedMap;
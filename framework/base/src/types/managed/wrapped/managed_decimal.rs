@@ -0,0 +1,355 @@
+use crate::{
+    abi::{
+        StructFieldDescription, TypeAbi, TypeContents, TypeDescription, TypeDescriptionContainer,
+        TypeName,
+    },
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    types::{BigFloat, BigUint},
+};
+
+use crate::codec::{
+    DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
+    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+
+use alloc::{string::ToString, vec::Vec};
+use core::cmp::Ordering;
+
+/// Number of decimals, i.e. the scale of a `ManagedDecimal`.
+pub type NumDecimals = usize;
+
+/// Provides the scale of a `ManagedDecimal`, either at compile time, or at runtime.
+pub trait Decimals {
+    fn num_decimals(&self) -> NumDecimals;
+
+    /// 10 to the power of the number of decimals.
+    fn scaling_factor<M: ManagedTypeApi>(&self) -> BigUint<M> {
+        BigUint::from(10u64).pow(self.num_decimals() as u32)
+    }
+}
+
+/// The scale is only known at runtime, and is serialized together with the value.
+impl Decimals for NumDecimals {
+    fn num_decimals(&self) -> NumDecimals {
+        *self
+    }
+}
+
+/// The scale is part of the type, so it is not serialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstDecimals<const DECIMALS: NumDecimals>;
+
+impl<const DECIMALS: NumDecimals> Decimals for ConstDecimals<DECIMALS> {
+    fn num_decimals(&self) -> NumDecimals {
+        DECIMALS
+    }
+}
+
+/// How to handle the digits that get dropped when reducing the scale, or when dividing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Drops the extra digits, i.e. rounds towards zero.
+    Down,
+
+    /// Rounds away from zero whenever there is anything to drop.
+    Up,
+
+    /// Rounds to the nearest value, and away from zero when exactly halfway.
+    HalfUp,
+
+    /// Rounds to the nearest value, and to the even neighbour when exactly halfway ("banker's rounding").
+    HalfEven,
+}
+
+/// Unsigned fixed-point decimal number, with an explicit scale.
+///
+/// The value is kept as an integer number of units of `10^-scale`,
+/// e.g. 1.5 with 18 decimals is stored as `1_500_000_000_000_000_000`.
+///
+/// The scale can be fixed at compile time, with `ConstDecimals`,
+/// or chosen at runtime, with `NumDecimals`.
+#[derive(Debug, Clone)]
+pub struct ManagedDecimal<M: ManagedTypeApi, D: Decimals> {
+    data: BigUint<M>,
+    decimals: D,
+}
+
+impl<M: ManagedTypeApi, D: Decimals> ManagedDecimal<M, D> {
+    /// Creates a decimal from its raw units, e.g. `(1500, 3)` is 1.5.
+    pub fn from_raw_units(data: BigUint<M>, decimals: D) -> Self {
+        ManagedDecimal { data, decimals }
+    }
+
+    /// Creates a decimal with the given integer value, e.g. `(1, 3)` is 1.000.
+    pub fn from_integer(integer: BigUint<M>, decimals: D) -> Self {
+        let data = integer * decimals.scaling_factor::<M>();
+        ManagedDecimal { data, decimals }
+    }
+
+    /// The value, in units of `10^-scale`.
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.data
+    }
+
+    pub fn into_parts(self) -> (BigUint<M>, D) {
+        (self.data, self.decimals)
+    }
+
+    pub fn decimals(&self) -> &D {
+        &self.decimals
+    }
+
+    pub fn scale(&self) -> NumDecimals {
+        self.decimals.num_decimals()
+    }
+
+    pub fn scaling_factor(&self) -> BigUint<M> {
+        self.decimals.scaling_factor()
+    }
+
+    /// The integer part, the decimals are dropped.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.data / &self.scaling_factor()
+    }
+
+    /// Converts to another scale, dropping any extra digits.
+    pub fn rescale<T: Decimals>(&self, scale_to: T) -> ManagedDecimal<M, T> {
+        self.rescale_with_rounding(scale_to, RoundingMode::Down)
+    }
+
+    /// Converts to another scale. Increasing the scale is always exact,
+    /// reducing it rounds according to the given rounding mode.
+    pub fn rescale_with_rounding<T: Decimals>(
+        &self,
+        scale_to: T,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, T> {
+        let from_scale = self.scale();
+        let to_scale = scale_to.num_decimals();
+        let data = match from_scale.cmp(&to_scale) {
+            Ordering::Equal => self.data.clone(),
+            Ordering::Less => &self.data * &pow10(to_scale - from_scale),
+            Ordering::Greater => self
+                .data
+                .div_with_rounding(&pow10(from_scale - to_scale), rounding),
+        };
+        ManagedDecimal::from_raw_units(data, scale_to)
+    }
+
+    /// Multiplies, keeping the scale of `self`.
+    pub fn mul_with_rounding<T: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, T>,
+        rounding: RoundingMode,
+    ) -> Self
+    where
+        D: Clone,
+    {
+        let data = self
+            .data
+            .mul_div(&other.data, &other.scaling_factor(), rounding);
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    /// Divides, keeping the scale of `self`.
+    pub fn div_with_rounding<T: Decimals>(
+        &self,
+        other: &ManagedDecimal<M, T>,
+        rounding: RoundingMode,
+    ) -> Self
+    where
+        D: Clone,
+    {
+        let data = self
+            .data
+            .mul_div(&other.scaling_factor(), &other.data, rounding);
+        ManagedDecimal::from_raw_units(data, self.decimals.clone())
+    }
+
+    pub fn to_big_float(&self) -> BigFloat<M> {
+        BigFloat::from(&self.data) / BigFloat::from(self.scaling_factor())
+    }
+
+    /// Converts from a big float, dropping the digits beyond the given scale.
+    ///
+    /// Signals an error if the big float is negative.
+    pub fn from_big_float(big_float: &BigFloat<M>, decimals: D) -> Self {
+        let scaling_factor = BigFloat::from(decimals.scaling_factor::<M>());
+        let data = big_float
+            .to_fixed_point(&scaling_factor)
+            .into_big_uint()
+            .into_option()
+            .unwrap_or_else(|| M::error_api_impl().signal_error(err_msg::MANAGED_DECIMAL_NEGATIVE));
+        ManagedDecimal::from_raw_units(data, decimals)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> ManagedDecimal<M, ConstDecimals<DECIMALS>> {
+    pub fn const_decimals_from_raw(data: BigUint<M>) -> Self {
+        ManagedDecimal::from_raw_units(data, ConstDecimals)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> From<BigUint<M>>
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn from(integer: BigUint<M>) -> Self {
+        ManagedDecimal::from_integer(integer, ConstDecimals)
+    }
+}
+
+fn pow10<M: ManagedTypeApi>(exp: NumDecimals) -> BigUint<M> {
+    exp.scaling_factor()
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TopDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::const_decimals_from_raw(
+            BigUint::top_decode_or_handle_err(input, h)?,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedEncode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> NestedDecode
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(ManagedDecimal::const_decimals_from_raw(
+            BigUint::dep_decode_or_handle_err(input, h)?,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi> TopEncode for ManagedDecimal<M, NumDecimals> {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        let mut buffer = output.start_nested_encode();
+        self.dep_encode_or_handle_err(&mut buffer, h)?;
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> TopDecode for ManagedDecimal<M, NumDecimals> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut nested_buffer = input.into_nested_buffer();
+        let result = Self::dep_decode_or_handle_err(&mut nested_buffer, h)?;
+        if !NestedDecodeInput::is_depleted(&nested_buffer) {
+            return Err(h.handle_error(crate::codec::DecodeError::INPUT_TOO_LONG));
+        }
+        Ok(result)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedEncode for ManagedDecimal<M, NumDecimals> {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.data.dep_encode_or_handle_err(dest, h)?;
+        self.decimals.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for ManagedDecimal<M, NumDecimals> {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let data = BigUint::dep_decode_or_handle_err(input, h)?;
+        let decimals = NumDecimals::dep_decode_or_handle_err(input, h)?;
+        Ok(ManagedDecimal::from_raw_units(data, decimals))
+    }
+}
+
+/// The ABI shows the scale when it is fixed, e.g. `ManagedDecimal<18>`.
+impl<M: ManagedTypeApi, const DECIMALS: NumDecimals> TypeAbi
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    fn type_name() -> TypeName {
+        let mut name = TypeName::from("ManagedDecimal<");
+        name.push_str(DECIMALS.to_string().as_str());
+        name.push('>');
+        name
+    }
+}
+
+/// The scale is only known at runtime, and is encoded after the value.
+impl<M: ManagedTypeApi> TypeAbi for ManagedDecimal<M, NumDecimals> {
+    fn type_name() -> TypeName {
+        TypeName::from("ManagedDecimal<usize>")
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        let type_name = Self::type_name();
+        if !accumulator.contains_type(&type_name) {
+            accumulator.reserve_type_name(type_name.clone());
+            let mut field_descriptions = Vec::new();
+            field_descriptions.push(StructFieldDescription::new(
+                &["The value, in units of 10^-decimals."],
+                "data",
+                BigUint::<M>::type_name(),
+            ));
+            BigUint::<M>::provide_type_descriptions(accumulator);
+            field_descriptions.push(StructFieldDescription::new(
+                &["The scale."],
+                "decimals",
+                NumDecimals::type_name(),
+            ));
+            NumDecimals::provide_type_descriptions(accumulator);
+            accumulator.insert(
+                type_name.clone(),
+                TypeDescription::new(
+                    &["Unsigned fixed-point decimal number, with the scale chosen at runtime."],
+                    type_name,
+                    TypeContents::Struct(field_descriptions),
+                ),
+            );
+        }
+    }
+}
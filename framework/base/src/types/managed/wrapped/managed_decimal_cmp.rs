@@ -0,0 +1,26 @@
+use core::cmp::Ordering;
+
+use crate::{
+    api::ManagedTypeApi,
+    types::{Decimals, ManagedDecimal},
+};
+
+/// Decimals with different scales are compared by value, e.g. 1.5 equals 1.50.
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> PartialEq<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    fn eq(&self, other: &ManagedDecimal<M, D2>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> PartialOrd<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    fn partial_cmp(&self, other: &ManagedDecimal<M, D2>) -> Option<Ordering> {
+        let scale = self.scale().max(other.scale());
+        let self_data = self.rescale(scale);
+        let other_data = other.rescale(scale);
+        self_data.raw_units().partial_cmp(other_data.raw_units())
+    }
+}
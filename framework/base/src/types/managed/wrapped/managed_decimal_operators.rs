@@ -0,0 +1,104 @@
+use crate::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    err_msg,
+    types::{BigUint, ConstDecimals, Decimals, ManagedDecimal, NumDecimals},
+};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The result has the larger of the two scales, so no digits are lost.
+impl<M: ManagedTypeApi, D2: Decimals> Add<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, NumDecimals>
+{
+    type Output = Self;
+
+    fn add(self, other: ManagedDecimal<M, D2>) -> Self {
+        let scale = self.scale().max(other.scale());
+        let data = self.rescale(scale).into_parts().0 + other.rescale(scale).into_parts().0;
+        ManagedDecimal::from_raw_units(data, scale)
+    }
+}
+
+/// The result keeps the fixed scale of the left operand.
+///
+/// Signals an error if the right operand has more decimals, instead of dropping them.
+impl<M: ManagedTypeApi, D2: Decimals, const DECIMALS: NumDecimals> Add<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    type Output = Self;
+
+    fn add(self, other: ManagedDecimal<M, D2>) -> Self {
+        let other_data = rescale_up(other, DECIMALS);
+        let (data, decimals) = self.into_parts();
+        ManagedDecimal::from_raw_units(data + other_data, decimals)
+    }
+}
+
+/// The result has the larger of the two scales, so no digits are lost.
+///
+/// Signals an error if the result would be negative.
+impl<M: ManagedTypeApi, D2: Decimals> Sub<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, NumDecimals>
+{
+    type Output = Self;
+
+    fn sub(self, other: ManagedDecimal<M, D2>) -> Self {
+        let scale = self.scale().max(other.scale());
+        let data = self.rescale(scale).into_parts().0 - other.rescale(scale).into_parts().0;
+        ManagedDecimal::from_raw_units(data, scale)
+    }
+}
+
+/// The result keeps the fixed scale of the left operand.
+///
+/// Signals an error if the right operand has more decimals, instead of dropping them,
+/// or if the result would be negative.
+impl<M: ManagedTypeApi, D2: Decimals, const DECIMALS: NumDecimals> Sub<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, ConstDecimals<DECIMALS>>
+{
+    type Output = Self;
+
+    fn sub(self, other: ManagedDecimal<M, D2>) -> Self {
+        let other_data = rescale_up(other, DECIMALS);
+        let (data, decimals) = self.into_parts();
+        ManagedDecimal::from_raw_units(data - other_data, decimals)
+    }
+}
+
+/// The raw units at a scale at least as large, signals an error otherwise.
+fn rescale_up<M: ManagedTypeApi, D: Decimals>(
+    decimal: ManagedDecimal<M, D>,
+    scale: NumDecimals,
+) -> BigUint<M> {
+    if decimal.scale() > scale {
+        M::error_api_impl().signal_error(err_msg::MANAGED_DECIMAL_SCALE_TOO_LARGE);
+    }
+    decimal.rescale(scale).into_parts().0
+}
+
+/// The result keeps the scale of the left operand, extra digits are dropped.
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> Mul<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    type Output = Self;
+
+    fn mul(self, other: ManagedDecimal<M, D2>) -> Self {
+        let scaling_factor = other.scaling_factor();
+        let (data, decimals) = self.into_parts();
+        let (other_data, _) = other.into_parts();
+        ManagedDecimal::from_raw_units(data * other_data / scaling_factor, decimals)
+    }
+}
+
+/// The result keeps the scale of the left operand, extra digits are dropped.
+impl<M: ManagedTypeApi, D1: Decimals, D2: Decimals> Div<ManagedDecimal<M, D2>>
+    for ManagedDecimal<M, D1>
+{
+    type Output = Self;
+
+    fn div(self, other: ManagedDecimal<M, D2>) -> Self {
+        let scaling_factor = other.scaling_factor();
+        let (data, decimals) = self.into_parts();
+        let (other_data, _) = other.into_parts();
+        ManagedDecimal::from_raw_units(data * scaling_factor / other_data, decimals)
+    }
+}
@@ -0,0 +1,5 @@
+#[macro_use]
+extern crate quote;
+
+pub mod model;
+pub mod parse;
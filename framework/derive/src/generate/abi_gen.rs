@@ -171,6 +171,54 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+fn generate_storage_key_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    let module_name = contract.trait_name.to_string();
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| {
+            let (identifier, num_key_args) = match &m.implementation {
+                MethodImpl::Generated(
+                    AutoImpl::StorageGetter { identifier }
+                    | AutoImpl::StorageMapper { identifier }
+                    | AutoImpl::StorageIsEmpty { identifier }
+                    | AutoImpl::StorageClear { identifier },
+                ) => (identifier, m.method_args.len()),
+                // the last argument of the setter is the value
+                MethodImpl::Generated(AutoImpl::StorageSetter { identifier }) => {
+                    (identifier, m.method_args.len().saturating_sub(1))
+                },
+                _ => return None,
+            };
+            let sub_keys = generate_storage_sub_keys(m, num_key_args);
+            let rust_method_name = m.name.to_string();
+            Some(quote! {
+                contract_abi.storage_keys.push(multiversx_sc::abi::StorageKeyAbi::new(
+                    #module_name,
+                    #rust_method_name,
+                    #identifier,
+                    #sub_keys,
+                ));
+            })
+        })
+        .collect()
+}
+
+/// Key arguments are appended to the storage key, anything can follow it then.
+fn generate_storage_sub_keys(m: &Method, num_key_args: usize) -> proc_macro2::TokenStream {
+    if num_key_args > 0 {
+        return quote! { &[multiversx_sc::abi::StorageSubKeyAbi::prefix("")] };
+    }
+    match (&m.implementation, &m.return_type) {
+        (MethodImpl::Generated(AutoImpl::StorageMapper { .. }), syn::ReturnType::Type(_, ty)) => {
+            quote! {
+                <#ty as multiversx_sc::storage::mappers::StorageMapper<Self::Api>>::SUB_KEYS
+            }
+        },
+        _ => quote! { &[multiversx_sc::abi::StorageSubKeyAbi::exact("")] },
+    }
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -217,6 +265,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_key_snippets = generate_storage_key_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -245,6 +294,7 @@ fn generate_abi_method_body(
         );
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_key_snippets)*
         #(#supertrait_snippets)*
         #(#esdt_attributes)*
         contract_abi
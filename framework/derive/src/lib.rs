@@ -16,12 +16,12 @@ mod macro_contract;
 mod macro_module;
 mod macro_proxy;
 mod managed_vec_item_derive;
-mod model;
-mod parse;
 mod preprocessing;
 mod type_abi_derive;
 mod validate;
 
+use multiversx_sc_derive_model::{model, parse};
+
 #[proc_macro_attribute]
 pub fn contract(
     args: proc_macro::TokenStream,
@@ -35,6 +35,21 @@ fn add_managed_type_with_generics(
     substitutions.add_substitution(quote!(#type_name<), quote!(#type_name<Self::Api, ));
 }
 
+/// For managed types whose other generics have defaults, so they can be used with or without them.
+/// Their associated functions are left to type inference.
+fn add_managed_type_with_default_generics(
+    substitutions: &mut SubstitutionsMap,
+    type_name: &proc_macro2::TokenStream,
+) {
+    add_managed_type_with_generics(substitutions, type_name);
+    substitutions.add_substitution(quote!(#type_name<Self::Api>), quote!(#type_name<Self::Api>));
+    substitutions.add_substitution(quote!(#type_name::), quote!(#type_name::));
+    substitutions.add_substitution(
+        quote!(#type_name),
+        quote!(multiversx_sc::types::#type_name<Self::Api>),
+    );
+}
+
 fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     // types::io
     add_managed_type(substitutions, &quote!(ManagedSCError));
@@ -49,7 +64,7 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     add_managed_type(substitutions, &quote!(EllipticCurveComponents));
     add_managed_type(substitutions, &quote!(EllipticCurve));
     add_managed_type(substitutions, &quote!(ManagedBuffer));
-    add_managed_type(substitutions, &quote!(ManagedMap));
+    add_managed_type_with_default_generics(substitutions, &quote!(ManagedMap));
 
     // types::managed::multi-value
     add_managed_type(substitutions, &quote!(ManagedAsyncCallError));
@@ -85,6 +85,12 @@ impl TypeDescriptionJson {
                         .map(EnumVariantDescriptionJson::to_enum_variant_description)
                         .collect(),
                 ),
+                TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => TypeContents::ExplicitEnum(
+                    self.variants
+                        .iter()
+                        .map(EnumVariantDescriptionJson::to_explicit_enum_variant_description)
+                        .collect(),
+                ),
                 _ => TypeContents::NotSpecified,
             },
         }
@@ -179,4 +185,11 @@ impl EnumVariantDescriptionJson {
                 .collect(),
         }
     }
+
+    pub fn to_explicit_enum_variant_description(&self) -> ExplicitEnumVariantDescription {
+        ExplicitEnumVariantDescription {
+            docs: self.docs.clone(),
+            name: self.name.clone(),
+        }
+    }
 }
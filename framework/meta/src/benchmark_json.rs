@@ -0,0 +1,247 @@
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+use crate::print_util::format_table;
+
+/// Results of running a benchmark, also used as the stored baseline.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkReportJson {
+    pub name: String,
+    pub entries: Vec<BenchmarkEntryJson>,
+}
+
+/// Measurements for a single benchmarked call.
+///
+/// Gas is only available when running on the Go VM,
+/// VM hook counts only when running on the Rust debugger.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkEntryJson {
+    pub name: String,
+    pub endpoint: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_used: Option<u64>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vm_hook_count: Option<u64>,
+}
+
+impl BenchmarkReportJson {
+    pub fn new(name: &str) -> Self {
+        BenchmarkReportJson {
+            name: name.to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn find_entry(&self, name: &str) -> Option<&BenchmarkEntryJson> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    pub fn find_entry_mut(&mut self, name: &str) -> Option<&mut BenchmarkEntryJson> {
+        self.entries.iter_mut().find(|entry| entry.name == name)
+    }
+
+    /// Adds the measurements of another report, for entries with the same name.
+    ///
+    /// Used to combine the Go VM and debugger runs of the same benchmark.
+    pub fn merge(&mut self, other: &BenchmarkReportJson) {
+        for other_entry in &other.entries {
+            if let Some(entry) = self.find_entry_mut(&other_entry.name) {
+                entry.gas_used = entry.gas_used.or(other_entry.gas_used);
+                entry.vm_hook_count = entry.vm_hook_count.or(other_entry.vm_hook_count);
+            } else {
+                self.entries.push(other_entry.clone());
+            }
+        }
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Self {
+        let contents = fs::read_to_string(path.as_ref()).unwrap_or_else(|e| {
+            panic!(
+                "failed to read benchmark file {}: {e}",
+                path.as_ref().display()
+            )
+        });
+        serde_json::from_str(contents.as_str()).expect("invalid benchmark json")
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent).expect("failed to create benchmark output directory");
+        }
+        fs::write(path, serialize_benchmark_json(self)).expect("failed to write benchmark file");
+    }
+
+    /// Compares the current results against a baseline.
+    ///
+    /// A measurement counts as a regression if it exceeds the baseline by more than `tolerance_percent`.
+    pub fn compare(
+        &self,
+        baseline: &BenchmarkReportJson,
+        tolerance_percent: f64,
+    ) -> BenchmarkComparison {
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let baseline_entry = baseline.find_entry(&entry.name);
+                BenchmarkComparisonRow {
+                    name: entry.name.clone(),
+                    endpoint: entry.endpoint.clone(),
+                    gas_used: MeasurementComparison::new(
+                        baseline_entry.and_then(|b| b.gas_used),
+                        entry.gas_used,
+                        tolerance_percent,
+                    ),
+                    vm_hook_count: MeasurementComparison::new(
+                        baseline_entry.and_then(|b| b.vm_hook_count),
+                        entry.vm_hook_count,
+                        tolerance_percent,
+                    ),
+                }
+            })
+            .collect();
+        BenchmarkComparison {
+            name: self.name.clone(),
+            rows,
+        }
+    }
+}
+
+pub fn serialize_benchmark_json(report: &BenchmarkReportJson) -> String {
+    let buf = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut ser = serde_json::Serializer::with_formatter(buf, formatter);
+    report.serialize(&mut ser).unwrap();
+    let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
+    serialized.push('\n');
+    serialized
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeasurementComparison {
+    pub baseline: Option<u64>,
+    pub current: Option<u64>,
+    pub regression: bool,
+}
+
+impl MeasurementComparison {
+    fn new(baseline: Option<u64>, current: Option<u64>, tolerance_percent: f64) -> Self {
+        let regression = match (baseline, current) {
+            (Some(baseline), Some(current)) => {
+                current as f64 > baseline as f64 * (1.0 + tolerance_percent / 100.0)
+            },
+            _ => false,
+        };
+        MeasurementComparison {
+            baseline,
+            current,
+            regression,
+        }
+    }
+
+    /// Relative change, in percent. Only available if both values are present.
+    pub fn change_percent(&self) -> Option<f64> {
+        match (self.baseline, self.current) {
+            (Some(0), Some(0)) => Some(0.0),
+            (Some(0), Some(_)) => None,
+            (Some(baseline), Some(current)) => {
+                Some((current as f64 - baseline as f64) * 100.0 / baseline as f64)
+            },
+            _ => None,
+        }
+    }
+
+    fn format_cell(&self) -> String {
+        let value = match self.current {
+            Some(current) => current.to_string(),
+            None => "-".to_string(),
+        };
+        match self.change_percent() {
+            Some(change) => format!("{value} ({change:+.2}%)"),
+            None if self.current.is_some() && self.baseline.is_none() => format!("{value} (new)"),
+            None => value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkComparisonRow {
+    pub name: String,
+    pub endpoint: String,
+    pub gas_used: MeasurementComparison,
+    pub vm_hook_count: MeasurementComparison,
+}
+
+impl BenchmarkComparisonRow {
+    pub fn is_regression(&self) -> bool {
+        self.gas_used.regression || self.vm_hook_count.regression
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkComparison {
+    pub name: String,
+    pub rows: Vec<BenchmarkComparisonRow>,
+}
+
+impl BenchmarkComparison {
+    pub fn has_regressions(&self) -> bool {
+        self.rows.iter().any(BenchmarkComparisonRow::is_regression)
+    }
+
+    pub fn regressions(&self) -> impl Iterator<Item = &BenchmarkComparisonRow> {
+        self.rows.iter().filter(|row| row.is_regression())
+    }
+
+    /// Formats the comparison as a plain text table, one row per benchmarked call.
+    pub fn table_string(&self) -> String {
+        let lines: Vec<[String; 5]> = self
+            .rows
+            .iter()
+            .map(|row| {
+                [
+                    row.name.clone(),
+                    row.endpoint.clone(),
+                    row.gas_used.format_cell(),
+                    row.vm_hook_count.format_cell(),
+                    if row.is_regression() {
+                        "REGRESSION".to_string()
+                    } else {
+                        "ok".to_string()
+                    },
+                ]
+            })
+            .collect();
+
+        format_table(
+            ["call", "endpoint", "gas used", "vm hooks", "status"],
+            &lines,
+        )
+    }
+
+    pub fn print_table(&self) {
+        println!("{} {}", "Benchmark".green(), self.name);
+        for (i, line) in self.table_string().lines().enumerate() {
+            if i > 0 && line.ends_with("REGRESSION") {
+                println!("{}", line.red());
+            } else {
+                println!("{line}");
+            }
+        }
+
+        let num_regressions = self.regressions().count();
+        if num_regressions > 0 {
+            println!(
+                "{}",
+                format!("{num_regressions} regression(s) found.").red()
+            );
+        }
+    }
+}
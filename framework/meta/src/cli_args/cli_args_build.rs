@@ -69,6 +69,10 @@ pub struct BuildArgs {
     #[arg(long = "twiggy-dominators", verbatim_doc_comment)]
     pub twiggy_dominators: bool,
 
+    /// Rebuilds all contract variants, even if their build fingerprint shows them to be up to date.
+    #[arg(long = "force", verbatim_doc_comment)]
+    pub force: bool,
+
     /// Backwards compatibility with mxpy, delete when github actions are fixed.
     #[deprecated]
     #[arg(long = "target", verbatim_doc_comment)]
@@ -103,6 +107,7 @@ impl Default for BuildArgs {
             twiggy_paths: false,
             twiggy_monos: false,
             twiggy_dominators: false,
+            force: false,
             target: None,
             release: false,
             out_dir: None,
@@ -165,6 +170,9 @@ impl CliArgsToRaw for BuildArgs {
         if self.twiggy_dominators {
             raw.push("--twiggy-dominators".to_string());
         }
+        if self.force {
+            raw.push("--force".to_string());
+        }
         raw
     }
 }
@@ -63,6 +63,14 @@ pub enum StandaloneCliAction {
     TestGen(TestGenArgs),
     #[command(name = "test", about = "Runs cargo test")]
     Test(TestArgs),
+
+    #[command(about = "Statically analyzes contract sources and reports common mistakes.")]
+    Lint(LintArgs),
+
+    #[command(
+        about = "Calls any contract endpoint from the command line, driven only by the contract ABI."
+    )]
+    Interact(InteractArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -95,6 +103,109 @@ pub struct TestArgs {
     /// If scen and go are both specified, scen overrides the go argument.
     #[arg(short, long, default_value = "false", verbatim_doc_comment)]
     pub scen: bool,
+
+    /// Tests each contract crate separately, this many at a time.
+    /// A contract is only tested after all the contracts it depends on.
+    /// If not specified, the tests are run once, in the target directory.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub jobs: Option<usize>,
+
+    /// Ignore all directories with these names. Only used when testing contracts separately.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// Saves a JSON report with the result of each contract at the given path.
+    /// Only used when testing contracts separately.
+    #[arg(long = "report-json", verbatim_doc_comment)]
+    pub report_json: Option<String>,
+
+    /// Saves a JUnit XML report with the result of each contract at the given path.
+    /// Only used when testing contracts separately.
+    #[arg(long = "report-junit", verbatim_doc_comment)]
+    pub report_junit: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct LintArgs {
+    /// Target directory where to lint contracts.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// Lint rules to skip, e.g. `--allow unbounded-iteration`.
+    #[arg(long, verbatim_doc_comment)]
+    pub allow: Vec<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct InteractArgs {
+    /// Path to the contract ABI file (`*.abi.json`).
+    #[arg(long, verbatim_doc_comment)]
+    pub abi: String,
+
+    /// Gateway URL.
+    #[arg(
+        long,
+        default_value = "https://devnet-gateway.multiversx.com",
+        verbatim_doc_comment
+    )]
+    pub proxy: String,
+
+    /// Contract address, in bech32. Not needed for deploy.
+    #[arg(long, verbatim_doc_comment)]
+    pub contract: Option<String>,
+
+    /// PEM file used to sign transactions.
+    #[arg(long, verbatim_doc_comment)]
+    pub pem: Option<String>,
+
+    /// JSON keystore file used to sign transactions, as an alternative to the PEM.
+    #[arg(long, verbatim_doc_comment)]
+    pub keystore: Option<String>,
+
+    /// File containing the keystore password.
+    /// If not specified, the password is taken from the MX_KEYSTORE_PASSWORD environment variable,
+    /// or else read from the standard input.
+    #[arg(long = "password-file", verbatim_doc_comment)]
+    pub password_file: Option<String>,
+
+    /// Gas limit for transactions.
+    #[arg(long = "gas-limit", default_value = "10000000", verbatim_doc_comment)]
+    pub gas_limit: u64,
+
+    /// EGLD value to send, in the smallest denomination.
+    /// Cannot be combined with token payments.
+    #[arg(long, conflicts_with = "esdt", verbatim_doc_comment)]
+    pub value: Option<String>,
+
+    /// Token payment, as TOKEN:NONCE:AMOUNT, or TOKEN:AMOUNT for fungible tokens.
+    /// Can be repeated for multiple payments.
+    #[arg(long, verbatim_doc_comment)]
+    pub esdt: Vec<String>,
+
+    /// Path to the contract code (`*.wasm`), only used by deploy.
+    #[arg(long, verbatim_doc_comment)]
+    pub code: Option<String>,
+
+    /// Code metadata for deploy, as a list of flags (upgradeable, readable, payable, payable-by-sc) or as hex.
+    #[arg(long, default_value = "upgradeable,readable", verbatim_doc_comment)]
+    pub metadata: String,
+
+    /// The endpoint to call, followed by its arguments.
+    /// Simple arguments are given as text, composite ones (structs, lists, options) as JSON.
+    /// Use `help` to list the endpoints, or `help <endpoint>` for its arguments.
+    #[arg(
+        trailing_var_arg = true,
+        allow_hyphen_values = true,
+        verbatim_doc_comment
+    )]
+    pub command: Vec<String>,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -131,6 +242,28 @@ pub struct AllArgs {
     #[arg(long = "target-dir-all", verbatim_doc_comment)]
     #[clap(global = true)]
     pub target_dir_all: Option<String>,
+
+    /// Keeps watching the contract sources after running,
+    /// and calls again the meta crates of the contracts whose sources changed.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true)]
+    pub watch: bool,
+
+    /// Number of contracts to process in parallel.
+    /// A contract only starts after all the contracts it depends on are done.
+    #[arg(short = 'j', long, default_value = "1", verbatim_doc_comment)]
+    #[clap(global = true)]
+    pub jobs: usize,
+
+    /// Saves a JSON report with the result of each contract at the given path.
+    #[arg(long = "report-json", verbatim_doc_comment)]
+    #[clap(global = true)]
+    pub report_json: Option<String>,
+
+    /// Saves a JUnit XML report with the result of each contract at the given path.
+    #[arg(long = "report-junit", verbatim_doc_comment)]
+    #[clap(global = true)]
+    pub report_junit: Option<String>,
 }
 
 impl AllArgs {
@@ -3,8 +3,11 @@ use std::fs;
 use multiversx_sc::abi::ContractAbi;
 
 use crate::{
-    cli_args::BuildArgs, find_workspace::find_current_workspace,
-    print_util::print_workspace_target_dir, tools::check_tools_installed, CargoTomlContents,
+    cli_args::BuildArgs,
+    find_workspace::find_current_workspace,
+    print_util::print_workspace_target_dir,
+    tools::{check_tools_installed, SourceFingerprints},
+    CargoTomlContents,
 };
 
 use super::{
@@ -87,8 +90,13 @@ impl MetaConfig {
         check_tools_installed(&mut build_args);
         adjust_target_dir_wasm(&mut build_args);
 
+        let mut source_fingerprints = SourceFingerprints::new();
         for contract_variant in &self.sc_config.contracts {
-            contract_variant.build_contract(&build_args, self.output_dir.as_str());
+            contract_variant.build_contract(
+                &build_args,
+                self.output_dir.as_str(),
+                &mut source_fingerprints,
+            );
         }
     }
 
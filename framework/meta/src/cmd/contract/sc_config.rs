@@ -5,6 +5,7 @@ mod oc_global_config;
 mod oc_settings;
 mod oc_validate;
 mod wasm_build;
+mod wasm_build_fingerprint;
 mod wasm_clean;
 mod wasm_crate_gen;
 mod wasm_update;
@@ -12,6 +13,6 @@ mod wasm_update;
 pub use multi_contract_serde::*;
 pub use oc_builder::*;
 pub use oc_config::ContractVariant;
-pub use oc_global_config::ScConfig;
+pub use oc_global_config::{ScConfig, SC_CONFIG_FILE_NAMES};
 pub use oc_settings::ContractVariantSettings;
 pub use wasm_build::*;
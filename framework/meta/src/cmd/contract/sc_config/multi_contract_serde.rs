@@ -59,6 +59,12 @@ pub struct ContractVariantSerde {
 #[derive(Deserialize, Default, Debug)]
 pub struct MultiContractGeneralSettingsSerde {
     pub main: Option<String>,
+
+    /// Disables the storage key collision check between modules.
+    /// Only use it if the modules are meant to share storage.
+    #[serde(default)]
+    #[serde(rename = "allow-storage-key-collisions")]
+    pub allow_storage_key_collisions: bool,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -195,6 +195,7 @@ fn build_contract_abi(builder: ContractVariantBuilder, original_abi: &ContractAb
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
         esdt_attributes: original_abi.esdt_attributes.clone(),
+        storage_keys: original_abi.storage_keys.clone(),
     }
 }
 
@@ -274,6 +275,7 @@ impl ScConfig {
         ScConfig {
             default_contract_config_name: config.settings.main.clone().unwrap_or_default(),
             contracts,
+            allow_storage_key_collisions: config.settings.allow_storage_key_collisions,
         }
     }
 
@@ -293,6 +295,7 @@ impl ScConfig {
                 wasm_crate_name,
                 abi: original_abi.clone(),
             }],
+            allow_storage_key_collisions: false,
         }
     }
 
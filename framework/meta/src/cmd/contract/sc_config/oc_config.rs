@@ -122,6 +122,10 @@ impl ContractVariant {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
 
+    pub fn fingerprint_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.fingerprint.json", self.output_name_base(build_args))
+    }
+
     pub fn twiggy_top_name(&self, build_args: &BuildArgs) -> String {
         format!("twiggy-top-{}.txt", self.output_name_base(build_args))
     }
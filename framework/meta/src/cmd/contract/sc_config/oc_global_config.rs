@@ -1,4 +1,7 @@
-use super::{oc_validate::validate_contract_variant, ContractVariant};
+use super::{
+    oc_validate::{validate_contract_variant, validate_storage_keys},
+    ContractVariant,
+};
 
 /// Allowed file names for the SC config.
 ///
@@ -15,6 +18,7 @@ pub const SC_CONFIG_FILE_NAMES: &[&str] = &["sc-config.toml", "multicontract.tom
 pub struct ScConfig {
     pub default_contract_config_name: String,
     pub contracts: Vec<ContractVariant>,
+    pub allow_storage_key_collisions: bool,
 }
 
 impl ScConfig {
@@ -78,5 +82,14 @@ impl ScConfig {
                 panic!("Invalid contract variant {}: {err}", contract.contract_name)
             });
         }
+
+        if !self.allow_storage_key_collisions {
+            validate_storage_keys(&self.main_contract().abi).unwrap_or_else(|err| {
+                panic!(
+                    "Invalid contract {}: {err}",
+                    self.main_contract().contract_name
+                )
+            });
+        }
     }
 }
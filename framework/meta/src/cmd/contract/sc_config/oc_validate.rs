@@ -67,9 +67,33 @@ fn validate_endpoint_var_args_order(endpoint_abi: &EndpointAbi) -> Result<(), St
     Ok(())
 }
 
+/// Storage keys from different modules must not address the same storage entries,
+/// otherwise the modules can end up reading and writing each other's storage.
+///
+/// The keys are compared fully expanded, including the sub-keys of the storage mappers.
+pub fn validate_storage_keys(abi: &ContractAbi) -> Result<(), String> {
+    if let Some(collision) = abi.storage_key_collisions().first() {
+        return Err(format!(
+            "Storage key collision: \"{}\" ({}::{}) and \"{}\" ({}::{}). Storage keys from different modules must not address the same storage.",
+            collision.first_key,
+            collision.first.module_name,
+            collision.first.rust_method_name,
+            collision.second_key,
+            collision.second.module_name,
+            collision.second.rust_method_name,
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use multiversx_sc::abi::{InputAbi, TypeName};
+    use multiversx_sc::{
+        abi::{InputAbi, StorageKeyAbi, StorageSubKeyAbi, TypeName},
+        api::uncallable::UncallableApi,
+        storage::mappers::{SingleValueMapper, StorageMapper, VecMapper},
+    };
 
     use super::*;
 
@@ -124,4 +148,97 @@ mod tests {
         endpoint_def.inputs.push(var_arg_1);
         assert_eq!(Ok(()), validate_endpoint_var_args_order(&endpoint_def));
     }
+
+    #[test]
+    fn validate_storage_keys_test() {
+        let mut abi = ContractAbi::default();
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "Contract",
+            "get_fee",
+            "fee",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "Contract",
+            "set_fee",
+            "fee",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "FeatureModule",
+            "feature_flags",
+            "feature",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "OtherModule",
+            "feat",
+            "feat",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        assert_eq!(Ok(()), validate_storage_keys(&abi));
+
+        // key arguments follow the key
+        abi.storage_keys.pop();
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "OtherModule",
+            "feat_per_user",
+            "feat",
+            &[StorageSubKeyAbi::prefix("")],
+        ));
+        assert_eq!(Err("Storage key collision: \"feature\" (FeatureModule::feature_flags) and \"feat\" (OtherModule::feat_per_user). Storage keys from different modules must not address the same storage.".to_string()), validate_storage_keys(&abi));
+    }
+
+    #[test]
+    fn validate_storage_keys_duplicate_test() {
+        let mut abi = ContractAbi::default();
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "Contract",
+            "fee",
+            "fee",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "OtherModule",
+            "other_fee",
+            "fee",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        assert_eq!(Err("Storage key collision: \"fee\" (Contract::fee) and \"fee\" (OtherModule::other_fee). Storage keys from different modules must not address the same storage.".to_string()), validate_storage_keys(&abi));
+    }
+
+    #[test]
+    fn validate_storage_keys_mapper_sub_keys_test() {
+        let mut abi = ContractAbi::default();
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "Contract",
+            "users",
+            "users",
+            <VecMapper<UncallableApi, u64> as StorageMapper<UncallableApi>>::SUB_KEYS,
+        ));
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "OtherModule",
+            "user_count",
+            "users.count",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        assert_eq!(Ok(()), validate_storage_keys(&abi));
+
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "OtherModule",
+            "users_len",
+            "users.len",
+            &[StorageSubKeyAbi::exact("")],
+        ));
+        assert_eq!(Err("Storage key collision: \"users.len\" (Contract::users) and \"users.len\" (OtherModule::users_len). Storage keys from different modules must not address the same storage.".to_string()), validate_storage_keys(&abi));
+
+        abi.storage_keys.pop();
+        abi.storage_keys.push(StorageKeyAbi::new(
+            "OtherModule",
+            "first_user",
+            "users.item",
+            <SingleValueMapper<UncallableApi, u64> as StorageMapper<UncallableApi>>::SUB_KEYS,
+        ));
+        assert_eq!(Err("Storage key collision: \"users.item\" (Contract::users) and \"users.item\" (OtherModule::first_user). Storage keys from different modules must not address the same storage.".to_string()), validate_storage_keys(&abi));
+    }
 }
@@ -1,17 +1,42 @@
-use std::{ffi::OsStr, fs, process::Command};
+use std::{ffi::OsStr, fs, path::Path, process::Command};
 
-use super::ContractVariant;
+use super::{wasm_build_fingerprint::BuildFingerprintJson, ContractVariant};
 use crate::{
     abi_json::ContractAbiJson,
-    cli_args::BuildArgs,
+    cli_args::{BuildArgs, CliArgsToRaw},
     ei::EIVersion,
     mxsc_file_json::{save_mxsc_file_json, MxscFileJson},
     print_util::*,
-    tools,
+    tools::{self, hash_file, hash_strings, SourceFingerprints},
 };
 
 impl ContractVariant {
-    pub fn build_contract(&self, build_args: &BuildArgs, output_path: &str) {
+    pub fn build_contract(
+        &self,
+        build_args: &BuildArgs,
+        output_path: &str,
+        source_fingerprints: &mut SourceFingerprints,
+    ) {
+        let previous_fingerprint =
+            BuildFingerprintJson::load_from_file(self.fingerprint_path(build_args, output_path))
+                .unwrap_or_default();
+        let fingerprint = BuildFingerprintJson {
+            source_hash: self.source_hash(source_fingerprints),
+            settings_hash: self.settings_hash(build_args),
+            ..Default::default()
+        };
+        if !build_args.force
+            && self.is_build_up_to_date(
+                build_args,
+                output_path,
+                &previous_fingerprint,
+                &fingerprint,
+            )
+        {
+            print_build_up_to_date(self.wasm_output_name(build_args));
+            return;
+        }
+
         let mut command = self.compose_build_command(build_args);
 
         print_build_command(self.wasm_output_name(build_args), &command);
@@ -24,7 +49,33 @@ impl ContractVariant {
 
         assert!(exit_status.success(), "contract build process failed");
 
-        self.finalize_build(build_args, output_path);
+        self.finalize_build(build_args, output_path, &previous_fingerprint, fingerprint);
+    }
+
+    fn is_build_up_to_date(
+        &self,
+        build_args: &BuildArgs,
+        output_path: &str,
+        previous_fingerprint: &BuildFingerprintJson,
+        fingerprint: &BuildFingerprintJson,
+    ) -> bool {
+        previous_fingerprint.source_hash == fingerprint.source_hash
+            && previous_fingerprint.settings_hash == fingerprint.settings_hash
+            && self
+                .expected_output_paths(build_args, output_path)
+                .iter()
+                .all(|path| Path::new(path).is_file())
+    }
+
+    /// The build arguments (except `--force`) and the compiler flags.
+    fn settings_hash(&self, build_args: &BuildArgs) -> String {
+        let mut settings: Vec<String> = build_args
+            .to_raw()
+            .into_iter()
+            .filter(|arg| arg != "--force")
+            .collect();
+        settings.push(self.compose_rustflags(build_args).0);
+        hash_strings(&settings)
     }
 
     fn compose_build_command(&self, build_args: &BuildArgs) -> Command {
@@ -67,13 +118,39 @@ impl ContractVariant {
         rustflags
     }
 
-    fn finalize_build(&self, build_args: &BuildArgs, output_path: &str) {
-        self.copy_contracts_to_output(build_args, output_path);
-        self.run_wasm_opt(build_args, output_path);
-        self.run_wasm2wat(build_args, output_path);
+    fn finalize_build(
+        &self,
+        build_args: &BuildArgs,
+        output_path: &str,
+        previous_fingerprint: &BuildFingerprintJson,
+        mut fingerprint: BuildFingerprintJson,
+    ) {
+        let source_wasm_path = self.wasm_compilation_output_path(&build_args.target_dir_wasm);
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        fingerprint.compiled_wasm_hash =
+            hash_file(&source_wasm_path).expect("failed to read compiled contract");
+
+        // if the compiler produced the same binary as last time, the post-processing results are still valid
+        let output_unchanged = !build_args.force
+            && previous_fingerprint.settings_hash == fingerprint.settings_hash
+            && previous_fingerprint.compiled_wasm_hash == fingerprint.compiled_wasm_hash
+            && hash_file(&output_wasm_path).as_ref()
+                == Some(&previous_fingerprint.output_wasm_hash);
+
+        if output_unchanged {
+            print_compiled_wasm_unchanged(&output_wasm_path);
+        } else {
+            self.copy_contracts_to_output(build_args, output_path);
+            self.run_wasm_opt(build_args, output_path);
+        }
+        self.run_wasm2wat(build_args, output_path, output_unchanged);
         self.extract_imports(build_args, output_path);
-        self.run_twiggy(build_args, output_path);
+        self.run_twiggy(build_args, output_path, output_unchanged);
         self.pack_mxsc_file(build_args, output_path);
+
+        fingerprint.output_wasm_hash =
+            hash_file(&output_wasm_path).expect("failed to read output contract");
+        fingerprint.save_to_file(self.fingerprint_path(build_args, output_path));
     }
 
     fn copy_contracts_to_output(&self, build_args: &BuildArgs, output_path: &str) {
@@ -112,13 +189,16 @@ impl ContractVariant {
         tools::run_wasm_opt(output_wasm_path.as_str());
     }
 
-    fn run_wasm2wat(&self, build_args: &BuildArgs, output_path: &str) {
+    fn run_wasm2wat(&self, build_args: &BuildArgs, output_path: &str, output_unchanged: bool) {
         if !build_args.wat {
             return;
         }
 
         let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
         let output_wat_path = format!("{output_path}/{}", self.wat_output_name(build_args));
+        if is_report_up_to_date(output_unchanged, &output_wat_path) {
+            return;
+        }
         print_call_wasm2wat(&output_wasm_path, &output_wat_path);
         tools::wasm_to_wat(output_wasm_path.as_str(), output_wat_path.as_str());
     }
@@ -165,46 +245,59 @@ fn validate_ei(import_names: &[String], check_ei: &Option<EIVersion>) {
 }
 
 impl ContractVariant {
-    fn run_twiggy(&self, build_args: &BuildArgs, output_path: &str) {
+    fn run_twiggy(&self, build_args: &BuildArgs, output_path: &str, output_unchanged: bool) {
         if build_args.has_twiggy_call() {
             let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
 
             if build_args.twiggy_top {
                 let output_twiggy_top_path =
                     format!("{output_path}/{}", self.twiggy_top_name(build_args));
-                tools::twiggy::run_twiggy_top(
-                    output_wasm_path.as_str(),
-                    output_twiggy_top_path.as_str(),
-                );
+                if !is_report_up_to_date(output_unchanged, &output_twiggy_top_path) {
+                    tools::twiggy::run_twiggy_top(
+                        output_wasm_path.as_str(),
+                        output_twiggy_top_path.as_str(),
+                    );
+                }
             }
             if build_args.twiggy_paths {
                 let output_twiggy_paths_path =
                     format!("{output_path}/{}", self.twiggy_paths_name(build_args));
-                tools::twiggy::run_twiggy_paths(
-                    output_wasm_path.as_str(),
-                    output_twiggy_paths_path.as_str(),
-                );
+                if !is_report_up_to_date(output_unchanged, &output_twiggy_paths_path) {
+                    tools::twiggy::run_twiggy_paths(
+                        output_wasm_path.as_str(),
+                        output_twiggy_paths_path.as_str(),
+                    );
+                }
             }
             if build_args.twiggy_monos {
                 let output_twiggy_monos_path =
                     format!("{output_path}/{}", self.twiggy_monos_name(build_args));
-                tools::twiggy::run_twiggy_monos(
-                    output_wasm_path.as_str(),
-                    output_twiggy_monos_path.as_str(),
-                );
+                if !is_report_up_to_date(output_unchanged, &output_twiggy_monos_path) {
+                    tools::twiggy::run_twiggy_monos(
+                        output_wasm_path.as_str(),
+                        output_twiggy_monos_path.as_str(),
+                    );
+                }
             }
             if build_args.twiggy_dominators {
                 let output_twiggy_dominators_path =
                     format!("{output_path}/{}", self.twiggy_dominators_name(build_args));
-                tools::twiggy::run_twiggy_dominators(
-                    output_wasm_path.as_str(),
-                    output_twiggy_dominators_path.as_str(),
-                );
+                if !is_report_up_to_date(output_unchanged, &output_twiggy_dominators_path) {
+                    tools::twiggy::run_twiggy_dominators(
+                        output_wasm_path.as_str(),
+                        output_twiggy_dominators_path.as_str(),
+                    );
+                }
             }
         }
     }
 }
 
+/// Reports generated from the output binary (wat, twiggy) only need to be regenerated if the binary changed.
+fn is_report_up_to_date(output_unchanged: bool, report_path: &str) -> bool {
+    output_unchanged && Path::new(report_path).is_file()
+}
+
 /// For convenience, for building rustflags.
 #[derive(Default)]
 struct Rustflags(String);
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+use super::ContractVariant;
+use crate::{
+    cli_args::BuildArgs,
+    tools::{hash_strings, SourceFingerprints},
+};
+
+/// Saved next to the build outputs, allows skipping build steps whose inputs have not changed.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildFingerprintJson {
+    /// Hash of the wasm crate sources, their local dependencies and the compiler version.
+    pub source_hash: String,
+
+    /// Hash of the build arguments and compiler flags.
+    pub settings_hash: String,
+
+    /// Hash of the binary produced by the compiler, before any post-processing.
+    pub compiled_wasm_hash: String,
+
+    /// Hash of the final binary, as found in the output folder.
+    pub output_wasm_hash: String,
+}
+
+impl BuildFingerprintJson {
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        serde_json::from_str(contents.as_str()).ok()
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json).expect("failed to write build fingerprint file");
+    }
+}
+
+impl ContractVariant {
+    pub(super) fn fingerprint_path(&self, build_args: &BuildArgs, output_path: &str) -> String {
+        format!("{output_path}/{}", self.fingerprint_output_name(build_args))
+    }
+
+    pub(super) fn source_hash(&self, source_fingerprints: &mut SourceFingerprints) -> String {
+        let crate_hash = source_fingerprints
+            .crate_with_dependencies_hash(Path::new(self.wasm_crate_path().as_str()));
+        let rustc_version = rustc_version::version_meta().unwrap();
+        hash_strings(&[crate_hash, rustc_version.short_version_string])
+    }
+
+    /// All outputs that a build with the given arguments should have produced.
+    pub(super) fn expected_output_paths(
+        &self,
+        build_args: &BuildArgs,
+        output_path: &str,
+    ) -> Vec<String> {
+        let mut names = vec![
+            self.wasm_output_name(build_args),
+            self.mxsc_file_output_name(build_args),
+        ];
+        if build_args.wat {
+            names.push(self.wat_output_name(build_args));
+        }
+        if build_args.extract_imports {
+            names.push(self.imports_json_output_name(build_args));
+        }
+        if build_args.twiggy_top {
+            names.push(self.twiggy_top_name(build_args));
+        }
+        if build_args.twiggy_paths {
+            names.push(self.twiggy_paths_name(build_args));
+        }
+        if build_args.twiggy_monos {
+            names.push(self.twiggy_monos_name(build_args));
+        }
+        if build_args.twiggy_dominators {
+            names.push(self.twiggy_dominators_name(build_args));
+        }
+        names
+            .into_iter()
+            .map(|name| format!("{output_path}/{name}"))
+            .collect()
+    }
+}
@@ -1,5 +1,7 @@
-mod all;
+pub mod all;
 mod info;
+pub mod interact;
+pub mod lint;
 mod local_deps;
 mod print_util;
 pub mod scen_test_gen;
@@ -10,7 +12,10 @@ pub(crate) mod upgrade;
 use crate::cli_args::{StandaloneCliAction, StandaloneCliArgs};
 use all::call_all_meta;
 use clap::Parser;
+use colored::Colorize;
 use info::call_info;
+use interact::interact;
+use lint::lint;
 use local_deps::local_deps;
 use scen_test_gen::test_gen_tool;
 use template::{create_contract, print_template_names};
@@ -39,6 +44,13 @@ pub fn cli_main_standalone() {
             test_gen_tool(args);
         },
         Some(StandaloneCliAction::Test(args)) => test(args),
+        Some(StandaloneCliAction::Lint(args)) => {
+            if let Err(err) = lint(args) {
+                println!("\n{}", err.to_string().yellow());
+                std::process::exit(1);
+            }
+        },
+        Some(StandaloneCliAction::Interact(args)) => interact(args),
         None => {},
     }
 }
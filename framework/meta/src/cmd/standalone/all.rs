@@ -1,9 +1,26 @@
-use super::print_util::{print_all_command, print_all_count, print_all_index};
+mod all_jobs;
+mod all_report;
+
+use super::print_util::{print_all_command, print_all_count, print_watching};
 use crate::{
-    cli_args::AllArgs,
+    cli_args::{AllArgs, CliArgsToRaw, ContractCliAction},
+    cmd::contract::sc_config::SC_CONFIG_FILE_NAMES,
     folder_structure::{dir_pretty_print, RelevantDirectories},
+    tools::{hash_file, hash_strings, SourceFingerprints},
+};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, Instant},
 };
-use std::{path::Path, process::Command};
+
+pub use all_jobs::{run_contract_jobs, ContractJob, ContractJobResult, ContractJobStatus};
+pub use all_report::{AllReportContractJson, AllReportJson, WasmOutputSizeJson};
+
+/// How often the sources are checked for changes in watch mode.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 pub fn call_all_meta(args: &AllArgs) {
     let path = if let Some(some_path) = &args.path {
@@ -12,11 +29,15 @@ pub fn call_all_meta(args: &AllArgs) {
         "./"
     };
 
-    perform_call_all_meta(path, args.ignore.as_slice(), args.to_cargo_run_args());
+    if args.watch {
+        watch_all_meta(path, args);
+    } else {
+        perform_call_all_meta(path, args);
+    }
 }
 
-fn perform_call_all_meta(path: impl AsRef<Path>, ignore: &[String], raw_args: Vec<String>) {
-    let dirs = RelevantDirectories::find_all(path, ignore);
+fn perform_call_all_meta(path: &str, args: &AllArgs) {
+    let dirs = RelevantDirectories::find_all(path, args.ignore.as_slice());
     dir_pretty_print(dirs.iter_contract_crates(), "", &|_| {});
 
     let num_contract_crates = dirs.iter_contract_crates().count();
@@ -26,19 +47,120 @@ fn perform_call_all_meta(path: impl AsRef<Path>, ignore: &[String], raw_args: Ve
         return;
     }
 
-    for (i, contract_crate) in dirs.iter_contract_crates().enumerate() {
-        print_all_index(i + 1, num_contract_crates);
-        call_contract_meta(contract_crate.path.as_path(), raw_args.as_slice());
+    let contract_paths: Vec<PathBuf> = dirs
+        .iter_contract_crates()
+        .map(|contract_crate| contract_crate.path.clone())
+        .collect();
+    let report = run_all_meta(&contract_paths, args);
+    if report.has_failures() {
+        std::process::exit(1);
     }
 }
 
-pub fn call_contract_meta(contract_crate_path: &Path, cargo_run_args: &[String]) {
+/// Calls the meta crates of the given contracts, prints the summary and saves the requested reports.
+fn run_all_meta(contract_paths: &[PathBuf], args: &AllArgs) -> AllReportJson {
+    let cargo_run_args = args.to_cargo_run_args();
+    let jobs = ContractJob::for_contracts(contract_paths, "cargo", &cargo_run_args, |path| {
+        meta_crate_path(path)
+    });
+
+    let start = Instant::now();
+    let results = run_contract_jobs(&jobs, args.jobs);
+    let report = AllReportJson::new(
+        args.command.to_raw().join(" "),
+        args.jobs,
+        start.elapsed(),
+        &results,
+        is_build_command(&args.command),
+    );
+    report.print_summary();
+    save_reports(&report, &args.report_json, &args.report_junit);
+    report
+}
+
+pub(crate) fn save_reports(
+    report: &AllReportJson,
+    report_json: &Option<String>,
+    report_junit: &Option<String>,
+) {
+    if let Some(report_json_path) = report_json {
+        report.save_json(report_json_path);
+    }
+    if let Some(report_junit_path) = report_junit {
+        report.save_junit_xml(report_junit_path);
+    }
+}
+
+fn is_build_command(command: &ContractCliAction) -> bool {
+    matches!(
+        command,
+        ContractCliAction::Build(_) | ContractCliAction::BuildDbg(_) | ContractCliAction::Twiggy(_)
+    )
+}
+
+fn meta_crate_path(contract_crate_path: &Path) -> PathBuf {
     let meta_path = contract_crate_path.join("meta");
     assert!(
         meta_path.exists(),
         "Contract meta crate not found at {}",
         meta_path.as_path().display()
     );
+    meta_path
+}
+
+/// Calls all meta crates once, then keeps polling the contract sources,
+/// calling again only the meta crates of the contracts that changed.
+///
+/// A contract is considered changed when its sources, its configuration,
+/// its meta crate or any of its local dependencies change.
+///
+/// The fingerprints are kept between polls, so only crates whose file metadata changed are hashed again.
+fn watch_all_meta(path: &str, args: &AllArgs) -> ! {
+    let mut known_hashes: HashMap<PathBuf, String> = HashMap::new();
+    let mut source_fingerprints = SourceFingerprints::new();
+    loop {
+        let dirs = RelevantDirectories::find_all(path, args.ignore.as_slice());
+        let changed_crates: Vec<PathBuf> = dirs
+            .iter_contract_crates()
+            .filter(|contract_crate| {
+                let hash = contract_source_hash(&mut source_fingerprints, &contract_crate.path);
+                known_hashes.get(&contract_crate.path) != Some(&hash)
+            })
+            .map(|contract_crate| contract_crate.path.clone())
+            .collect();
+
+        if !changed_crates.is_empty() {
+            print_all_count(changed_crates.len());
+            run_all_meta(&changed_crates, args);
+
+            // hashing again after the build, in case it changed any of the inputs (e.g. Cargo.lock)
+            for contract_crate_path in changed_crates {
+                let hash = contract_source_hash(&mut source_fingerprints, &contract_crate_path);
+                known_hashes.insert(contract_crate_path, hash);
+            }
+            print_watching();
+        }
+
+        thread::sleep(WATCH_POLL_INTERVAL);
+    }
+}
+
+fn contract_source_hash(
+    source_fingerprints: &mut SourceFingerprints,
+    contract_crate_path: &Path,
+) -> String {
+    let mut parts = vec![
+        source_fingerprints.crate_with_dependencies_hash(contract_crate_path),
+        source_fingerprints.crate_with_dependencies_hash(&contract_crate_path.join("meta")),
+    ];
+    for config_file_name in SC_CONFIG_FILE_NAMES {
+        parts.push(hash_file(contract_crate_path.join(config_file_name)).unwrap_or_default());
+    }
+    hash_strings(&parts)
+}
+
+pub fn call_contract_meta(contract_crate_path: &Path, cargo_run_args: &[String]) {
+    let meta_path = meta_crate_path(contract_crate_path);
 
     print_all_command(meta_path.as_path(), cargo_run_args);
 
@@ -0,0 +1,248 @@
+use colored::Colorize;
+use serde::Serialize;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::folder_structure::{local_dependency_crates, FRAMEWORK_CRATE_NAMES};
+
+use super::super::print_util::print_all_index;
+
+/// A command to be run for one contract crate, e.g. calling its meta crate, or running its tests.
+#[derive(Clone, Debug)]
+pub struct ContractJob {
+    pub contract_path: PathBuf,
+    pub working_dir: PathBuf,
+    pub program: String,
+    pub args: Vec<String>,
+    /// Indexes of the jobs that need to finish successfully before this one can start.
+    pub dependencies: Vec<usize>,
+}
+
+impl ContractJob {
+    /// Creates one job per contract, with the dependencies between them resolved from their local dependencies.
+    ///
+    /// The working dir is obtained from the contract crate path, e.g. to point to the meta crate.
+    pub fn for_contracts<F>(
+        contract_paths: &[PathBuf],
+        program: &str,
+        args: &[String],
+        working_dir_fn: F,
+    ) -> Vec<ContractJob>
+    where
+        F: Fn(&Path) -> PathBuf,
+    {
+        let canonical_paths: Vec<PathBuf> = contract_paths
+            .iter()
+            .map(|path| path.canonicalize().unwrap_or_else(|_| path.clone()))
+            .collect();
+        contract_paths
+            .iter()
+            .map(|contract_path| {
+                let dependencies = local_dependency_crates(contract_path, FRAMEWORK_CRATE_NAMES)
+                    .iter()
+                    .filter_map(|dep_path| canonical_paths.iter().position(|p| p == dep_path))
+                    .collect();
+                ContractJob {
+                    contract_path: contract_path.clone(),
+                    working_dir: working_dir_fn(contract_path),
+                    program: program.to_string(),
+                    args: args.to_vec(),
+                    dependencies,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContractJobStatus {
+    Success,
+    Failed,
+    /// Not run, because one of its dependencies did not succeed.
+    Skipped,
+}
+
+impl ContractJobStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContractJobStatus::Success => "ok",
+            ContractJobStatus::Failed => "FAILED",
+            ContractJobStatus::Skipped => "skipped",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ContractJobResult {
+    pub contract_path: PathBuf,
+    pub status: ContractJobStatus,
+    pub duration: Duration,
+    /// The collected output of the command. Only available when running jobs in parallel,
+    /// otherwise the output goes directly to the console.
+    pub output: Option<String>,
+}
+
+impl ContractJobResult {
+    fn skipped(job: &ContractJob) -> Self {
+        ContractJobResult {
+            contract_path: job.contract_path.clone(),
+            status: ContractJobStatus::Skipped,
+            duration: Duration::ZERO,
+            output: None,
+        }
+    }
+}
+
+/// Runs all jobs, at most `num_threads` at a time, respecting the dependencies between them.
+///
+/// Jobs whose dependencies failed are skipped.
+/// When running on more than one thread, the output of each job is collected and printed once it finishes,
+/// to avoid interleaving the output of different contracts.
+pub fn run_contract_jobs(jobs: &[ContractJob], num_threads: usize) -> Vec<ContractJobResult> {
+    let num_threads = num_threads.max(1);
+    let capture_output = num_threads > 1;
+    let num_jobs = jobs.len();
+    let mut results: Vec<Option<ContractJobResult>> = vec![None; num_jobs];
+    let mut started = vec![false; num_jobs];
+    let mut num_running = 0;
+    let mut num_finished = 0;
+    let (sender, receiver) = mpsc::channel();
+
+    loop {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (i, job) in jobs.iter().enumerate() {
+                if started[i] {
+                    continue;
+                }
+
+                let dependency_statuses: Vec<Option<ContractJobStatus>> = job
+                    .dependencies
+                    .iter()
+                    .map(|&dep| results[dep].as_ref().map(|result| result.status))
+                    .collect();
+                if dependency_statuses
+                    .iter()
+                    .any(|status| matches!(status, Some(s) if *s != ContractJobStatus::Success))
+                {
+                    started[i] = true;
+                    changed = true;
+                    results[i] = Some(ContractJobResult::skipped(job));
+                    num_finished += 1;
+                    print_job_finished(num_finished, num_jobs, results[i].as_ref().unwrap());
+                    continue;
+                }
+
+                let dependencies_done = dependency_statuses.iter().all(Option::is_some);
+                if dependencies_done && num_running < num_threads {
+                    started[i] = true;
+                    num_running += 1;
+                    if !capture_output {
+                        print_all_index(num_finished + 1, num_jobs);
+                    }
+                    print_job_command(job);
+
+                    let job = job.clone();
+                    let sender = sender.clone();
+                    thread::spawn(move || {
+                        let result = run_contract_job(&job, capture_output);
+                        sender.send((i, result)).unwrap();
+                    });
+                }
+            }
+        }
+
+        if num_running == 0 {
+            break;
+        }
+
+        let (i, result) = receiver
+            .recv()
+            .expect("contract job thread stopped unexpectedly");
+        num_running -= 1;
+        num_finished += 1;
+        print_job_finished(num_finished, num_jobs, &result);
+        results[i] = Some(result);
+    }
+
+    // only jobs in a dependency cycle can remain unstarted, there is nothing we can do for them
+    results
+        .into_iter()
+        .zip(jobs.iter())
+        .map(|(result, job)| result.unwrap_or_else(|| ContractJobResult::skipped(job)))
+        .collect()
+}
+
+fn run_contract_job(job: &ContractJob, capture_output: bool) -> ContractJobResult {
+    let start = Instant::now();
+    let mut command = Command::new(&job.program);
+    command.current_dir(&job.working_dir).args(&job.args);
+
+    let (success, output) = if capture_output {
+        match command.output() {
+            Ok(output) => {
+                let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+                text.push_str(&String::from_utf8_lossy(&output.stderr));
+                (output.status.success(), Some(text))
+            },
+            Err(error) => (
+                false,
+                Some(format!("failed to run {}: {error}", job.program)),
+            ),
+        }
+    } else {
+        let success = command
+            .spawn()
+            .and_then(|mut child| child.wait())
+            .map(|status| status.success())
+            .unwrap_or(false);
+        (success, None)
+    };
+
+    ContractJobResult {
+        contract_path: job.contract_path.clone(),
+        status: if success {
+            ContractJobStatus::Success
+        } else {
+            ContractJobStatus::Failed
+        },
+        duration: start.elapsed(),
+        output,
+    }
+}
+
+fn print_job_finished(index: usize, num_jobs: usize, result: &ContractJobResult) {
+    if let Some(output) = &result.output {
+        print!("{output}");
+    }
+    let status = match result.status {
+        ContractJobStatus::Success => result.status.name().green(),
+        ContractJobStatus::Failed => result.status.name().red(),
+        ContractJobStatus::Skipped => result.status.name().yellow(),
+    };
+    println!(
+        "{} {} {} ({:.1}s)",
+        format!("({index}/{num_jobs})").truecolor(128, 128, 128),
+        result.contract_path.display(),
+        status,
+        result.duration.as_secs_f64(),
+    );
+}
+
+fn print_job_command(job: &ContractJob) {
+    println!(
+        "{} {}\n{} `{} {}`",
+        "In".green(),
+        job.working_dir.display(),
+        "Calling".green(),
+        job.program,
+        job.args.join(" "),
+    );
+}
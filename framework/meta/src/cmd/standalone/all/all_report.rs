@@ -0,0 +1,213 @@
+use colored::Colorize;
+use serde::Serialize;
+use std::{fs, path::Path, time::Duration};
+
+use super::all_jobs::{ContractJobResult, ContractJobStatus};
+use crate::print_util::format_table;
+
+const OUTPUT_DIR_NAME: &str = "output";
+const WASM_EXTENSION: &str = "wasm";
+
+/// Summary of running a command over all contracts, can be saved as JSON or JUnit XML.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AllReportJson {
+    pub command: String,
+    pub jobs: usize,
+    pub duration_secs: f64,
+    pub contracts: Vec<AllReportContractJson>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AllReportContractJson {
+    pub path: String,
+    pub status: ContractJobStatus,
+    pub duration_secs: f64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub wasm_outputs: Vec<WasmOutputSizeJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmOutputSizeJson {
+    pub name: String,
+    pub size: u64,
+}
+
+impl AllReportJson {
+    /// Set `include_wasm_sizes` for commands that build contracts.
+    pub fn new(
+        command: String,
+        jobs: usize,
+        duration: Duration,
+        results: &[ContractJobResult],
+        include_wasm_sizes: bool,
+    ) -> Self {
+        let contracts = results
+            .iter()
+            .map(|result| AllReportContractJson {
+                path: result.contract_path.to_string_lossy().into_owned(),
+                status: result.status,
+                duration_secs: result.duration.as_secs_f64(),
+                wasm_outputs: if include_wasm_sizes && result.status == ContractJobStatus::Success {
+                    wasm_output_sizes(&result.contract_path)
+                } else {
+                    Vec::new()
+                },
+                output: result.output.clone(),
+            })
+            .collect();
+        AllReportJson {
+            command,
+            jobs,
+            duration_secs: duration.as_secs_f64(),
+            contracts,
+        }
+    }
+
+    pub fn count(&self, status: ContractJobStatus) -> usize {
+        self.contracts
+            .iter()
+            .filter(|contract| contract.status == status)
+            .count()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.count(ContractJobStatus::Success) < self.contracts.len()
+    }
+
+    pub fn summary_table_string(&self) -> String {
+        let lines: Vec<[String; 4]> = self
+            .contracts
+            .iter()
+            .map(|contract| {
+                [
+                    contract.path.clone(),
+                    contract.status.name().to_string(),
+                    format_wasm_sizes(&contract.wasm_outputs),
+                    format!("{:.1}s", contract.duration_secs),
+                ]
+            })
+            .collect();
+
+        format_table(["contract", "status", "wasm size", "duration"], &lines)
+    }
+
+    pub fn print_summary(&self) {
+        println!("\n{}", "Summary:".green());
+        print!("{}", self.summary_table_string());
+
+        let summary = format!(
+            "{} succeeded, {} failed, {} skipped, in {:.1}s.",
+            self.count(ContractJobStatus::Success),
+            self.count(ContractJobStatus::Failed),
+            self.count(ContractJobStatus::Skipped),
+            self.duration_secs,
+        );
+        if self.has_failures() {
+            println!("{}", summary.red());
+        } else {
+            println!("{}", summary.green());
+        }
+    }
+
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json).expect("failed to write JSON report");
+    }
+
+    pub fn to_junit_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites>\n  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+            xml_escape(&self.command),
+            self.contracts.len(),
+            self.count(ContractJobStatus::Failed),
+            self.count(ContractJobStatus::Skipped),
+            self.duration_secs,
+        ));
+        for contract in &self.contracts {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                xml_escape(&contract.path),
+                xml_escape(&self.command),
+                contract.duration_secs,
+            ));
+            match contract.status {
+                ContractJobStatus::Success => xml.push_str(" />\n"),
+                ContractJobStatus::Failed => {
+                    xml.push_str(">\n      <failure message=\"command failed\">");
+                    xml.push_str(&xml_escape(contract.output.as_deref().unwrap_or_default()));
+                    xml.push_str("</failure>\n    </testcase>\n");
+                },
+                ContractJobStatus::Skipped => {
+                    xml.push_str(
+                        ">\n      <skipped message=\"a dependency failed\" />\n    </testcase>\n",
+                    );
+                },
+            }
+        }
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+
+    pub fn save_junit_xml<P: AsRef<Path>>(&self, path: P) {
+        fs::write(path, self.to_junit_xml()).expect("failed to write JUnit report");
+    }
+}
+
+/// The sizes of all wasm files in the contract output folder.
+fn wasm_output_sizes(contract_path: &Path) -> Vec<WasmOutputSizeJson> {
+    let Ok(read_dir) = fs::read_dir(contract_path.join(OUTPUT_DIR_NAME)) else {
+        return Vec::new();
+    };
+    let mut sizes: Vec<WasmOutputSizeJson> = read_dir
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == WASM_EXTENSION)
+        })
+        .filter_map(|entry| {
+            let size = entry.metadata().ok()?.len();
+            Some(WasmOutputSizeJson {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                size,
+            })
+        })
+        .collect();
+    sizes.sort_by(|a, b| a.name.cmp(&b.name));
+    sizes
+}
+
+fn format_wasm_sizes(wasm_outputs: &[WasmOutputSizeJson]) -> String {
+    match wasm_outputs {
+        [] => "-".to_string(),
+        [single] => format!("{} B", single.size),
+        multiple => multiple
+            .iter()
+            .map(|output| format!("{} {} B", output.name, output.size))
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            c if c.is_control() && c != '\n' && c != '\t' && c != '\r' => {},
+            c => result.push(c),
+        }
+    }
+    result
+}
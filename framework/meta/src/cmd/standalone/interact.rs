@@ -0,0 +1,106 @@
+mod interact_abi;
+mod interact_code_metadata;
+mod interact_command;
+mod interact_decode;
+mod interact_encode;
+mod interact_error;
+mod interact_runner;
+mod interact_type;
+
+use multiversx_sdk::{blockchain::CommunicationProxy, data::address::Address, wallet::Wallet};
+
+use crate::cli_args::InteractArgs;
+
+const PASSWORD_ENV_VAR: &str = "MX_KEYSTORE_PASSWORD";
+
+pub use interact_abi::{InteractAbi, InteractEndpoint, InteractParam, DEPLOY_COMMAND_NAME};
+pub use interact_code_metadata::{format_code_metadata, parse_code_metadata};
+pub use interact_command::{build_interact_command, parse_interact_command, InteractCommand};
+pub use interact_decode::ResultDecoder;
+pub use interact_encode::ArgEncoder;
+pub use interact_error::{InteractError, InteractResult};
+pub use interact_runner::{InteractPayment, InteractRunner};
+pub use interact_type::AbiType;
+
+/// Calls a contract endpoint, driven only by the ABI file, prints the result as JSON.
+pub fn interact(args: &InteractArgs) {
+    let abi = InteractAbi::load_file(&args.abi).unwrap_or_else(|err| exit_with_error(err));
+    let command = parse_interact_command(&abi, &args.command).unwrap_or_else(|err| err.exit());
+    let runner = build_runner(args, abi).unwrap_or_else(|err| exit_with_error(err));
+
+    let runtime = tokio::runtime::Runtime::new().expect("failed to start tokio runtime");
+    match runtime.block_on(runner.run(&command)) {
+        Ok(report) => println!("{}", serde_json::to_string_pretty(&report).unwrap()),
+        Err(err) => exit_with_error(err),
+    }
+}
+
+fn build_runner(args: &InteractArgs, abi: InteractAbi) -> InteractResult<InteractRunner> {
+    let proxy = CommunicationProxy::new(args.proxy.clone());
+    let mut runner = InteractRunner::new(abi, proxy).with_gas_limit(args.gas_limit);
+
+    if let Some(wallet) = load_wallet(args)? {
+        runner = runner.with_wallet(wallet);
+    }
+    if let Some(contract) = &args.contract {
+        let address = Address::from_bech32_string(contract)
+            .map_err(|err| InteractError::Command(format!("invalid contract address: {err}")))?;
+        runner = runner.with_contract(address);
+    }
+    if let Some(value) = &args.value {
+        let value = value
+            .parse()
+            .map_err(|_| InteractError::Command(format!("invalid EGLD value `{value}`")))?;
+        runner = runner.with_value(value);
+    }
+    for payment in &args.esdt {
+        runner = runner.with_payment(InteractPayment::parse(payment)?);
+    }
+    if let Some(code_path) = &args.code {
+        let code = std::fs::read(code_path).map_err(|err| {
+            InteractError::Command(format!("cannot read contract code {code_path}: {err}"))
+        })?;
+        runner = runner.with_code(code);
+    }
+    let code_metadata = parse_code_metadata(&args.metadata).map_err(InteractError::Command)?;
+    Ok(runner.with_code_metadata(code_metadata))
+}
+
+fn load_wallet(args: &InteractArgs) -> InteractResult<Option<Wallet>> {
+    if let Some(pem) = &args.pem {
+        return Wallet::from_pem_file(pem)
+            .map(Some)
+            .map_err(|err| InteractError::Wallet(err.to_string()));
+    }
+    if let Some(keystore) = &args.keystore {
+        let password = keystore_password(args)?;
+        return Wallet::from_keystore_file(keystore, &password)
+            .map(Some)
+            .map_err(|err| InteractError::Wallet(err.to_string()));
+    }
+    Ok(None)
+}
+
+/// Never taken from the command line, where it would end up in the shell history and process list.
+fn keystore_password(args: &InteractArgs) -> InteractResult<String> {
+    if let Some(password_file) = &args.password_file {
+        let password = std::fs::read_to_string(password_file).map_err(|err| {
+            InteractError::Wallet(format!("cannot read password file {password_file}: {err}"))
+        })?;
+        return Ok(password.trim_end_matches(['\r', '\n']).to_string());
+    }
+    if let Ok(password) = std::env::var(PASSWORD_ENV_VAR) {
+        return Ok(password);
+    }
+    prompt_password()
+}
+
+fn prompt_password() -> InteractResult<String> {
+    rpassword::prompt_password("Keystore password: ")
+        .map_err(|err| InteractError::Wallet(err.to_string()))
+}
+
+fn exit_with_error(err: InteractError) -> ! {
+    eprintln!("{err}");
+    std::process::exit(1);
+}
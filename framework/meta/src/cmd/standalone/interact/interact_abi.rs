@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use multiversx_sc::abi::{
+    TypeContents, TypeDescription, TypeDescriptionContainer, TypeDescriptionContainerImpl,
+};
+
+use crate::abi_json::{
+    deserialize_abi_from_json, ContractAbiJson, EndpointMutabilityAbiJson, InputAbiJson,
+    OutputAbiJson,
+};
+
+use super::{AbiType, InteractError, InteractResult};
+
+/// Name of the sub-command that deploys the contract, calling its constructor.
+pub const DEPLOY_COMMAND_NAME: &str = "deploy";
+
+/// The parts of a contract ABI needed to call it, with all type names already parsed.
+#[derive(Clone, Debug)]
+pub struct InteractAbi {
+    pub name: String,
+    pub constructor: Option<InteractEndpoint>,
+    pub endpoints: Vec<InteractEndpoint>,
+    pub types: TypeDescriptionContainerImpl,
+}
+
+#[derive(Clone, Debug)]
+pub struct InteractEndpoint {
+    pub name: String,
+    pub docs: Vec<String>,
+    /// Readonly and pure endpoints are called via VM queries, mutable ones via transactions.
+    pub readonly: bool,
+    pub payable_in_tokens: Vec<String>,
+    pub inputs: Vec<InteractParam>,
+    pub outputs: Vec<InteractParam>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InteractParam {
+    pub name: String,
+    pub type_name: String,
+    pub abi_type: AbiType,
+}
+
+impl InteractAbi {
+    pub fn load_file<P: AsRef<Path>>(path: P) -> InteractResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| InteractError::Abi(format!("cannot read {}: {err}", path.display())))?;
+        Self::from_json_str(&contents)
+    }
+
+    pub fn from_json_str(json: &str) -> InteractResult<Self> {
+        let abi_json = deserialize_abi_from_json(json).map_err(InteractError::Abi)?;
+        Self::from_abi_json(&abi_json)
+    }
+
+    pub fn from_abi_json(abi_json: &ContractAbiJson) -> InteractResult<Self> {
+        let constructor = abi_json
+            .constructor
+            .as_ref()
+            .map(|constructor| {
+                InteractEndpoint::new(
+                    DEPLOY_COMMAND_NAME,
+                    &constructor.docs,
+                    false,
+                    &constructor.payable_in_tokens,
+                    &constructor.inputs,
+                    &constructor.outputs,
+                )
+            })
+            .transpose()?;
+
+        let endpoints = abi_json
+            .endpoints
+            .iter()
+            .map(|endpoint| {
+                InteractEndpoint::new(
+                    &endpoint.name,
+                    &endpoint.docs,
+                    !matches!(endpoint.mutability, EndpointMutabilityAbiJson::Mutable),
+                    &endpoint.payable_in_tokens,
+                    &endpoint.inputs,
+                    &endpoint.outputs,
+                )
+            })
+            .collect::<InteractResult<Vec<_>>>()?;
+
+        let mut types = TypeDescriptionContainerImpl::new();
+        for (type_name, type_json) in &abi_json.types {
+            let mut type_description = type_json.to_type_description(type_name);
+            // older ABIs do not list discriminants, they follow declaration order
+            if let TypeContents::Enum(variants) = &mut type_description.contents {
+                for (index, (variant, variant_json)) in
+                    variants.iter_mut().zip(&type_json.variants).enumerate()
+                {
+                    if variant_json.discriminant.is_none() {
+                        variant.discriminant = index;
+                    }
+                }
+            }
+            types.insert(type_name.clone(), type_description);
+        }
+
+        Ok(InteractAbi {
+            name: abi_json.name.clone(),
+            constructor,
+            endpoints,
+            types,
+        })
+    }
+
+    pub fn endpoint(&self, name: &str) -> Option<&InteractEndpoint> {
+        if name == DEPLOY_COMMAND_NAME {
+            return self.constructor.as_ref();
+        }
+        self.endpoints.iter().find(|endpoint| endpoint.name == name)
+    }
+
+    pub fn endpoint_or_err(&self, name: &str) -> InteractResult<&InteractEndpoint> {
+        self.endpoint(name).ok_or_else(|| {
+            if name == DEPLOY_COMMAND_NAME {
+                InteractError::Command(format!("contract `{}` has no constructor", self.name))
+            } else {
+                InteractError::Command(format!("unknown endpoint `{name}`"))
+            }
+        })
+    }
+
+    pub fn type_description(&self, type_name: &str) -> InteractResult<&TypeDescription> {
+        self.types
+            .0
+            .get(type_name)
+            .filter(|type_description| type_description.contents.is_specified())
+            .ok_or_else(|| InteractError::Abi(format!("unknown type `{type_name}`")))
+    }
+}
+
+impl InteractEndpoint {
+    fn new(
+        name: &str,
+        docs: &[String],
+        readonly: bool,
+        payable_in_tokens: &[String],
+        inputs: &[InputAbiJson],
+        outputs: &[OutputAbiJson],
+    ) -> InteractResult<Self> {
+        Ok(InteractEndpoint {
+            name: name.to_string(),
+            docs: docs.to_vec(),
+            readonly,
+            payable_in_tokens: payable_in_tokens.to_vec(),
+            inputs: inputs
+                .iter()
+                .map(|input| InteractParam::new(&input.arg_name, &input.type_name))
+                .collect::<InteractResult<_>>()?,
+            outputs: outputs
+                .iter()
+                .enumerate()
+                .map(|(index, output)| {
+                    if output.output_name.is_empty() {
+                        InteractParam::new(&format!("out{index}"), &output.type_name)
+                    } else {
+                        InteractParam::new(&output.output_name, &output.type_name)
+                    }
+                })
+                .collect::<InteractResult<_>>()?,
+        })
+    }
+
+    pub fn is_payable(&self) -> bool {
+        !self.payable_in_tokens.is_empty()
+    }
+}
+
+impl InteractParam {
+    fn new(name: &str, type_name: &str) -> InteractResult<Self> {
+        Ok(InteractParam {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            abi_type: AbiType::parse(type_name)?,
+        })
+    }
+}
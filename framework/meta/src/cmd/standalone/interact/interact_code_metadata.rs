@@ -0,0 +1,47 @@
+use multiversx_sc::types::CodeMetadata;
+
+const FLAG_NAMES: &[(&str, CodeMetadata)] = &[
+    ("upgradeable", CodeMetadata::UPGRADEABLE),
+    ("readable", CodeMetadata::READABLE),
+    ("payable", CodeMetadata::PAYABLE),
+    ("payable-by-sc", CodeMetadata::PAYABLE_BY_SC),
+];
+
+/// Parses code metadata given either as `0x`-prefixed hex,
+/// or as a list of flags, e.g. `upgradeable,readable,payable-by-sc`.
+pub fn parse_code_metadata(text: &str) -> Result<[u8; 2], String> {
+    if let Some(hex) = text.strip_prefix("0x") {
+        let bytes = hex::decode(hex).map_err(|err| format!("invalid hex: {err}"))?;
+        return <[u8; 2]>::try_from(bytes.as_slice())
+            .map_err(|_| "code metadata must be 2 bytes".to_string());
+    }
+
+    let mut metadata = CodeMetadata::DEFAULT;
+    for flag in text.split([',', '|']) {
+        let flag = flag.trim().to_lowercase();
+        if flag.is_empty() || flag == "default" {
+            continue;
+        }
+        let (_, flag_value) = FLAG_NAMES
+            .iter()
+            .find(|(name, _)| name.replace('-', "") == flag.replace('-', ""))
+            .ok_or_else(|| format!("unknown code metadata flag `{flag}`"))?;
+        metadata |= *flag_value;
+    }
+    Ok(metadata.to_byte_array())
+}
+
+/// The inverse of [`parse_code_metadata`], lists the flags that are set.
+pub fn format_code_metadata(bytes: [u8; 2]) -> String {
+    let metadata = CodeMetadata::from(bytes);
+    let flags: Vec<&str> = FLAG_NAMES
+        .iter()
+        .filter(|(_, flag_value)| metadata.contains(*flag_value))
+        .map(|(name, _)| *name)
+        .collect();
+    if flags.is_empty() {
+        "default".to_string()
+    } else {
+        flags.join(",")
+    }
+}
@@ -0,0 +1,105 @@
+use clap::{Arg, ArgAction, Command};
+
+use super::{InteractAbi, InteractEndpoint, DEPLOY_COMMAND_NAME};
+
+const ARGS_ID: &str = "args";
+
+/// An endpoint call, as given on the command line, before argument encoding.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractCommand {
+    pub endpoint: String,
+    pub args: Vec<String>,
+}
+
+impl InteractCommand {
+    pub fn new(endpoint: &str, args: &[&str]) -> Self {
+        InteractCommand {
+            endpoint: endpoint.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+}
+
+/// Builds a clap command at runtime, with one sub-command per endpoint, plus `deploy`.
+pub fn build_interact_command(abi: &InteractAbi) -> Command {
+    let mut command = Command::new(abi.name.clone())
+        .no_binary_name(true)
+        .subcommand_required(true)
+        .arg_required_else_help(true);
+    if let Some(constructor) = &abi.constructor {
+        command = command.subcommand(endpoint_command(constructor));
+    }
+    for endpoint in &abi.endpoints {
+        command = command.subcommand(endpoint_command(endpoint));
+    }
+    command
+}
+
+fn endpoint_command(endpoint: &InteractEndpoint) -> Command {
+    let mut about = endpoint.docs.first().cloned().unwrap_or_default();
+    if endpoint.name == DEPLOY_COMMAND_NAME {
+        about = format!("Deploys the contract. {about}");
+    } else if endpoint.readonly {
+        about = format!("[query] {about}");
+    }
+
+    let mut details = String::from("Arguments:\n");
+    if endpoint.inputs.is_empty() {
+        details.push_str("  (none)\n");
+    }
+    for input in &endpoint.inputs {
+        details.push_str(&format!("  {}: {}\n", input.name, input.type_name));
+    }
+    if !endpoint.outputs.is_empty() {
+        details.push_str("Results:\n");
+        for output in &endpoint.outputs {
+            details.push_str(&format!("  {}: {}\n", output.name, output.type_name));
+        }
+    }
+    if endpoint.is_payable() {
+        details.push_str(&format!(
+            "Payable in: {}\n",
+            endpoint.payable_in_tokens.join(", ")
+        ));
+    }
+
+    let value_names: Vec<String> = endpoint
+        .inputs
+        .iter()
+        .map(|input| input.name.clone())
+        .collect();
+    let mut args = Arg::new(ARGS_ID)
+        .action(ArgAction::Append)
+        .num_args(0..)
+        .allow_hyphen_values(true)
+        .trailing_var_arg(true);
+    if !value_names.is_empty() {
+        args = args.value_names(value_names);
+    }
+
+    Command::new(endpoint.name.clone())
+        .about(about)
+        .after_help(details)
+        .arg(args)
+}
+
+/// Splits raw command line arguments into the endpoint name and its arguments.
+///
+/// Help requests and unknown endpoints come back as clap errors, with a ready-made message.
+pub fn parse_interact_command(
+    abi: &InteractAbi,
+    raw_args: &[String],
+) -> Result<InteractCommand, clap::Error> {
+    let matches = build_interact_command(abi).try_get_matches_from(raw_args)?;
+    let (endpoint, endpoint_matches) = matches
+        .subcommand()
+        .expect("subcommand is required by the command");
+    let args = endpoint_matches
+        .get_many::<String>(ARGS_ID)
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+    Ok(InteractCommand {
+        endpoint: endpoint.to_string(),
+        args,
+    })
+}
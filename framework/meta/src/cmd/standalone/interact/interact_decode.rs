@@ -0,0 +1,352 @@
+use multiversx_sc::abi::{StructFieldDescription, TypeContents, TypeDescription};
+use multiversx_sdk::{
+    data::address::Address,
+    num_bigint::{BigInt, BigUint},
+};
+use serde_json::{Map, Value};
+
+use super::{
+    interact_code_metadata::format_code_metadata, AbiType, InteractAbi, InteractEndpoint,
+    InteractError, InteractParam, InteractResult,
+};
+
+/// Turns serialized endpoint results back into JSON, following the ABI.
+///
+/// Uses the same conventions as the [`ArgEncoder`](super::ArgEncoder), so results can be fed back as arguments.
+pub struct ResultDecoder<'a> {
+    abi: &'a InteractAbi,
+}
+
+impl<'a> ResultDecoder<'a> {
+    pub fn new(abi: &'a InteractAbi) -> Self {
+        ResultDecoder { abi }
+    }
+
+    /// Decodes all results of an endpoint, one JSON value per output.
+    ///
+    /// Multi-value outputs consume several raw results, `variadic` and `multi` become arrays.
+    pub fn decode_endpoint_results(
+        &self,
+        endpoint: &InteractEndpoint,
+        raw_results: &[Vec<u8>],
+    ) -> InteractResult<Vec<Value>> {
+        let mut remaining = raw_results;
+        let mut values = Vec::new();
+        for output in &endpoint.outputs {
+            let (value, rest) = self.decode_output(output, &output.abi_type, remaining)?;
+            values.push(value);
+            remaining = rest;
+        }
+        if !remaining.is_empty() {
+            return Err(InteractError::decode(
+                &endpoint.name,
+                format!("{} unexpected extra results", remaining.len()),
+            ));
+        }
+        Ok(values)
+    }
+
+    fn decode_output<'r>(
+        &self,
+        output: &InteractParam,
+        abi_type: &AbiType,
+        raw_results: &'r [Vec<u8>],
+    ) -> InteractResult<(Value, &'r [Vec<u8>])> {
+        match abi_type {
+            AbiType::Variadic(item_type) => {
+                let mut items = Vec::new();
+                let mut remaining = raw_results;
+                while !remaining.is_empty() {
+                    let (item, rest) = self.decode_output(output, item_type, remaining)?;
+                    items.push(item);
+                    remaining = rest;
+                }
+                Ok((Value::Array(items), remaining))
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let (count, mut remaining) =
+                    self.decode_output(output, &AbiType::Unsigned(4), raw_results)?;
+                let mut items = Vec::new();
+                for _ in 0..count.as_u64().unwrap_or_default() {
+                    let (item, rest) = self.decode_output(output, item_type, remaining)?;
+                    items.push(item);
+                    remaining = rest;
+                }
+                Ok((Value::Array(items), remaining))
+            },
+            AbiType::Optional(item_type) => {
+                if raw_results.is_empty() {
+                    Ok((Value::Null, raw_results))
+                } else {
+                    self.decode_output(output, item_type, raw_results)
+                }
+            },
+            AbiType::Multi(item_types) => {
+                let mut items = Vec::new();
+                let mut remaining = raw_results;
+                for item_type in item_types {
+                    let (item, rest) = self.decode_output(output, item_type, remaining)?;
+                    items.push(item);
+                    remaining = rest;
+                }
+                Ok((Value::Array(items), remaining))
+            },
+            AbiType::Ignore => Ok((Value::Null, &raw_results[raw_results.len()..])),
+            _ => {
+                let Some((raw_result, remaining)) = raw_results.split_first() else {
+                    return Err(InteractError::decode(&output.name, "missing result"));
+                };
+                let value = self
+                    .top_decode(raw_result, abi_type)
+                    .map_err(|message| InteractError::decode(&output.name, message))?;
+                Ok((value, remaining))
+            },
+        }
+    }
+
+    pub fn top_decode(&self, bytes: &[u8], abi_type: &AbiType) -> Result<Value, String> {
+        match abi_type {
+            AbiType::Unsigned(size) => {
+                if bytes.len() > *size {
+                    return Err(format!(
+                        "{} bytes do not fit in a u{}",
+                        bytes.len(),
+                        size * 8
+                    ));
+                }
+                Ok(unsigned_to_json(BigUint::from_bytes_be(bytes)))
+            },
+            AbiType::Signed(size) => {
+                if bytes.len() > *size {
+                    return Err(format!(
+                        "{} bytes do not fit in an i{}",
+                        bytes.len(),
+                        size * 8
+                    ));
+                }
+                Ok(signed_to_json(BigInt::from_signed_bytes_be(bytes)))
+            },
+            AbiType::BigUint => Ok(Value::String(BigUint::from_bytes_be(bytes).to_string())),
+            AbiType::BigInt => Ok(Value::String(
+                BigInt::from_signed_bytes_be(bytes).to_string(),
+            )),
+            AbiType::Bool => match bytes {
+                [] => Ok(Value::Bool(false)),
+                [1] => Ok(Value::Bool(true)),
+                _ => Err("invalid bool".to_string()),
+            },
+            AbiType::Bytes => Ok(bytes_to_json(bytes)),
+            AbiType::Utf8String | AbiType::TokenIdentifier | AbiType::EgldOrEsdtTokenIdentifier => {
+                utf8_to_json(bytes)
+            },
+            AbiType::Option(item_type) => match bytes.split_first() {
+                None => Ok(Value::Null),
+                Some((1, rest)) => self.nested_decode_all(rest, item_type),
+                Some(_) => Err("invalid Option".to_string()),
+            },
+            AbiType::List(item_type) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.nested_decode(&mut input, item_type)?);
+                }
+                Ok(Value::Array(items))
+            },
+            AbiType::Custom(type_name) => {
+                let description = self.type_description(type_name)?;
+                match &description.contents {
+                    TypeContents::Enum(variants) => {
+                        let first_fieldless = variants
+                            .first()
+                            .is_some_and(|variant| variant.fields.is_empty());
+                        let all_fieldless =
+                            variants.iter().all(|variant| variant.fields.is_empty());
+                        if bytes.is_empty() && first_fieldless {
+                            self.nested_decode_all(&[0], abi_type)
+                        } else if all_fieldless && bytes.len() > 1 {
+                            Err("invalid enum discriminant".to_string())
+                        } else {
+                            self.nested_decode_all(bytes, abi_type)
+                        }
+                    },
+                    TypeContents::ExplicitEnum(_) => utf8_to_json(bytes),
+                    _ => self.nested_decode_all(bytes, abi_type),
+                }
+            },
+            AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Multi(_)
+            | AbiType::Ignore => Err("multi-values can only be top-level results".to_string()),
+            _ => self.nested_decode_all(bytes, abi_type),
+        }
+    }
+
+    fn nested_decode_all(&self, bytes: &[u8], abi_type: &AbiType) -> Result<Value, String> {
+        let mut input = bytes;
+        let value = self.nested_decode(&mut input, abi_type)?;
+        if !input.is_empty() {
+            return Err("input too long".to_string());
+        }
+        Ok(value)
+    }
+
+    pub fn nested_decode(&self, input: &mut &[u8], abi_type: &AbiType) -> Result<Value, String> {
+        let value = match abi_type {
+            AbiType::Unsigned(size) | AbiType::Signed(size) => {
+                let bytes = take(input, *size)?;
+                self.top_decode(bytes, abi_type)?
+            },
+            AbiType::Bool => match take(input, 1)? {
+                [0] => Value::Bool(false),
+                [1] => Value::Bool(true),
+                _ => return Err("invalid bool".to_string()),
+            },
+            AbiType::Address => {
+                let bytes = take(input, 32)?;
+                let address = Address::from_bytes(bytes.try_into().unwrap());
+                Value::String(address.to_bech32_string().map_err(|err| err.to_string())?)
+            },
+            AbiType::H256 => Value::String(hex::encode(take(input, 32)?)),
+            AbiType::CodeMetadata => {
+                let bytes = take(input, 2)?;
+                Value::String(format_code_metadata(bytes.try_into().unwrap()))
+            },
+            AbiType::Option(item_type) => match take(input, 1)? {
+                [0] => Value::Null,
+                [1] => self.nested_decode(input, item_type)?,
+                _ => return Err("invalid Option".to_string()),
+            },
+            AbiType::List(item_type) => {
+                let len = take_length(input)?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.nested_decode(input, item_type)?);
+                }
+                Value::Array(items)
+            },
+            AbiType::Array(len, item_type) => {
+                if **item_type == AbiType::Unsigned(1) {
+                    bytes_to_json(take(input, *len)?)
+                } else {
+                    let mut items = Vec::new();
+                    for _ in 0..*len {
+                        items.push(self.nested_decode(input, item_type)?);
+                    }
+                    Value::Array(items)
+                }
+            },
+            AbiType::Tuple(item_types) => Value::Array(
+                item_types
+                    .iter()
+                    .map(|item_type| self.nested_decode(input, item_type))
+                    .collect::<Result<_, _>>()?,
+            ),
+            AbiType::Custom(type_name) => {
+                let description = self.type_description(type_name)?;
+                match &description.contents {
+                    TypeContents::Struct(fields) => self.nested_decode_fields(input, fields)?,
+                    TypeContents::Enum(variants) => {
+                        let discriminant = take(input, 1)?[0] as usize;
+                        let variant = variants
+                            .iter()
+                            .find(|variant| variant.discriminant == discriminant)
+                            .ok_or_else(|| {
+                                format!("invalid discriminant {discriminant} for `{type_name}`")
+                            })?;
+                        if variant.fields.is_empty() {
+                            Value::String(variant.name.clone())
+                        } else {
+                            let mut map = Map::new();
+                            map.insert(
+                                variant.name.clone(),
+                                self.nested_decode_fields(input, &variant.fields)?,
+                            );
+                            Value::Object(map)
+                        }
+                    },
+                    TypeContents::ExplicitEnum(_) => {
+                        let len = take_length(input)?;
+                        utf8_to_json(take(input, len)?)?
+                    },
+                    TypeContents::NotSpecified => {
+                        return Err(format!("type `{type_name}` has no description"))
+                    },
+                }
+            },
+            AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Multi(_)
+            | AbiType::Ignore => return Err("multi-values cannot be nested".to_string()),
+            _ => {
+                // dynamically sized values are length-prefixed when nested
+                let len = take_length(input)?;
+                self.top_decode(take(input, len)?, abi_type)?
+            },
+        };
+        Ok(value)
+    }
+
+    /// Struct fields become a JSON object, keyed by field name.
+    fn nested_decode_fields(
+        &self,
+        input: &mut &[u8],
+        fields: &[StructFieldDescription],
+    ) -> Result<Value, String> {
+        let mut map = Map::new();
+        for field in fields {
+            let field_type = AbiType::parse(&field.field_type).map_err(|err| err.to_string())?;
+            let value = self
+                .nested_decode(input, &field_type)
+                .map_err(|message| format!("field `{}`: {message}", field.name))?;
+            map.insert(field.name.clone(), value);
+        }
+        Ok(Value::Object(map))
+    }
+
+    fn type_description(&self, type_name: &str) -> Result<&TypeDescription, String> {
+        self.abi
+            .type_description(type_name)
+            .map_err(|err| err.to_string())
+    }
+}
+
+fn take<'i>(input: &mut &'i [u8], len: usize) -> Result<&'i [u8], String> {
+    if input.len() < len {
+        return Err("input too short".to_string());
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_length(input: &mut &[u8]) -> Result<usize, String> {
+    let bytes = take(input, 4)?;
+    Ok(u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+}
+
+fn unsigned_to_json(number: BigUint) -> Value {
+    let digits = number.to_u64_digits();
+    match digits.as_slice() {
+        [] => Value::from(0u64),
+        [single] => Value::from(*single),
+        _ => Value::String(number.to_string()),
+    }
+}
+
+fn signed_to_json(number: BigInt) -> Value {
+    i64::try_from(&number)
+        .map(Value::from)
+        .unwrap_or_else(|_| Value::String(number.to_string()))
+}
+
+fn bytes_to_json(bytes: &[u8]) -> Value {
+    Value::String(format!("0x{}", hex::encode(bytes)))
+}
+
+fn utf8_to_json(bytes: &[u8]) -> Result<Value, String> {
+    String::from_utf8(bytes.to_vec())
+        .map(Value::String)
+        .map_err(|_| "invalid utf-8".to_string())
+}
@@ -0,0 +1,515 @@
+use multiversx_sc::abi::{StructFieldDescription, TypeContents, TypeDescription};
+use multiversx_sdk::{
+    data::address::Address,
+    num_bigint::{BigInt, BigUint, Sign},
+};
+use serde_json::Value;
+
+use super::{
+    interact_code_metadata::parse_code_metadata, AbiType, InteractAbi, InteractEndpoint,
+    InteractError, InteractParam, InteractResult,
+};
+
+/// Turns human-friendly command line arguments into serialized endpoint arguments.
+///
+/// Simple values (numbers, addresses, strings, token identifiers, fieldless enum variants)
+/// are written as plain text. Composite values (structs, lists, options, tuples) are written as JSON,
+/// using the same conventions for the values inside.
+pub struct ArgEncoder<'a> {
+    abi: &'a InteractAbi,
+}
+
+impl<'a> ArgEncoder<'a> {
+    pub fn new(abi: &'a InteractAbi) -> Self {
+        ArgEncoder { abi }
+    }
+
+    /// Encodes all arguments of an endpoint, each one top-encoded.
+    ///
+    /// Multi-value inputs (`variadic`, `optional`, `multi`) consume several raw arguments.
+    pub fn encode_endpoint_args(
+        &self,
+        endpoint: &InteractEndpoint,
+        raw_args: &[String],
+    ) -> InteractResult<Vec<Vec<u8>>> {
+        let mut remaining = raw_args;
+        let mut result = Vec::new();
+        for input in &endpoint.inputs {
+            remaining = self.encode_input(input, &input.abi_type, remaining, &mut result)?;
+        }
+        if !remaining.is_empty() {
+            return Err(InteractError::Command(format!(
+                "too many arguments for `{}`: expected {}, got {}",
+                endpoint.name,
+                endpoint.inputs.len(),
+                raw_args.len()
+            )));
+        }
+        Ok(result)
+    }
+
+    fn encode_input<'r>(
+        &self,
+        input: &InteractParam,
+        abi_type: &AbiType,
+        raw_args: &'r [String],
+        result: &mut Vec<Vec<u8>>,
+    ) -> InteractResult<&'r [String]> {
+        match abi_type {
+            AbiType::Variadic(item_type) => {
+                let mut remaining = raw_args;
+                while !remaining.is_empty() {
+                    remaining = self.encode_input(input, item_type, remaining, result)?;
+                }
+                Ok(remaining)
+            },
+            AbiType::CountedVariadic(item_type) => {
+                let count_index = result.len();
+                result.push(Vec::new());
+                let mut remaining = raw_args;
+                let mut count = 0u32;
+                while !remaining.is_empty() {
+                    remaining = self.encode_input(input, item_type, remaining, result)?;
+                    count += 1;
+                }
+                result[count_index] = top_encode_unsigned(&BigUint::from(count));
+                Ok(remaining)
+            },
+            AbiType::Optional(item_type) => {
+                if raw_args.is_empty() {
+                    Ok(raw_args)
+                } else {
+                    self.encode_input(input, item_type, raw_args, result)
+                }
+            },
+            AbiType::Multi(item_types) => {
+                let mut remaining = raw_args;
+                for item_type in item_types {
+                    remaining = self.encode_input(input, item_type, remaining, result)?;
+                }
+                Ok(remaining)
+            },
+            AbiType::Ignore => Ok(&raw_args[raw_args.len()..]),
+            _ => {
+                let Some((raw_arg, remaining)) = raw_args.split_first() else {
+                    return Err(InteractError::Command(format!(
+                        "missing argument `{}` of type {}",
+                        input.name, input.type_name
+                    )));
+                };
+                let value = self
+                    .parse_arg(abi_type, raw_arg)
+                    .map_err(|message| InteractError::encode(&input.name, message))?;
+                let encoded = self
+                    .top_encode(&value, abi_type)
+                    .map_err(|message| InteractError::encode(&input.name, message))?;
+                result.push(encoded);
+                Ok(remaining)
+            },
+        }
+    }
+
+    /// Interprets a raw command line argument, as text or JSON, depending on the type.
+    pub fn parse_arg(&self, abi_type: &AbiType, raw_arg: &str) -> Result<Value, String> {
+        if self.is_textual(abi_type) {
+            Ok(Value::String(raw_arg.to_string()))
+        } else {
+            serde_json::from_str(raw_arg).map_err(|err| format!("expected JSON value: {err}"))
+        }
+    }
+
+    fn is_textual(&self, abi_type: &AbiType) -> bool {
+        match abi_type {
+            AbiType::Custom(type_name) => match self.abi.type_description(type_name) {
+                Ok(description) => match &description.contents {
+                    TypeContents::Enum(variants) => {
+                        variants.iter().all(|variant| variant.fields.is_empty())
+                    },
+                    TypeContents::ExplicitEnum(_) => true,
+                    _ => false,
+                },
+                Err(_) => false,
+            },
+            AbiType::Option(_)
+            | AbiType::List(_)
+            | AbiType::Array(..)
+            | AbiType::Tuple(_)
+            | AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Multi(_)
+            | AbiType::Ignore => false,
+            _ => true,
+        }
+    }
+
+    pub fn top_encode(&self, value: &Value, abi_type: &AbiType) -> Result<Vec<u8>, String> {
+        match abi_type {
+            AbiType::Unsigned(_) | AbiType::BigUint => {
+                let number = parse_unsigned(value)?;
+                check_unsigned_size(&number, abi_type)?;
+                Ok(top_encode_unsigned(&number))
+            },
+            AbiType::Signed(_) | AbiType::BigInt => {
+                let number = parse_signed(value)?;
+                check_signed_size(&number, abi_type)?;
+                Ok(top_encode_signed(&number))
+            },
+            AbiType::Bool => Ok(if parse_bool(value)? {
+                vec![1]
+            } else {
+                Vec::new()
+            }),
+            AbiType::Bytes => parse_bytes(value),
+            AbiType::Utf8String | AbiType::TokenIdentifier | AbiType::EgldOrEsdtTokenIdentifier => {
+                Ok(as_str(value)?.as_bytes().to_vec())
+            },
+            AbiType::Option(item_type) => {
+                if value.is_null() {
+                    Ok(Vec::new())
+                } else {
+                    let mut result = vec![1];
+                    self.nested_encode(value, item_type, &mut result)?;
+                    Ok(result)
+                }
+            },
+            AbiType::List(item_type) => {
+                let mut result = Vec::new();
+                for item in as_array(value)? {
+                    self.nested_encode(item, item_type, &mut result)?;
+                }
+                Ok(result)
+            },
+            AbiType::Custom(type_name) => {
+                let description = self.type_description(type_name)?;
+                match &description.contents {
+                    TypeContents::Enum(variants) => {
+                        let (variant_name, _) = enum_variant_value(value)?;
+                        let variant = variants
+                            .iter()
+                            .find(|variant| variant.name == variant_name)
+                            .ok_or_else(|| unknown_variant(type_name, variant_name))?;
+                        if variant.fields.is_empty() {
+                            // fieldless variants are top-encoded as a plain u8
+                            Ok(top_encode_unsigned(&BigUint::from(variant.discriminant)))
+                        } else {
+                            let mut result = Vec::new();
+                            self.nested_encode(value, abi_type, &mut result)?;
+                            Ok(result)
+                        }
+                    },
+                    TypeContents::ExplicitEnum(variants) => {
+                        let variant_name = as_str(value)?;
+                        if variants.iter().any(|variant| variant.name == variant_name) {
+                            Ok(variant_name.as_bytes().to_vec())
+                        } else {
+                            Err(unknown_variant(type_name, variant_name))
+                        }
+                    },
+                    _ => {
+                        let mut result = Vec::new();
+                        self.nested_encode(value, abi_type, &mut result)?;
+                        Ok(result)
+                    },
+                }
+            },
+            AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Multi(_)
+            | AbiType::Ignore => Err("multi-values can only be top-level arguments".to_string()),
+            _ => {
+                // everything else is top-encoded the same way it is nested-encoded
+                let mut result = Vec::new();
+                self.nested_encode(value, abi_type, &mut result)?;
+                Ok(result)
+            },
+        }
+    }
+
+    pub fn nested_encode(
+        &self,
+        value: &Value,
+        abi_type: &AbiType,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        match abi_type {
+            AbiType::Unsigned(size) => {
+                let number = parse_unsigned(value)?;
+                check_unsigned_size(&number, abi_type)?;
+                let bytes = top_encode_unsigned(&number);
+                dest.resize(dest.len() + size - bytes.len(), 0u8);
+                dest.extend(bytes);
+            },
+            AbiType::Signed(size) => {
+                let number = parse_signed(value)?;
+                check_signed_size(&number, abi_type)?;
+                let bytes = top_encode_signed(&number);
+                let padding = if number.sign() == Sign::Minus {
+                    0xffu8
+                } else {
+                    0u8
+                };
+                dest.resize(dest.len() + size - bytes.len(), padding);
+                dest.extend(bytes);
+            },
+            AbiType::Bool => dest.push(parse_bool(value)? as u8),
+            AbiType::Address => dest.extend(parse_address(value)?),
+            AbiType::H256 => dest.extend(parse_fixed_hex(value, 32)?),
+            AbiType::CodeMetadata => dest.extend(parse_code_metadata(as_str(value)?)?),
+            AbiType::Option(item_type) => {
+                if value.is_null() {
+                    dest.push(0);
+                } else {
+                    dest.push(1);
+                    self.nested_encode(value, item_type, dest)?;
+                }
+            },
+            AbiType::List(item_type) => {
+                let items = as_array(value)?;
+                push_length(items.len(), dest)?;
+                for item in items {
+                    self.nested_encode(item, item_type, dest)?;
+                }
+            },
+            AbiType::Array(len, item_type) => {
+                let items = if **item_type == AbiType::Unsigned(1) && value.is_string() {
+                    // byte arrays can be given as hex or text
+                    parse_bytes(value)?.into_iter().map(Value::from).collect()
+                } else {
+                    as_array(value)?.clone()
+                };
+                if items.len() != *len {
+                    return Err(format!("expected {len} items, got {}", items.len()));
+                }
+                for item in &items {
+                    self.nested_encode(item, item_type, dest)?;
+                }
+            },
+            AbiType::Tuple(item_types) => {
+                let items = as_array(value)?;
+                if items.len() != item_types.len() {
+                    return Err(format!(
+                        "expected a tuple of {} items, got {}",
+                        item_types.len(),
+                        items.len()
+                    ));
+                }
+                for (item, item_type) in items.iter().zip(item_types) {
+                    self.nested_encode(item, item_type, dest)?;
+                }
+            },
+            AbiType::Custom(type_name) => {
+                let description = self.type_description(type_name)?;
+                match &description.contents {
+                    TypeContents::Struct(fields) => {
+                        self.nested_encode_fields(value, fields, dest)?
+                    },
+                    TypeContents::Enum(variants) => {
+                        let (variant_name, fields_value) = enum_variant_value(value)?;
+                        let variant = variants
+                            .iter()
+                            .find(|variant| variant.name == variant_name)
+                            .ok_or_else(|| unknown_variant(type_name, variant_name))?;
+                        dest.push(u8::try_from(variant.discriminant).map_err(|_| {
+                            format!("discriminant of `{variant_name}` does not fit in a byte")
+                        })?);
+                        if !variant.fields.is_empty() {
+                            let fields_value = fields_value.ok_or_else(|| {
+                                format!("variant `{variant_name}` requires fields")
+                            })?;
+                            self.nested_encode_fields(fields_value, &variant.fields, dest)?;
+                        }
+                    },
+                    TypeContents::ExplicitEnum(_) => {
+                        let encoded = self.top_encode(value, abi_type)?;
+                        push_length(encoded.len(), dest)?;
+                        dest.extend(encoded);
+                    },
+                    TypeContents::NotSpecified => {
+                        return Err(format!("type `{type_name}` has no description"))
+                    },
+                }
+            },
+            AbiType::Variadic(_)
+            | AbiType::CountedVariadic(_)
+            | AbiType::Optional(_)
+            | AbiType::Multi(_)
+            | AbiType::Ignore => return Err("multi-values cannot be nested".to_string()),
+            _ => {
+                // dynamically sized values are length-prefixed when nested
+                let encoded = self.top_encode(value, abi_type)?;
+                push_length(encoded.len(), dest)?;
+                dest.extend(encoded);
+            },
+        }
+        Ok(())
+    }
+
+    fn type_description(&self, type_name: &str) -> Result<&TypeDescription, String> {
+        self.abi
+            .type_description(type_name)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Struct fields come as a JSON object, by name, or as a JSON array, in order.
+    fn nested_encode_fields(
+        &self,
+        value: &Value,
+        fields: &[StructFieldDescription],
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        for (index, field) in fields.iter().enumerate() {
+            let field_value = match value {
+                Value::Object(map) => map.get(&field.name),
+                Value::Array(items) => items.get(index),
+                _ => return Err("expected a JSON object or array of fields".to_string()),
+            }
+            .ok_or_else(|| format!("missing field `{}`", field.name))?;
+            let field_type = AbiType::parse(&field.field_type).map_err(|err| err.to_string())?;
+            self.nested_encode(field_value, &field_type, dest)
+                .map_err(|message| format!("field `{}`: {message}", field.name))?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn top_encode_unsigned(number: &BigUint) -> Vec<u8> {
+    if *number == BigUint::default() {
+        Vec::new()
+    } else {
+        number.to_bytes_be()
+    }
+}
+
+fn top_encode_signed(number: &BigInt) -> Vec<u8> {
+    if *number == BigInt::default() {
+        Vec::new()
+    } else {
+        number.to_signed_bytes_be()
+    }
+}
+
+fn push_length(len: usize, dest: &mut Vec<u8>) -> Result<(), String> {
+    let len = u32::try_from(len).map_err(|_| "value too long".to_string())?;
+    dest.extend(len.to_be_bytes());
+    Ok(())
+}
+
+fn check_unsigned_size(number: &BigUint, abi_type: &AbiType) -> Result<(), String> {
+    if let AbiType::Unsigned(size) = abi_type {
+        if number.bits() > (*size as u64) * 8 {
+            return Err(format!("{number} does not fit in {} bits", size * 8));
+        }
+    }
+    Ok(())
+}
+
+fn check_signed_size(number: &BigInt, abi_type: &AbiType) -> Result<(), String> {
+    if let AbiType::Signed(size) = abi_type {
+        if number.to_signed_bytes_be().len() > *size {
+            return Err(format!("{number} does not fit in {} bits", size * 8));
+        }
+    }
+    Ok(())
+}
+
+fn parse_unsigned(value: &Value) -> Result<BigUint, String> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .map(BigUint::from)
+            .ok_or_else(|| format!("expected unsigned integer, got {number}")),
+        Value::String(text) => {
+            let text = text.replace('_', "");
+            let parsed = if let Some(hex) = text.strip_prefix("0x") {
+                BigUint::parse_bytes(hex.as_bytes(), 16)
+            } else {
+                BigUint::parse_bytes(text.as_bytes(), 10)
+            };
+            parsed.ok_or_else(|| format!("expected unsigned integer, got `{text}`"))
+        },
+        _ => Err(format!("expected unsigned integer, got {value}")),
+    }
+}
+
+fn parse_signed(value: &Value) -> Result<BigInt, String> {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .map(BigInt::from)
+            .ok_or_else(|| format!("expected integer, got {number}")),
+        Value::String(text) => BigInt::parse_bytes(text.replace('_', "").as_bytes(), 10)
+            .ok_or_else(|| format!("expected integer, got `{text}`")),
+        _ => Err(format!("expected integer, got {value}")),
+    }
+}
+
+fn parse_bool(value: &Value) -> Result<bool, String> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        Value::String(text) if text == "true" => Ok(true),
+        Value::String(text) if text == "false" => Ok(false),
+        _ => Err(format!("expected true or false, got {value}")),
+    }
+}
+
+/// Bytes are given as `0x`-prefixed hex, otherwise the text itself is used.
+fn parse_bytes(value: &Value) -> Result<Vec<u8>, String> {
+    let text = as_str(value)?;
+    if let Some(hex) = text.strip_prefix("0x") {
+        hex::decode(hex).map_err(|err| format!("invalid hex: {err}"))
+    } else {
+        Ok(text.as_bytes().to_vec())
+    }
+}
+
+fn parse_fixed_hex(value: &Value, len: usize) -> Result<Vec<u8>, String> {
+    let text = as_str(value)?;
+    let bytes =
+        hex::decode(text.trim_start_matches("0x")).map_err(|err| format!("invalid hex: {err}"))?;
+    if bytes.len() != len {
+        return Err(format!("expected {len} bytes, got {}", bytes.len()));
+    }
+    Ok(bytes)
+}
+
+/// Addresses are given in bech32, or as 32 bytes of hex.
+fn parse_address(value: &Value) -> Result<Vec<u8>, String> {
+    let text = as_str(value)?;
+    if text.starts_with("erd1") {
+        Address::from_bech32_string(text)
+            .map(|address| address.to_bytes().to_vec())
+            .map_err(|err| format!("invalid bech32 address: {err}"))
+    } else {
+        parse_fixed_hex(value, 32)
+    }
+}
+
+fn as_str(value: &Value) -> Result<&str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("expected string, got {value}"))
+}
+
+fn as_array(value: &Value) -> Result<&Vec<Value>, String> {
+    value
+        .as_array()
+        .ok_or_else(|| format!("expected JSON array, got {value}"))
+}
+
+/// Enum values are either the variant name, or an object with the variant name as its single key.
+fn enum_variant_value(value: &Value) -> Result<(&str, Option<&Value>), String> {
+    match value {
+        Value::String(name) => Ok((name.as_str(), None)),
+        Value::Object(map) if map.len() == 1 => {
+            let (name, fields) = map.iter().next().unwrap();
+            Ok((name.as_str(), Some(fields)))
+        },
+        _ => Err(format!("expected enum variant, got {value}")),
+    }
+}
+
+fn unknown_variant(type_name: &str, variant_name: &str) -> String {
+    format!("`{variant_name}` is not a variant of `{type_name}`")
+}
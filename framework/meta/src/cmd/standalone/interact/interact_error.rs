@@ -0,0 +1,84 @@
+use std::fmt;
+
+use multiversx_sdk::gateway::GatewayError;
+
+/// Everything that can go wrong while interacting with a contract from its ABI.
+#[derive(Debug)]
+pub enum InteractError {
+    /// The ABI file could not be loaded, or describes something we cannot handle.
+    Abi(String),
+
+    /// The command line does not match the endpoint signature.
+    Command(String),
+
+    /// An argument could not be encoded as its ABI type.
+    Encode {
+        arg: String,
+        message: String,
+    },
+
+    /// A result could not be decoded as its ABI type.
+    Decode {
+        output: String,
+        message: String,
+    },
+
+    /// No wallet, or the wallet could not be loaded.
+    Wallet(String),
+
+    Gateway(GatewayError),
+
+    /// The transaction or query was processed, but failed.
+    Execution(String),
+}
+
+pub type InteractResult<T> = Result<T, InteractError>;
+
+impl InteractError {
+    pub(crate) fn encode(arg: &str, message: impl Into<String>) -> Self {
+        InteractError::Encode {
+            arg: arg.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn decode(output: &str, message: impl Into<String>) -> Self {
+        InteractError::Decode {
+            output: output.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for InteractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InteractError::Abi(message) => write!(f, "invalid ABI: {message}"),
+            InteractError::Command(message) => write!(f, "{message}"),
+            InteractError::Encode { arg, message } => {
+                write!(f, "cannot encode argument `{arg}`: {message}")
+            },
+            InteractError::Decode { output, message } => {
+                write!(f, "cannot decode result `{output}`: {message}")
+            },
+            InteractError::Wallet(message) => write!(f, "wallet error: {message}"),
+            InteractError::Gateway(err) => write!(f, "{err}"),
+            InteractError::Execution(message) => write!(f, "execution failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for InteractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InteractError::Gateway(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<GatewayError> for InteractError {
+    fn from(err: GatewayError) -> Self {
+        InteractError::Gateway(err)
+    }
+}
@@ -0,0 +1,339 @@
+use std::time::{Duration, Instant};
+
+use multiversx_sdk::{
+    blockchain::CommunicationProxy,
+    data::{address::Address, transaction::Transaction, vm::VmValueRequest},
+    gateway::GatewayError,
+    num_bigint::BigUint,
+    tx_outcome::TxOutcome,
+    wallet::Wallet,
+};
+use serde_json::{json, Map, Value};
+
+use super::{
+    interact_encode::top_encode_unsigned, parse_code_metadata, ArgEncoder, InteractAbi,
+    InteractCommand, InteractEndpoint, InteractError, InteractResult, ResultDecoder,
+    DEPLOY_COMMAND_NAME,
+};
+
+const DEPLOY_RECEIVER: [u8; 32] = [0u8; 32];
+const VM_TYPE_WASM: &str = "0500";
+const MULTI_ESDT_NFT_TRANSFER_FUNC_NAME: &str = "MultiESDTNFTTransfer";
+const DEFAULT_GAS_LIMIT: u64 = 10_000_000;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(3);
+const DEFAULT_TX_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A token payment, given on the command line as `TOKEN:NONCE:AMOUNT`, or `TOKEN:AMOUNT` for fungibles.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractPayment {
+    pub token_identifier: String,
+    pub nonce: u64,
+    pub amount: BigUint,
+}
+
+impl InteractPayment {
+    pub fn parse(text: &str) -> InteractResult<Self> {
+        let invalid = || {
+            InteractError::Command(format!(
+                "invalid payment `{text}`, expected TOKEN:NONCE:AMOUNT"
+            ))
+        };
+        let parts: Vec<&str> = text.split(':').collect();
+        let (token_identifier, nonce, amount) = match parts.as_slice() {
+            [token_identifier, amount] => (*token_identifier, "0", *amount),
+            [token_identifier, nonce, amount] => (*token_identifier, *nonce, *amount),
+            _ => return Err(invalid()),
+        };
+        Ok(InteractPayment {
+            token_identifier: token_identifier.to_string(),
+            nonce: nonce.parse().map_err(|_| invalid())?,
+            amount: amount.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Calls contract endpoints described by an ABI, encoding arguments and decoding results as JSON.
+///
+/// Readonly endpoints go through VM queries, everything else is signed and sent as a transaction.
+pub struct InteractRunner {
+    abi: InteractAbi,
+    proxy: CommunicationProxy,
+    wallet: Option<Wallet>,
+    contract: Option<Address>,
+    gas_limit: u64,
+    value: BigUint,
+    payments: Vec<InteractPayment>,
+    code: Option<Vec<u8>>,
+    code_metadata: [u8; 2],
+    poll_interval: Duration,
+    tx_timeout: Duration,
+}
+
+impl InteractRunner {
+    pub fn new(abi: InteractAbi, proxy: CommunicationProxy) -> Self {
+        InteractRunner {
+            abi,
+            proxy,
+            wallet: None,
+            contract: None,
+            gas_limit: DEFAULT_GAS_LIMIT,
+            value: BigUint::default(),
+            payments: Vec::new(),
+            code: None,
+            code_metadata: parse_code_metadata("upgradeable,readable").unwrap(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            tx_timeout: DEFAULT_TX_TIMEOUT,
+        }
+    }
+
+    pub fn with_wallet(mut self, wallet: Wallet) -> Self {
+        self.wallet = Some(wallet);
+        self
+    }
+
+    pub fn with_contract(mut self, contract: Address) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// EGLD value sent with the transaction, in the smallest denomination.
+    pub fn with_value(mut self, value: BigUint) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn with_payment(mut self, payment: InteractPayment) -> Self {
+        self.payments.push(payment);
+        self
+    }
+
+    /// Contract bytecode, only used by `deploy`.
+    pub fn with_code(mut self, code: Vec<u8>) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_code_metadata(mut self, code_metadata: [u8; 2]) -> Self {
+        self.code_metadata = code_metadata;
+        self
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_tx_timeout(mut self, tx_timeout: Duration) -> Self {
+        self.tx_timeout = tx_timeout;
+        self
+    }
+
+    pub fn abi(&self) -> &InteractAbi {
+        &self.abi
+    }
+
+    /// Runs the command, yielding a JSON report with the decoded results.
+    pub async fn run(&self, command: &InteractCommand) -> InteractResult<Value> {
+        let endpoint = self.abi.endpoint_or_err(&command.endpoint)?;
+        let args = ArgEncoder::new(&self.abi).encode_endpoint_args(endpoint, &command.args)?;
+
+        if command.endpoint == DEPLOY_COMMAND_NAME {
+            self.deploy(endpoint, &args).await
+        } else if endpoint.readonly {
+            self.query(endpoint, &args).await
+        } else {
+            self.call(endpoint, &args).await
+        }
+    }
+
+    async fn query(&self, endpoint: &InteractEndpoint, args: &[Vec<u8>]) -> InteractResult<Value> {
+        let contract = self.contract()?;
+        let caller = self
+            .wallet
+            .as_ref()
+            .map_or_else(|| contract.clone(), Wallet::address);
+        let request = VmValueRequest {
+            sc_address: contract.clone(),
+            func_name: endpoint.name.clone(),
+            caller,
+            value: "0".to_string(),
+            args: args.iter().map(hex::encode).collect(),
+        };
+        let response = self.proxy.execute_vmquery(&request).await?;
+        if response.data.return_code != "ok" {
+            return Err(InteractError::Execution(format!(
+                "{}: {}",
+                response.data.return_code, response.data.return_message
+            )));
+        }
+
+        let raw_results = response
+            .data
+            .return_data
+            .iter()
+            .map(|data| {
+                base64::decode(data).map_err(|err| {
+                    InteractError::Gateway(GatewayError::Decode(format!(
+                        "query result is not valid base64: {err}"
+                    )))
+                })
+            })
+            .collect::<InteractResult<Vec<_>>>()?;
+        let results =
+            ResultDecoder::new(&self.abi).decode_endpoint_results(endpoint, &raw_results)?;
+        Ok(json!({ "results": results }))
+    }
+
+    async fn call(&self, endpoint: &InteractEndpoint, args: &[Vec<u8>]) -> InteractResult<Value> {
+        let contract = self.contract()?;
+        let wallet = self.wallet()?;
+        let sender = wallet.address();
+
+        let (receiver, data) = if self.payments.is_empty() {
+            (contract.clone(), tx_data(&endpoint.name, args))
+        } else if self.value != BigUint::default() {
+            return Err(InteractError::Command(
+                "an EGLD value cannot be sent together with token payments".into(),
+            ));
+        } else {
+            // token transfers are sent to self, with the contract call inside
+            let mut transfer_args = vec![
+                contract.to_bytes().to_vec(),
+                top_encode_unsigned(&BigUint::from(self.payments.len())),
+            ];
+            for payment in &self.payments {
+                transfer_args.push(payment.token_identifier.as_bytes().to_vec());
+                transfer_args.push(top_encode_unsigned(&BigUint::from(payment.nonce)));
+                transfer_args.push(top_encode_unsigned(&payment.amount));
+            }
+            transfer_args.push(endpoint.name.as_bytes().to_vec());
+            transfer_args.extend(args.iter().cloned());
+            (
+                sender.clone(),
+                tx_data(MULTI_ESDT_NFT_TRANSFER_FUNC_NAME, &transfer_args),
+            )
+        };
+
+        let (tx_hash, outcome) = self.send_and_wait(&wallet, receiver, data).await?;
+        let results = ResultDecoder::new(&self.abi)
+            .decode_endpoint_results(endpoint, &outcome.return_data)?;
+        Ok(json!({ "txHash": tx_hash, "results": results }))
+    }
+
+    async fn deploy(&self, endpoint: &InteractEndpoint, args: &[Vec<u8>]) -> InteractResult<Value> {
+        let wallet = self.wallet()?;
+        let code = self
+            .code
+            .as_ref()
+            .ok_or_else(|| InteractError::Command("deploy requires the contract code".into()))?;
+
+        let mut data = format!(
+            "{}@{VM_TYPE_WASM}@{}",
+            hex::encode(code),
+            hex::encode(self.code_metadata)
+        );
+        for arg in args {
+            data.push('@');
+            data.push_str(&hex::encode(arg));
+        }
+
+        let receiver = Address::from_bytes(DEPLOY_RECEIVER);
+        let (tx_hash, outcome) = self.send_and_wait(&wallet, receiver, data).await?;
+        let address = outcome
+            .deployed_address
+            .as_ref()
+            .and_then(|address| address.to_bech32_string().ok());
+        let results = ResultDecoder::new(&self.abi)
+            .decode_endpoint_results(endpoint, &outcome.return_data)?;
+
+        let mut report = Map::new();
+        report.insert("txHash".to_string(), Value::String(tx_hash));
+        report.insert(
+            "address".to_string(),
+            address.map_or(Value::Null, Value::String),
+        );
+        report.insert("results".to_string(), Value::Array(results));
+        Ok(Value::Object(report))
+    }
+
+    async fn send_and_wait(
+        &self,
+        wallet: &Wallet,
+        receiver: Address,
+        data: String,
+    ) -> InteractResult<(String, TxOutcome)> {
+        let sender = wallet.address();
+        let network_config = self.proxy.get_network_config().await?;
+        let account = self.proxy.get_account(&sender).await?;
+
+        let mut tx = Transaction {
+            nonce: account.nonce,
+            value: self.value.clone(),
+            receiver,
+            sender,
+            gas_price: network_config.min_gas_price,
+            gas_limit: self.gas_limit,
+            data: Some(base64::encode(data)),
+            signature: None,
+            chain_id: network_config.chain_id,
+            version: network_config.min_transaction_version,
+            options: 0,
+        };
+        tx.signature = Some(hex::encode(wallet.sign_tx(&tx)));
+        let tx_hash = self.proxy.send_transaction(&tx).await?;
+
+        let start = Instant::now();
+        loop {
+            let status = self.proxy.get_transaction_status(&tx_hash).await?;
+            if !status.is_pending() {
+                break;
+            }
+            if start.elapsed() >= self.tx_timeout {
+                return Err(InteractError::Execution(format!(
+                    "transaction {tx_hash} still pending after {:?}",
+                    self.tx_timeout
+                )));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+
+        let tx_on_network = self
+            .proxy
+            .get_transaction_info_with_results(&tx_hash)
+            .await?;
+        let outcome = TxOutcome::from_network_tx(&tx_on_network);
+        if let Some(error) = &outcome.error {
+            return Err(InteractError::Execution(format!(
+                "transaction {tx_hash} failed: {}",
+                error.message
+            )));
+        }
+        Ok((tx_hash, outcome))
+    }
+
+    fn contract(&self) -> InteractResult<&Address> {
+        self.contract
+            .as_ref()
+            .ok_or_else(|| InteractError::Command("missing contract address".into()))
+    }
+
+    fn wallet(&self) -> InteractResult<Wallet> {
+        self.wallet.ok_or_else(|| {
+            InteractError::Wallet("sending transactions requires a PEM or keystore".into())
+        })
+    }
+}
+
+fn tx_data(function: &str, args: &[Vec<u8>]) -> String {
+    let mut data = function.to_string();
+    for arg in args {
+        data.push('@');
+        data.push_str(&hex::encode(arg));
+    }
+    data
+}
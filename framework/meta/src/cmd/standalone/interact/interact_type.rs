@@ -0,0 +1,149 @@
+use super::{InteractError, InteractResult};
+
+/// An ABI type name, parsed into its structure.
+///
+/// Anything that is not a known framework type is kept as `Custom`,
+/// to be looked up in the type descriptions of the ABI.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AbiType {
+    /// Unsigned integer, with its size in bytes.
+    Unsigned(usize),
+    /// Signed integer, with its size in bytes.
+    Signed(usize),
+    BigUint,
+    BigInt,
+    Bool,
+    Bytes,
+    Utf8String,
+    Address,
+    H256,
+    TokenIdentifier,
+    EgldOrEsdtTokenIdentifier,
+    CodeMetadata,
+    Option(Box<AbiType>),
+    List(Box<AbiType>),
+    Array(usize, Box<AbiType>),
+    Tuple(Vec<AbiType>),
+    Variadic(Box<AbiType>),
+    CountedVariadic(Box<AbiType>),
+    Optional(Box<AbiType>),
+    Multi(Vec<AbiType>),
+    Ignore,
+    Custom(String),
+}
+
+impl AbiType {
+    pub fn parse(type_name: &str) -> InteractResult<Self> {
+        let type_name = type_name.trim();
+        let (base, args) = split_generic(type_name)?;
+        let single_arg = || -> InteractResult<Box<AbiType>> {
+            match args.as_slice() {
+                [arg] => Ok(Box::new(AbiType::parse(arg)?)),
+                _ => Err(InteractError::Abi(format!(
+                    "type `{type_name}` expects exactly one type argument"
+                ))),
+            }
+        };
+        let all_args = || -> InteractResult<Vec<AbiType>> {
+            args.iter().map(|arg| AbiType::parse(arg)).collect()
+        };
+
+        let abi_type = match base {
+            "u8" => AbiType::Unsigned(1),
+            "u16" => AbiType::Unsigned(2),
+            "u32" | "usize" | "NonZeroUsize" => AbiType::Unsigned(4),
+            "u64" => AbiType::Unsigned(8),
+            "i8" => AbiType::Signed(1),
+            "i16" => AbiType::Signed(2),
+            "i32" | "isize" => AbiType::Signed(4),
+            "i64" => AbiType::Signed(8),
+            "BigUint" => AbiType::BigUint,
+            "BigInt" => AbiType::BigInt,
+            "bool" => AbiType::Bool,
+            "bytes" => AbiType::Bytes,
+            "utf-8 string" => AbiType::Utf8String,
+            "Address" => AbiType::Address,
+            "H256" => AbiType::H256,
+            "TokenIdentifier" => AbiType::TokenIdentifier,
+            "EgldOrEsdtTokenIdentifier" => AbiType::EgldOrEsdtTokenIdentifier,
+            "CodeMetadata" => AbiType::CodeMetadata,
+            "ignore" => AbiType::Ignore,
+            "Option" => AbiType::Option(single_arg()?),
+            "List" | "Queue" => AbiType::List(single_arg()?),
+            "tuple" => AbiType::Tuple(all_args()?),
+            "variadic" => AbiType::Variadic(single_arg()?),
+            "counted-variadic" => AbiType::CountedVariadic(single_arg()?),
+            "optional" => AbiType::Optional(single_arg()?),
+            "multi" => AbiType::Multi(all_args()?),
+            _ => {
+                if let Some(len) = base.strip_prefix("array") {
+                    let len = len.parse::<usize>().map_err(|_| {
+                        InteractError::Abi(format!("invalid array length in `{type_name}`"))
+                    })?;
+                    AbiType::Array(len, single_arg()?)
+                } else if args.is_empty() {
+                    AbiType::Custom(base.to_string())
+                } else {
+                    return Err(InteractError::Abi(format!(
+                        "unsupported generic type `{type_name}`"
+                    )));
+                }
+            },
+        };
+        Ok(abi_type)
+    }
+
+    /// Multi-value types are spread over several arguments or results.
+    pub fn is_multi_value(&self) -> bool {
+        matches!(
+            self,
+            AbiType::Variadic(_)
+                | AbiType::CountedVariadic(_)
+                | AbiType::Optional(_)
+                | AbiType::Multi(_)
+                | AbiType::Ignore
+        )
+    }
+}
+
+/// Splits `name<A, B<C>>` into `name` and `[A, B<C>]`.
+fn split_generic(type_name: &str) -> InteractResult<(&str, Vec<&str>)> {
+    let Some(open) = type_name.find('<') else {
+        return Ok((type_name, Vec::new()));
+    };
+    if !type_name.ends_with('>') {
+        return Err(InteractError::Abi(format!(
+            "unbalanced type name `{type_name}`"
+        )));
+    }
+
+    let base = &type_name[..open];
+    let inner = &type_name[open + 1..type_name.len() - 1];
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    InteractError::Abi(format!("unbalanced type name `{type_name}`"))
+                })?
+            },
+            ',' if depth == 0 => {
+                args.push(inner[start..index].trim());
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    if depth != 0 {
+        return Err(InteractError::Abi(format!(
+            "unbalanced type name `{type_name}`"
+        )));
+    }
+    if !inner.trim().is_empty() {
+        args.push(inner[start..].trim());
+    }
+    Ok((base, args))
+}
@@ -0,0 +1,179 @@
+mod lint_body;
+mod lint_model;
+mod lint_parse;
+mod lint_rules;
+
+use std::{
+    collections::BTreeSet,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use colored::Colorize;
+
+use crate::{
+    cli_args::LintArgs,
+    folder_structure::{local_dependency_crates, RelevantDirectories},
+};
+
+use super::print_util::{print_all_count, print_all_index};
+
+pub use lint_model::{LintContract, LintMethod, LintMethodRole, LintStorageKind, LintTrait};
+pub use lint_parse::parse_lint_traits;
+pub use lint_rules::{lint_contract, LintRule, LintWarning};
+
+/// Framework crates that contain no contract modules, no point in parsing them.
+const NON_MODULE_FRAMEWORK_CRATES: &[&str] = &[
+    "multiversx-sc",
+    "multiversx-sc-meta",
+    "multiversx-sc-scenario",
+    "multiversx-sc-wasm-adapter",
+    "elrond-wasm",
+    "elrond-wasm-debug",
+    "elrond-wasm-node",
+];
+
+/// Why a lint run did not pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintError {
+    UnknownRule(String),
+    Warnings(usize),
+}
+
+impl fmt::Display for LintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintError::UnknownRule(name) => write!(f, "unknown lint rule: {name}"),
+            LintError::Warnings(num_warnings) => {
+                write!(f, "lint finished with {num_warnings} warning(s)")
+            },
+        }
+    }
+}
+
+impl std::error::Error for LintError {}
+
+pub fn lint(args: &LintArgs) -> Result<(), LintError> {
+    let path = if let Some(some_path) = &args.path {
+        some_path.as_str()
+    } else {
+        "./"
+    };
+
+    let allowed = args
+        .allow
+        .iter()
+        .map(|name| LintRule::from_name(name).ok_or_else(|| LintError::UnknownRule(name.clone())))
+        .collect::<Result<Vec<LintRule>, LintError>>()?;
+
+    let num_warnings = perform_lint_all(path, args.ignore.as_slice(), allowed.as_slice());
+    if num_warnings > 0 {
+        return Err(LintError::Warnings(num_warnings));
+    }
+
+    println!("\n{}", "Lint finished, no warnings.".green());
+    Ok(())
+}
+
+fn perform_lint_all(path: impl AsRef<Path>, ignore: &[String], allowed: &[LintRule]) -> usize {
+    let dirs = RelevantDirectories::find_all(path, ignore);
+    let num_contract_crates = dirs.iter_contract_crates().count();
+    print_all_count(num_contract_crates);
+
+    let mut num_warnings = 0;
+    for (i, contract_crate) in dirs.iter_contract_crates().enumerate() {
+        print_all_index(i + 1, num_contract_crates);
+        println!("{} {}", "Linting".green(), contract_crate.path.display());
+
+        for contract in load_lint_contracts(contract_crate.path.as_path()) {
+            for warning in lint_contract(&contract) {
+                if allowed.contains(&warning.rule) {
+                    continue;
+                }
+                print_lint_warning(&warning);
+                num_warnings += 1;
+            }
+        }
+    }
+    num_warnings
+}
+
+fn print_lint_warning(warning: &LintWarning) {
+    println!(
+        "    {} {}::{}: {}",
+        format!("warning[{}]", warning.rule.name()).yellow(),
+        warning.trait_name,
+        warning.method_name,
+        warning.message,
+    );
+}
+
+/// Loads all contract traits of a contract crate, together with their supertraits.
+///
+/// Supertraits are searched for in the contract crate and in all its local dependencies.
+pub fn load_lint_contracts(contract_crate_path: &Path) -> Vec<LintContract> {
+    let mut own_traits = Vec::new();
+    collect_crate_traits(contract_crate_path, &mut own_traits);
+
+    let mut dep_traits = Vec::new();
+    for dep_path in local_dependency_crates(contract_crate_path, NON_MODULE_FRAMEWORK_CRATES) {
+        collect_crate_traits(dep_path.as_path(), &mut dep_traits);
+    }
+
+    let all_traits: Vec<LintTrait> = own_traits
+        .iter()
+        .chain(dep_traits.iter())
+        .cloned()
+        .collect();
+    own_traits
+        .iter()
+        .filter(|lint_trait| lint_trait.is_contract)
+        .map(|contract_trait| assemble_contract(contract_trait, all_traits.as_slice()))
+        .collect()
+}
+
+/// Gathers the contract trait and all its supertraits, in declaration order.
+pub fn assemble_contract(contract_trait: &LintTrait, all_traits: &[LintTrait]) -> LintContract {
+    let mut visited = BTreeSet::new();
+    let mut traits = Vec::new();
+    let mut stack = vec![contract_trait.clone()];
+    while let Some(lint_trait) = stack.pop() {
+        if !visited.insert(lint_trait.name.clone()) {
+            continue;
+        }
+        for supertrait_name in lint_trait.supertraits.iter().rev() {
+            if let Some(supertrait) = all_traits.iter().find(|t| &t.name == supertrait_name) {
+                stack.push(supertrait.clone());
+            }
+        }
+        traits.push(lint_trait);
+    }
+    LintContract { traits }
+}
+
+fn collect_crate_traits(crate_path: &Path, result: &mut Vec<LintTrait>) {
+    let mut source_files = Vec::new();
+    collect_source_files(crate_path.join("src").as_path(), &mut source_files);
+    for file_path in source_files {
+        if let Ok(source) = fs::read_to_string(&file_path) {
+            result.extend(parse_lint_traits(file_path.as_path(), source.as_str()));
+        }
+    }
+}
+
+fn collect_source_files(dir_path: &Path, result: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir_path) else {
+        return;
+    };
+    let mut entries: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    entries.sort();
+    for entry_path in entries {
+        if entry_path.is_dir() {
+            collect_source_files(entry_path.as_path(), result);
+        } else if entry_path.extension().is_some_and(|ext| ext == "rs") {
+            result.push(entry_path);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::BTreeSet;
+
+use syn::visit::Visit;
+
+/// A chain of method calls starting from `self`, e.g. `self.balances(&addr).update(...)`
+/// is recorded as `["balances", "update"]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfCallChain {
+    pub methods: Vec<String>,
+}
+
+impl SelfCallChain {
+    pub fn root(&self) -> &str {
+        self.methods[0].as_str()
+    }
+
+    pub fn contains(&self, method_name: &str) -> bool {
+        self.methods.iter().any(|m| m == method_name)
+    }
+
+    pub fn last(&self) -> &str {
+        self.methods.last().unwrap().as_str()
+    }
+}
+
+/// Facts about a method body, gathered in one pass.
+#[derive(Clone, Debug, Default)]
+pub struct BodyFacts {
+    pub chains: Vec<SelfCallChain>,
+    /// Chains that appear as the end of a range, e.g. `0..self.items().len()`.
+    pub range_bound_chains: Vec<SelfCallChain>,
+    pub all_method_calls: BTreeSet<String>,
+    pub handles_error: bool,
+}
+
+impl BodyFacts {
+    pub fn from_block(block: &syn::Block) -> Self {
+        let mut visitor = BodyVisitor::default();
+        visitor.visit_block(block);
+        visitor.facts
+    }
+
+    pub fn self_calls(&self) -> impl Iterator<Item = &str> {
+        self.chains.iter().map(|chain| chain.root())
+    }
+}
+
+#[derive(Default)]
+struct BodyVisitor {
+    facts: BodyFacts,
+    /// Set when visiting the receiver of a method call, to only record the full chain.
+    in_receiver: bool,
+}
+
+fn is_self_expr(expr: &syn::Expr) -> bool {
+    if let syn::Expr::Path(expr_path) = expr {
+        expr_path.path.is_ident("self")
+    } else {
+        false
+    }
+}
+
+/// Unrolls a method call chain, if it starts with `self`.
+fn self_call_chain(expr: &syn::Expr) -> Option<SelfCallChain> {
+    unroll_self_call_chain(Vec::new(), expr)
+}
+
+fn unroll_self_call_chain(mut methods: Vec<String>, expr: &syn::Expr) -> Option<SelfCallChain> {
+    let mut current = expr;
+    loop {
+        match current {
+            syn::Expr::MethodCall(method_call) => {
+                methods.push(method_call.method.to_string());
+                current = &method_call.receiver;
+            },
+            syn::Expr::Paren(paren) => current = &paren.expr,
+            syn::Expr::Reference(reference) => current = &reference.expr,
+            syn::Expr::Try(expr_try) => current = &expr_try.expr,
+            _ => break,
+        }
+    }
+
+    if methods.is_empty() || !is_self_expr(current) {
+        return None;
+    }
+
+    methods.reverse();
+    Some(SelfCallChain { methods })
+}
+
+fn is_err_pattern(pat: &syn::Pat) -> bool {
+    match pat {
+        syn::Pat::TupleStruct(pat_tuple_struct) => pat_tuple_struct
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Err")
+            .unwrap_or(false),
+        syn::Pat::Or(pat_or) => pat_or.cases.iter().any(is_err_pattern),
+        _ => false,
+    }
+}
+
+fn is_empty_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Block(expr_block) => expr_block.block.stmts.is_empty(),
+        syn::Expr::Tuple(expr_tuple) => expr_tuple.elems.is_empty(),
+        _ => false,
+    }
+}
+
+impl<'ast> Visit<'ast> for BodyVisitor {
+    fn visit_expr_method_call(&mut self, method_call: &'ast syn::ExprMethodCall) {
+        let method_name = method_call.method.to_string();
+        if method_name == "is_err" {
+            self.facts.handles_error = true;
+        }
+        self.facts.all_method_calls.insert(method_name.clone());
+
+        if !std::mem::take(&mut self.in_receiver) {
+            if let Some(chain) = unroll_self_call_chain(vec![method_name], &method_call.receiver) {
+                self.facts.chains.push(chain);
+            }
+        }
+
+        self.in_receiver = matches!(*method_call.receiver, syn::Expr::MethodCall(_));
+        self.visit_expr(&method_call.receiver);
+        self.in_receiver = false;
+        for arg in &method_call.args {
+            self.visit_expr(arg);
+        }
+    }
+
+    fn visit_expr_range(&mut self, expr_range: &'ast syn::ExprRange) {
+        if let Some(to) = &expr_range.to {
+            if let Some(chain) = self_call_chain(to) {
+                self.facts.range_bound_chains.push(chain);
+            }
+        }
+
+        syn::visit::visit_expr_range(self, expr_range);
+    }
+
+    fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+        let handles_case = is_err_pattern(&arm.pat) || matches!(arm.pat, syn::Pat::Wild(_));
+        if handles_case && !is_empty_expr(&arm.body) {
+            self.facts.handles_error = true;
+        }
+
+        syn::visit::visit_arm(self, arm);
+    }
+
+    fn visit_expr_if(&mut self, expr_if: &'ast syn::ExprIf) {
+        if let syn::Expr::Let(expr_let) = &*expr_if.cond {
+            if is_err_pattern(&expr_let.pat) && !expr_if.then_branch.stmts.is_empty() {
+                self.facts.handles_error = true;
+            }
+        }
+
+        syn::visit::visit_expr_if(self, expr_if);
+    }
+}
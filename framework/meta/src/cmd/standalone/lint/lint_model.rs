@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+/// Simplified view of a contract or module trait, as seen by the linter.
+///
+/// Flattened from the contract model built by the contract macros (`multiversx-sc-derive-model`),
+/// keeping only what the lints need, method bodies included.
+#[derive(Clone, Debug)]
+pub struct LintTrait {
+    pub name: String,
+    pub file_path: PathBuf,
+    pub is_contract: bool,
+    pub supertraits: Vec<String>,
+    pub only_owner: bool,
+    pub only_admin: bool,
+    pub methods: Vec<LintMethod>,
+}
+
+impl LintTrait {
+    pub fn find_method(&self, name: &str) -> Option<&LintMethod> {
+        self.methods.iter().find(|m| m.name == name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintStorageKind {
+    Mapper,
+    Get,
+    Set,
+    IsEmpty,
+    Clear,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintMethodRole {
+    Init,
+    Upgrade,
+    Endpoint,
+    View,
+    Callback,
+    PromisesCallback,
+    CallbackRaw,
+    Storage { kind: LintStorageKind, key: String },
+    Event,
+    Proxy,
+    Private,
+}
+
+#[derive(Clone, Debug)]
+pub struct LintMethod {
+    pub name: String,
+    pub role: LintMethodRole,
+    pub payable: bool,
+    pub payment_args: bool,
+    pub only_owner: bool,
+    pub only_admin: bool,
+    pub call_result_arg: bool,
+    pub body: Option<syn::Block>,
+}
+
+impl LintMethod {
+    /// Endpoints that can change state: init, upgrade and regular endpoints, but not views.
+    pub fn is_mutable_endpoint(&self) -> bool {
+        matches!(
+            self.role,
+            LintMethodRole::Init | LintMethodRole::Upgrade | LintMethodRole::Endpoint
+        )
+    }
+
+    pub fn is_callback(&self) -> bool {
+        matches!(
+            self.role,
+            LintMethodRole::Callback | LintMethodRole::PromisesCallback
+        )
+    }
+
+    pub fn storage_key(&self) -> Option<&str> {
+        if let LintMethodRole::Storage { key, .. } = &self.role {
+            Some(key.as_str())
+        } else {
+            None
+        }
+    }
+
+    pub fn storage_kind(&self) -> Option<LintStorageKind> {
+        if let LintMethodRole::Storage { kind, .. } = &self.role {
+            Some(*kind)
+        } else {
+            None
+        }
+    }
+}
+
+/// All traits that make up a contract: the contract trait itself and all its supertraits that could be found.
+#[derive(Clone, Debug, Default)]
+pub struct LintContract {
+    pub traits: Vec<LintTrait>,
+}
+
+impl LintContract {
+    pub fn find_method(&self, name: &str) -> Option<(&LintTrait, &LintMethod)> {
+        self.traits
+            .iter()
+            .find_map(|t| t.find_method(name).map(|m| (t, m)))
+    }
+
+    pub fn iter_methods(&self) -> impl Iterator<Item = (&LintTrait, &LintMethod)> {
+        self.traits
+            .iter()
+            .flat_map(|t| t.methods.iter().map(move |m| (t, m)))
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+};
+
+use multiversx_sc_derive_model::{
+    model::{AutoImpl, ContractTrait, EndpointMutabilityMetadata, Method, MethodImpl, PublicRole},
+    parse::parse_contract_trait,
+};
+
+use super::lint_model::{LintMethod, LintMethodRole, LintStorageKind, LintTrait};
+
+static CONTRACT_TRAIT_ATTR: &str = "contract";
+static MODULE_TRAIT_ATTR: &str = "module";
+static UPGRADE_ENDPOINT_NAME: &str = "upgrade";
+
+/// Extracts all contract and module traits from a Rust source file.
+///
+/// The traits are parsed into the same model the contract macros build.
+/// Files and traits that do not parse are ignored, the compiler will report those anyway.
+pub fn parse_lint_traits(file_path: &Path, source: &str) -> Vec<LintTrait> {
+    let mut result = Vec::new();
+    if let Ok(file) = syn::parse_file(source) {
+        collect_traits(file_path, &file.items, &mut result);
+    }
+    result
+}
+
+fn collect_traits(file_path: &Path, items: &[syn::Item], result: &mut Vec<LintTrait>) {
+    for item in items {
+        match item {
+            syn::Item::Trait(item_trait) => {
+                if let Some(lint_trait) = parse_trait(file_path, item_trait) {
+                    result.push(lint_trait);
+                }
+            },
+            syn::Item::Mod(item_mod) => {
+                if let Some((_, mod_items)) = &item_mod.content {
+                    collect_traits(file_path, mod_items, result);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn last_segment_is(path: &syn::Path, name: &str) -> bool {
+    path.segments
+        .last()
+        .map(|segment| segment.ident == name)
+        .unwrap_or(false)
+}
+
+fn parse_trait(file_path: &Path, item_trait: &syn::ItemTrait) -> Option<LintTrait> {
+    let is_contract = item_trait
+        .attrs
+        .iter()
+        .any(|attr| last_segment_is(&attr.path, CONTRACT_TRAIT_ATTR));
+    let is_module = item_trait
+        .attrs
+        .iter()
+        .any(|attr| last_segment_is(&attr.path, MODULE_TRAIT_ATTR));
+    if !is_contract && !is_module {
+        return None;
+    }
+
+    // the contract macros report invalid traits by panicking
+    let contract_trait = catch_unwind(AssertUnwindSafe(|| {
+        parse_contract_trait(Vec::new(), item_trait)
+    }))
+    .ok()?;
+    Some(lint_trait(file_path, is_contract, contract_trait))
+}
+
+fn lint_trait(file_path: &Path, is_contract: bool, contract_trait: ContractTrait) -> LintTrait {
+    LintTrait {
+        name: contract_trait.trait_name.to_string(),
+        file_path: file_path.to_path_buf(),
+        is_contract,
+        supertraits: contract_trait
+            .supertraits
+            .iter()
+            .map(|supertrait| supertrait.trait_name.ident.to_string())
+            .collect(),
+        only_owner: contract_trait.trait_attributes.only_owner,
+        only_admin: contract_trait.trait_attributes.only_admin,
+        methods: contract_trait.methods.iter().map(lint_method).collect(),
+    }
+}
+
+fn lint_method_role(method: &Method) -> LintMethodRole {
+    if let MethodImpl::Generated(auto_impl) = &method.implementation {
+        let (kind, key) = match auto_impl {
+            AutoImpl::StorageMapper { identifier } => (LintStorageKind::Mapper, identifier),
+            AutoImpl::StorageGetter { identifier } => (LintStorageKind::Get, identifier),
+            AutoImpl::StorageSetter { identifier } => (LintStorageKind::Set, identifier),
+            AutoImpl::StorageIsEmpty { identifier } => (LintStorageKind::IsEmpty, identifier),
+            AutoImpl::StorageClear { identifier } => (LintStorageKind::Clear, identifier),
+            AutoImpl::Event { .. } => return LintMethodRole::Event,
+            AutoImpl::ProxyGetter => return LintMethodRole::Proxy,
+        };
+        return LintMethodRole::Storage {
+            kind,
+            key: key.clone(),
+        };
+    }
+
+    match &method.public_role {
+        PublicRole::Init(_) => LintMethodRole::Init,
+        PublicRole::Endpoint(endpoint_metadata) => match endpoint_metadata.mutability {
+            EndpointMutabilityMetadata::Mutable
+                if endpoint_metadata.public_name == UPGRADE_ENDPOINT_NAME =>
+            {
+                LintMethodRole::Upgrade
+            },
+            EndpointMutabilityMetadata::Mutable => LintMethodRole::Endpoint,
+            _ => LintMethodRole::View,
+        },
+        PublicRole::Callback(_) => LintMethodRole::Callback,
+        PublicRole::CallbackPromise(_) => LintMethodRole::PromisesCallback,
+        PublicRole::CallbackRaw => LintMethodRole::CallbackRaw,
+        PublicRole::Private => LintMethodRole::Private,
+    }
+}
+
+fn lint_method(method: &Method) -> LintMethod {
+    let (payable, only_owner, only_admin) = match &method.public_role {
+        PublicRole::Init(init_metadata) => (init_metadata.payable.is_payable(), false, false),
+        PublicRole::Endpoint(endpoint_metadata) => (
+            endpoint_metadata.payable.is_payable(),
+            endpoint_metadata.only_owner,
+            endpoint_metadata.only_admin,
+        ),
+        _ => (false, false, false),
+    };
+
+    LintMethod {
+        name: method.name.to_string(),
+        role: lint_method_role(method),
+        payable,
+        payment_args: method
+            .method_args
+            .iter()
+            .any(|arg| arg.metadata.payment.is_payment_arg()),
+        only_owner,
+        only_admin,
+        call_result_arg: method
+            .method_args
+            .iter()
+            .any(|arg| arg.metadata.callback_call_result),
+        body: match &method.implementation {
+            MethodImpl::Explicit(body) => Some(body.clone()),
+            _ => None,
+        },
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use multiversx_sc::abi::{StorageKeyAbi, StorageSubKeyAbi};
+
+use super::{
+    lint_body::{BodyFacts, SelfCallChain},
+    lint_model::{LintContract, LintMethod, LintMethodRole, LintStorageKind, LintTrait},
+};
+
+/// Storage mapper methods that modify storage, when called directly on the mapper.
+const MAPPER_WRITE_METHODS: &[&str] = &[
+    "set",
+    "set_if_empty",
+    "update",
+    "clear",
+    "take",
+    "replace",
+    "insert",
+    "remove",
+    "swap_remove",
+    "push",
+    "push_back",
+    "push_front",
+    "push_after",
+    "push_before",
+    "pop_front",
+    "pop_back",
+    "extend",
+    "extend_from_slice",
+    "add",
+    "swap_indexes",
+    "get_or_create_user",
+    "get_or_create_users",
+    "set_token_id",
+    "mint",
+    "mint_and_send",
+    "burn",
+];
+
+/// Map mapper entry methods that modify storage, e.g. `self.map().entry(key).or_insert(value)`.
+const MAPPER_ENTRY_WRITE_METHODS: &[&str] = &[
+    "or_insert",
+    "or_insert_with",
+    "or_insert_with_key",
+    "or_default",
+    "and_modify",
+];
+
+/// Storage mapper methods that iterate over the whole mapper.
+const MAPPER_ITER_METHODS: &[&str] = &["iter", "iter_from", "keys", "values"];
+
+const MAPPER_BOUNDING_METHODS: &[&str] = &["take", "next", "find", "any", "position"];
+
+/// Calls that are considered to perform access control.
+const ACCESS_CONTROL_CALLS: &[&str] = &[
+    "check_caller_is_owner",
+    "require_caller_is_admin",
+    "require_caller_is_owner",
+    "require_whitelisted",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintRule {
+    PayableWithoutCallValue,
+    UnprotectedOwnerStorage,
+    ViewWritesStorage,
+    StorageKeyCollision,
+    CallbackIgnoresError,
+    UnboundedIteration,
+}
+
+impl LintRule {
+    pub const ALL: &'static [LintRule] = &[
+        LintRule::PayableWithoutCallValue,
+        LintRule::UnprotectedOwnerStorage,
+        LintRule::ViewWritesStorage,
+        LintRule::StorageKeyCollision,
+        LintRule::CallbackIgnoresError,
+        LintRule::UnboundedIteration,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::PayableWithoutCallValue => "payable-without-call-value",
+            LintRule::UnprotectedOwnerStorage => "unprotected-owner-storage",
+            LintRule::ViewWritesStorage => "view-writes-storage",
+            LintRule::StorageKeyCollision => "storage-key-collision",
+            LintRule::CallbackIgnoresError => "callback-ignores-error",
+            LintRule::UnboundedIteration => "unbounded-iteration",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<LintRule> {
+        LintRule::ALL
+            .iter()
+            .copied()
+            .find(|rule| rule.name() == name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintWarning {
+    pub rule: LintRule,
+    pub trait_name: String,
+    pub method_name: String,
+    pub message: String,
+}
+
+/// Pre-computed body facts for all methods of a contract, used by all rules.
+struct LintAnalysis<'a> {
+    contract: &'a LintContract,
+    facts: BTreeMap<&'a str, BodyFacts>,
+}
+
+impl<'a> LintAnalysis<'a> {
+    fn new(contract: &'a LintContract) -> Self {
+        let facts = contract
+            .iter_methods()
+            .filter_map(|(_, method)| {
+                method
+                    .body
+                    .as_ref()
+                    .map(|body| (method.name.as_str(), BodyFacts::from_block(body)))
+            })
+            .collect();
+        LintAnalysis { contract, facts }
+    }
+
+    fn storage_method(&self, name: &str) -> Option<&'a LintMethod> {
+        self.contract
+            .find_method(name)
+            .map(|(_, method)| method)
+            .filter(|method| method.storage_key().is_some())
+    }
+
+    fn mapper_method(&self, name: &str) -> Option<&'a LintMethod> {
+        self.storage_method(name)
+            .filter(|method| method.storage_kind() == Some(LintStorageKind::Mapper))
+    }
+
+    /// The method itself, plus all methods with bodies it calls, directly or indirectly.
+    fn reachable_facts(&self, method_name: &str) -> Vec<&BodyFacts> {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![method_name.to_string()];
+        let mut result = Vec::new();
+        while let Some(name) = stack.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+            if let Some(facts) = self.facts.get(name.as_str()) {
+                result.push(facts);
+                stack.extend(facts.self_calls().map(str::to_string));
+            }
+        }
+        result
+    }
+
+    fn storage_writes(&self, method_name: &str) -> BTreeSet<String> {
+        let mut writes = BTreeSet::new();
+        for facts in self.reachable_facts(method_name) {
+            for chain in &facts.chains {
+                let Some(storage_method) = self.storage_method(chain.root()) else {
+                    continue;
+                };
+                let is_write = match storage_method.storage_kind() {
+                    Some(LintStorageKind::Set) | Some(LintStorageKind::Clear) => true,
+                    Some(LintStorageKind::Mapper) => writes_mapper(chain),
+                    _ => false,
+                };
+                if is_write {
+                    writes.insert(storage_method.storage_key().unwrap().to_string());
+                }
+            }
+        }
+        writes
+    }
+
+    fn reads_call_value(&self, method_name: &str) -> bool {
+        self.reachable_facts(method_name)
+            .iter()
+            .any(|facts| facts.self_calls().any(|root| root == "call_value"))
+    }
+
+    fn has_access_control(&self, lint_trait: &LintTrait, method: &LintMethod) -> bool {
+        if method.only_owner || method.only_admin || lint_trait.only_owner || lint_trait.only_admin
+        {
+            return true;
+        }
+
+        self.reachable_facts(&method.name).iter().any(|facts| {
+            ACCESS_CONTROL_CALLS
+                .iter()
+                .any(|call| facts.all_method_calls.contains(*call))
+        })
+    }
+
+    fn unbounded_iterated_mappers(&self, method_name: &str) -> BTreeSet<String> {
+        let mut result = BTreeSet::new();
+        for facts in self.reachable_facts(method_name) {
+            for chain in &facts.chains {
+                if self.mapper_method(chain.root()).is_none() {
+                    continue;
+                }
+                let bounded = MAPPER_BOUNDING_METHODS.iter().any(|m| chain.contains(m));
+                if iterates_mapper(chain) && !bounded {
+                    result.insert(chain.root().to_string());
+                }
+            }
+            for chain in &facts.range_bound_chains {
+                if self.mapper_method(chain.root()).is_some() && chain.last() == "len" {
+                    result.insert(chain.root().to_string());
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Only the method called on the mapper itself counts, or those called on one of its entries.
+///
+/// Methods further down the chain are called on values read from storage,
+/// e.g. `add` in `self.total().get().add(&amount)` does not modify anything.
+fn writes_mapper(chain: &SelfCallChain) -> bool {
+    match chain.methods.get(1).map(String::as_str) {
+        Some("entry") => chain
+            .methods
+            .get(2)
+            .is_some_and(|m| MAPPER_ENTRY_WRITE_METHODS.contains(&m.as_str())),
+        Some(method) => MAPPER_WRITE_METHODS.contains(&method),
+        None => false,
+    }
+}
+
+fn iterates_mapper(chain: &SelfCallChain) -> bool {
+    chain.methods[1..]
+        .iter()
+        .any(|m| MAPPER_ITER_METHODS.contains(&m.as_str()))
+}
+
+fn warning(
+    rule: LintRule,
+    lint_trait: &LintTrait,
+    method: &LintMethod,
+    message: String,
+) -> LintWarning {
+    LintWarning {
+        rule,
+        trait_name: lint_trait.name.clone(),
+        method_name: method.name.clone(),
+        message,
+    }
+}
+
+/// Runs all lint rules on a contract.
+pub fn lint_contract(contract: &LintContract) -> Vec<LintWarning> {
+    let analysis = LintAnalysis::new(contract);
+    let mut warnings = Vec::new();
+    check_payable_without_call_value(&analysis, &mut warnings);
+    check_unprotected_owner_storage(&analysis, &mut warnings);
+    check_view_writes_storage(&analysis, &mut warnings);
+    check_storage_key_collisions(contract, &mut warnings);
+    check_callback_ignores_error(&analysis, &mut warnings);
+    check_unbounded_iteration(&analysis, &mut warnings);
+    warnings
+}
+
+fn check_payable_without_call_value(analysis: &LintAnalysis, warnings: &mut Vec<LintWarning>) {
+    for (lint_trait, method) in analysis.contract.iter_methods() {
+        if method.is_mutable_endpoint()
+            && method.payable
+            && !method.payment_args
+            && !analysis.reads_call_value(&method.name)
+        {
+            warnings.push(warning(
+                LintRule::PayableWithoutCallValue,
+                lint_trait,
+                method,
+                "payable endpoint never reads the call value, any payment would be locked in the contract".to_string(),
+            ));
+        }
+    }
+}
+
+fn check_unprotected_owner_storage(analysis: &LintAnalysis, warnings: &mut Vec<LintWarning>) {
+    let mut protected_keys = BTreeSet::new();
+    let mut unprotected_endpoints = Vec::new();
+    for (lint_trait, method) in analysis.contract.iter_methods() {
+        if method.role != LintMethodRole::Endpoint {
+            continue;
+        }
+        if analysis.has_access_control(lint_trait, method) {
+            protected_keys.extend(analysis.storage_writes(&method.name));
+        } else {
+            unprotected_endpoints.push((lint_trait, method));
+        }
+    }
+
+    for (lint_trait, method) in unprotected_endpoints {
+        for key in analysis.storage_writes(&method.name) {
+            if protected_keys.contains(&key) {
+                warnings.push(warning(
+                    LintRule::UnprotectedOwnerStorage,
+                    lint_trait,
+                    method,
+                    format!("endpoint without access control modifies storage `{key}`, which is also modified by owner-only endpoints"),
+                ));
+            }
+        }
+    }
+}
+
+fn check_view_writes_storage(analysis: &LintAnalysis, warnings: &mut Vec<LintWarning>) {
+    for (lint_trait, method) in analysis.contract.iter_methods() {
+        if method.role != LintMethodRole::View {
+            continue;
+        }
+        for key in analysis.storage_writes(&method.name) {
+            warnings.push(warning(
+                LintRule::ViewWritesStorage,
+                lint_trait,
+                method,
+                format!("view modifies storage `{key}`"),
+            ));
+        }
+    }
+}
+
+fn check_storage_key_collisions(contract: &LintContract, warnings: &mut Vec<LintWarning>) {
+    // keys that could not be parsed are empty, nothing to compare them with
+    // the mapper types are not resolved here, so all keys are treated as prefixes
+    let storage_methods: Vec<(&LintTrait, &LintMethod, StorageKeyAbi)> = contract
+        .iter_methods()
+        .filter_map(|(lint_trait, method)| {
+            method
+                .storage_key()
+                .filter(|key| !key.is_empty())
+                .map(|key| {
+                    let key_abi = StorageKeyAbi::new(
+                        &lint_trait.name,
+                        &method.name,
+                        key,
+                        &[StorageSubKeyAbi::prefix("")],
+                    );
+                    (lint_trait, method, key_abi)
+                })
+        })
+        .collect();
+
+    let mut reported = BTreeSet::new();
+    for (i, (trait_a, method_a, key_abi_a)) in storage_methods.iter().enumerate() {
+        for (trait_b, method_b, key_abi_b) in &storage_methods[i + 1..] {
+            if key_abi_a.collision_with(key_abi_b).is_none() {
+                continue;
+            }
+            let (key_a, key_b) = (key_abi_a.key.as_str(), key_abi_b.key.as_str());
+            if !reported.insert((trait_a.name.as_str(), trait_b.name.as_str(), key_a, key_b)) {
+                continue;
+            }
+            let message = if key_a == key_b {
+                format!(
+                    "storage key `{key_a}` is also used by {}::{}",
+                    trait_b.name, method_b.name
+                )
+            } else {
+                format!(
+                    "storage key `{key_a}` overlaps with key `{key_b}` of {}::{}",
+                    trait_b.name, method_b.name
+                )
+            };
+            warnings.push(warning(
+                LintRule::StorageKeyCollision,
+                trait_a,
+                method_a,
+                message,
+            ));
+        }
+    }
+}
+
+fn check_callback_ignores_error(analysis: &LintAnalysis, warnings: &mut Vec<LintWarning>) {
+    for (lint_trait, method) in analysis.contract.iter_methods() {
+        if !method.is_callback() || !method.call_result_arg {
+            continue;
+        }
+        let handles_error = analysis
+            .facts
+            .get(method.name.as_str())
+            .map(|facts| facts.handles_error)
+            .unwrap_or(true);
+        if !handles_error {
+            warnings.push(warning(
+                LintRule::CallbackIgnoresError,
+                lint_trait,
+                method,
+                "callback does not handle the error branch of its call result".to_string(),
+            ));
+        }
+    }
+}
+
+fn check_unbounded_iteration(analysis: &LintAnalysis, warnings: &mut Vec<LintWarning>) {
+    for (lint_trait, method) in analysis.contract.iter_methods() {
+        if !method.is_mutable_endpoint() {
+            continue;
+        }
+        for mapper in analysis.unbounded_iterated_mappers(&method.name) {
+            warnings.push(warning(
+                LintRule::UnboundedIteration,
+                lint_trait,
+                method,
+                format!("endpoint iterates over the entire `{mapper}` storage mapper, gas cost grows with its size"),
+            ));
+        }
+    }
+}
@@ -24,3 +24,10 @@ pub fn print_all_command(meta_path: &Path, cargo_run_args: &[String]) {
         cargo_run_args.join(" "),
     );
 }
+
+pub fn print_watching() {
+    println!(
+        "\n{}",
+        "Watching for changes, press Ctrl+C to stop ...".truecolor(128, 128, 128),
+    );
+}
@@ -1,8 +1,13 @@
-use std::process::Command;
+use std::{path::PathBuf, process::Command, time::Instant};
 
 use colored::Colorize;
 
-use crate::cli_args::TestArgs;
+use crate::{cli_args::TestArgs, folder_structure::RelevantDirectories};
+
+use super::{
+    all::{run_contract_jobs, save_reports, AllReportJson, ContractJob},
+    print_util::print_all_count,
+};
 
 pub fn test(test_args: &TestArgs) {
     let path = test_args.path.as_deref().unwrap_or("./");
@@ -25,6 +30,11 @@ pub fn test(test_args: &TestArgs) {
         args.extend(["test"]);
     }
 
+    if let Some(jobs) = test_args.jobs {
+        test_contracts(path, program, &args, jobs, test_args);
+        return;
+    }
+
     let args_str = args.join(" ");
 
     println!(
@@ -47,3 +57,32 @@ pub fn test(test_args: &TestArgs) {
     println!("Process finished with: {status}");
     assert!(status.success());
 }
+
+/// Runs the tests of each contract crate separately, possibly in parallel.
+fn test_contracts(path: &str, program: &str, args: &[&str], jobs: usize, test_args: &TestArgs) {
+    let dirs = RelevantDirectories::find_all(path, test_args.ignore.as_slice());
+    let contract_paths: Vec<PathBuf> = dirs
+        .iter_contract_crates()
+        .map(|contract_crate| contract_crate.path.clone())
+        .collect();
+    print_all_count(contract_paths.len());
+
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    let contract_jobs =
+        ContractJob::for_contracts(&contract_paths, program, &args, |path| path.to_path_buf());
+
+    let start = Instant::now();
+    let results = run_contract_jobs(&contract_jobs, jobs);
+    let report = AllReportJson::new(
+        format!("{program} {}", args.join(" ")),
+        jobs,
+        start.elapsed(),
+        &results,
+        false,
+    );
+    report.print_summary();
+    save_reports(&report, &test_args.report_json, &test_args.report_junit);
+    if report.has_failures() {
+        std::process::exit(1);
+    }
+}
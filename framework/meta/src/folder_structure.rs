@@ -1,7 +1,9 @@
+mod local_dependencies;
 mod pretty_print;
 mod relevant_directory;
 mod version_req;
 
+pub use local_dependencies::*;
 pub use pretty_print::*;
 pub use relevant_directory::*;
 pub use version_req::*;
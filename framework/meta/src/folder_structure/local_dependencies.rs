@@ -0,0 +1,29 @@
+use std::path::{Path, PathBuf};
+
+use crate::CargoTomlContents;
+
+use super::CARGO_TOML_FILE_NAME;
+
+/// All local (path) dependencies of a crate, direct or indirect.
+///
+/// Dependencies named in `ignore_deps` are skipped, together with their own dependencies.
+pub fn local_dependency_crates(crate_path: &Path, ignore_deps: &[&str]) -> Vec<PathBuf> {
+    let mut result: Vec<PathBuf> = Vec::new();
+    let mut queue = vec![crate_path.to_path_buf()];
+    while let Some(parent) = queue.pop() {
+        let cargo_toml_path = parent.join(CARGO_TOML_FILE_NAME);
+        if !cargo_toml_path.is_file() {
+            continue;
+        }
+        let cargo_toml_contents = CargoTomlContents::load_from_file(cargo_toml_path);
+        for child in cargo_toml_contents.local_dependency_paths(ignore_deps) {
+            if let Ok(child_path) = parent.join(child).canonicalize() {
+                if !result.contains(&child_path) {
+                    result.push(child_path.clone());
+                    queue.push(child_path);
+                }
+            }
+        }
+    }
+    result
+}
@@ -1,4 +1,5 @@
 pub mod abi_json;
+pub mod benchmark_json;
 mod cargo_toml_contents;
 pub mod cli_args;
 pub mod cmd;
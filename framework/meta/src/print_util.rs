@@ -20,6 +20,29 @@ pub fn format_command(command: &Command) -> String {
     result
 }
 
+/// Formats a plain text table, with columns padded to the widest cell and separated by `|`.
+pub fn format_table<const N: usize>(header: [&str; N], lines: &[[String; N]]) -> String {
+    let header = header.map(str::to_string);
+    let mut widths = header.clone().map(|cell| cell.len());
+    for line in lines {
+        for (width, cell) in widths.iter_mut().zip(line.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut result = String::new();
+    for line in std::iter::once(&header).chain(lines.iter()) {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        result.push_str(cells.join(" | ").trim_end());
+        result.push('\n');
+    }
+    result
+}
+
 pub fn print_build_command(contract_name: String, command: &Command) {
     let path = command
         .get_current_dir()
@@ -40,6 +63,20 @@ pub fn print_copy_contract(source_wasm_path: &str, output_wasm_path: &str) {
     );
 }
 
+pub fn print_build_up_to_date(contract_name: String) {
+    println!(
+        "{}",
+        format!("Contract {contract_name} is up to date, skipping build.").green(),
+    );
+}
+
+pub fn print_compiled_wasm_unchanged(output_wasm_path: &str) {
+    println!(
+        "{}",
+        format!("Compiled contract unchanged, keeping {output_wasm_path} ...").green(),
+    );
+}
+
 pub fn print_call_wasm_opt(wasm_path: &str) {
     println!("{}", format!("Calling wasm-opt on {wasm_path} ...").green(),);
 }
@@ -1,11 +1,13 @@
 pub mod find_workspace;
 mod git_describe;
+mod source_fingerprint;
 pub mod twiggy;
 mod wasm_imports;
 mod wasm_opt;
 mod wasm_to_wat;
 
 pub use git_describe::git_describe;
+pub use source_fingerprint::{hash_file, hash_strings, SourceFingerprints};
 pub use wasm_imports::extract_wasm_imports;
 pub use wasm_opt::run_wasm_opt;
 pub use wasm_to_wat::wasm_to_wat;
@@ -0,0 +1,235 @@
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use crate::folder_structure::{local_dependency_crates, CARGO_TOML_FILE_NAME};
+
+const CARGO_LOCK_FILE_NAME: &str = "Cargo.lock";
+const SOURCE_DIR_NAME: &str = "src";
+
+/// Hex-encoded SHA-256 of a file's contents, `None` if the file cannot be read.
+pub fn hash_file<P: AsRef<Path>>(path: P) -> Option<String> {
+    fs::read(path).ok().map(|contents| hash_bytes(&contents))
+}
+
+pub fn hash_bytes(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Hashes several strings together, in order.
+pub fn hash_strings<S: AsRef<str>>(parts: &[S]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_ref().len().to_le_bytes());
+        hasher.update(part.as_ref().as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Content hash of a crate, together with the file metadata it was computed from.
+struct CrateFingerprint {
+    metadata_stamp: String,
+    hash: String,
+}
+
+/// Computes content hashes of crate sources.
+///
+/// The hash of each crate is cached, since the same dependencies
+/// (e.g. the framework crates) are shared by many contracts.
+/// A cached hash is only recomputed when the paths, sizes or modification times
+/// of the crate files change, so polling the same crates repeatedly is cheap.
+#[derive(Default)]
+pub struct SourceFingerprints {
+    crate_fingerprints: HashMap<PathBuf, CrateFingerprint>,
+}
+
+impl SourceFingerprints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash of the crate sources, together with the sources of all its local dependencies.
+    ///
+    /// Only the manifest, the lock file and the `src` folder are considered,
+    /// so build outputs and other crates in the same folder do not interfere.
+    pub fn crate_with_dependencies_hash(&mut self, crate_path: &Path) -> String {
+        let mut crate_paths = vec![crate_path
+            .canonicalize()
+            .unwrap_or_else(|_| crate_path.to_path_buf())];
+        crate_paths.extend(local_dependency_crates(crate_path, &[]));
+
+        let crate_hashes: Vec<String> = crate_paths
+            .iter()
+            .map(|path| self.crate_hash(path))
+            .collect();
+        hash_strings(&crate_hashes)
+    }
+
+    fn crate_hash(&mut self, crate_path: &Path) -> String {
+        let files = crate_files(crate_path);
+        let metadata_stamp = metadata_stamp(&files);
+        if let Some(fingerprint) = self.crate_fingerprints.get(crate_path) {
+            if fingerprint.metadata_stamp == metadata_stamp {
+                return fingerprint.hash.clone();
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        for file_path in &files {
+            let relative_path = file_path.strip_prefix(crate_path).unwrap_or(file_path);
+            hasher.update(relative_path.to_string_lossy().as_bytes());
+            hasher.update(hash_file(file_path).unwrap_or_default().as_bytes());
+        }
+        let hash = hex::encode(hasher.finalize());
+        self.crate_fingerprints.insert(
+            crate_path.to_path_buf(),
+            CrateFingerprint {
+                metadata_stamp,
+                hash: hash.clone(),
+            },
+        );
+        hash
+    }
+}
+
+/// The manifest, the lock file and everything in the `src` folder.
+fn crate_files(crate_path: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for file_name in [CARGO_TOML_FILE_NAME, CARGO_LOCK_FILE_NAME] {
+        let file_path = crate_path.join(file_name);
+        if file_path.is_file() {
+            files.push(file_path);
+        }
+    }
+    collect_files(&crate_path.join(SOURCE_DIR_NAME), &mut files);
+    files
+}
+
+/// Summarizes the paths, sizes and modification times of the files, without reading them.
+fn metadata_stamp(files: &[PathBuf]) -> String {
+    let parts: Vec<String> = files
+        .iter()
+        .map(|file_path| {
+            let (len, modified) = fs::metadata(file_path)
+                .map(|metadata| (metadata.len(), metadata.modified().ok()))
+                .unwrap_or_default();
+            let modified_nanos = modified
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_nanos())
+                .unwrap_or_default();
+            format!("{}:{len}:{modified_nanos}", file_path.display())
+        })
+        .collect();
+    hash_strings(&parts)
+}
+
+fn collect_files(dir_path: &Path, result: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir_path) else {
+        return;
+    };
+    let mut entries: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    entries.sort();
+    for entry_path in entries {
+        if entry_path.is_dir() {
+            collect_files(&entry_path, result);
+        } else {
+            result.push(entry_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_crate(crate_path: &Path, cargo_toml: &str, lib_rs: &str) {
+        fs::create_dir_all(crate_path.join("src")).unwrap();
+        fs::write(crate_path.join(CARGO_TOML_FILE_NAME), cargo_toml).unwrap();
+        fs::write(crate_path.join("src/lib.rs"), lib_rs).unwrap();
+    }
+
+    /// Separate for each test and each test process, so that concurrent runs do not interfere.
+    fn unique_temp_dir(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "sc-meta-source-fingerprint-{test_name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn crate_with_dependencies_hash_test() {
+        let root = unique_temp_dir("crate-with-dependencies-hash");
+        let contract_path = root.join("contract");
+        let dep_path = root.join("dep");
+        write_crate(
+            &contract_path,
+            "[package]\nname = \"contract\"\n\n[dependencies.dep]\npath = \"../dep\"\n",
+            "pub fn a() {}",
+        );
+        write_crate(&dep_path, "[package]\nname = \"dep\"\n", "pub fn b() {}");
+
+        let initial_hash = SourceFingerprints::new().crate_with_dependencies_hash(&contract_path);
+        assert_eq!(
+            SourceFingerprints::new().crate_with_dependencies_hash(&contract_path),
+            initial_hash
+        );
+
+        // build outputs are not part of the fingerprint
+        fs::create_dir_all(contract_path.join("output")).unwrap();
+        fs::write(contract_path.join("output/contract.wasm"), "wasm").unwrap();
+        assert_eq!(
+            SourceFingerprints::new().crate_with_dependencies_hash(&contract_path),
+            initial_hash
+        );
+
+        // changes in local dependencies are
+        fs::write(dep_path.join("src/lib.rs"), "pub fn c() {}").unwrap();
+        assert_ne!(
+            SourceFingerprints::new().crate_with_dependencies_hash(&contract_path),
+            initial_hash
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn cached_hash_invalidation_test() {
+        let crate_path = unique_temp_dir("cached-hash-invalidation");
+        write_crate(&crate_path, "[package]\nname = \"c\"\n", "pub fn a() {}");
+
+        let mut fingerprints = SourceFingerprints::new();
+        let initial_hash = fingerprints.crate_with_dependencies_hash(&crate_path);
+        assert_eq!(
+            fingerprints.crate_with_dependencies_hash(&crate_path),
+            initial_hash
+        );
+
+        fs::write(crate_path.join("src/lib.rs"), "pub fn ab() {}").unwrap();
+        let changed_hash = fingerprints.crate_with_dependencies_hash(&crate_path);
+        assert_ne!(changed_hash, initial_hash);
+        assert_eq!(
+            changed_hash,
+            SourceFingerprints::new().crate_with_dependencies_hash(&crate_path)
+        );
+
+        fs::remove_dir_all(&crate_path).unwrap();
+    }
+
+    #[test]
+    fn hash_strings_test() {
+        assert_eq!(hash_strings(&["ab", "c"]), hash_strings(&["ab", "c"]));
+        assert_ne!(hash_strings(&["ab", "c"]), hash_strings(&["a", "bc"]));
+        assert_eq!(
+            hash_bytes(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
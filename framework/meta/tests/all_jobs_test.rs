@@ -0,0 +1,63 @@
+use std::{path::PathBuf, time::Duration};
+
+use multiversx_sc_meta::cmd::standalone::all::{
+    run_contract_jobs, AllReportJson, ContractJob, ContractJobStatus,
+};
+
+fn shell_job(name: &str, script: &str, dependencies: Vec<usize>) -> ContractJob {
+    ContractJob {
+        contract_path: PathBuf::from(name),
+        working_dir: std::env::temp_dir(),
+        program: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        dependencies,
+    }
+}
+
+#[test]
+fn run_contract_jobs_test() {
+    let jobs = vec![
+        shell_job("failing", "echo 'broken <build>'; exit 1", vec![]),
+        shell_job("dependent", "exit 0", vec![0]),
+        shell_job("independent", "echo hello", vec![]),
+        shell_job("after-independent", "exit 0", vec![2]),
+    ];
+
+    let results = run_contract_jobs(&jobs, 2);
+    let statuses: Vec<ContractJobStatus> = results.iter().map(|result| result.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ContractJobStatus::Failed,
+            ContractJobStatus::Skipped,
+            ContractJobStatus::Success,
+            ContractJobStatus::Success,
+        ]
+    );
+    assert_eq!(results[2].output.as_deref(), Some("hello\n"));
+
+    let report = AllReportJson::new("build".to_string(), 2, Duration::ZERO, &results, false);
+    assert!(report.has_failures());
+    assert_eq!(report.count(ContractJobStatus::Success), 2);
+
+    let junit = report.to_junit_xml();
+    assert!(junit.contains(r#"<testsuite name="build" tests="4" failures="1" skipped="1""#));
+    assert!(junit.contains("<failure message=\"command failed\">broken &lt;build&gt;\n</failure>"));
+    assert!(junit.contains(r#"<skipped message="a dependency failed" />"#));
+
+    let table = report.summary_table_string();
+    assert!(table.starts_with("contract          | status  | wasm size | duration\n"));
+    assert!(table.contains("dependent         | skipped | -         | 0.0s\n"));
+}
+
+#[test]
+fn run_contract_jobs_sequential_test() {
+    let jobs = vec![
+        shell_job("first", "exit 0", vec![]),
+        shell_job("second", "exit 0", vec![0]),
+    ];
+    let results = run_contract_jobs(&jobs, 1);
+    assert!(results
+        .iter()
+        .all(|result| result.status == ContractJobStatus::Success && result.output.is_none()));
+}
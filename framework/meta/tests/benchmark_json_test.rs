@@ -0,0 +1,93 @@
+use multiversx_sc_meta::benchmark_json::{
+    serialize_benchmark_json, BenchmarkEntryJson, BenchmarkReportJson,
+};
+
+fn entry(name: &str, gas_used: Option<u64>, vm_hook_count: Option<u64>) -> BenchmarkEntryJson {
+    BenchmarkEntryJson {
+        name: name.to_string(),
+        endpoint: name.to_string(),
+        gas_used,
+        vm_hook_count,
+    }
+}
+
+fn report(entries: Vec<BenchmarkEntryJson>) -> BenchmarkReportJson {
+    BenchmarkReportJson {
+        name: "bench".to_string(),
+        entries,
+    }
+}
+
+#[test]
+fn benchmark_json_serde_test() {
+    let report = report(vec![
+        entry("add", Some(1200), None),
+        entry("sub", None, Some(7)),
+    ]);
+    let json = serialize_benchmark_json(&report);
+    assert_eq!(
+        json,
+        r#"{
+    "name": "bench",
+    "entries": [
+        {
+            "name": "add",
+            "endpoint": "add",
+            "gasUsed": 1200
+        },
+        {
+            "name": "sub",
+            "endpoint": "sub",
+            "vmHookCount": 7
+        }
+    ]
+}
+"#
+    );
+    let deserialized: BenchmarkReportJson = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, report);
+}
+
+#[test]
+fn benchmark_merge_test() {
+    let mut debugger_report = report(vec![entry("add", None, Some(10))]);
+    debugger_report.merge(&report(vec![entry("add", Some(1000), None)]));
+    assert_eq!(
+        debugger_report,
+        report(vec![entry("add", Some(1000), Some(10))])
+    );
+}
+
+#[test]
+fn benchmark_compare_test() {
+    let baseline = report(vec![
+        entry("add", Some(1000), Some(10)),
+        entry("sub", Some(1000), Some(10)),
+    ]);
+    let current = report(vec![
+        entry("add", Some(1040), Some(10)),
+        entry("sub", Some(900), Some(12)),
+        entry("mul", Some(500), Some(5)),
+    ]);
+
+    let comparison = current.compare(&baseline, 5.0);
+    assert!(comparison.has_regressions());
+    let regressions: Vec<&str> = comparison
+        .regressions()
+        .map(|row| row.name.as_str())
+        .collect();
+    assert_eq!(regressions, vec!["sub"]);
+    assert_eq!(comparison.rows[1].gas_used.change_percent(), Some(-10.0));
+    assert_eq!(comparison.rows[2].gas_used.change_percent(), None);
+
+    assert_eq!(
+        comparison.table_string(),
+        "call | endpoint | gas used      | vm hooks     | status
+add  | add      | 1040 (+4.00%) | 10 (+0.00%)  | ok
+sub  | sub      | 900 (-10.00%) | 12 (+20.00%) | REGRESSION
+mul  | mul      | 500 (new)     | 5 (new)      | ok
+"
+    );
+
+    assert!(!current.compare(&current, 0.0).has_regressions());
+}
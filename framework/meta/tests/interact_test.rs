@@ -0,0 +1,273 @@
+use clap::{error::ErrorKind, Parser};
+use multiversx_sc_meta::{
+    cli_args::StandaloneCliArgs,
+    cmd::standalone::interact::{
+        parse_code_metadata, parse_interact_command, AbiType, ArgEncoder, InteractAbi,
+        InteractCommand, ResultDecoder,
+    },
+};
+use serde_json::json;
+
+const TEST_ABI_JSON: &str = r#"{
+    "name": "Test",
+    "constructor": {
+        "inputs": [{ "name": "initial", "type": "BigUint" }],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "setConfig",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "config", "type": "Config" },
+                { "name": "status", "type": "Status" },
+                { "name": "extra", "type": "variadic<multi<TokenIdentifier,u64>>", "multi_arg": true }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "getConfig",
+            "mutability": "readonly",
+            "inputs": [],
+            "outputs": [
+                { "type": "Config" },
+                { "type": "optional<Action>", "multi_result": true }
+            ]
+        }
+    ],
+    "types": {
+        "Config": {
+            "type": "struct",
+            "fields": [
+                { "name": "owner", "type": "Address" },
+                { "name": "limit", "type": "u32" },
+                { "name": "fee", "type": "BigUint" },
+                { "name": "tags", "type": "List<bytes>" },
+                { "name": "backup", "type": "Option<i16>" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 }
+            ]
+        },
+        "Action": {
+            "type": "enum",
+            "variants": [
+                { "name": "Nothing" },
+                {
+                    "name": "Send",
+                    "fields": [
+                        { "name": "0", "type": "Address" },
+                        { "name": "1", "type": "u8" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+const OWNER: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+const OWNER_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+
+fn test_abi() -> InteractAbi {
+    InteractAbi::from_json_str(TEST_ABI_JSON).unwrap()
+}
+
+fn to_strings(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn abi_type_parse_test() {
+    assert_eq!(AbiType::parse("u64").unwrap(), AbiType::Unsigned(8));
+    assert_eq!(
+        AbiType::parse("variadic<multi<TokenIdentifier,u64>>").unwrap(),
+        AbiType::Variadic(Box::new(AbiType::Multi(vec![
+            AbiType::TokenIdentifier,
+            AbiType::Unsigned(8)
+        ])))
+    );
+    assert_eq!(
+        AbiType::parse("Option<tuple<i8, List<Foo>>>").unwrap(),
+        AbiType::Option(Box::new(AbiType::Tuple(vec![
+            AbiType::Signed(1),
+            AbiType::List(Box::new(AbiType::Custom("Foo".to_string())))
+        ])))
+    );
+    assert_eq!(
+        AbiType::parse("array32<u8>").unwrap(),
+        AbiType::Array(32, Box::new(AbiType::Unsigned(1)))
+    );
+    assert!(AbiType::parse("List<u8").is_err());
+}
+
+#[test]
+fn encode_endpoint_args_test() {
+    let abi = test_abi();
+    let endpoint = abi.endpoint("setConfig").unwrap();
+    let config = format!(
+        r#"{{"owner": "{OWNER}", "limit": 5, "fee": "1000", "tags": ["ab", "0x01"], "backup": -2}}"#
+    );
+    let raw_args = vec![
+        config,
+        "Active".to_string(),
+        "TOKEN-123456".to_string(),
+        "7".to_string(),
+        "OTHER-abcdef".to_string(),
+        "0".to_string(),
+    ];
+    let args = ArgEncoder::new(&abi)
+        .encode_endpoint_args(endpoint, &raw_args)
+        .unwrap();
+
+    let expected_config = [
+        OWNER_HEX, "00000005", "00000002", "03e8", "00000002", "00000002", "6162", "00000001",
+        "01", "01", "fffe",
+    ]
+    .concat();
+    assert_eq!(
+        args.iter().map(hex::encode).collect::<Vec<_>>(),
+        vec![
+            expected_config,
+            "01".to_string(),
+            hex::encode("TOKEN-123456"),
+            "07".to_string(),
+            hex::encode("OTHER-abcdef"),
+            "".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn encode_endpoint_args_errors_test() {
+    let abi = test_abi();
+    let endpoint = abi.endpoint("setConfig").unwrap();
+    let encoder = ArgEncoder::new(&abi);
+
+    let missing = encoder.encode_endpoint_args(endpoint, &to_strings(&[]));
+    assert!(missing.is_err());
+
+    let config = format!(
+        r#"{{"owner": "{OWNER}", "limit": 5000000000, "fee": "0", "tags": [], "backup": null}}"#
+    );
+    let overflow = encoder.encode_endpoint_args(endpoint, &[config, "Active".to_string()]);
+    assert!(overflow
+        .unwrap_err()
+        .to_string()
+        .contains("does not fit in 32 bits"));
+
+    let config =
+        format!(r#"{{"owner": "{OWNER}", "limit": 1, "fee": "0", "tags": [], "backup": null}}"#);
+    let bad_variant = encoder.encode_endpoint_args(endpoint, &[config, "Paused".to_string()]);
+    assert!(bad_variant
+        .unwrap_err()
+        .to_string()
+        .contains("`Paused` is not a variant of `Status`"));
+}
+
+#[test]
+fn decode_endpoint_results_test() {
+    let abi = test_abi();
+    let endpoint = abi.endpoint("getConfig").unwrap();
+    let config_bytes = hex::decode(
+        [
+            OWNER_HEX, "00000005", "00000002", "03e8", "00000001", "00000002", "6162", "00",
+        ]
+        .concat(),
+    )
+    .unwrap();
+    let send_bytes = hex::decode(["01", OWNER_HEX, "09"].concat()).unwrap();
+
+    let decoder = ResultDecoder::new(&abi);
+    let results = decoder
+        .decode_endpoint_results(endpoint, &[config_bytes.clone(), send_bytes])
+        .unwrap();
+    assert_eq!(
+        results,
+        vec![
+            json!({
+                "owner": OWNER,
+                "limit": 5,
+                "fee": "1000",
+                "tags": ["0x6162"],
+                "backup": null
+            }),
+            json!({ "Send": { "0": OWNER, "1": 9 } }),
+        ]
+    );
+
+    // optional result missing, then an empty top-encoded enum
+    let results = decoder
+        .decode_endpoint_results(endpoint, std::slice::from_ref(&config_bytes))
+        .unwrap();
+    assert_eq!(results[1], json!(null));
+    let results = decoder
+        .decode_endpoint_results(endpoint, &[config_bytes, Vec::new()])
+        .unwrap();
+    assert_eq!(results[1], json!("Nothing"));
+}
+
+#[test]
+fn encode_decode_round_trip_test() {
+    let abi = test_abi();
+    let encoder = ArgEncoder::new(&abi);
+    let decoder = ResultDecoder::new(&abi);
+
+    for (type_name, value) in [
+        ("i64", json!(-1234567)),
+        ("u16", json!(65535)),
+        ("BigInt", json!("-99999999999999999999")),
+        ("bool", json!(true)),
+        ("Option<u8>", json!(3)),
+        ("tuple<u8,bytes>", json!([1, "0x0203"])),
+        ("array2<u16>", json!([1, 2])),
+        ("CodeMetadata", json!("upgradeable,payable")),
+        ("Action", json!({ "Send": { "0": OWNER, "1": 1 } })),
+    ] {
+        let abi_type = AbiType::parse(type_name).unwrap();
+        let encoded = encoder.top_encode(&value, &abi_type).unwrap();
+        assert_eq!(decoder.top_decode(&encoded, &abi_type).unwrap(), value);
+    }
+}
+
+#[test]
+fn code_metadata_test() {
+    assert_eq!(parse_code_metadata("upgradeable,readable").unwrap(), [5, 0]);
+    assert_eq!(parse_code_metadata("PayableBySC").unwrap(), [0, 4]);
+    assert_eq!(parse_code_metadata("0x0102").unwrap(), [1, 2]);
+    assert!(parse_code_metadata("immortal").is_err());
+}
+
+#[test]
+fn parse_interact_command_test() {
+    let abi = test_abi();
+    let command =
+        parse_interact_command(&abi, &to_strings(&["setConfig", "{}", "Active", "-1"])).unwrap();
+    assert_eq!(
+        command,
+        InteractCommand::new("setConfig", &["{}", "Active", "-1"])
+    );
+
+    let command = parse_interact_command(&abi, &to_strings(&["deploy", "100"])).unwrap();
+    assert_eq!(command, InteractCommand::new("deploy", &["100"]));
+
+    assert!(parse_interact_command(&abi, &to_strings(&["unknownEndpoint"])).is_err());
+}
+
+#[test]
+fn interact_value_with_payments_cli_test() {
+    let cli_args = |payment_args: &[&str]| {
+        let mut args = vec!["sc-meta", "interact", "--abi", "adder.abi.json"];
+        args.extend_from_slice(payment_args);
+        args.extend_from_slice(&["add", "3"]);
+        StandaloneCliArgs::try_parse_from(args)
+    };
+
+    assert!(cli_args(&["--value", "1"]).is_ok());
+    assert!(cli_args(&["--esdt", "TOKEN-123456:5"]).is_ok());
+    let err = cli_args(&["--value", "1", "--esdt", "TOKEN-123456:5"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+}
@@ -0,0 +1,291 @@
+use std::path::Path;
+
+use multiversx_sc_meta::{
+    cli_args::LintArgs,
+    cmd::standalone::lint::{
+        assemble_contract, lint, lint_contract, parse_lint_traits, LintError, LintRule, LintWarning,
+    },
+};
+
+fn lint_source(source: &str) -> Vec<LintWarning> {
+    let traits = parse_lint_traits(Path::new("lib.rs"), source);
+    let contract_trait = traits.iter().find(|t| t.is_contract).unwrap();
+    lint_contract(&assemble_contract(contract_trait, traits.as_slice()))
+}
+
+fn rules_of(warnings: &[LintWarning]) -> Vec<(LintRule, &str)> {
+    warnings
+        .iter()
+        .map(|w| (w.rule, w.method_name.as_str()))
+        .collect()
+}
+
+const CLEAN_CONTRACT: &str = r#"
+#[multiversx_sc::contract]
+pub trait Clean: crate::config::ConfigModule {
+    #[init]
+    fn init(&self, limit: BigUint) {
+        self.limit().set(limit);
+    }
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn deposit(&self) {
+        let payment = self.call_value().egld_value();
+        self.deposits(&self.blockchain().get_caller()).update(|d| *d += &*payment);
+    }
+
+    #[only_owner]
+    #[endpoint(setLimit)]
+    fn set_limit(&self, limit: BigUint) {
+        self.limit().set(limit);
+    }
+
+    #[view(getLimit)]
+    fn get_limit(&self) -> BigUint {
+        self.limit().get()
+    }
+
+    #[callback]
+    fn my_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {},
+            ManagedAsyncCallResult::Err(_) => self.failed().set(true),
+        }
+    }
+
+    #[storage_mapper("limit")]
+    fn limit(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("failed")]
+    fn failed(&self) -> SingleValueMapper<bool>;
+}
+
+mod config {
+    #[multiversx_sc::module]
+    pub trait ConfigModule {
+        #[storage_mapper("deposits")]
+        fn deposits(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    }
+}
+"#;
+
+#[test]
+fn lint_clean_contract_test() {
+    assert_eq!(lint_source(CLEAN_CONTRACT), Vec::new());
+}
+
+const FAULTY_CONTRACT: &str = r#"
+#[multiversx_sc::contract]
+pub trait Faulty: feature::FeatureModule {
+    #[init]
+    fn init(&self) {}
+
+    #[payable("*")]
+    #[endpoint]
+    fn donate(&self) {}
+
+    #[only_owner]
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee: BigUint) {
+        self.fee().set(fee);
+    }
+
+    #[endpoint(resetFee)]
+    fn reset_fee(&self) {
+        self.clear_fee();
+    }
+
+    #[view(getFee)]
+    fn get_fee(&self) -> BigUint {
+        self.fee().set_if_empty(BigUint::zero());
+        self.fee().get()
+    }
+
+    #[endpoint(distribute)]
+    fn distribute(&self) {
+        for user in self.users().iter() {
+            self.send_reward(&user);
+        }
+    }
+
+    #[endpoint(distributeSome)]
+    fn distribute_some(&self) {
+        for user in self.users().iter().take(10) {
+            self.send_reward(&user);
+        }
+    }
+
+    #[callback]
+    fn my_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+        if let ManagedAsyncCallResult::Ok(()) = result {
+            self.fee().clear();
+        }
+    }
+
+    fn clear_fee(&self) {
+        self.fee().clear();
+    }
+
+    fn send_reward(&self, _user: &ManagedAddress) {}
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("users")]
+    fn users(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
+
+mod feature {
+    #[multiversx_sc::module]
+    pub trait FeatureModule {
+        #[storage_mapper("feeRecipient")]
+        fn fee_recipient(&self) -> SingleValueMapper<ManagedAddress>;
+
+        #[storage_mapper("users")]
+        fn feature_users(&self) -> VecMapper<ManagedAddress>;
+    }
+}
+"#;
+
+#[test]
+fn lint_faulty_contract_test() {
+    let warnings = lint_source(FAULTY_CONTRACT);
+    assert_eq!(
+        rules_of(&warnings),
+        vec![
+            (LintRule::PayableWithoutCallValue, "donate"),
+            (LintRule::UnprotectedOwnerStorage, "reset_fee"),
+            (LintRule::ViewWritesStorage, "get_fee"),
+            (LintRule::StorageKeyCollision, "fee"),
+            (LintRule::StorageKeyCollision, "users"),
+            (LintRule::CallbackIgnoresError, "my_callback"),
+            (LintRule::UnboundedIteration, "distribute"),
+        ]
+    );
+}
+
+const SWALLOWED_ERRORS_CONTRACT: &str = r#"
+#[multiversx_sc::contract]
+pub trait Swallowed {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee: BigUint) {
+        let owner = self.blockchain().get_owner_address();
+        self.fee_set_event(&owner);
+        self.fee().set(fee);
+    }
+
+    #[only_owner]
+    #[endpoint(resetFee)]
+    fn reset_fee(&self) {
+        self.fee().clear();
+    }
+
+    #[callback]
+    fn match_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => self.fee().clear(),
+            ManagedAsyncCallResult::Err(_) => {},
+        }
+    }
+
+    #[callback]
+    fn if_let_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+        if let ManagedAsyncCallResult::Err(_) = result {}
+    }
+
+    #[event("feeSet")]
+    fn fee_set_event(&self, #[indexed] owner: &ManagedAddress);
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<BigUint>;
+}
+"#;
+
+#[test]
+fn lint_swallowed_errors_test() {
+    let warnings = lint_source(SWALLOWED_ERRORS_CONTRACT);
+    assert_eq!(
+        rules_of(&warnings),
+        vec![
+            (LintRule::UnprotectedOwnerStorage, "set_fee"),
+            (LintRule::CallbackIgnoresError, "match_callback"),
+            (LintRule::CallbackIgnoresError, "if_let_callback"),
+        ]
+    );
+}
+
+const MAPPER_RECEIVER_CONTRACT: &str = r#"
+#[multiversx_sc::contract]
+pub trait MapperReceiver {
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint(setTotal)]
+    fn set_total(&self, total: BigUint) {
+        self.total().set(total);
+    }
+
+    #[view(getTotalWith)]
+    fn get_total_with(&self, amount: BigUint) -> BigUint {
+        self.total().get().add(&amount)
+    }
+
+    #[view(whitelistCaller)]
+    fn whitelist_caller(&self) {
+        self.whitelist().add(&self.blockchain().get_caller());
+    }
+
+    #[view(defaultBalance)]
+    fn default_balance(&self, address: ManagedAddress) {
+        self.balances().entry(address).or_default();
+    }
+
+    #[storage_mapper("total")]
+    fn total(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("whitelist")]
+    fn whitelist(&self) -> WhitelistMapper<ManagedAddress>;
+
+    #[storage_mapper("balances")]
+    fn balances(&self) -> MapMapper<ManagedAddress, BigUint>;
+}
+"#;
+
+#[test]
+fn lint_mapper_receiver_test() {
+    // `add` on a value read from storage is not a write, `add` on a whitelist mapper is
+    let warnings = lint_source(MAPPER_RECEIVER_CONTRACT);
+    assert_eq!(
+        rules_of(&warnings),
+        vec![
+            (LintRule::ViewWritesStorage, "whitelist_caller"),
+            (LintRule::ViewWritesStorage, "default_balance"),
+        ]
+    );
+}
+
+#[test]
+fn lint_unknown_rule_test() {
+    let args = LintArgs {
+        path: Some("./no-such-dir".to_string()),
+        ignore: Vec::new(),
+        allow: vec!["no-such-rule".to_string()],
+    };
+    assert_eq!(
+        lint(&args),
+        Err(LintError::UnknownRule("no-such-rule".to_string()))
+    );
+}
+
+#[test]
+fn lint_rule_names_test() {
+    for rule in LintRule::ALL {
+        assert_eq!(LintRule::from_name(rule.name()), Some(*rule));
+    }
+    assert_eq!(LintRule::from_name("no-such-rule"), None);
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{cell::Cell, sync::Arc};
 
 use multiversx_chain_vm::{
     executor::{BreakpointValue, VMHooks},
@@ -11,6 +11,18 @@ use crate::debug_executor::{StaticVarData, StaticVarStack};
 
 use super::{DebugHandle, VMHooksApi, VMHooksApiBackend};
 
+thread_local!(
+    /// Counts all VM hook calls performed by contracts running on the current thread.
+    ///
+    /// The debugger runs contracts on the calling thread, so benchmarks can use it
+    /// as a rough measure of the work done by an endpoint.
+    static VM_HOOK_CALL_COUNT: Cell<u64> = const { Cell::new(0) }
+);
+
+fn count_vm_hook_call() {
+    VM_HOOK_CALL_COUNT.with(|count| count.set(count.get() + 1));
+}
+
 #[derive(Clone)]
 pub struct DebugApiBackend;
 
@@ -21,6 +33,7 @@ impl VMHooksApiBackend for DebugApiBackend {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
+        count_vm_hook_call();
         let top_context = TxContextStack::static_peek();
         let wrapper = DebugApiVMHooksHandler::new(top_context);
         let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
@@ -31,6 +44,7 @@ impl VMHooksApiBackend for DebugApiBackend {
     where
         F: FnOnce(&dyn VMHooks) -> R,
     {
+        count_vm_hook_call();
         let wrapper = DebugApiVMHooksHandler::new(handle.context);
         let dispatcher = VMHooksDispatcher::new(Box::new(wrapper));
         f(&dispatcher)
@@ -85,6 +99,15 @@ impl DebugApi {
         TxContextStack::static_push(tx_context_arc);
         StaticVarStack::static_push();
     }
+
+    /// Number of VM hook calls performed on the current thread since the last reset.
+    pub fn vm_hook_call_count() -> u64 {
+        VM_HOOK_CALL_COUNT.with(Cell::get)
+    }
+
+    pub fn reset_vm_hook_call_count() {
+        VM_HOOK_CALL_COUNT.with(|count| count.set(0));
+    }
 }
 
 impl std::fmt::Debug for DebugApi {
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+const TX_ID_PREFIX: &str = "In txID: ";
+const TOTAL_GAS_USED_PREFIX: &str = "total gas used: ";
+
+/// Extracts the gas used by each transaction from the output of the Go VM scenario runner.
+///
+/// The relevant lines look like this:
+/// `In txID: my_tx , step type:ScCall , function: add , total gas used: 1234`
+pub fn parse_go_gas_log(output: &str) -> HashMap<String, u64> {
+    output.lines().filter_map(parse_gas_line).collect()
+}
+
+fn parse_gas_line(line: &str) -> Option<(String, u64)> {
+    let rest = line.trim().strip_prefix(TX_ID_PREFIX)?;
+    let tx_id = rest.split_whitespace().next()?;
+    let gas_start = rest.find(TOTAL_GAS_USED_PREFIX)? + TOTAL_GAS_USED_PREFIX.len();
+    let gas_digits: String = rest[gas_start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    let gas_used = gas_digits.parse().ok()?;
+    Some((tx_id.to_string(), gas_used))
+}
@@ -0,0 +1,9 @@
+mod go_gas_log;
+mod scenario_benchmark;
+
+pub use go_gas_log::parse_go_gas_log;
+pub use multiversx_sc_meta::benchmark_json::{
+    BenchmarkComparison, BenchmarkComparisonRow, BenchmarkEntryJson, BenchmarkReportJson,
+    MeasurementComparison,
+};
+pub use scenario_benchmark::ScenarioBenchmark;
@@ -0,0 +1,200 @@
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+use multiversx_sc_meta::benchmark_json::{BenchmarkEntryJson, BenchmarkReportJson};
+
+use crate::{
+    api::DebugApi,
+    facade::ScenarioWorld,
+    scenario::{run_trace::ScenarioTrace, ScenarioRunner},
+    scenario_model::{ExternalStepsStep, ScCallStep},
+    vm_go_tool::run_vm_go_tool_with_output,
+};
+
+use super::parse_go_gas_log;
+
+const GENERATED_SCENARIO_SUFFIX: &str = ".bench.scen.json";
+
+/// Removes the generated scenario file when dropped, even if the Go VM tool panics.
+struct GeneratedScenarioFile(PathBuf);
+
+impl Drop for GeneratedScenarioFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+struct BenchmarkCall {
+    name: String,
+    step: ScCallStep,
+}
+
+/// A set of named contract calls, measured on the Go VM (gas used) and on the Rust debugger (VM hook calls).
+///
+/// The calls are executed in order, after an optional setup scenario,
+/// which typically deploys the contract and sets up the accounts.
+pub struct ScenarioBenchmark {
+    name: String,
+    setup_path: Option<PathBuf>,
+    calls: Vec<BenchmarkCall>,
+}
+
+impl ScenarioBenchmark {
+    pub fn new(name: &str) -> Self {
+        ScenarioBenchmark {
+            name: name.to_string(),
+            setup_path: None,
+            calls: Vec::new(),
+        }
+    }
+
+    /// Scenario file to run before the benchmarked calls, relative to the world's current directory.
+    ///
+    /// Required for running on the Go VM, since there the calls run in a generated scenario.
+    pub fn setup_scenario<P: AsRef<Path>>(mut self, relative_path: P) -> Self {
+        self.setup_path = Some(relative_path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds a call to the benchmark. The name is also used as transaction id in the Go VM output,
+    /// so it can only contain alphanumeric characters and underscores.
+    pub fn call(mut self, name: &str, mut step: ScCallStep) -> Self {
+        assert!(
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            "invalid benchmark call name: {name}"
+        );
+        assert!(
+            self.calls.iter().all(|call| call.name != name),
+            "duplicate benchmark call name: {name}"
+        );
+
+        name.clone_into(&mut step.id);
+        step.tx_id = Some(name.to_string());
+        self.calls.push(BenchmarkCall {
+            name: name.to_string(),
+            step,
+        });
+        self
+    }
+
+    fn new_report(&self) -> BenchmarkReportJson {
+        let mut report = BenchmarkReportJson::new(&self.name);
+        report.entries = self
+            .calls
+            .iter()
+            .map(|call| BenchmarkEntryJson {
+                name: call.name.clone(),
+                endpoint: call.step.tx.function.clone(),
+                ..Default::default()
+            })
+            .collect();
+        report
+    }
+
+    /// Runs the benchmark on the Rust debugger, counting the VM hook calls performed by each call.
+    ///
+    /// The world must have all the involved contracts registered.
+    pub fn run_debugger(&self, world: &mut ScenarioWorld) -> BenchmarkReportJson {
+        if let Some(setup_path) = &self.setup_path {
+            let absolute_path = world.current_dir.join(setup_path);
+            world
+                .get_mut_debugger_backend()
+                .run_scenario_file(&absolute_path);
+        }
+
+        let mut report = self.new_report();
+        for (call, entry) in self.calls.iter().zip(report.entries.iter_mut()) {
+            let mut step = call.step.clone();
+            DebugApi::reset_vm_hook_call_count();
+            world.run_sc_call_step(&mut step);
+            entry.vm_hook_count = Some(DebugApi::vm_hook_call_count());
+        }
+        report
+    }
+
+    /// Runs the benchmark on the Go VM, recording the gas used by each call.
+    ///
+    /// The calls are written to a temporary scenario file next to the setup scenario,
+    /// so that the relative paths in the setup still resolve. The file is always removed afterwards.
+    /// If the Go tests are disabled (the `run-go-tests` feature is not active), no gas is recorded.
+    pub fn run_vm_go(&self, current_dir: &Path) -> BenchmarkReportJson {
+        let setup_path = current_dir.join(
+            self.setup_path
+                .as_ref()
+                .expect("Go VM benchmarks require a setup scenario"),
+        );
+        let setup_dir = setup_path.parent().unwrap();
+        let scenario_path = setup_dir.join(format!("{}{GENERATED_SCENARIO_SUFFIX}", self.name));
+
+        let mut trace = ScenarioTrace::default();
+        trace.scenario_trace.name = Some(self.name.clone());
+        trace.run_external_steps(&ExternalStepsStep {
+            comment: None,
+            path: setup_path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned(),
+        });
+        for call in &self.calls {
+            trace.run_sc_call_step(&mut call.step.clone());
+        }
+        let generated_file = GeneratedScenarioFile(scenario_path);
+        trace.write_scenario_trace(&generated_file.0);
+
+        let output = run_vm_go_tool_with_output(&generated_file.0);
+        drop(generated_file);
+
+        let mut report = self.new_report();
+        if let Some(output) = output {
+            let gas_log = parse_go_gas_log(output.as_str());
+            for entry in report.entries.iter_mut() {
+                entry.gas_used = gas_log.get(&entry.name).copied();
+            }
+        }
+        report
+    }
+
+    /// Runs the benchmark on both backends and merges the results.
+    pub fn run(&self, world: &mut ScenarioWorld) -> BenchmarkReportJson {
+        let mut report = self.run_debugger(world);
+        if self.setup_path.is_some() {
+            report.merge(&self.run_vm_go(world.current_dir()));
+        }
+        report
+    }
+
+    /// Runs the benchmark, then compares the results against a baseline file, relative to the world's current directory.
+    ///
+    /// Prints the comparison table and panics if any measurement exceeds its baseline by more than `tolerance_percent`.
+    /// If the baseline file does not exist, the current results are saved as the new baseline.
+    pub fn run_against_baseline<P: AsRef<Path>>(
+        &self,
+        world: &mut ScenarioWorld,
+        baseline_path: P,
+        tolerance_percent: f64,
+    ) -> BenchmarkReportJson {
+        let report = self.run(world);
+        let baseline_path = world.current_dir.join(baseline_path);
+        if !baseline_path.is_file() {
+            println!(
+                "{} {}",
+                "Saving new benchmark baseline".yellow(),
+                baseline_path.display()
+            );
+            report.save_to_file(&baseline_path);
+            return report;
+        }
+
+        let baseline = BenchmarkReportJson::load_from_file(&baseline_path);
+        let comparison = report.compare(&baseline, tolerance_percent);
+        comparison.print_table();
+        assert!(
+            !comparison.has_regressions(),
+            "benchmark {} regressed compared to baseline {}",
+            self.name,
+            baseline_path.display()
+        );
+        report
+    }
+}
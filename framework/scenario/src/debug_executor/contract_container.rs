@@ -1,4 +1,7 @@
-use multiversx_chain_vm::tx_mock::{TxContextRef, TxFunctionName, TxPanic};
+use multiversx_chain_vm::{
+    tx_mock::{TxContextRef, TxFunctionName, TxPanic},
+    vm_err_msg,
+};
 use multiversx_chain_vm_executor::{BreakpointValue, ExecutorError, Instance, MemLength, MemPtr};
 use multiversx_sc::contract_base::CallableContract;
 use std::sync::Arc;
@@ -81,7 +84,10 @@ impl Instance for ContractContainerRef {
             if call_successful {
                 Ok(())
             } else {
-                Err(TxPanic::new(1, "invalid function (not found)"))
+                Err(TxPanic::new(
+                    vm_err_msg::RETURN_CODE_FUNCTION_NOT_FOUND,
+                    vm_err_msg::FUNCTION_NOT_FOUND,
+                ))
             }
         });
 
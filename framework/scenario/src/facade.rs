@@ -1,11 +1,13 @@
 mod contract_info;
 mod debugger_backend;
+mod dual_backend;
 mod scenario_world;
 mod scenario_world_runner;
 mod scenario_world_steps;
 mod scenario_world_steps_deprecated;
 mod scenario_world_whitebox;
 mod whitebox_contract;
+pub mod world_tx;
 
 pub use contract_info::ContractInfo;
 pub use scenario_world::ScenarioWorld;
@@ -90,41 +90,48 @@ impl ScenarioRunner for DebuggerBackend {
 }
 
 impl DebuggerBackend {
-    pub(super) fn run_scenario_file(&mut self, steps_path: &Path) {
-        let mut scenario = scenario::parse_scenario(steps_path);
-
-        for step in &mut scenario.steps {
-            match step {
-                Step::ExternalSteps(external_steps_step) => {
-                    let parent_path = steps_path.parent().unwrap();
-                    let new_path = parent_path.join(external_steps_step.path.as_str());
-                    self.run_scenario_file(new_path.as_path());
-                },
-                Step::SetState(set_state_step) => {
-                    self.run_set_state_step(set_state_step);
-                },
-                Step::ScCall(sc_call_step) => {
-                    self.run_sc_call_step(sc_call_step);
-                },
-                Step::ScQuery(sc_query_step) => {
-                    self.run_sc_query_step(sc_query_step);
-                },
-                Step::ScDeploy(sc_deploy_step) => {
-                    self.run_sc_deploy_step(sc_deploy_step);
-                },
-                Step::Transfer(transfer_step) => {
-                    self.run_transfer_step(transfer_step);
-                },
-                Step::ValidatorReward(validator_reward_step) => {
-                    self.run_validator_reward_step(validator_reward_step);
-                },
-                Step::CheckState(check_state_step) => {
-                    self.run_check_state_step(check_state_step);
-                },
-                Step::DumpState(_) => {
-                    self.run_dump_state_step();
-                },
-            }
+    pub(crate) fn run_scenario_file(&mut self, steps_path: &Path) {
+        run_scenario_file(self, steps_path);
+    }
+}
+
+/// Parses a scenario file and feeds its steps to a runner, one by one.
+///
+/// External steps are expanded in place.
+pub(crate) fn run_scenario_file<R: ScenarioRunner>(runner: &mut R, steps_path: &Path) {
+    let mut scenario = scenario::parse_scenario(steps_path);
+
+    for step in &mut scenario.steps {
+        match step {
+            Step::ExternalSteps(external_steps_step) => {
+                let parent_path = steps_path.parent().unwrap();
+                let new_path = parent_path.join(external_steps_step.path.as_str());
+                run_scenario_file(runner, new_path.as_path());
+            },
+            Step::SetState(set_state_step) => {
+                runner.run_set_state_step(set_state_step);
+            },
+            Step::ScCall(sc_call_step) => {
+                runner.run_sc_call_step(sc_call_step);
+            },
+            Step::ScQuery(sc_query_step) => {
+                runner.run_sc_query_step(sc_query_step);
+            },
+            Step::ScDeploy(sc_deploy_step) => {
+                runner.run_sc_deploy_step(sc_deploy_step);
+            },
+            Step::Transfer(transfer_step) => {
+                runner.run_transfer_step(transfer_step);
+            },
+            Step::ValidatorReward(validator_reward_step) => {
+                runner.run_validator_reward_step(validator_reward_step);
+            },
+            Step::CheckState(check_state_step) => {
+                runner.run_check_state_step(check_state_step);
+            },
+            Step::DumpState(_) => {
+                runner.run_dump_state_step();
+            },
         }
     }
 }
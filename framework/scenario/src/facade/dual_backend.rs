@@ -0,0 +1,416 @@
+use crate::{
+    multiversx_sc::{
+        api::{
+            ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
+        },
+        types::Address,
+    },
+    scenario::{model::*, run_trace::ScenarioTrace, ScenarioRunner},
+    scenario_model::TxResponse,
+    vm_go_tool::{try_run_vm_go_tool, VmGoToolOutcome},
+};
+use multiversx_chain_vm::{
+    tx_execution::execute_current_tx_context_input,
+    tx_mock::{TxLog, TxResult},
+    world_mock::{AccountData, BlockchainState, EsdtData, EsdtInstance},
+};
+use num_traits::Zero;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use super::debugger_backend::{run_scenario_file, DebuggerBackend};
+
+/// File name suffix of the scenarios recorded by the dual backend.
+const DUAL_RECORDING_SUFFIX: &str = ".dual.scen.json";
+
+/// Name of the recording of steps that do not come from a scenario file, if the test thread has no name.
+const DEFAULT_RECORDING_NAME: &str = "steps";
+
+/// Logs whose data is not compared, the Go VM fills it in differently for these builtin functions.
+const LOG_DATA_NOT_COMPARED: &[&str] = &[
+    ESDT_TRANSFER_FUNC_NAME,
+    ESDT_NFT_TRANSFER_FUNC_NAME,
+    ESDT_MULTI_TRANSFER_FUNC_NAME,
+];
+
+/// Runs every step on the Rust debugger and records the outcome,
+/// then replays the recording on the Go VM and compares.
+///
+/// The recording is a regular scenario, where:
+/// - each transaction expects exactly the results, error and logs produced by the debugger;
+/// - each state-changing step is followed by a `checkState` with the full debugger state.
+///
+/// Scenario files are compared as soon as they finish.
+/// Steps run one by one are compared when the backend is dropped, unless the test already failed.
+/// The comparison stops at the first divergence, with a want/have diff.
+///
+/// Known differences, not compared:
+/// - gas, since the debugger does not meter it;
+/// - the data of the logs in [`LOG_DATA_NOT_COMPARED`];
+/// - the error log (`signalError` or `internalVMErrors`) the Go VM adds at the end of failed transactions.
+pub(crate) struct DualBackend {
+    pub debugger: DebuggerBackend,
+    pub recording: ScenarioTrace,
+    pub recording_dir: Option<PathBuf>,
+}
+
+impl DualBackend {
+    /// Panics if the Go tests are disabled, since nothing could be compared.
+    pub fn new(debugger: DebuggerBackend) -> Self {
+        if cfg!(not(feature = "run-go-tests")) {
+            panic!("the dual backend needs the Go VM, enable the `run-go-tests` feature of `multiversx-sc-scenario`");
+        }
+        DualBackend {
+            debugger,
+            recording: ScenarioTrace::default(),
+            recording_dir: None,
+        }
+    }
+
+    /// Runs a scenario file on the debugger, then compares the recording against the Go VM.
+    ///
+    /// The recording is saved as `<name>.dual.scen.json`, in the recording directory, if configured,
+    /// or next to the scenario file otherwise.
+    pub fn run_scenario_file(&mut self, steps_path: &Path) {
+        run_scenario_file(self, steps_path);
+
+        let file_name = steps_path.file_name().unwrap().to_str().unwrap();
+        let recording_name = file_name
+            .trim_end_matches(".json")
+            .trim_end_matches(".scen");
+        let recording_path = self.recording_path(steps_path.parent().unwrap(), recording_name);
+        self.compare_with_vm_go(&recording_path);
+    }
+
+    fn recording_path(&self, source_dir: &Path, recording_name: &str) -> PathBuf {
+        self.recording_dir
+            .as_deref()
+            .unwrap_or(source_dir)
+            .join(format!("{recording_name}{DUAL_RECORDING_SUFFIX}"))
+    }
+
+    /// Saves the steps recorded so far and replays them on the Go VM.
+    ///
+    /// The recording starts over afterwards, so the same steps are never compared twice.
+    fn compare_with_vm_go(&mut self, recording_path: &Path) {
+        std::fs::create_dir_all(recording_path.parent().unwrap())
+            .expect("could not create the dual backend recording directory");
+        let mut recording = std::mem::take(&mut self.recording);
+        recording.write_scenario_trace(recording_path);
+
+        match try_run_vm_go_tool(recording_path) {
+            Some(VmGoToolOutcome::Passed(_)) => {},
+            Some(VmGoToolOutcome::Failed(output)) => panic!(
+                "the Go VM diverges from the Rust debugger\nrecording: {}\n{output}",
+                recording_path.display(),
+            ),
+            None => panic!(
+                "the Go tests are disabled, could not compare the recording: {}",
+                recording_path.display(),
+            ),
+        }
+    }
+
+    fn record_state(&mut self, comment: String) {
+        let step = check_state_snapshot(&self.debugger.vm_runner.blockchain_mock.state, comment);
+        self.recording.run_check_state_step(&step);
+    }
+}
+
+/// Steps run one by one are compared when the test ends,
+/// in a recording named after the test.
+impl Drop for DualBackend {
+    fn drop(&mut self) {
+        if std::thread::panicking() || self.recording.scenario_trace.steps.is_empty() {
+            return;
+        }
+
+        let recording_name = std::thread::current()
+            .name()
+            .map(|name| name.replace("::", "-"))
+            .unwrap_or_else(|| DEFAULT_RECORDING_NAME.to_string());
+        let current_dir = std::env::current_dir().unwrap();
+        let recording_path = self.recording_path(&current_dir, &recording_name);
+        self.compare_with_vm_go(&recording_path);
+    }
+}
+
+impl ScenarioRunner for DualBackend {
+    fn run_external_steps(&mut self, step: &ExternalStepsStep) {
+        self.debugger.run_external_steps(step);
+    }
+
+    fn run_set_state_step(&mut self, step: &SetStateStep) {
+        self.debugger.run_set_state_step(step);
+        self.recording.run_set_state_step(step);
+    }
+
+    fn run_sc_call_step(&mut self, step: &mut ScCallStep) {
+        self.record_sc_call(step);
+        self.record_state(format!("state after scCall `{}`", step.id));
+    }
+
+    fn run_multi_sc_call_step(&mut self, steps: &mut [ScCallStep]) {
+        for step in steps.iter_mut() {
+            self.record_sc_call(step);
+        }
+        self.record_state("state after multiple scCall steps".to_string());
+    }
+
+    fn run_multi_sc_deploy_step(&mut self, steps: &mut [ScDeployStep]) {
+        for step in steps.iter_mut() {
+            self.record_sc_deploy(step);
+        }
+        self.record_state("state after multiple scDeploy steps".to_string());
+    }
+
+    fn run_sc_query_step(&mut self, step: &mut ScQueryStep) {
+        let tx_result = self
+            .debugger
+            .vm_runner
+            .perform_sc_query_lambda_and_check(step, execute_current_tx_context_input);
+        let (expect, response) = recorded_expect(tx_result);
+        step.save_response(response);
+        step.expect = Some(expect);
+        self.recording.run_sc_query_step(step);
+    }
+
+    fn run_sc_deploy_step(&mut self, step: &mut ScDeployStep) {
+        self.record_sc_deploy(step);
+        self.record_state(format!("state after scDeploy `{}`", step.id));
+    }
+
+    fn run_transfer_step(&mut self, step: &TransferStep) {
+        self.debugger.run_transfer_step(step);
+        self.recording.run_transfer_step(step);
+        self.record_state(format!("state after transfer `{}`", step.id));
+    }
+
+    fn run_validator_reward_step(&mut self, step: &ValidatorRewardStep) {
+        self.debugger.run_validator_reward_step(step);
+        self.recording.run_validator_reward_step(step);
+        self.record_state(format!("state after validatorReward `{}`", step.id));
+    }
+
+    fn run_check_state_step(&mut self, step: &CheckStateStep) {
+        self.debugger.run_check_state_step(step);
+        self.recording.run_check_state_step(step);
+    }
+
+    fn run_dump_state_step(&mut self) {
+        self.debugger.run_dump_state_step();
+    }
+}
+
+impl DualBackend {
+    /// Runs the call directly on the debugger VM, to record the logs exactly as produced.
+    fn record_sc_call(&mut self, step: &mut ScCallStep) {
+        let tx_result = self
+            .debugger
+            .vm_runner
+            .perform_sc_call_lambda_and_check(step, execute_current_tx_context_input);
+        let (expect, response) = recorded_expect(tx_result);
+        step.save_response(response);
+        step.expect = Some(expect);
+        self.recording.run_sc_call_step(step);
+    }
+
+    fn record_sc_deploy(&mut self, step: &mut ScDeployStep) {
+        let (new_address, tx_result) = self
+            .debugger
+            .vm_runner
+            .perform_sc_deploy_lambda_and_check(step, execute_current_tx_context_input);
+        let (expect, mut response) = recorded_expect(tx_result);
+        response.new_deployed_address = Some(new_address);
+        step.save_response(response);
+        step.expect = Some(expect);
+        self.recording.run_sc_deploy_step(step);
+    }
+}
+
+/// Expects exactly the outcome of the debugger, logs included, for failed transactions too.
+fn recorded_expect(tx_result: TxResult) -> (TxExpect, TxResponse) {
+    let logs = check_logs(&tx_result);
+    let response = TxResponse::from_tx_result(tx_result);
+    let mut expect = response.to_expect();
+    expect.logs = logs;
+    (expect, response)
+}
+
+/// The Go VM adds an error log at the end of failed transactions, which the debugger does not produce.
+fn check_logs(tx_result: &TxResult) -> CheckLogs {
+    CheckLogs::List(CheckLogList {
+        list: tx_result.result_logs.iter().map(check_log).collect(),
+        more_allowed_at_end: tx_result.result_status != 0,
+    })
+}
+
+fn check_log(log: &TxLog) -> CheckLog {
+    let data = if LOG_DATA_NOT_COMPARED.contains(&log.endpoint.as_str()) {
+        CheckValue::Star
+    } else {
+        CheckValue::Equal(
+            log.data
+                .iter()
+                .map(|data| CheckValue::Equal(BytesValue::from(data.as_slice())))
+                .collect(),
+        )
+    };
+
+    CheckLog {
+        address: CheckValue::Equal(BytesValue::from(log.address.as_bytes())),
+        endpoint: CheckValue::Equal(BytesValue::from(format!("str:{}", log.endpoint))),
+        topics: CheckValue::Equal(
+            log.topics
+                .iter()
+                .map(|topic| CheckValue::Equal(BytesValue::from(topic.as_slice())))
+                .collect(),
+        ),
+        data,
+    }
+}
+
+/// Expects all accounts exactly as they are in the debugger.
+///
+/// Other accounts are allowed, since the Go VM also keeps some protocol accounts.
+/// Code is not compared, the debugger only knows the contract path.
+/// Guardians are not compared either, the Go VM scenario format does not model them.
+fn check_state_snapshot(state: &BlockchainState, comment: String) -> CheckStateStep {
+    let accounts = state
+        .accounts
+        .values()
+        .map(|account| {
+            let address = Address::from(*account.address.as_array());
+            (AddressKey::from(&address), check_account(account))
+        })
+        .collect();
+
+    CheckStateStep {
+        comment: Some(comment),
+        accounts: CheckAccounts {
+            other_accounts_allowed: true,
+            accounts,
+        },
+    }
+}
+
+fn check_account(account: &AccountData) -> CheckAccount {
+    let storages = account
+        .storage
+        .iter()
+        .map(|(key, value)| {
+            (
+                hex_bytes_key(key),
+                CheckValue::Equal(BytesValue::from(value.as_slice())),
+            )
+        })
+        .collect();
+
+    let esdts = account
+        .esdt
+        .iter()
+        .filter_map(|(token_identifier, esdt_data)| {
+            check_esdt(esdt_data).map(|check| (token_identifier_key(token_identifier), check))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    CheckAccount {
+        comment: None,
+        nonce: CheckValue::Equal(U64Value::from(account.nonce)),
+        balance: CheckValue::Equal(BigUintValue::from(&account.egld_balance)),
+        esdt: CheckEsdtMap::Equal(CheckEsdtMapContents {
+            contents: esdts,
+            other_esdts_allowed: false,
+        }),
+        username: CheckValue::Equal(BytesValue::from(account.username.as_slice())),
+        storage: CheckStorage::Equal(CheckStorageDetails {
+            storages,
+            other_storages_allowed: false,
+        }),
+        code: CheckValue::Star,
+        owner: CheckValue::Star,
+        developer_rewards: CheckValue::Equal(BigUintValue::from(&account.developer_rewards)),
+        async_call_data: CheckValue::Star,
+        guardians: CheckGuardians::default(),
+    }
+}
+
+fn hex_bytes_key(bytes: &[u8]) -> BytesKey {
+    BytesKey {
+        value: bytes.to_vec(),
+        original: format!("0x{}", hex::encode(bytes)),
+    }
+}
+
+fn token_identifier_key(token_identifier: &[u8]) -> BytesKey {
+    match std::str::from_utf8(token_identifier) {
+        Ok(token_identifier_str) => BytesKey {
+            value: token_identifier.to_vec(),
+            original: format!("str:{token_identifier_str}"),
+        },
+        Err(_) => hex_bytes_key(token_identifier),
+    }
+}
+
+/// Empty instances are skipped, the Go VM deletes them.
+fn check_esdt(esdt_data: &EsdtData) -> Option<CheckEsdt> {
+    let all_instances = esdt_data.instances.get_instances();
+    let instances = all_instances
+        .values()
+        .filter(|instance| !instance.balance.is_zero())
+        .collect::<Vec<_>>();
+
+    if esdt_data.last_nonce == 0 && !esdt_data.frozen {
+        if instances.is_empty() {
+            return None;
+        }
+        if all_instances.len() == 1 && instances[0].nonce == 0 {
+            return Some(CheckEsdt::Short(BigUintValue::from(&instances[0].balance)));
+        }
+    }
+
+    Some(CheckEsdt::Full(CheckEsdtData {
+        instances: CheckEsdtInstances::Equal(
+            instances.into_iter().map(check_esdt_instance).collect(),
+        ),
+        last_nonce: CheckValue::Equal(U64Value::from(esdt_data.last_nonce)),
+        frozen: CheckValue::Equal(U64Value::from(esdt_data.frozen as u64)),
+    }))
+}
+
+fn check_esdt_instance(instance: &EsdtInstance) -> CheckEsdtInstance {
+    let balance = CheckValue::Equal(BigUintValue::from(&instance.balance));
+    if instance.nonce == 0 {
+        return CheckEsdtInstance {
+            nonce: U64Value::from(0u64),
+            balance,
+            ..Default::default()
+        };
+    }
+
+    let metadata = &instance.metadata;
+    let creator = metadata
+        .creator
+        .as_ref()
+        .map(|creator| creator.as_bytes())
+        .unwrap_or_default();
+    CheckEsdtInstance {
+        nonce: U64Value::from(instance.nonce),
+        balance,
+        creator: CheckValue::Equal(BytesValue::from(creator)),
+        royalties: CheckValue::Equal(U64Value::from(metadata.royalties)),
+        hash: CheckValue::Equal(BytesValue::from(
+            metadata.hash.as_deref().unwrap_or_default(),
+        )),
+        uri: CheckValue::Equal(
+            metadata
+                .uri
+                .iter()
+                .map(|uri| CheckValue::Equal(BytesValue::from(uri.as_slice())))
+                .collect(),
+        ),
+        attributes: CheckValue::Equal(BytesValue::from(metadata.attributes.as_slice())),
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
 use multiversx_sc_meta::find_workspace::find_current_workspace;
 use std::path::{Path, PathBuf};
 
-use super::debugger_backend::DebuggerBackend;
+use super::{debugger_backend::DebuggerBackend, dual_backend::DualBackend};
 
 /// A facade for contracts tests.
 ///
@@ -32,7 +32,8 @@ pub struct ScenarioWorld {
 
 pub(crate) enum Backend {
     Debugger(DebuggerBackend),
-    VmGoBackend,
+    VmGo,
+    Dual(DualBackend),
 }
 
 impl Default for ScenarioWorld {
@@ -45,10 +46,7 @@ impl ScenarioWorld {
     pub fn debugger() -> Self {
         ScenarioWorld {
             current_dir: std::env::current_dir().unwrap(),
-            backend: Backend::Debugger(DebuggerBackend {
-                vm_runner: ScenarioVMRunner::new(),
-                trace: None,
-            }),
+            backend: Backend::Debugger(new_debugger_backend()),
         }
     }
 
@@ -60,7 +58,22 @@ impl ScenarioWorld {
     pub fn vm_go() -> Self {
         ScenarioWorld {
             current_dir: std::env::current_dir().unwrap(),
-            backend: Backend::VmGoBackend,
+            backend: Backend::VmGo,
+        }
+    }
+
+    /// Runs all steps on the Rust debugger, and replays them on the Go VM,
+    /// expecting exactly the same results, logs and account state after each step.
+    ///
+    /// The debugger outcome is recorded as a scenario (`<name>.dual.scen.json`),
+    /// which can later be replayed offline, on any backend.
+    /// Scenario files are compared when they finish, steps run one by one when the world is dropped.
+    ///
+    /// Requires the `run-go-tests` feature, panics otherwise, since there would be nothing to compare against.
+    pub fn dual() -> Self {
+        ScenarioWorld {
+            current_dir: std::env::current_dir().unwrap(),
+            backend: Backend::Dual(DualBackend::new(new_debugger_backend())),
         }
     }
 
@@ -74,28 +87,55 @@ impl ScenarioWorld {
             Backend::Debugger(mut debugger) => {
                 debugger.run_scenario_file(&absolute_path);
             },
-            Backend::VmGoBackend => {
+            Backend::VmGo => {
                 run_vm_go_tool(&absolute_path);
             },
+            Backend::Dual(mut dual) => {
+                dual.run_scenario_file(&absolute_path);
+            },
         }
     }
 
     pub(crate) fn get_debugger_backend(&self) -> &DebuggerBackend {
-        if let Backend::Debugger(debugger) = &self.backend {
-            debugger
-        } else {
-            panic!("operation only available for the contract debugger backend")
+        match &self.backend {
+            Backend::Debugger(debugger) => debugger,
+            Backend::Dual(dual) => &dual.debugger,
+            Backend::VmGo => {
+                panic!("operation only available for the contract debugger backend")
+            },
         }
     }
 
     pub(crate) fn get_mut_debugger_backend(&mut self) -> &mut DebuggerBackend {
-        if let Backend::Debugger(debugger) = &mut self.backend {
-            debugger
+        match &mut self.backend {
+            Backend::Debugger(debugger) => debugger,
+            Backend::Dual(dual) => &mut dual.debugger,
+            Backend::VmGo => {
+                panic!("operation only available for the contract debugger backend")
+            },
+        }
+    }
+
+    fn get_mut_dual_backend(&mut self) -> &mut DualBackend {
+        if let Backend::Dual(dual) = &mut self.backend {
+            dual
         } else {
-            panic!("operation only available for the contract debugger backend")
+            panic!("operation only available for the dual backend")
         }
     }
 
+    /// Sets the directory where the dual backend saves its recordings.
+    ///
+    /// By default, recordings are saved next to the scenario files they come from,
+    /// so they can be committed and replayed later.
+    /// Steps run one by one are recorded in the current directory, as `<test name>.dual.scen.json`.
+    pub fn dual_recording_dir<P: AsRef<Path>>(&mut self, dir_path: P) -> &mut Self {
+        let mut absolute_path = self.current_dir.clone();
+        absolute_path.push(dir_path);
+        self.get_mut_dual_backend().recording_dir = Some(absolute_path);
+        self
+    }
+
     pub(crate) fn get_state(&self) -> &BlockchainState {
         &self.get_debugger_backend().vm_runner.blockchain_mock.state
     }
@@ -237,3 +277,10 @@ impl ScenarioWorld {
         self.write_scenario_trace(file_path);
     }
 }
+
+fn new_debugger_backend() -> DebuggerBackend {
+    DebuggerBackend {
+        vm_runner: ScenarioVMRunner::new(),
+        trace: None,
+    }
+}
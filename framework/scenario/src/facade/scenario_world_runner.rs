@@ -14,7 +14,8 @@ impl ScenarioWorld {
                     f(trace);
                 }
             },
-            Backend::VmGoBackend => {
+            Backend::Dual(dual) => f(dual),
+            Backend::VmGo => {
                 panic!("the VM Go backend does not support step-by-step execution")
             },
         }
@@ -0,0 +1,14 @@
+mod scenario_env;
+mod scenario_exec_call;
+mod scenario_exec_deploy;
+mod scenario_query_call;
+mod scenario_rh_impl;
+mod scenario_tx_address;
+mod tx_to_step;
+
+pub use scenario_env::{ScenarioEnvExec, ScenarioEnvQuery, ScenarioTxEnv, ScenarioTxEnvData};
+pub use scenario_exec_call::{ScenarioTxExpect, ScenarioTxRun};
+pub use scenario_rh_impl::ReturnsNewAddress;
+pub use tx_to_step::{
+    tx_to_sc_call_step, tx_to_sc_deploy_step, tx_to_sc_query_step, tx_to_transfer_step, TxCodeValue,
+};
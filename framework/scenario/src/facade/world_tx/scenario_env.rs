@@ -0,0 +1,123 @@
+use multiversx_sc::types::{ManagedAddress, Tx, TxBaseWithEnv, TxEnv};
+
+use crate::{
+    api::StaticApi, scenario_format::interpret_trait::InterpreterContext, scenario_model::TxExpect,
+    ScenarioWorld,
+};
+
+/// Default gas limit for transactions in tests, when none is specified.
+const DEFAULT_GAS: u64 = 5_000_000;
+
+/// Data common to all scenario-based transaction environments (tests and interactors).
+#[derive(Clone)]
+pub struct ScenarioTxEnvData {
+    pub interpreter_context: InterpreterContext,
+    pub tx_id: Option<String>,
+    pub expect: Option<TxExpect>,
+}
+
+impl Default for ScenarioTxEnvData {
+    fn default() -> Self {
+        ScenarioTxEnvData {
+            interpreter_context: InterpreterContext::default(),
+            tx_id: None,
+            expect: Some(TxExpect::ok()),
+        }
+    }
+}
+
+impl ScenarioTxEnvData {
+    pub fn with_interpreter_context(interpreter_context: InterpreterContext) -> Self {
+        ScenarioTxEnvData {
+            interpreter_context,
+            ..Default::default()
+        }
+    }
+}
+
+/// Environments whose transactions get converted to scenario steps before being executed.
+pub trait ScenarioTxEnv: TxEnv<Api = StaticApi> {
+    fn env_data(&self) -> &ScenarioTxEnvData;
+
+    fn env_data_mut(&mut self) -> &mut ScenarioTxEnvData;
+}
+
+fn resolve_missing_sender() -> ManagedAddress<StaticApi> {
+    panic!("transactions in tests need an explicit sender, please specify it using `.from(...)`")
+}
+
+/// Executes transactions on the `ScenarioWorld`, they also end up in the trace.
+pub struct ScenarioEnvExec<'w> {
+    pub world: &'w mut ScenarioWorld,
+    pub data: ScenarioTxEnvData,
+}
+
+impl<'w> TxEnv for ScenarioEnvExec<'w> {
+    type Api = StaticApi;
+
+    fn resolve_sender_address(&self) -> ManagedAddress<StaticApi> {
+        resolve_missing_sender()
+    }
+
+    fn default_gas(&self) -> u64 {
+        DEFAULT_GAS
+    }
+}
+
+impl<'w> ScenarioTxEnv for ScenarioEnvExec<'w> {
+    fn env_data(&self) -> &ScenarioTxEnvData {
+        &self.data
+    }
+
+    fn env_data_mut(&mut self) -> &mut ScenarioTxEnvData {
+        &mut self.data
+    }
+}
+
+/// Performs queries (calls that do not change the state) on the `ScenarioWorld`.
+pub struct ScenarioEnvQuery<'w> {
+    pub world: &'w mut ScenarioWorld,
+    pub data: ScenarioTxEnvData,
+}
+
+impl<'w> TxEnv for ScenarioEnvQuery<'w> {
+    type Api = StaticApi;
+
+    fn resolve_sender_address(&self) -> ManagedAddress<StaticApi> {
+        resolve_missing_sender()
+    }
+
+    fn default_gas(&self) -> u64 {
+        DEFAULT_GAS
+    }
+}
+
+impl<'w> ScenarioTxEnv for ScenarioEnvQuery<'w> {
+    fn env_data(&self) -> &ScenarioTxEnvData {
+        &self.data
+    }
+
+    fn env_data_mut(&mut self) -> &mut ScenarioTxEnvData {
+        &mut self.data
+    }
+}
+
+impl ScenarioWorld {
+    fn new_env_data(&self) -> ScenarioTxEnvData {
+        ScenarioTxEnvData::with_interpreter_context(self.interpreter_context())
+    }
+
+    /// Starts building a transaction, using the same builder as contracts and interactors.
+    ///
+    /// It gets executed (and added to the trace) by calling `run`.
+    pub fn tx(&mut self) -> TxBaseWithEnv<ScenarioEnvExec<'_>> {
+        let data = self.new_env_data();
+        Tx::new_with_env(ScenarioEnvExec { world: self, data })
+    }
+
+    /// Starts building a query, using the same builder as contracts and interactors.
+    pub fn query(&mut self) -> TxBaseWithEnv<ScenarioEnvQuery<'_>> {
+        let data = self.new_env_data();
+        Tx::new_with_env(ScenarioEnvQuery { world: self, data })
+    }
+}
@@ -0,0 +1,91 @@
+use multiversx_sc::types::{
+    FunctionCall, Tx, TxData, TxFrom, TxGas, TxPayment, TxResultHandler, TxRunnableResultHandler,
+    TxTo, TxToSpecified,
+};
+
+use crate::{
+    api::StaticApi,
+    scenario_model::{TxExpect, TxResponse},
+};
+
+use super::{tx_to_sc_call_step, tx_to_transfer_step, ScenarioEnvExec, ScenarioTxEnv};
+
+/// Executes transactions built in a test environment.
+pub trait ScenarioTxRun {
+    type Returns;
+
+    /// Runs the transaction on the `ScenarioWorld` (adding it to the trace) and returns what the result handler requested.
+    fn run(self) -> Self::Returns;
+}
+
+/// Replaces the default "expect" section of transactions in tests, which only checks that the transaction succeeded.
+pub trait ScenarioTxExpect {
+    fn expect(self, expect: TxExpect) -> Self;
+
+    /// No checks are performed on the result.
+    fn no_expect(self) -> Self;
+
+    /// Sets the transaction id, as it appears in the trace.
+    fn id(self, tx_id: &str) -> Self;
+}
+
+impl<Env, From, To, Payment, Gas, Data, RH> ScenarioTxExpect
+    for Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: ScenarioTxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxData<Env>,
+    RH: TxResultHandler<Env>,
+{
+    fn expect(mut self, expect: TxExpect) -> Self {
+        self.env.env_data_mut().expect = Some(expect);
+        self
+    }
+
+    fn no_expect(mut self) -> Self {
+        self.env.env_data_mut().expect = None;
+        self
+    }
+
+    fn id(mut self, tx_id: &str) -> Self {
+        self.env.env_data_mut().tx_id = Some(tx_id.to_string());
+        self
+    }
+}
+
+impl<'w, From, To, Payment, Gas, RH> ScenarioTxRun
+    for Tx<ScenarioEnvExec<'w>, From, To, Payment, Gas, FunctionCall<StaticApi>, RH>
+where
+    From: TxFrom<ScenarioEnvExec<'w>>,
+    To: TxToSpecified<ScenarioEnvExec<'w>>,
+    Payment: TxPayment<ScenarioEnvExec<'w>>,
+    Gas: TxGas<ScenarioEnvExec<'w>>,
+    RH: TxRunnableResultHandler<TxResponse, ScenarioEnvExec<'w>>,
+{
+    type Returns = RH::Returns;
+
+    fn run(self) -> Self::Returns {
+        let (env, mut step, result_handler) = tx_to_sc_call_step(self);
+        env.world.sc_call(&mut step);
+        result_handler.process_result(step.response())
+    }
+}
+
+impl<'w, From, To, Payment, Gas> ScenarioTxRun
+    for Tx<ScenarioEnvExec<'w>, From, To, Payment, Gas, (), ()>
+where
+    From: TxFrom<ScenarioEnvExec<'w>>,
+    To: TxToSpecified<ScenarioEnvExec<'w>>,
+    Payment: TxPayment<ScenarioEnvExec<'w>>,
+    Gas: TxGas<ScenarioEnvExec<'w>>,
+{
+    type Returns = ();
+
+    fn run(self) -> Self::Returns {
+        let (env, step) = tx_to_transfer_step(self);
+        env.world.transfer_step(step);
+    }
+}
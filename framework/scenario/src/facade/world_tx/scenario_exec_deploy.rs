@@ -0,0 +1,33 @@
+use multiversx_sc::types::{
+    Code, DeployCall, Tx, TxFrom, TxGas, TxPaymentEgldOnly, TxRunnableResultHandler,
+};
+
+use crate::scenario_model::TxResponse;
+
+use super::{tx_to_sc_deploy_step, ScenarioEnvExec, ScenarioTxRun, TxCodeValue};
+
+impl<'w, From, Payment, Gas, CodeValue, RH> ScenarioTxRun
+    for Tx<
+        ScenarioEnvExec<'w>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<ScenarioEnvExec<'w>, Code<CodeValue>>,
+        RH,
+    >
+where
+    From: TxFrom<ScenarioEnvExec<'w>>,
+    Payment: TxPaymentEgldOnly<ScenarioEnvExec<'w>>,
+    Gas: TxGas<ScenarioEnvExec<'w>>,
+    CodeValue: TxCodeValue,
+    RH: TxRunnableResultHandler<TxResponse, ScenarioEnvExec<'w>>,
+{
+    type Returns = RH::Returns;
+
+    fn run(self) -> Self::Returns {
+        let (env, mut step, result_handler) = tx_to_sc_deploy_step(self);
+        env.world.sc_deploy(&mut step);
+        result_handler.process_result(step.response())
+    }
+}
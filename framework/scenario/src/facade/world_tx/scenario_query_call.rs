@@ -0,0 +1,20 @@
+use multiversx_sc::types::{FunctionCall, Tx, TxRunnableResultHandler, TxToSpecified};
+
+use crate::{api::StaticApi, scenario_model::TxResponse};
+
+use super::{tx_to_sc_query_step, ScenarioEnvQuery, ScenarioTxRun};
+
+impl<'w, To, RH> ScenarioTxRun
+    for Tx<ScenarioEnvQuery<'w>, (), To, (), (), FunctionCall<StaticApi>, RH>
+where
+    To: TxToSpecified<ScenarioEnvQuery<'w>>,
+    RH: TxRunnableResultHandler<TxResponse, ScenarioEnvQuery<'w>>,
+{
+    type Returns = RH::Returns;
+
+    fn run(self) -> Self::Returns {
+        let (env, mut step, result_handler) = tx_to_sc_query_step(self);
+        env.world.sc_query(&mut step);
+        result_handler.process_result(step.response())
+    }
+}
@@ -0,0 +1,88 @@
+use multiversx_sc::{
+    codec::{CodecFrom, PanicErrorHandler, TopDecodeMulti, TopEncodeMulti},
+    types::{
+        heap::Address, ManagedAddress, RHListItem, ReturnsNewManagedAddress, ReturnsRawResult,
+        ReturnsResult, ReturnsResultAs, TxEnv,
+    },
+};
+
+use crate::scenario_model::TxResponse;
+
+/// Returns the address of the newly deployed contract.
+pub struct ReturnsNewAddress;
+
+fn decode_out<T: TopDecodeMulti>(response: &TxResponse) -> T {
+    assert!(
+        response.tx_error.is_success(),
+        "cannot decode the result of a failed transaction: {}",
+        response.tx_error
+    );
+    let mut raw_result = response.out.clone();
+    let Ok(decoded) = T::multi_decode_or_handle_err(&mut raw_result, PanicErrorHandler);
+    decoded
+}
+
+fn new_deployed_address(response: &TxResponse) -> Address {
+    response
+        .new_deployed_address
+        .clone()
+        .expect("missing new address after deploy")
+}
+
+impl<Env, Original> RHListItem<TxResponse, Env, Original> for ReturnsResult
+where
+    Env: TxEnv,
+    Original: TopDecodeMulti,
+{
+    type Returns = Original;
+
+    fn item_process_result(self, raw_result: &TxResponse) -> Original {
+        decode_out(raw_result)
+    }
+}
+
+impl<Env, Original, T> RHListItem<TxResponse, Env, Original> for ReturnsResultAs<T>
+where
+    Env: TxEnv,
+    Original: TopEncodeMulti,
+    T: CodecFrom<Original>,
+{
+    type Returns = T;
+
+    fn item_process_result(self, raw_result: &TxResponse) -> T {
+        decode_out(raw_result)
+    }
+}
+
+impl<Env, Original> RHListItem<TxResponse, Env, Original> for ReturnsRawResult
+where
+    Env: TxEnv,
+{
+    type Returns = Vec<Vec<u8>>;
+
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        raw_result.out.clone()
+    }
+}
+
+impl<Env, Original> RHListItem<TxResponse, Env, Original> for ReturnsNewAddress
+where
+    Env: TxEnv,
+{
+    type Returns = Address;
+
+    fn item_process_result(self, raw_result: &TxResponse) -> Address {
+        new_deployed_address(raw_result)
+    }
+}
+
+impl<Env, Original> RHListItem<TxResponse, Env, Original> for ReturnsNewManagedAddress
+where
+    Env: TxEnv,
+{
+    type Returns = ManagedAddress<Env::Api>;
+
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        ManagedAddress::from(new_deployed_address(raw_result))
+    }
+}
@@ -0,0 +1,86 @@
+use multiversx_sc::{
+    contract_base::ProxyObjBase,
+    types::{ManagedAddress, TxFrom, TxFromSpecified, TxTo, TxToSpecified},
+};
+
+use crate::{api::StaticApi, facade::ContractInfo, scenario_model::AddressValue};
+
+use super::{ScenarioEnvExec, ScenarioEnvQuery, ScenarioTxEnv};
+
+impl<Env> TxFrom<Env> for AddressValue
+where
+    Env: ScenarioTxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<StaticApi> {
+        ManagedAddress::from(&self.value)
+    }
+}
+impl<Env> TxFromSpecified<Env> for AddressValue where Env: ScenarioTxEnv {}
+
+impl<Env> TxFrom<Env> for &AddressValue
+where
+    Env: ScenarioTxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<StaticApi> {
+        ManagedAddress::from(&self.value)
+    }
+}
+impl<Env> TxFromSpecified<Env> for &AddressValue where Env: ScenarioTxEnv {}
+
+impl<Env> TxTo<Env> for AddressValue where Env: ScenarioTxEnv {}
+impl<Env> TxToSpecified<Env> for AddressValue
+where
+    Env: ScenarioTxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<StaticApi> {
+        ManagedAddress::from(&self.value)
+    }
+}
+
+impl<Env> TxTo<Env> for &AddressValue where Env: ScenarioTxEnv {}
+impl<Env> TxToSpecified<Env> for &AddressValue
+where
+    Env: ScenarioTxEnv,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<StaticApi> {
+        ManagedAddress::from(&self.value)
+    }
+}
+
+impl<Env, P> TxTo<Env> for &ContractInfo<P>
+where
+    Env: ScenarioTxEnv,
+    P: ProxyObjBase,
+{
+}
+impl<Env, P> TxToSpecified<Env> for &ContractInfo<P>
+where
+    Env: ScenarioTxEnv,
+    P: ProxyObjBase,
+{
+    fn resolve_address(&self, _env: &Env) -> ManagedAddress<StaticApi> {
+        ManagedAddress::from(self.to_address())
+    }
+}
+
+/// Scenario address expressions, e.g. `"address:owner"` or `"sc:adder"`.
+macro_rules! impl_str_address {
+    ($env:ident) => {
+        impl<'w> TxFrom<$env<'w>> for &str {
+            fn resolve_address(&self, _env: &$env<'w>) -> ManagedAddress<StaticApi> {
+                ManagedAddress::from(&AddressValue::from(*self).value)
+            }
+        }
+        impl<'w> TxFromSpecified<$env<'w>> for &str {}
+
+        impl<'w> TxTo<$env<'w>> for &str {}
+        impl<'w> TxToSpecified<$env<'w>> for &str {
+            fn resolve_address(&self, _env: &$env<'w>) -> ManagedAddress<StaticApi> {
+                ManagedAddress::from(&AddressValue::from(*self).value)
+            }
+        }
+    };
+}
+
+impl_str_address! {ScenarioEnvExec}
+impl_str_address! {ScenarioEnvQuery}
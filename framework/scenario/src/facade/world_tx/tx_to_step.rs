@@ -0,0 +1,195 @@
+use multiversx_sc::types::{
+    Code, DeployCall, FunctionCall, ManagedAddress, ManagedArgBuffer, Tx, TxDataFunctionCall,
+    TxFrom, TxGas, TxPayment, TxPaymentEgldOnly, TxResultHandler, TxToSpecified,
+};
+
+use crate::{
+    api::StaticApi,
+    scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
+    scenario_model::{
+        AddressValue, BigUintValue, BytesValue, ScCallStep, ScDeployStep, ScQueryStep,
+        TransferStep, TxESDT, U64Value,
+    },
+};
+
+use super::ScenarioTxEnv;
+
+/// Contract code, as accepted by deploys in tests and interactors.
+pub trait TxCodeValue {
+    fn into_code_value(self, context: &InterpreterContext) -> BytesValue;
+}
+
+/// Code expressions, e.g. `"mxsc:output/adder.mxsc.json"`, are interpreted relative to the current directory.
+impl TxCodeValue for &str {
+    fn into_code_value(self, context: &InterpreterContext) -> BytesValue {
+        BytesValue::interpret_from(self, context)
+    }
+}
+
+impl TxCodeValue for String {
+    fn into_code_value(self, context: &InterpreterContext) -> BytesValue {
+        BytesValue::interpret_from(self, context)
+    }
+}
+
+impl TxCodeValue for BytesValue {
+    fn into_code_value(self, _context: &InterpreterContext) -> BytesValue {
+        self
+    }
+}
+
+impl TxCodeValue for &BytesValue {
+    fn into_code_value(self, _context: &InterpreterContext) -> BytesValue {
+        self.clone()
+    }
+}
+
+fn address_value(address: &ManagedAddress<StaticApi>) -> AddressValue {
+    AddressValue::from(&address.to_address())
+}
+
+fn function_name_string(function_call: &FunctionCall<StaticApi>) -> String {
+    String::from_utf8(function_call.function_name.to_boxed_bytes().into_vec())
+        .expect("function name is not valid UTF-8")
+}
+
+fn argument_values(arg_buffer: &ManagedArgBuffer<StaticApi>) -> Vec<BytesValue> {
+    arg_buffer
+        .to_raw_args_vec()
+        .into_iter()
+        .map(BytesValue::from)
+        .collect()
+}
+
+fn esdt_values<Env, Payment>(payment: Payment) -> (BigUintValue, Vec<TxESDT>)
+where
+    Env: ScenarioTxEnv,
+    Payment: TxPayment<Env>,
+{
+    let full_payment = payment.into_full_payment_data();
+    let egld_value = BigUintValue::from(full_payment.egld_value());
+    let esdt_values = full_payment
+        .multi_esdt
+        .iter()
+        .map(|esdt| TxESDT {
+            esdt_token_identifier: BytesValue::from(
+                esdt.token_identifier
+                    .as_managed_buffer()
+                    .to_boxed_bytes()
+                    .into_vec(),
+            ),
+            nonce: U64Value::from(esdt.token_nonce),
+            esdt_value: BigUintValue::from(esdt.amount.clone()),
+        })
+        .collect();
+    (egld_value, esdt_values)
+}
+
+/// Converts a call to a scenario step. The environment and the result handler are given back, for running the step.
+pub fn tx_to_sc_call_step<Env, From, To, Payment, Gas, Data, RH>(
+    tx: Tx<Env, From, To, Payment, Gas, Data, RH>,
+) -> (Env, ScCallStep, RH)
+where
+    Env: ScenarioTxEnv,
+    From: TxFrom<Env>,
+    To: TxToSpecified<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxDataFunctionCall<Env>,
+    RH: TxResultHandler<Env>,
+{
+    let mut step = ScCallStep::new();
+    step.tx.from = address_value(&tx.from.resolve_address(&tx.env));
+    step.tx.to = address_value(&tx.to.resolve_address(&tx.env));
+    step.tx.gas_limit = U64Value::from(tx.gas.resolve_gas(&tx.env));
+    let (egld_value, esdt_values) = esdt_values::<Env, Payment>(tx.payment);
+    step.tx.egld_value = egld_value;
+    step.tx.esdt_value = esdt_values;
+
+    let function_call: FunctionCall<StaticApi> = tx.data.into();
+    step.tx.function = function_name_string(&function_call);
+    step.tx.arguments = argument_values(&function_call.arg_buffer);
+
+    let env_data = tx.env.env_data();
+    step.tx_id = env_data.tx_id.clone();
+    step.expect = env_data.expect.clone();
+    (tx.env, step, tx.result_handler)
+}
+
+/// Converts a deploy to a scenario step. The environment and the result handler are given back, for running the step.
+pub fn tx_to_sc_deploy_step<Env, From, Payment, Gas, CodeValue, RH>(
+    tx: Tx<Env, From, (), Payment, Gas, DeployCall<Env, Code<CodeValue>>, RH>,
+) -> (Env, ScDeployStep, RH)
+where
+    Env: ScenarioTxEnv,
+    From: TxFrom<Env>,
+    Payment: TxPaymentEgldOnly<Env>,
+    Gas: TxGas<Env>,
+    CodeValue: TxCodeValue,
+    RH: TxResultHandler<Env>,
+{
+    let mut step = ScDeployStep::new();
+    step.tx.from = address_value(&tx.from.resolve_address(&tx.env));
+    step.tx.gas_limit = U64Value::from(tx.gas.resolve_gas(&tx.env));
+    step.tx.egld_value = BigUintValue::from(tx.payment.into_full_payment_data().egld_value());
+
+    let env_data = tx.env.env_data();
+    step.tx.contract_code = tx
+        .data
+        .code_source
+        .0
+        .into_code_value(&env_data.interpreter_context);
+    step.tx.code_metadata = tx.data.code_metadata;
+    step.tx.arguments = argument_values(&tx.data.arg_buffer);
+
+    step.tx_id = env_data.tx_id.clone();
+    step.expect = env_data.expect.clone();
+    (tx.env, step, tx.result_handler)
+}
+
+/// Converts a query to a scenario step. The environment and the result handler are given back, for running the step.
+pub fn tx_to_sc_query_step<Env, To, Data, RH>(
+    tx: Tx<Env, (), To, (), (), Data, RH>,
+) -> (Env, ScQueryStep, RH)
+where
+    Env: ScenarioTxEnv,
+    To: TxToSpecified<Env>,
+    Data: TxDataFunctionCall<Env>,
+    RH: TxResultHandler<Env>,
+{
+    let mut step = ScQueryStep::new();
+    step.tx.to = address_value(&tx.to.resolve_address(&tx.env));
+
+    let function_call: FunctionCall<StaticApi> = tx.data.into();
+    step.tx.function = function_name_string(&function_call);
+    step.tx.arguments = argument_values(&function_call.arg_buffer);
+
+    let env_data = tx.env.env_data();
+    step.tx_id = env_data.tx_id.clone();
+    step.expect = env_data.expect.clone();
+    (tx.env, step, tx.result_handler)
+}
+
+/// Converts a simple transfer, without data, to a scenario step.
+pub fn tx_to_transfer_step<Env, From, To, Payment, Gas>(
+    tx: Tx<Env, From, To, Payment, Gas, (), ()>,
+) -> (Env, TransferStep)
+where
+    Env: ScenarioTxEnv,
+    From: TxFrom<Env>,
+    To: TxToSpecified<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+{
+    let mut step = TransferStep::new();
+    step.tx.from = address_value(&tx.from.resolve_address(&tx.env));
+    step.tx.to = address_value(&tx.to.resolve_address(&tx.env));
+    if tx.gas.is_explicit() {
+        step.tx.gas_limit = U64Value::from(tx.gas.resolve_gas(&tx.env));
+    }
+    let (egld_value, esdt_values) = esdt_values::<Env, Payment>(tx.payment);
+    step.tx.egld_value = egld_value;
+    step.tx.esdt_value = esdt_values;
+    step.tx_id = tx.env.env_data().tx_id.clone();
+    (tx.env, step)
+}
@@ -3,6 +3,7 @@
 
 pub mod api;
 pub mod bech32;
+pub mod benchmark;
 pub mod debug_executor;
 pub mod display_util;
 mod facade;
@@ -44,7 +45,7 @@ pub use crate::scenario as mandos_system;
 // Re-exporting the whole mandos crate for easier use in tests.
 pub use multiversx_chain_scenario_format as scenario_format;
 
-pub use facade::{ContractInfo, ScenarioWorld, WhiteboxContract};
+pub use facade::{world_tx, ContractInfo, ScenarioWorld, WhiteboxContract};
 
 use std::path::Path;
 
@@ -1,6 +1,7 @@
 use crate::{
     scenario::model::{
-        AddressValue, BigUintValue, BytesKey, BytesValue, Esdt, EsdtObject, U64Value,
+        AddressValue, BigUintValue, BytesKey, BytesValue, Esdt, EsdtObject, Guardian, Guardians,
+        U64Value,
     },
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
@@ -21,6 +22,7 @@ pub struct Account {
     pub code: Option<BytesValue>,
     pub owner: Option<AddressValue>,
     pub developer_rewards: Option<BigUintValue>,
+    pub guardians: Option<Guardians>,
 }
 
 impl Account {
@@ -176,6 +178,23 @@ impl Account {
         self.owner = Some(AddressValue::from(owner_expr));
         self
     }
+
+    pub fn active_guardian(mut self, guardian: Guardian) -> Self {
+        self.guardians.get_or_insert_with(Default::default).active = Some(guardian);
+        self
+    }
+
+    pub fn pending_guardian(mut self, guardian: Guardian) -> Self {
+        self.guardians.get_or_insert_with(Default::default).pending = Some(guardian);
+        self
+    }
+
+    /// Guarded (frozen) accounts need all their transactions co-signed by the active guardian.
+    pub fn guarded(mut self, guarded: bool) -> Self {
+        self.guardians.get_or_insert_with(Default::default).guarded =
+            Some(U64Value::from(guarded as u64));
+        self
+    }
 }
 
 impl InterpretableFrom<AccountRaw> for Account {
@@ -214,6 +233,9 @@ impl InterpretableFrom<AccountRaw> for Account {
             developer_rewards: from
                 .developer_rewards
                 .map(|b| BigUintValue::interpret_from(b, context)),
+            guardians: from
+                .guardians
+                .map(|g| Guardians::interpret_from(g, context)),
         }
     }
 }
@@ -238,6 +260,7 @@ impl IntoRaw<AccountRaw> for Account {
             code: self.code.map(|n| n.original),
             owner: self.owner.map(|n| n.original),
             developer_rewards: self.developer_rewards.map(|n| n.original),
+            guardians: self.guardians.map(|g| g.into_raw()),
         }
     }
 }
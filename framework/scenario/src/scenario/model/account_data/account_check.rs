@@ -3,7 +3,8 @@ use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode};
 use crate::{
     scenario::model::{
         BigUintValue, BytesKey, BytesValue, CheckEsdt, CheckEsdtInstances, CheckEsdtMap,
-        CheckEsdtMapContents, CheckStorage, CheckStorageDetails, CheckValue, U64Value,
+        CheckEsdtMapContents, CheckGuardians, CheckStorage, CheckStorageDetails, CheckValue,
+        U64Value,
     },
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
@@ -25,6 +26,7 @@ pub struct CheckAccount {
     pub owner: CheckValue<BytesValue>, // WARNING! Not currently checked. TODO: implement check
     pub developer_rewards: CheckValue<BigUintValue>,
     pub async_call_data: CheckValue<BytesValue>,
+    pub guardians: CheckGuardians,
 }
 
 impl CheckAccount {
@@ -184,6 +186,7 @@ impl InterpretableFrom<Box<CheckAccountRaw>> for CheckAccount {
                 from.async_call_data,
                 context,
             ),
+            guardians: CheckGuardians::interpret_from(from.guardians, context),
         }
     }
 }
@@ -201,6 +204,7 @@ impl IntoRaw<CheckAccountRaw> for CheckAccount {
             owner: self.owner.into_raw_explicit(), // TODO: convert back to into_raw after VM CI upgrade
             developer_rewards: self.developer_rewards.into_raw(),
             async_call_data: self.async_call_data.into_raw(),
+            guardians: self.guardians.into_raw(),
         }
     }
 }
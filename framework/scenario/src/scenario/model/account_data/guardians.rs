@@ -0,0 +1,94 @@
+use crate::{
+    scenario::model::{AddressValue, BytesValue, U64Value},
+    scenario_format::{
+        interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
+        serde_raw::{GuardianRaw, GuardiansRaw},
+    },
+};
+
+/// The guardians of a user account, and whether it is guarded (frozen).
+#[derive(Debug, Default, Clone)]
+pub struct Guardians {
+    pub active: Option<Guardian>,
+    pub pending: Option<Guardian>,
+    pub guarded: Option<U64Value>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Guardian {
+    pub address: AddressValue,
+    pub activation_epoch: Option<U64Value>,
+    pub service_uid: Option<BytesValue>,
+}
+
+impl Guardian {
+    pub fn new<A>(address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        Guardian {
+            address: AddressValue::from(address),
+            ..Default::default()
+        }
+    }
+
+    pub fn activation_epoch<V>(mut self, epoch: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.activation_epoch = Some(U64Value::from(epoch));
+        self
+    }
+
+    pub fn service_uid<V>(mut self, service_uid: V) -> Self
+    where
+        BytesValue: From<V>,
+    {
+        self.service_uid = Some(BytesValue::from(service_uid));
+        self
+    }
+}
+
+impl InterpretableFrom<GuardiansRaw> for Guardians {
+    fn interpret_from(from: GuardiansRaw, context: &InterpreterContext) -> Self {
+        Guardians {
+            active: from.active.map(|g| Guardian::interpret_from(g, context)),
+            pending: from.pending.map(|g| Guardian::interpret_from(g, context)),
+            guarded: from.guarded.map(|g| U64Value::interpret_from(g, context)),
+        }
+    }
+}
+
+impl IntoRaw<GuardiansRaw> for Guardians {
+    fn into_raw(self) -> GuardiansRaw {
+        GuardiansRaw {
+            active: self.active.map(|g| g.into_raw()),
+            pending: self.pending.map(|g| g.into_raw()),
+            guarded: self.guarded.map(|g| g.original),
+        }
+    }
+}
+
+impl InterpretableFrom<GuardianRaw> for Guardian {
+    fn interpret_from(from: GuardianRaw, context: &InterpreterContext) -> Self {
+        Guardian {
+            address: AddressValue::interpret_from(from.address, context),
+            activation_epoch: from
+                .activation_epoch
+                .map(|e| U64Value::interpret_from(e, context)),
+            service_uid: from
+                .service_uid
+                .map(|s| BytesValue::interpret_from(s, context)),
+        }
+    }
+}
+
+impl IntoRaw<GuardianRaw> for Guardian {
+    fn into_raw(self) -> GuardianRaw {
+        GuardianRaw {
+            address: self.address.into_raw(),
+            activation_epoch: self.activation_epoch.map(|e| e.original),
+            service_uid: self.service_uid.map(|s| s.original),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    scenario::model::{BytesValue, CheckValue, U64Value},
+    scenario_format::{
+        interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
+        serde_raw::{CheckGuardianRaw, CheckGuardiansRaw},
+    },
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckGuardians {
+    pub active: Option<CheckGuardian>,
+    pub pending: Option<CheckGuardian>,
+    pub guarded: CheckValue<U64Value>,
+}
+
+/// An empty address checks that there is no such guardian.
+#[derive(Debug, Default, Clone)]
+pub struct CheckGuardian {
+    pub address: CheckValue<BytesValue>,
+    pub activation_epoch: CheckValue<U64Value>,
+    pub service_uid: CheckValue<BytesValue>,
+}
+
+impl InterpretableFrom<CheckGuardiansRaw> for CheckGuardians {
+    fn interpret_from(from: CheckGuardiansRaw, context: &InterpreterContext) -> Self {
+        CheckGuardians {
+            active: from
+                .active
+                .map(|g| CheckGuardian::interpret_from(g, context)),
+            pending: from
+                .pending
+                .map(|g| CheckGuardian::interpret_from(g, context)),
+            guarded: CheckValue::<U64Value>::interpret_from(from.guarded, context),
+        }
+    }
+}
+
+impl IntoRaw<CheckGuardiansRaw> for CheckGuardians {
+    fn into_raw(self) -> CheckGuardiansRaw {
+        CheckGuardiansRaw {
+            active: self.active.map(|g| g.into_raw()),
+            pending: self.pending.map(|g| g.into_raw()),
+            guarded: self.guarded.into_raw(),
+        }
+    }
+}
+
+impl InterpretableFrom<CheckGuardianRaw> for CheckGuardian {
+    fn interpret_from(from: CheckGuardianRaw, context: &InterpreterContext) -> Self {
+        CheckGuardian {
+            address: CheckValue::<BytesValue>::interpret_from(from.address, context),
+            activation_epoch: CheckValue::<U64Value>::interpret_from(
+                from.activation_epoch,
+                context,
+            ),
+            service_uid: CheckValue::<BytesValue>::interpret_from(from.service_uid, context),
+        }
+    }
+}
+
+impl IntoRaw<CheckGuardianRaw> for CheckGuardian {
+    fn into_raw(self) -> CheckGuardianRaw {
+        CheckGuardianRaw {
+            address: self.address.into_raw(),
+            activation_epoch: self.activation_epoch.into_raw(),
+            service_uid: self.service_uid.into_raw(),
+        }
+    }
+}
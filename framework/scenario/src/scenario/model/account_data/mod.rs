@@ -1,7 +1,11 @@
 mod account;
 mod account_check;
 mod accounts_check;
+mod guardians;
+mod guardians_check;
 
 pub use account::*;
 pub use account_check::*;
 pub use accounts_check::*;
+pub use guardians::*;
+pub use guardians_check::*;
@@ -132,6 +132,15 @@ impl ScCallStep {
         self
     }
 
+    /// Marks the transaction as co-signed by the given guardian of the sender.
+    pub fn guardian<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.guardian = Some(AddressValue::from(address));
+        self
+    }
+
     /// Sets following fields based on the smart contract proxy:
     /// - "to"
     /// - "function"
@@ -79,4 +79,13 @@ impl TransferStep {
         self.tx.gas_limit = U64Value::from(value);
         self
     }
+
+    /// Marks the transaction as co-signed by the given guardian of the sender.
+    pub fn guardian<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.guardian = Some(AddressValue::from(address));
+        self
+    }
 }
@@ -18,6 +18,7 @@ pub struct TxDeploy {
     pub arguments: Vec<BytesValue>,
     pub gas_limit: U64Value,
     pub gas_price: U64Value,
+    pub guardian: Option<AddressValue>,
 }
 
 impl Default for TxDeploy {
@@ -30,6 +31,7 @@ impl Default for TxDeploy {
             arguments: Default::default(),
             gas_limit: U64Value::from(DEFAULT_GAS_EXPR),
             gas_price: Default::default(),
+            guardian: None,
         }
     }
 }
@@ -39,7 +41,7 @@ impl InterpretableFrom<TxDeployRaw> for TxDeploy {
         TxDeploy {
             from: AddressValue::interpret_from(from.from, context),
             egld_value: interpret_egld_value(from.value, from.egld_value, context),
-            code_metadata: CodeMetadata::empty(), // not yet modelled in scenarios
+            code_metadata: CodeMetadata::all(), // not yet modelled in scenarios, most permissive
             contract_code: BytesValue::interpret_from(from.contract_code, context),
             arguments: from
                 .arguments
@@ -48,6 +50,9 @@ impl InterpretableFrom<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: U64Value::interpret_from(from.gas_limit, context),
             gas_price: U64Value::interpret_from(from.gas_price.unwrap_or_default(), context),
+            guardian: from
+                .guardian
+                .map(|guardian| AddressValue::interpret_from(guardian, context)),
         }
     }
 }
@@ -66,6 +71,7 @@ impl IntoRaw<TxDeployRaw> for TxDeploy {
                 .collect(),
             gas_limit: self.gas_limit.into_raw(),
             gas_price: self.gas_price.into_raw_opt(),
+            guardian: self.guardian.map(|guardian| guardian.into_raw()),
         }
     }
 }
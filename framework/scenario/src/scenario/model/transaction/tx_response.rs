@@ -1,17 +1,14 @@
 use crate::multiversx_sc::types::Address;
 use multiversx_chain_vm::tx_mock::TxResult;
-use multiversx_sdk::data::transaction::{
-    ApiLogs, ApiSmartContractResult, Events, TransactionOnNetwork,
+use multiversx_sdk::{
+    data::transaction::{
+        ApiLogs, ApiSmartContractResult, TransactionOnNetwork, TxSimulationResult,
+    },
+    tx_outcome::TxOutcome,
 };
 
-use super::{
-    decode_scr_data_or_panic, is_out_scr, process_topics_error, Log, TxExpect, TxResponseStatus,
-};
-
-const LOG_IDENTIFIER_SC_DEPLOY: &str = "SCDeploy";
-const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
-
-const SYSTEM_SC_BECH32: &str = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+use super::{Log, TxExpect, TxResponseStatus};
+use crate::scenario_model::BytesValue;
 
 #[derive(Debug, Default, Clone)]
 /// The response of a transaction.
@@ -45,24 +42,67 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            logs: tx_result
+                .result_logs
+                .iter()
+                .map(|tx_log| Log {
+                    address: BytesValue::from(tx_log.address.as_bytes()),
+                    endpoint: BytesValue::from(format!("str:{}", tx_log.endpoint.as_str())),
+                    topics: tx_log
+                        .topics
+                        .iter()
+                        .map(|topic| BytesValue::from(topic.as_slice()))
+                        .collect(),
+                    data: BytesValue::from(tx_log.data.concat()),
+                })
+                .collect(),
             ..Default::default()
         }
     }
 
     /// Creates a [`TxResponse`] from a [`TransactionOnNetwork`].
+    ///
+    /// A transaction with a failed status is always reported as an error, even without an error event:
+    /// the status itself becomes the error message. Previously such transactions were reported as successful.
     pub fn from_network_tx(tx: TransactionOnNetwork) -> Self {
-        let mut response = Self {
-            api_scrs: tx.smart_contract_results.unwrap_or_default(),
-            api_logs: tx.logs,
-            ..Default::default()
-        };
+        let outcome = TxOutcome::from_network_tx(&tx);
+        Self::from_outcome(
+            outcome,
+            tx.smart_contract_results.unwrap_or_default(),
+            tx.logs,
+        )
+    }
 
-        response.tx_error = response.process_signal_error();
-        if !response.tx_error.is_success() {
-            return response;
-        }
+    /// Creates a [`TxResponse`] from a transaction simulated by the network.
+    pub fn from_simulation_result(result: TxSimulationResult) -> Self {
+        let outcome = TxOutcome::from_simulation_result(&result);
+        Self::from_outcome(
+            outcome,
+            result.sc_results.into_values().collect(),
+            result.logs,
+        )
+    }
 
-        response.process()
+    fn from_outcome(
+        outcome: TxOutcome,
+        api_scrs: Vec<ApiSmartContractResult>,
+        api_logs: Option<ApiLogs>,
+    ) -> Self {
+        let tx_error = match &outcome.error {
+            Some(error) => TxResponseStatus::signal_error(&error.message),
+            None => TxResponseStatus::default(),
+        };
+        TxResponse {
+            out: outcome.return_data,
+            new_deployed_address: outcome
+                .deployed_address
+                .map(|address| Address::from(address.to_bytes())),
+            new_issued_token_identifier: outcome.issued_token_identifier,
+            tx_error,
+            api_scrs,
+            api_logs,
+            ..Default::default()
+        }
     }
 
     /// Creates a [`TxResponse`] from raw results.
@@ -100,107 +140,6 @@ impl TxResponse {
     pub fn is_success(&self) -> bool {
         self.tx_error.is_success()
     }
-
-    fn process_signal_error(&self) -> TxResponseStatus {
-        if let Some(event) = self.find_log(LOG_IDENTIFIER_SIGNAL_ERROR) {
-            let topics = event.topics.as_ref();
-            if let Some(error) = process_topics_error(topics) {
-                return TxResponseStatus::signal_error(&error);
-            }
-
-            let error_raw = base64::decode(topics.unwrap().get(1).unwrap()).unwrap();
-            let error = String::from_utf8(error_raw).unwrap();
-            return TxResponseStatus::signal_error(&error);
-        }
-
-        TxResponseStatus::default()
-    }
-
-    fn process(self) -> Self {
-        self.process_out()
-            .process_new_deployed_address()
-            .process_new_issued_token_identifier()
-    }
-
-    fn process_out(mut self) -> Self {
-        let out_scr = self.api_scrs.iter().find(is_out_scr);
-
-        if let Some(out_scr) = out_scr {
-            self.out = decode_scr_data_or_panic(&out_scr.data);
-        } else if let Some(data) = self.process_out_from_log() {
-            self.out = data
-        }
-
-        self
-    }
-
-    fn process_out_from_log(&self) -> Option<Vec<Vec<u8>>> {
-        if let Some(logs) = &self.api_logs {
-            logs.events.iter().rev().find_map(|event| {
-                if event.identifier == "writeLog" {
-                    if let Some(data) = &event.data {
-                        let decoded_data =
-                            String::from_utf8(base64::decode(data).unwrap()).unwrap();
-                        if decoded_data.starts_with('@') {
-                            let out = decode_scr_data_or_panic(decoded_data.as_str());
-                            return Some(out);
-                        }
-                    }
-                }
-
-                None
-            })
-        } else {
-            None
-        }
-    }
-
-    fn process_new_deployed_address(mut self) -> Self {
-        if let Some(event) = self.find_log(LOG_IDENTIFIER_SC_DEPLOY).cloned() {
-            let topics = event.topics.as_ref();
-            if process_topics_error(topics).is_some() {
-                return self;
-            }
-
-            let address_raw = base64::decode(topics.unwrap().get(0).unwrap()).unwrap();
-            let address: Address = Address::from_slice(address_raw.as_slice());
-            self.new_deployed_address = Some(address);
-        }
-
-        self
-    }
-
-    fn process_new_issued_token_identifier(mut self) -> Self {
-        let token_identifier_issue_scr: Option<&ApiSmartContractResult> = self
-            .api_scrs
-            .iter()
-            .find(|scr| scr.sender.to_string() == SYSTEM_SC_BECH32 && scr.data.starts_with("@00@"));
-
-        if token_identifier_issue_scr.is_none() {
-            return self;
-        }
-
-        let token_identifier_issue_scr = token_identifier_issue_scr.unwrap();
-        let encoded_tid = token_identifier_issue_scr.data.split('@').nth(2);
-        if encoded_tid.is_none() {
-            return self;
-        }
-
-        self.new_issued_token_identifier =
-            Some(String::from_utf8(hex::decode(encoded_tid.unwrap()).unwrap()).unwrap());
-
-        self
-    }
-
-    fn find_log(&self, log_identifier: &str) -> Option<&Events> {
-        if let Some(logs) = &self.api_logs {
-            logs.events
-                .iter()
-                .find(|event| event.identifier == log_identifier)
-        } else {
-            None
-        }
-    }
 }
 
 #[cfg(test)]
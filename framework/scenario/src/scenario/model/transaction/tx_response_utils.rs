@@ -1,6 +1,10 @@
-use multiversx_sdk::data::transaction::ApiSmartContractResult;
+use multiversx_sdk::data::transaction::{ApiSmartContractResult, ScrData};
 
 /// Checks for invalid topics.
+#[deprecated(
+    since = "0.45.2",
+    note = "No longer used, error events are decoded by `multiversx_sdk::tx_outcome::TxOutcome`."
+)]
 pub fn process_topics_error(topics: Option<&Vec<String>>) -> Option<String> {
     if topics.is_none() {
         return Some("missing topics".to_string());
@@ -18,18 +22,23 @@ pub fn process_topics_error(topics: Option<&Vec<String>>) -> Option<String> {
 }
 
 /// Decodes the data of a smart contract result.
+#[deprecated(
+    since = "0.45.2",
+    note = "No longer used, use `ScrData::parse`, which reports errors instead of panicking."
+)]
 pub fn decode_scr_data_or_panic(data: &str) -> Vec<Vec<u8>> {
-    let mut split = data.split('@');
-    let _ = split.next().expect("SCR data should start with '@'");
-    let result_code = split.next().expect("missing result code");
-    assert_eq!(result_code, "6f6b", "result code is not 'ok'");
+    let scr_data = ScrData::parse(data).expect("error hex-decoding result");
+    let result_code = scr_data.return_code().expect("missing result code");
+    assert_eq!(result_code, "ok", "result code is not 'ok'");
 
-    split
-        .map(|encoded_arg| hex::decode(encoded_arg).expect("error hex-decoding result"))
-        .collect()
+    scr_data.return_values().to_vec()
 }
 
 /// Checks if the given smart contract result is an out smart contract result.
+#[deprecated(
+    since = "0.45.2",
+    note = "No longer used, results are decoded by `multiversx_sdk::tx_outcome::TxOutcome`."
+)]
 pub fn is_out_scr(scr: &&ApiSmartContractResult) -> bool {
     scr.nonce != 0 && scr.data.starts_with('@')
 }
@@ -1,12 +1,16 @@
 use crate::scenario::model::{
     AddressKey, BytesValue, CheckAccounts, CheckEsdt, CheckEsdtData, CheckEsdtInstance,
-    CheckEsdtInstances, CheckEsdtMap, CheckStateStep, CheckStorage, CheckValue, Checkable,
+    CheckEsdtInstances, CheckEsdtMap, CheckGuardian, CheckGuardians, CheckStateStep, CheckStorage,
+    CheckValue, Checkable,
 };
 use num_traits::Zero;
 
 use multiversx_chain_vm::{
     display_util::{bytes_to_string, verbose_hex, verbose_hex_list},
-    world_mock::{AccountEsdt, BlockchainState, EsdtData, EsdtInstance, EsdtInstances},
+    world_mock::{
+        AccountEsdt, AccountGuardians, BlockchainState, EsdtData, EsdtInstance, EsdtInstances,
+        Guardian,
+    },
 };
 
 use super::ScenarioVMRunner;
@@ -106,6 +110,11 @@ fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
                 }
             }
             check_account_esdt(expected_address, &expected_account.esdt, &account.esdt);
+            check_account_guardians(
+                expected_address,
+                &expected_account.guardians,
+                &account.guardians,
+            );
         } else {
             assert!(
                 accounts.other_accounts_allowed,
@@ -115,6 +124,74 @@ fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
     }
 }
 
+fn check_account_guardians(
+    address: &AddressKey,
+    expected: &CheckGuardians,
+    actual: &AccountGuardians,
+) {
+    assert!(
+        expected.guarded.check(actual.guarded as u64),
+        "bad account guarded flag. Address: {}. Want: {}. Have: {}",
+        address,
+        expected.guarded,
+        actual.guarded
+    );
+    if let Some(expected_active) = &expected.active {
+        check_guardian(address, "active", expected_active, actual.active.as_ref());
+    }
+    if let Some(expected_pending) = &expected.pending {
+        check_guardian(
+            address,
+            "pending",
+            expected_pending,
+            actual.pending.as_ref(),
+        );
+    }
+}
+
+fn check_guardian(
+    address: &AddressKey,
+    kind: &str,
+    expected: &CheckGuardian,
+    actual: Option<&Guardian>,
+) {
+    let actual_address = actual
+        .map(|guardian| guardian.address.as_bytes())
+        .unwrap_or_default();
+    assert!(
+        expected.address.check(actual_address),
+        "bad {} guardian address. Address: {}. Want: {}. Have: {}",
+        kind,
+        address,
+        expected.address,
+        verbose_hex(actual_address)
+    );
+
+    let actual_activation_epoch = actual
+        .map(|guardian| guardian.activation_epoch)
+        .unwrap_or_default();
+    assert!(
+        expected.activation_epoch.check(actual_activation_epoch),
+        "bad {} guardian activation epoch. Address: {}. Want: {}. Have: {}",
+        kind,
+        address,
+        expected.activation_epoch,
+        actual_activation_epoch
+    );
+
+    let actual_service_uid = actual
+        .map(|guardian| guardian.service_uid.as_slice())
+        .unwrap_or_default();
+    assert!(
+        expected.service_uid.check(actual_service_uid),
+        "bad {} guardian service UID. Address: {}. Want: {}. Have: {}",
+        kind,
+        address,
+        expected.service_uid,
+        verbose_hex(actual_service_uid)
+    );
+}
+
 pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual: &AccountEsdt) {
     match expected {
         CheckEsdtMap::Star => {},
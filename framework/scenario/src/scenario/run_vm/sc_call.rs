@@ -5,7 +5,7 @@ use crate::{
 };
 
 use multiversx_chain_vm::{
-    tx_execution::execute_current_tx_context_input,
+    tx_execution::{check_guarded_tx, execute_current_tx_context_input},
     tx_mock::{TxInput, TxResult, TxTokenTransfer},
 };
 
@@ -48,6 +48,9 @@ impl ScenarioVMRunner {
         F: FnOnce(),
     {
         let tx_input = tx_input_from_call(sc_call_step);
+        if let Err(err) = check_guarded_tx(&tx_input, &self.blockchain_mock.state) {
+            return TxResult::from_panic_obj(&err);
+        }
 
         // nonce gets increased irrespective of whether the tx fails or not
         self.blockchain_mock
@@ -93,6 +96,10 @@ fn tx_input_from_call(sc_call_step: &ScCallStep) -> TxInput {
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash(&sc_call_step.id, &sc_call_step.explicit_tx_hash),
+        guardian: tx
+            .guardian
+            .as_ref()
+            .map(|guardian| guardian.to_vm_address()),
         ..Default::default()
     }
 }
@@ -3,8 +3,9 @@ use crate::{
 };
 
 use multiversx_chain_vm::{
-    tx_execution::execute_current_tx_context_input,
+    tx_execution::{check_guarded_tx, execute_current_tx_context_input},
     tx_mock::{TxFunctionName, TxInput, TxResult},
+    types::VMCodeMetadata,
 };
 
 use super::{check_tx_output, tx_input_util::generate_tx_hash, ScenarioVMRunner};
@@ -30,10 +31,16 @@ impl ScenarioVMRunner {
         F: FnOnce(),
     {
         let tx_input = tx_input_from_deploy(sc_deploy_step);
+        if let Err(err) = check_guarded_tx(&tx_input, &self.blockchain_mock.state) {
+            return (Address::zero(), TxResult::from_panic_obj(&err));
+        }
+
         let contract_code = &sc_deploy_step.tx.contract_code.value;
+        let code_metadata = VMCodeMetadata::from(sc_deploy_step.tx.code_metadata.to_byte_array());
         let (new_address, tx_result) = self.blockchain_mock.vm.sc_create(
             tx_input,
             contract_code,
+            code_metadata,
             &mut self.blockchain_mock.state,
             f,
         );
@@ -76,6 +83,10 @@ fn tx_input_from_deploy(sc_deploy_step: &ScDeployStep) -> TxInput {
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash(&sc_deploy_step.id, &sc_deploy_step.explicit_tx_hash),
+        guardian: tx
+            .guardian
+            .as_ref()
+            .map(|guardian| guardian.to_vm_address()),
         ..Default::default()
     }
 }
@@ -1,10 +1,10 @@
-use crate::scenario::model::SetStateStep;
+use crate::scenario::model::{Guardian as ScenarioGuardian, Guardians, SetStateStep};
 
 use multiversx_chain_vm::{
-    types::VMAddress,
+    types::{VMAddress, VMCodeMetadata},
     world_mock::{
-        AccountData, AccountEsdt, BlockInfo as CrateBlockInfo, BlockchainState, EsdtData,
-        EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles,
+        AccountData, AccountEsdt, AccountGuardians, BlockInfo as CrateBlockInfo, BlockchainState,
+        EsdtData, EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles, Guardian,
     },
 };
 
@@ -58,11 +58,22 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
                 .owner
                 .as_ref()
                 .map(|address_value| address_value.to_vm_address()),
+            // not yet modelled in scenarios, contracts get all flags
+            code_metadata: if account.code.is_some() {
+                VMCodeMetadata::all()
+            } else {
+                VMCodeMetadata::DEFAULT
+            },
             developer_rewards: account
                 .developer_rewards
                 .as_ref()
                 .map(|rewards| rewards.value.clone())
                 .unwrap_or_default(),
+            guardians: account
+                .guardians
+                .as_ref()
+                .map(convert_scenario_guardians_to_world_mock)
+                .unwrap_or_default(),
         });
     }
     for new_address in set_state_step.new_addresses.iter() {
@@ -198,3 +209,37 @@ fn update_block_info(
         block_info.block_random_seed = Box::from(seed);
     }
 }
+
+fn convert_scenario_guardians_to_world_mock(guardians: &Guardians) -> AccountGuardians {
+    AccountGuardians {
+        active: guardians
+            .active
+            .as_ref()
+            .map(convert_scenario_guardian_to_world_mock),
+        pending: guardians
+            .pending
+            .as_ref()
+            .map(convert_scenario_guardian_to_world_mock),
+        guarded: guardians
+            .guarded
+            .as_ref()
+            .map(|guarded| guarded.value != 0)
+            .unwrap_or_default(),
+    }
+}
+
+fn convert_scenario_guardian_to_world_mock(guardian: &ScenarioGuardian) -> Guardian {
+    Guardian {
+        address: guardian.address.to_vm_address(),
+        activation_epoch: guardian
+            .activation_epoch
+            .as_ref()
+            .map(|epoch| epoch.value)
+            .unwrap_or_default(),
+        service_uid: guardian
+            .service_uid
+            .as_ref()
+            .map(|service_uid| service_uid.value.clone())
+            .unwrap_or_default(),
+    }
+}
@@ -1,8 +1,8 @@
 use super::{sc_call::tx_esdt_transfers_from_scenario, ScenarioVMRunner};
 use crate::scenario::model::{TransferStep, TxTransfer, ValidatorRewardStep};
 use multiversx_chain_vm::{
-    tx_execution::{execute_current_tx_context_input, BlockchainVMRef},
-    tx_mock::{TxFunctionName, TxInput},
+    tx_execution::{check_guarded_tx, execute_current_tx_context_input, BlockchainVMRef},
+    tx_mock::{TxFunctionName, TxInput, TxResult},
     with_shared::Shareable,
     world_mock::BlockchainState,
 };
@@ -34,12 +34,19 @@ fn tx_input_from_transfer(tx_transfer: &TxTransfer) -> TxInput {
         args: Vec::new(),
         gas_limit: tx_transfer.gas_limit.value,
         gas_price: tx_transfer.gas_price.value,
+        guardian: tx_transfer
+            .guardian
+            .as_ref()
+            .map(|guardian| guardian.to_vm_address()),
         ..Default::default()
     }
 }
 
 fn execute(vm: BlockchainVMRef, state: &mut Shareable<BlockchainState>, tx_transfer: &TxTransfer) {
     let tx_input = tx_input_from_transfer(tx_transfer);
+    if let Err(err) = check_guarded_tx(&tx_input, state) {
+        TxResult::from_panic_obj(&err).assert_ok();
+    }
 
     // nonce gets increased irrespective of whether the tx fails or not
     state.increase_account_nonce(&tx_input.from);
@@ -59,6 +59,7 @@ pub async fn retrieve_account_as_scenario_set_state(
             code: retrieve_code(account.code),
             owner: None,
             developer_rewards: None,
+            guardians: None,
         },
     );
 
@@ -106,12 +107,30 @@ fn convert_esdt(
             .entry(format!("str:{}", token_identifier.clone()))
             .or_insert(EsdtRaw::Full(EsdtFullRaw::default()));
         if let EsdtRaw::Full(esdt_full_raw) = esdt_raw {
-            esdt_full_raw.instances.push(EsdtInstanceRaw {
+            let mut instance_raw = EsdtInstanceRaw {
                 nonce: Some(ValueSubTree::Str(nonce.to_string())),
-                balance: Some(ValueSubTree::Str(value.balance)),
-                // TODO: add creator, royalties, etc ...
+                balance: Some(ValueSubTree::Str(value.balance.to_string())),
                 ..Default::default()
-            });
+            };
+            if !value.is_fungible() {
+                instance_raw.creator = value
+                    .creator
+                    .as_ref()
+                    .map(|creator| ValueSubTree::Str(format!("bech32:{}", creator.to_string())));
+                instance_raw.royalties = Some(ValueSubTree::Str(value.royalties.to_string()));
+                instance_raw.uri = value
+                    .uris
+                    .iter()
+                    .map(|uri| ValueSubTree::Str(format!("0x{}", hex::encode(uri))))
+                    .collect();
+                if !value.attributes.is_empty() {
+                    instance_raw.attributes = Some(ValueSubTree::Str(format!(
+                        "0x{}",
+                        hex::encode(&value.attributes)
+                    )));
+                }
+            }
+            esdt_full_raw.instances.push(instance_raw);
         }
     }
 
@@ -10,12 +10,37 @@ struct ToolNotFound;
 /// Runs the VM executable,
 /// which reads parses and executes one or more mandos tests.
 pub fn run_vm_go_tool(absolute_path: &Path) {
+    if let Some(output) = run_vm_go_tool_with_output(absolute_path) {
+        println!("{output}");
+    }
+}
+
+/// Runs the VM executable and returns its standard output.
+///
+/// Returns `None` if the Go tests are disabled (the `run-go-tests` feature is not active).
+pub fn run_vm_go_tool_with_output(absolute_path: &Path) -> Option<String> {
+    match try_run_vm_go_tool(absolute_path)? {
+        VmGoToolOutcome::Passed(output) => Some(output),
+        VmGoToolOutcome::Failed(output) => panic!("{output}"),
+    }
+}
+
+/// The output of the VM executable, split by whether the scenarios passed or not.
+pub enum VmGoToolOutcome {
+    Passed(String),
+    Failed(String),
+}
+
+/// Runs the VM executable, without panicking if the scenarios fail.
+///
+/// Returns `None` if the Go tests are disabled (the `run-go-tests` feature is not active).
+pub fn try_run_vm_go_tool(absolute_path: &Path) -> Option<VmGoToolOutcome> {
     if cfg!(not(feature = "run-go-tests")) {
-        return;
+        return None;
     }
 
-    if run_scenario_tool(RUNNER_TOOL_NAME, absolute_path).is_ok() {
-        return;
+    if let Ok(outcome) = run_scenario_tool(RUNNER_TOOL_NAME, absolute_path) {
+        return Some(outcome);
     }
 
     // fallback - use the old binary
@@ -23,14 +48,14 @@ pub fn run_vm_go_tool(absolute_path: &Path) {
         "{}",
         format!("Warning: `{RUNNER_TOOL_NAME}` not found. Using `{RUNNER_TOOL_NAME_LEGACY}` as fallback.").yellow(),
     );
-    if run_scenario_tool(RUNNER_TOOL_NAME_LEGACY, absolute_path).is_ok() {
-        return;
+    if let Ok(outcome) = run_scenario_tool(RUNNER_TOOL_NAME_LEGACY, absolute_path) {
+        return Some(outcome);
     }
 
     panic!("Could not find `{RUNNER_TOOL_NAME_LEGACY}`, aborting.");
 }
 
-fn run_scenario_tool(tool_name: &str, path: &Path) -> Result<(), ToolNotFound> {
+fn run_scenario_tool(tool_name: &str, path: &Path) -> Result<VmGoToolOutcome, ToolNotFound> {
     let result = Command::new(tool_name).arg(path).output();
 
     if let Err(error) = &result {
@@ -41,16 +66,16 @@ fn run_scenario_tool(tool_name: &str, path: &Path) -> Result<(), ToolNotFound> {
 
     let output = result.expect("failed to execute process");
 
-    if output.status.success() {
-        println!("{}", String::from_utf8_lossy(output.stdout.as_slice()));
-    } else {
-        panic!(
+    if !output.status.success() {
+        return Ok(VmGoToolOutcome::Failed(format!(
             "{} output:\n{}\n{}",
             tool_name,
             String::from_utf8_lossy(output.stdout.as_slice()),
             String::from_utf8_lossy(output.stderr.as_slice())
-        );
+        )));
     }
 
-    Ok(())
+    Ok(VmGoToolOutcome::Passed(
+        String::from_utf8_lossy(output.stdout.as_slice()).into_owned(),
+    ))
 }
@@ -5,9 +5,9 @@ use crate::{
     scenario_format::serde_raw::{
         AccountRaw, CheckAccountRaw, CheckAccountsRaw, CheckBytesValueRaw, CheckEsdtDataRaw,
         CheckEsdtInstanceRaw, CheckEsdtInstancesRaw, CheckEsdtMapContentsRaw, CheckEsdtMapRaw,
-        CheckEsdtRaw, CheckLogsRaw, CheckStorageDetailsRaw, CheckStorageRaw, CheckValueListRaw,
-        EsdtFullRaw, EsdtInstanceRaw, EsdtRaw, TxCallRaw, TxESDTRaw, TxExpectRaw, TxQueryRaw,
-        ValueSubTree,
+        CheckEsdtRaw, CheckGuardiansRaw, CheckLogsRaw, CheckStorageDetailsRaw, CheckStorageRaw,
+        CheckValueListRaw, EsdtFullRaw, EsdtInstanceRaw, EsdtRaw, TxCallRaw, TxESDTRaw,
+        TxExpectRaw, TxQueryRaw, ValueSubTree,
     },
     scenario_model::U64Value,
 };
@@ -55,6 +55,7 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
         storage: storage_raw,
         username: None, // TODO: Add if needed
         developer_rewards: developer_rewards_raw,
+        guardians: None,
     }
 }
 
@@ -125,6 +126,7 @@ pub(crate) fn tx_call_as_raw(tx_call: &ScCallMandos) -> TxCallRaw {
         arguments: arguments_raw,
         gas_limit: u64_as_raw(tx_call.gas_limit),
         gas_price: u64_as_raw_opt(tx_call.gas_price),
+        guardian: None,
     }
 }
 
@@ -247,6 +249,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
         async_call_data: CheckBytesValueRaw::Unspecified,
         comment: None,
         username: CheckBytesValueRaw::Unspecified,
+        guardians: CheckGuardiansRaw::default(),
     };
 
     let mut all_accounts_check_raw = BTreeMap::new();
@@ -1,4 +1,4 @@
-use multiversx_sc::types::BigInt;
+use multiversx_sc::types::{BigInt, BigUint};
 use multiversx_sc_scenario::api::StaticApi;
 
 #[test]
@@ -7,3 +7,40 @@ fn test_big_int_add() {
     let y = BigInt::<StaticApi>::from(3);
     assert_eq!(x + y, BigInt::<StaticApi>::from(5))
 }
+
+#[test]
+fn test_big_int_checked_div() {
+    let x = BigInt::<StaticApi>::from(-7);
+    assert_eq!(x.checked_div(&BigInt::from(2)), Some(BigInt::from(-3)));
+    assert_eq!(x.checked_div(&BigInt::zero()), None);
+}
+
+#[test]
+fn test_big_int_gcd() {
+    let x = BigInt::<StaticApi>::from(-48);
+    let y = BigInt::<StaticApi>::from(18);
+    assert_eq!(x.gcd(&y), BigUint::from(6u32));
+    assert_eq!(x.gcd(&BigInt::zero()), BigUint::from(48u32));
+}
+
+#[test]
+fn test_big_int_min_max_clamp() {
+    let x = BigInt::<StaticApi>::from(-5);
+    assert_eq!(x.clone().min(BigInt::from(3)), BigInt::from(-5));
+    assert_eq!(x.clone().max(BigInt::from(3)), BigInt::from(3));
+    assert_eq!(
+        x.clone().clamp(BigInt::from(-2), BigInt::from(2)),
+        BigInt::from(-2)
+    );
+    assert_eq!(
+        x.clamp(BigInt::from(-10), BigInt::from(2)),
+        BigInt::from(-5)
+    );
+}
+
+#[test]
+#[should_panic(expected = "clamp lower bound exceeds upper bound")]
+fn test_big_int_clamp_invalid_bounds() {
+    let x = BigInt::<StaticApi>::from(0);
+    let _ = x.clamp(BigInt::from(2), BigInt::from(-2));
+}
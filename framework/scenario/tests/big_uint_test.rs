@@ -0,0 +1,90 @@
+use multiversx_sc::types::{BigUint, RoundingMode};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn big_uint(value: u64) -> BigUint<StaticApi> {
+    BigUint::from(value)
+}
+
+#[test]
+fn test_big_uint_checked_sub() {
+    assert_eq!(big_uint(10).checked_sub(&big_uint(3)), Some(big_uint(7)));
+    assert_eq!(big_uint(3).checked_sub(&big_uint(3)), Some(big_uint(0)));
+    assert_eq!(big_uint(3).checked_sub(&big_uint(10)), None);
+}
+
+#[test]
+fn test_big_uint_checked_div() {
+    assert_eq!(big_uint(10).checked_div(&big_uint(3)), Some(big_uint(3)));
+    assert_eq!(big_uint(10).checked_div(&big_uint(0)), None);
+}
+
+#[test]
+fn test_big_uint_mul_div() {
+    let check = |rounding: RoundingMode, expected: u64| {
+        assert_eq!(
+            big_uint(5).mul_div(&big_uint(5), &big_uint(10), rounding),
+            big_uint(expected)
+        );
+    };
+    check(RoundingMode::Down, 2);
+    check(RoundingMode::Up, 3);
+    check(RoundingMode::HalfUp, 3);
+    check(RoundingMode::HalfEven, 2);
+
+    assert_eq!(
+        big_uint(10).mul_div(&big_uint(10), &big_uint(3), RoundingMode::HalfEven),
+        big_uint(33)
+    );
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_big_uint_mul_div_by_zero() {
+    let _ = big_uint(5).mul_div(&big_uint(5), &big_uint(0), RoundingMode::Down);
+}
+
+#[test]
+fn test_big_uint_mod_pow() {
+    assert_eq!(
+        big_uint(4).mod_pow(&big_uint(13), &big_uint(497)),
+        big_uint(445)
+    );
+    assert_eq!(big_uint(2).mod_pow(&big_uint(0), &big_uint(7)), big_uint(1));
+    assert_eq!(
+        big_uint(123).mod_pow(&big_uint(45), &big_uint(1)),
+        big_uint(0)
+    );
+
+    // 3 ^ 4 = 1 mod 5, and 2 ^ 100 is a multiple of 4
+    let big_exp = big_uint(1) << 100;
+    assert_eq!(big_uint(3).mod_pow(&big_exp, &big_uint(5)), big_uint(1));
+}
+
+#[test]
+#[should_panic(expected = "modulus must be positive")]
+fn test_big_uint_mod_pow_zero_modulus() {
+    let _ = big_uint(4).mod_pow(&big_uint(13), &big_uint(0));
+}
+
+#[test]
+fn test_big_uint_gcd() {
+    assert_eq!(big_uint(48).gcd(&big_uint(18)), big_uint(6));
+    assert_eq!(big_uint(17).gcd(&big_uint(5)), big_uint(1));
+    assert_eq!(big_uint(0).gcd(&big_uint(5)), big_uint(5));
+    assert_eq!(big_uint(0).gcd(&big_uint(0)), big_uint(0));
+}
+
+#[test]
+fn test_big_uint_min_max_clamp() {
+    assert_eq!(big_uint(3).min(big_uint(5)), big_uint(3));
+    assert_eq!(big_uint(3).max(big_uint(5)), big_uint(5));
+    assert_eq!(big_uint(3).clamp(big_uint(5), big_uint(10)), big_uint(5));
+    assert_eq!(big_uint(30).clamp(big_uint(5), big_uint(10)), big_uint(10));
+    assert_eq!(big_uint(7).clamp(big_uint(5), big_uint(10)), big_uint(7));
+}
+
+#[test]
+#[should_panic(expected = "clamp lower bound exceeds upper bound")]
+fn test_big_uint_clamp_invalid_bounds() {
+    let _ = big_uint(7).clamp(big_uint(10), big_uint(5));
+}
@@ -0,0 +1,9 @@
+#![cfg(not(feature = "run-go-tests"))]
+
+use multiversx_sc_scenario::ScenarioWorld;
+
+#[test]
+#[should_panic(expected = "the dual backend needs the Go VM")]
+fn dual_requires_go_tests() {
+    ScenarioWorld::dual();
+}
@@ -0,0 +1,125 @@
+// The dual backend replays everything on the Go VM, so it is only available with the Go tests.
+#![cfg(feature = "run-go-tests")]
+
+use multiversx_sc_scenario::{scenario_model::*, *};
+use std::path::PathBuf;
+
+const RECORDING_SUFFIX: &str = ".dual.scen.json";
+
+fn recording_dir(test_name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join("dual-backend-test")
+        .join(test_name);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn world_dual(recording_dir: &PathBuf) -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::dual();
+    blockchain.set_current_dir_from_workspace("framework/scenario");
+    blockchain.dual_recording_dir(recording_dir);
+    blockchain
+}
+
+/// Runs a scenario on the dual backend, then checks that the debugger agrees with its own recording.
+fn run_and_replay(test_name: &str, scenario_dir: &str, scenario_name: &str) {
+    let recording_dir = recording_dir(test_name);
+    world_dual(&recording_dir).run(format!("{scenario_dir}/{scenario_name}.scen.json"));
+
+    let recording_path = recording_dir.join(format!("{scenario_name}{RECORDING_SUFFIX}"));
+    assert!(recording_path.is_file());
+    ScenarioWorld::debugger().run(recording_path);
+}
+
+#[test]
+fn dual_transfer_egld() {
+    run_and_replay("transfer_egld", "tests/scenarios-self", "transfer-egld");
+}
+
+#[test]
+fn dual_multi_transfer_esdt() {
+    run_and_replay(
+        "multi_transfer_esdt",
+        "tests/scenarios-self",
+        "multi-transfer-esdt",
+    );
+}
+
+#[test]
+fn dual_builtin_func_esdt_transfer() {
+    run_and_replay(
+        "builtin_func_esdt_transfer",
+        "tests/scenarios-self",
+        "builtin-func-esdt-transfer",
+    );
+}
+
+#[test]
+fn dual_validator_reward() {
+    run_and_replay(
+        "validator_reward",
+        "tests/scenarios-self",
+        "validatorReward",
+    );
+}
+
+#[test]
+fn dual_vm_errors() {
+    run_and_replay(
+        "vm_errors",
+        "tests/scenarios-self/vm-errors",
+        "esdt-transfer-errors",
+    );
+
+    // failed transactions get their logs checked too
+    let recording_path =
+        recording_dir("vm_errors").join(format!("esdt-transfer-errors{RECORDING_SUFFIX}"));
+    let recording = std::fs::read_to_string(recording_path).unwrap();
+    assert!(!recording.contains("\"logs\": \"*\""));
+}
+
+/// Runs a transfer step by step, the recording is saved when the world is dropped.
+fn run_transfer_steps(recording_dir: &PathBuf) {
+    let mut world = ScenarioWorld::dual();
+    world.dual_recording_dir(recording_dir);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new().nonce(1).balance(1_000u64))
+            .put_account("address:other", Account::new().nonce(1)),
+    );
+    world.transfer_step(
+        TransferStep::new()
+            .from("address:owner")
+            .to("address:other")
+            .egld_value(400u64),
+    );
+}
+
+#[test]
+fn dual_step_by_step() {
+    let recording_dir = recording_dir("step_by_step");
+    run_transfer_steps(&recording_dir);
+
+    // named after the test
+    let recording_path = recording_dir.join(format!("dual_step_by_step{RECORDING_SUFFIX}"));
+    let recording = std::fs::read_to_string(&recording_path).unwrap();
+    assert!(recording.contains("\"checkState\""));
+    assert!(recording.contains("\"address:other\""));
+    ScenarioWorld::debugger().run(recording_path);
+}
+
+#[test]
+#[should_panic(expected = "bad account balance. Address: address:owner")]
+fn dual_recording_checks_full_state() {
+    let recording_dir = recording_dir("full_state");
+    run_transfer_steps(&recording_dir);
+
+    // the recording expects the exact balances, so a different history diverges
+    let recording_path = recording_dir.join(format!(
+        "dual_recording_checks_full_state{RECORDING_SUFFIX}"
+    ));
+    let recording = std::fs::read_to_string(&recording_path).unwrap();
+    let altered_path = recording_dir.join(format!("full-state-altered{RECORDING_SUFFIX}"));
+    std::fs::write(&altered_path, recording.replace("\"400\"", "\"300\"")).unwrap();
+    ScenarioWorld::debugger().run(altered_path);
+}
@@ -0,0 +1,17 @@
+use multiversx_sc_scenario::benchmark::parse_go_gas_log;
+
+#[test]
+fn parse_go_gas_log_test() {
+    let output = r#"Scenario: bench.scen.json ...
+In txID: deploy , step type:Deploy , total gas used: 2000
+In txID: add_small , step type:ScCall , function: add , total gas used: 1234
+GasTrace: functionName: add ,  totalGasUsed: 1234 , numberOfCalls: 1
+In txID: add_large , step type:ScCall , function: add , total gas used: 5678
+Done. Passed: 1. Failed: 0. Skipped: 0.
+"#;
+    let gas_log = parse_go_gas_log(output);
+    assert_eq!(gas_log.len(), 3);
+    assert_eq!(gas_log["deploy"], 2000);
+    assert_eq!(gas_log["add_small"], 1234);
+    assert_eq!(gas_log["add_large"], 5678);
+}
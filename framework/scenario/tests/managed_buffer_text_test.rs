@@ -0,0 +1,105 @@
+use multiversx_sc::types::{BigUint, ManagedBuffer};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn mb(bytes: &[u8]) -> ManagedBuffer<StaticApi> {
+    ManagedBuffer::new_from_bytes(bytes)
+}
+
+#[test]
+fn test_managed_buffer_find() {
+    let haystack = mb(b"https://example.com/nft/1");
+    assert_eq!(haystack.find(&mb(b"https")), Some(0));
+    assert_eq!(haystack.find(&mb(b"/nft/")), Some(19));
+    assert_eq!(haystack.find(&mb(b"1")), Some(24));
+    assert_eq!(haystack.find(&mb(b"")), Some(0));
+    assert_eq!(haystack.find(&mb(b"ftp")), None);
+    assert!(haystack.contains(&mb(b"example")));
+    assert!(!mb(b"ab").contains(&mb(b"abc")));
+
+    // match beyond the first load batch
+    let mut long = ManagedBuffer::<StaticApi>::new_from_bytes(&[b'x'; 100]);
+    long.append_bytes(b"needle");
+    assert_eq!(long.find(&mb(b"needle")), Some(100));
+}
+
+#[test]
+fn test_managed_buffer_starts_ends_with() {
+    let buffer = mb(b"WEGLD-abcdef");
+    assert!(buffer.starts_with(&mb(b"WEGLD")));
+    assert!(!buffer.starts_with(&mb(b"abcdef")));
+    assert!(buffer.ends_with(&mb(b"abcdef")));
+    assert!(!buffer.ends_with(&mb(b"WEGLD-abcdef-")));
+    assert!(buffer.eq_range(5, &mb(b"-abc")));
+    assert!(!buffer.eq_range(10, &mb(b"efg")));
+}
+
+#[test]
+fn test_managed_buffer_split() {
+    let parts = mb(b"WEGLD-abcdef").split(b'-');
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts.get(0).clone_value(), mb(b"WEGLD"));
+    assert_eq!(parts.get(1).clone_value(), mb(b"abcdef"));
+
+    let parts = mb(b",a,,b,").split(b',');
+    let expected: [&[u8]; 5] = [b"", b"a", b"", b"b", b""];
+    assert_eq!(parts.len(), expected.len());
+    for (part, expected) in parts.iter().zip(expected) {
+        assert_eq!(*part, mb(expected));
+    }
+
+    assert_eq!(mb(b"").split(b',').len(), 1);
+}
+
+#[test]
+fn test_managed_buffer_ascii_case() {
+    assert_eq!(mb(b"Token-1a").to_ascii_uppercase(), mb(b"TOKEN-1A"));
+    assert_eq!(mb(b"Token-1A").to_ascii_lowercase(), mb(b"token-1a"));
+}
+
+#[test]
+fn test_managed_buffer_hex() {
+    assert_eq!(mb(&[0x01, 0xab, 0xff]).to_hex(), mb(b"01abff"));
+    assert_eq!(
+        ManagedBuffer::from_hex(&mb(b"01abFF")),
+        Some(mb(&[0x01, 0xab, 0xff]))
+    );
+    assert_eq!(ManagedBuffer::from_hex(&mb(b"")), Some(mb(b"")));
+    assert_eq!(ManagedBuffer::from_hex(&mb(b"abc")), None);
+    assert_eq!(ManagedBuffer::from_hex(&mb(b"0g")), None);
+}
+
+#[test]
+fn test_managed_buffer_base64() {
+    let check = |raw: &[u8], encoded: &[u8]| {
+        assert_eq!(mb(raw).to_base64(), mb(encoded));
+        assert_eq!(ManagedBuffer::from_base64(&mb(encoded)), Some(mb(raw)));
+    };
+    check(b"", b"");
+    check(b"f", b"Zg==");
+    check(b"fo", b"Zm8=");
+    check(b"foo", b"Zm9v");
+    check(b"foobar", b"Zm9vYmFy");
+
+    // spans several encode and decode batches
+    let raw: Vec<u8> = (0..=255u8).collect();
+    let encoded = mb(&raw).to_base64();
+    assert_eq!(ManagedBuffer::from_base64(&encoded), Some(mb(&raw)));
+
+    assert_eq!(ManagedBuffer::from_base64(&mb(b"Zm9")), None);
+    assert_eq!(ManagedBuffer::from_base64(&mb(b"Zm9$")), None);
+    assert_eq!(ManagedBuffer::from_base64(&mb(b"Zg==Zm9v")), None);
+}
+
+#[test]
+fn test_managed_buffer_big_uint_decimal() {
+    let value = BigUint::<StaticApi>::from(1u64) << 100;
+    let decimal = mb(b"1267650600228229401496703205376");
+    assert_eq!(ManagedBuffer::from_big_uint_decimal(&value), decimal);
+    assert_eq!(decimal.parse_as_big_uint_decimal(), Some(value));
+    assert_eq!(
+        mb(b"0042").parse_as_big_uint_decimal(),
+        Some(BigUint::from(42u32))
+    );
+    assert_eq!(mb(b"").parse_as_big_uint_decimal(), None);
+    assert_eq!(mb(b"12a").parse_as_big_uint_decimal(), None);
+}
@@ -0,0 +1,199 @@
+use multiversx_sc::{
+    abi::{TypeAbi, TypeContents, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    types::{BigFloat, BigUint, ConstDecimals, ManagedDecimal, NumDecimals, RoundingMode},
+};
+use multiversx_sc_scenario::{api::StaticApi, managed_test_util::check_managed_top_encode_decode};
+
+fn big_uint(value: u64) -> BigUint<StaticApi> {
+    BigUint::from(value)
+}
+
+#[test]
+fn test_managed_decimal_const_arithmetic() {
+    let fixed =
+        ManagedDecimal::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(big_uint(150));
+    let fixed_2 = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(big_uint(2));
+    assert_eq!(fixed_2.raw_units(), &big_uint(200));
+    assert_eq!(fixed.trunc(), big_uint(1));
+
+    let sum = fixed.clone() + fixed_2.clone();
+    assert_eq!(sum.raw_units(), &big_uint(350));
+
+    let difference = fixed_2.clone() - fixed.clone();
+    assert_eq!(difference.raw_units(), &big_uint(50));
+
+    let product = fixed.clone() * fixed_2.clone();
+    assert_eq!(product.raw_units(), &big_uint(300));
+
+    let quotient = fixed / fixed_2;
+    assert_eq!(quotient.raw_units(), &big_uint(75));
+}
+
+#[test]
+fn test_managed_decimal_mixed_scales() {
+    let one_and_a_half = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(15), 1);
+    let two = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(2000), 3);
+
+    assert_eq!(
+        one_and_a_half,
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(1500), 3)
+    );
+    assert!(one_and_a_half < two);
+
+    // the result has the larger scale, no digits are lost
+    let sum = one_and_a_half.clone() + two.clone();
+    assert_eq!(sum.scale(), 3);
+    assert_eq!(sum.raw_units(), &big_uint(3500));
+
+    let sum = two.clone() + one_and_a_half.clone();
+    assert_eq!(sum.scale(), 3);
+    assert_eq!(sum.raw_units(), &big_uint(3500));
+
+    let precise = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(2001), 3);
+    let difference = precise - one_and_a_half;
+    assert_eq!(difference.scale(), 3);
+    assert_eq!(difference.raw_units(), &big_uint(501));
+}
+
+#[test]
+fn test_managed_decimal_const_mixed_scales() {
+    let fixed =
+        ManagedDecimal::<StaticApi, ConstDecimals<3>>::const_decimals_from_raw(big_uint(2000));
+    let one_and_a_half = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(15), 1);
+
+    let sum = fixed.clone() + one_and_a_half.clone();
+    assert_eq!(sum.raw_units(), &big_uint(3500));
+
+    let difference = fixed - one_and_a_half;
+    assert_eq!(difference.raw_units(), &big_uint(500));
+}
+
+#[test]
+#[should_panic(expected = "managed decimal scale too large for the result")]
+fn test_managed_decimal_const_add_finer_scale() {
+    let fixed =
+        ManagedDecimal::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(big_uint(15));
+    let precise = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(1001), 3);
+    let _ = fixed + precise;
+}
+
+#[test]
+#[should_panic(expected = "managed decimal scale too large for the result")]
+fn test_managed_decimal_const_sub_finer_scale() {
+    let fixed =
+        ManagedDecimal::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(big_uint(15));
+    let precise = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(1), 3);
+    let _ = fixed - precise;
+}
+
+#[test]
+fn test_managed_decimal_rescale_rounding() {
+    let check = |raw: u64, rounding: RoundingMode, expected: u64| {
+        let decimal = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(raw), 2);
+        let rescaled = decimal.rescale_with_rounding(1usize, rounding);
+        assert_eq!(rescaled.raw_units(), &big_uint(expected));
+    };
+
+    check(125, RoundingMode::Down, 12);
+    check(125, RoundingMode::Up, 13);
+    check(125, RoundingMode::HalfUp, 13);
+    check(125, RoundingMode::HalfEven, 12);
+    check(135, RoundingMode::HalfEven, 14);
+    check(124, RoundingMode::HalfUp, 12);
+    check(126, RoundingMode::HalfEven, 13);
+    check(120, RoundingMode::Up, 12);
+
+    let decimal = ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(125), 2);
+    let rescaled = decimal.rescale(ConstDecimals::<4>);
+    assert_eq!(rescaled.raw_units(), &big_uint(12500));
+}
+
+#[test]
+fn test_managed_decimal_mul_div_rounding() {
+    let one_third = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(big_uint(1))
+        .div_with_rounding(
+            &ManagedDecimal::<StaticApi, ConstDecimals<0>>::from(big_uint(3)),
+            RoundingMode::Down,
+        );
+    assert_eq!(one_third.raw_units(), &big_uint(33));
+
+    let two_thirds = ManagedDecimal::<StaticApi, ConstDecimals<2>>::from(big_uint(2))
+        .div_with_rounding(
+            &ManagedDecimal::<StaticApi, ConstDecimals<0>>::from(big_uint(3)),
+            RoundingMode::HalfUp,
+        );
+    assert_eq!(two_thirds.raw_units(), &big_uint(67));
+
+    let product =
+        ManagedDecimal::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(big_uint(15))
+            .mul_with_rounding(
+                &ManagedDecimal::<StaticApi, ConstDecimals<1>>::const_decimals_from_raw(big_uint(
+                    15,
+                )),
+                RoundingMode::Up,
+            );
+    assert_eq!(product.raw_units(), &big_uint(23));
+}
+
+#[test]
+fn test_managed_decimal_big_float_conversion() {
+    let decimal =
+        ManagedDecimal::<StaticApi, ConstDecimals<3>>::const_decimals_from_raw(big_uint(2500));
+    let big_float = decimal.to_big_float();
+    assert_eq!(big_float, BigFloat::from_frac(5, 2));
+
+    let back =
+        ManagedDecimal::<StaticApi, ConstDecimals<3>>::from_big_float(&big_float, ConstDecimals);
+    assert_eq!(back.raw_units(), &big_uint(2500));
+
+    let third = BigFloat::<StaticApi>::from_frac(1, 3);
+    let truncated = ManagedDecimal::<StaticApi, NumDecimals>::from_big_float(&third, 4);
+    assert_eq!(truncated.raw_units(), &big_uint(3333));
+}
+
+#[test]
+#[should_panic]
+fn test_managed_decimal_negative_big_float() {
+    let negative = BigFloat::<StaticApi>::from(-1i64);
+    let _ = ManagedDecimal::<StaticApi, NumDecimals>::from_big_float(&negative, 2);
+}
+
+#[test]
+fn test_managed_decimal_serialization() {
+    check_managed_top_encode_decode(
+        ManagedDecimal::<StaticApi, ConstDecimals<2>>::const_decimals_from_raw(big_uint(5)),
+        &[5u8],
+    );
+    check_managed_top_encode_decode(
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(big_uint(5), 2),
+        &[0, 0, 0, 1, 5, 0, 0, 0, 2],
+    );
+}
+
+#[test]
+fn test_managed_decimal_type_abi() {
+    assert_eq!(
+        ManagedDecimal::<StaticApi, ConstDecimals<18>>::type_name(),
+        "ManagedDecimal<18>"
+    );
+    assert_eq!(
+        ManagedDecimal::<StaticApi, NumDecimals>::type_name(),
+        "ManagedDecimal<usize>"
+    );
+}
+
+#[test]
+fn test_managed_decimal_type_description() {
+    let mut accumulator = TypeDescriptionContainerImpl::new();
+    ManagedDecimal::<StaticApi, NumDecimals>::provide_type_descriptions(&mut accumulator);
+
+    let description = accumulator.0.get("ManagedDecimal<usize>").unwrap();
+    let TypeContents::Struct(fields) = &description.contents else {
+        panic!("ManagedDecimal<usize> should be described as a struct");
+    };
+    let fields = fields
+        .iter()
+        .map(|field| (field.name.as_str(), field.field_type.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec![("data", "BigUint"), ("decimals", "u32")]);
+}
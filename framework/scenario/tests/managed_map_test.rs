@@ -0,0 +1,122 @@
+use multiversx_sc::types::{
+    managed_map::{Entry, Iter, Keys, Values},
+    ManagedMap,
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+fn create_map() -> ManagedMap<StaticApi, u64, u64> {
+    let mut map = ManagedMap::new();
+    map.insert(1, 101);
+    map.insert(2, 102);
+    map.insert(3, 103);
+    map
+}
+
+#[test]
+fn test_managed_map_iterators() {
+    let map = create_map();
+
+    let keys: Keys<'_, StaticApi, u64, u64> = map.keys();
+    assert_eq!(keys.collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    let values: Values<'_, StaticApi, u64, u64> = map.values();
+    assert_eq!(values.collect::<Vec<_>>(), vec![101, 102, 103]);
+
+    let iter: Iter<'_, StaticApi, u64, u64> = map.iter();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![(1, 101), (2, 102), (3, 103)]);
+}
+
+#[test]
+fn test_managed_map_values_after_remove() {
+    let mut map = create_map();
+    assert_eq!(map.remove(&1), Some(101));
+
+    // the last key takes the place of the removed one
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![3, 2]);
+    assert_eq!(map.values().collect::<Vec<_>>(), vec![103, 102]);
+}
+
+#[test]
+fn test_managed_map_entry_vacant() {
+    let mut map = create_map();
+
+    match map.entry(4) {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.key(), &4);
+            let occupied = entry.insert(104);
+            assert_eq!(occupied.get(), 104);
+        },
+        Entry::Occupied(_) => panic!("entry should be vacant"),
+    }
+    assert_eq!(map.get(&4), Some(104));
+    assert_eq!(map.len(), 4);
+
+    assert_eq!(map.entry(5).or_insert(105).get(), 105);
+    assert_eq!(map.entry(6).or_insert_with(|| 106).get(), 106);
+    assert_eq!(map.entry(7).or_default().get(), 0);
+
+    // nothing to modify when vacant
+    map.entry(8).and_modify(|value| *value += 1);
+    assert!(!map.contains_key(&8));
+    assert_eq!(map.len(), 7);
+}
+
+#[test]
+fn test_managed_map_entry_occupied() {
+    let mut map = create_map();
+
+    match map.entry(2) {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &2);
+            assert_eq!(entry.get(), 102);
+            let doubled = entry.update(|value| {
+                *value *= 2;
+                *value
+            });
+            assert_eq!(doubled, 204);
+            assert_eq!(entry.insert(302), 204);
+        },
+        Entry::Vacant(_) => panic!("entry should be occupied"),
+    }
+    assert_eq!(map.get(&2), Some(302));
+
+    // existing values are kept
+    assert_eq!(map.entry(1).or_insert(999).get(), 101);
+    assert_eq!(map.entry(1).and_modify(|value| *value += 1).key(), &1);
+    assert_eq!(map.get(&1), Some(102));
+}
+
+#[test]
+fn test_managed_map_remove_entry() {
+    let mut map = create_map();
+
+    let (key, value) = match map.entry(2) {
+        Entry::Occupied(entry) => entry.remove_entry(),
+        Entry::Vacant(_) => panic!("entry should be occupied"),
+    };
+    assert_eq!((key, value), (2, 102));
+    assert!(!map.contains_key(&2));
+    assert_eq!(map.len(), 2);
+
+    let value = match map.entry(3) {
+        Entry::Occupied(entry) => entry.remove(),
+        Entry::Vacant(_) => panic!("entry should be occupied"),
+    };
+    assert_eq!(value, 103);
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(1, 101)]);
+}
+
+#[test]
+fn test_managed_map_zero_values() {
+    // zero encodes as an empty buffer, but the entries are still present
+    let mut map: ManagedMap<StaticApi, u64, u64> = ManagedMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.insert(0, 0), None);
+    assert!(map.contains_key(&0));
+    assert_eq!(map.get(&0), Some(0));
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(0, 0)]);
+    assert_eq!(map.remove(&0), Some(0));
+    assert!(map.is_empty());
+    assert_eq!(map.get(&0), None);
+}
@@ -123,6 +123,17 @@ fn set_check_storage_rs() {
     world().run("tests/scenarios-self/set-check/set-check-storage.scen.json");
 }
 
+#[test]
+#[should_panic]
+fn set_check_guardians_err_rs() {
+    world().run("tests/scenarios-self/set-check/set-check-guardians.err.json");
+}
+
+#[test]
+fn set_check_guardians_rs() {
+    world().run("tests/scenarios-self/set-check/set-check-guardians.scen.json");
+}
+
 #[test]
 #[should_panic]
 fn set_check_username_err_rs() {
@@ -139,6 +150,31 @@ fn builtin_func_esdt_transfer() {
     world().run("tests/scenarios-self/builtin-func-esdt-transfer.scen.json");
 }
 
+#[test]
+fn builtin_func_esdt_global_settings() {
+    world().run("tests/scenarios-self/builtin-func-esdt-global-settings.scen.json");
+}
+
+#[test]
+fn builtin_func_esdt_roles_freeze_wipe() {
+    world().run("tests/scenarios-self/builtin-func-esdt-roles-freeze-wipe.scen.json");
+}
+
+#[test]
+fn builtin_func_guardians() {
+    world().run("tests/scenarios-self/builtin-func-guardians.scen.json");
+}
+
+#[test]
+fn builtin_func_save_key_value() {
+    world().run("tests/scenarios-self/builtin-func-save-key-value.scen.json");
+}
+
+#[test]
+fn builtin_func_username() {
+    world().run("tests/scenarios-self/builtin-func-username.scen.json");
+}
+
 #[test]
 #[should_panic]
 fn esdt_non_zero_balance_check_err_rs() {
@@ -170,3 +206,13 @@ fn transfer_esdt_rs() {
 fn validator_reward_rs() {
     world().run("tests/scenarios-self/validatorReward.scen.json");
 }
+
+#[test]
+fn system_sc_delegation_rs() {
+    world().run("tests/scenarios-self/system-sc-delegation.scen.json");
+}
+
+#[test]
+fn system_sc_validator_rs() {
+    world().run("tests/scenarios-self/system-sc-validator.scen.json");
+}
@@ -0,0 +1,36 @@
+use multiversx_sc_scenario::*;
+
+// The same failing transactions, run on both backends,
+// to check that the Rust VM reports the same errors as the Go VM.
+
+fn world_rs() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("framework/scenario");
+    blockchain
+}
+
+fn world_go() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::vm_go();
+    blockchain.set_current_dir_from_workspace("framework/scenario");
+    blockchain
+}
+
+#[test]
+fn esdt_local_role_errors_rs() {
+    world_rs().run("tests/scenarios-self/vm-errors/esdt-local-role-errors.scen.json");
+}
+
+#[test]
+fn esdt_local_role_errors_go() {
+    world_go().run("tests/scenarios-self/vm-errors/esdt-local-role-errors.scen.json");
+}
+
+#[test]
+fn esdt_transfer_errors_rs() {
+    world_rs().run("tests/scenarios-self/vm-errors/esdt-transfer-errors.scen.json");
+}
+
+#[test]
+fn esdt_transfer_errors_go() {
+    world_go().run("tests/scenarios-self/vm-errors/esdt-transfer-errors.scen.json");
+}
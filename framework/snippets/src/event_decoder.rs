@@ -0,0 +1,160 @@
+use std::{collections::HashMap, fmt};
+
+use multiversx_sc_scenario::multiversx_sc::{
+    abi::{ContractAbi, EventAbi},
+    codec::TopDecode,
+};
+use multiversx_sdk::{data::address::Address, indexer::IndexedEvent};
+
+/// Why an indexed event could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventDecodeError {
+    /// No event in the ABI has the event's name.
+    UnknownEvent(String),
+    /// The number of topics does not match the indexed inputs of the event ABI.
+    TopicCount { expected: usize, found: usize },
+    /// The event ABI has no input with this name.
+    MissingField(String),
+    /// The field value does not decode as the requested type.
+    Field { name: String, message: String },
+}
+
+impl fmt::Display for EventDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventDecodeError::UnknownEvent(name) => write!(f, "unknown event: {name}"),
+            EventDecodeError::TopicCount { expected, found } => {
+                write!(f, "expected {expected} topics, found {found}")
+            },
+            EventDecodeError::MissingField(name) => write!(f, "event has no field {name}"),
+            EventDecodeError::Field { name, message } => {
+                write!(f, "could not decode field {name}: {message}")
+            },
+        }
+    }
+}
+
+impl std::error::Error for EventDecodeError {}
+
+/// An event whose raw topics and data were matched to the inputs of its [`EventAbi`].
+///
+/// Indexed inputs are stored in the topics, after the event name, in declaration order.
+/// The non-indexed input, if any, is the event data.
+#[derive(Debug, Clone)]
+pub struct DecodedEvent {
+    pub identifier: String,
+    pub tx_hash: String,
+    pub address: Address,
+    fields: Vec<(String, Vec<u8>)>,
+}
+
+impl DecodedEvent {
+    pub fn new(abi: &EventAbi, event: &IndexedEvent) -> Result<Self, EventDecodeError> {
+        let name = event
+            .name()
+            .map(|name| String::from_utf8_lossy(name).into_owned())
+            .unwrap_or_default();
+        if name != abi.identifier {
+            return Err(EventDecodeError::UnknownEvent(name));
+        }
+
+        let indexed_count = abi.inputs.iter().filter(|input| input.indexed).count();
+        let found = event.topics.len().saturating_sub(1);
+        if found != indexed_count {
+            return Err(EventDecodeError::TopicCount {
+                expected: indexed_count,
+                found,
+            });
+        }
+
+        let mut topics = event.topics[1..].iter();
+        let fields = abi
+            .inputs
+            .iter()
+            .map(|input| {
+                let value = if input.indexed {
+                    topics.next().cloned().unwrap_or_default()
+                } else {
+                    event.data.clone()
+                };
+                (input.arg_name.clone(), value)
+            })
+            .collect();
+
+        Ok(DecodedEvent {
+            identifier: abi.identifier.clone(),
+            tx_hash: event.tx_hash.clone(),
+            address: event.address.clone(),
+            fields,
+        })
+    }
+
+    /// The top-encoded value of a field.
+    pub fn raw(&self, name: &str) -> Option<&[u8]> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value.as_slice())
+    }
+
+    pub fn field<T: TopDecode>(&self, name: &str) -> Result<T, EventDecodeError> {
+        let raw = self
+            .raw(name)
+            .ok_or_else(|| EventDecodeError::MissingField(name.to_string()))?;
+        T::top_decode(raw).map_err(|err| EventDecodeError::Field {
+            name: name.to_string(),
+            message: err.message_str().to_string(),
+        })
+    }
+}
+
+/// Conversion from a decoded event into a typed struct.
+pub trait FromDecodedEvent: Sized {
+    fn from_decoded_event(event: &DecodedEvent) -> Result<Self, EventDecodeError>;
+}
+
+/// Decodes the events of one contract, looking up their ABI by event name.
+#[derive(Debug, Clone, Default)]
+pub struct EventDecoder {
+    events: HashMap<String, EventAbi>,
+}
+
+impl EventDecoder {
+    pub fn new(contract_abi: &ContractAbi) -> Self {
+        EventDecoder {
+            events: contract_abi
+                .events
+                .iter()
+                .map(|event| (event.identifier.clone(), event.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn with_event(mut self, event_abi: EventAbi) -> Self {
+        self.events.insert(event_abi.identifier.clone(), event_abi);
+        self
+    }
+
+    pub fn event_abi(&self, name: &str) -> Option<&EventAbi> {
+        self.events.get(name)
+    }
+
+    pub fn decode(&self, event: &IndexedEvent) -> Result<DecodedEvent, EventDecodeError> {
+        let name = event
+            .name()
+            .map(|name| String::from_utf8_lossy(name).into_owned())
+            .unwrap_or_default();
+        let abi = self
+            .events
+            .get(&name)
+            .ok_or(EventDecodeError::UnknownEvent(name))?;
+        DecodedEvent::new(abi, event)
+    }
+
+    pub fn decode_as<T: FromDecodedEvent>(
+        &self,
+        event: &IndexedEvent,
+    ) -> Result<T, EventDecodeError> {
+        T::from_decoded_event(&self.decode(event)?)
+    }
+}
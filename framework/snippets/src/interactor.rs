@@ -10,7 +10,7 @@ use multiversx_sdk::{
 };
 use std::{collections::HashMap, path::Path, time::Duration};
 
-use crate::Sender;
+use crate::{InteractorConfig, Sender};
 
 pub const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
 
@@ -18,6 +18,7 @@ pub struct Interactor {
     pub proxy: CommunicationProxy,
     pub network_config: NetworkConfig,
     pub sender_map: HashMap<Address, Sender>,
+    pub config: InteractorConfig,
 
     pub(crate) waiting_time_ms: u64,
     pub pre_runners: ScenarioRunnerList,
@@ -26,12 +27,17 @@ pub struct Interactor {
 
 impl Interactor {
     pub async fn new(gateway_url: &str) -> Self {
-        let proxy = CommunicationProxy::new(gateway_url.to_string());
+        Self::with_proxy(CommunicationProxy::new(gateway_url.to_string())).await
+    }
+
+    /// Interactor using a preconfigured proxy, e.g. one with a custom gateway transport.
+    pub async fn with_proxy(proxy: CommunicationProxy) -> Self {
         let network_config = proxy.get_network_config().await.unwrap();
         Self {
             proxy,
             network_config,
             sender_map: HashMap::new(),
+            config: InteractorConfig::default(),
             waiting_time_ms: 0,
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
@@ -56,6 +62,11 @@ impl Interactor {
         tokio::time::sleep(duration).await;
     }
 
+    pub fn with_config(mut self, config: InteractorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
     pub async fn with_tracer<P: AsRef<Path>>(mut self, path: P) -> Self {
         self.post_runners.push(ScenarioTraceFile::new(path));
         self
@@ -0,0 +1,98 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(2);
+const DEFAULT_TX_TIMEOUT: Duration = Duration::from_secs(180);
+const DEFAULT_BATCH_SIZE: NonZeroUsize = match NonZeroUsize::new(100) {
+    Some(batch_size) => batch_size,
+    None => unreachable!(),
+};
+const DEFAULT_MAX_NONCE_RESYNCS: usize = 3;
+
+/// Controls how the interactor sends transactions and waits for them.
+#[derive(Debug, Clone)]
+pub struct InteractorConfig {
+    /// Time between two transaction status checks.
+    pub polling_interval: Duration,
+
+    /// How long to wait for a transaction to complete before giving up.
+    pub tx_timeout: Duration,
+
+    /// Number of hyperblocks to wait for after the one including the transaction,
+    /// before retrieving its results.
+    pub confirmation_blocks: u64,
+
+    /// Maximum number of transactions sent in one `send-multiple` request.
+    pub batch_size: NonZeroUsize,
+
+    /// Pause between two batches, to throttle senders.
+    pub batch_interval: Duration,
+
+    /// How many times a transaction is re-signed and resent
+    /// after the network rejects its nonce.
+    pub max_nonce_resyncs: usize,
+
+    /// When set, the gas limit of each transaction is estimated by the network,
+    /// plus this margin (a percentage), instead of being taken from the step.
+    pub gas_estimation_margin_percent: Option<u64>,
+
+    /// Simulates each transaction before sending it, so failures surface without spending gas.
+    pub simulate: bool,
+}
+
+impl Default for InteractorConfig {
+    fn default() -> Self {
+        InteractorConfig {
+            polling_interval: DEFAULT_POLLING_INTERVAL,
+            tx_timeout: DEFAULT_TX_TIMEOUT,
+            confirmation_blocks: 0,
+            batch_size: DEFAULT_BATCH_SIZE,
+            batch_interval: Duration::ZERO,
+            max_nonce_resyncs: DEFAULT_MAX_NONCE_RESYNCS,
+            gas_estimation_margin_percent: None,
+            simulate: false,
+        }
+    }
+}
+
+impl InteractorConfig {
+    pub fn polling_interval(mut self, polling_interval: Duration) -> Self {
+        self.polling_interval = polling_interval;
+        self
+    }
+
+    pub fn tx_timeout(mut self, tx_timeout: Duration) -> Self {
+        self.tx_timeout = tx_timeout;
+        self
+    }
+
+    pub fn confirmation_blocks(mut self, confirmation_blocks: u64) -> Self {
+        self.confirmation_blocks = confirmation_blocks;
+        self
+    }
+
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = NonZeroUsize::new(batch_size).expect("batch size must be positive");
+        self
+    }
+
+    pub fn batch_interval(mut self, batch_interval: Duration) -> Self {
+        self.batch_interval = batch_interval;
+        self
+    }
+
+    pub fn max_nonce_resyncs(mut self, max_nonce_resyncs: usize) -> Self {
+        self.max_nonce_resyncs = max_nonce_resyncs;
+        self
+    }
+
+    /// Estimates gas limits on the network, adding the given safety margin, e.g. 10 for 10%.
+    pub fn estimate_gas(mut self, margin_percent: u64) -> Self {
+        self.gas_estimation_margin_percent = Some(margin_percent);
+        self
+    }
+
+    pub fn simulate(mut self, simulate: bool) -> Self {
+        self.simulate = simulate;
+        self
+    }
+}
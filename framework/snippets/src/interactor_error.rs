@@ -0,0 +1,86 @@
+use std::{fmt, time::Duration};
+
+use multiversx_sc_scenario::{bech32, multiversx_sc::types::Address, scenario_model::TxResponse};
+use multiversx_sdk::{data::transaction::TransactionOnNetwork, gateway::GatewayError};
+
+/// Everything that can go wrong in the fallible (`try_*`) interactor API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InteractorError {
+    /// The gateway could not be reached, or rejected the request.
+    Gateway(GatewayError),
+    /// The transaction could not be signed, e.g. because the sender wallet is not registered.
+    Signing(String),
+    /// The transaction was executed, but failed.
+    TxFailed {
+        tx_hash: String,
+        status: u64,
+        message: String,
+    },
+    /// The simulation run before sending the transaction failed, so it was not sent.
+    SimulationFailed { status: u64, message: String },
+    /// The transaction did not complete in time. It might still complete later.
+    Timeout { tx_hash: String, timeout: Duration },
+    /// A response could not be decoded.
+    Decode(String),
+}
+
+impl InteractorError {
+    pub(crate) fn unknown_sender(address: &Address) -> Self {
+        InteractorError::Signing(format!(
+            "the wallet that was supposed to sign is not registered: {}",
+            bech32::encode(address)
+        ))
+    }
+
+    /// Errors worth retrying: the same operation might succeed later.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            InteractorError::Gateway(err) => err.is_transient(),
+            InteractorError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for InteractorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InteractorError::Gateway(err) => write!(f, "{err}"),
+            InteractorError::Signing(message) => write!(f, "signing error: {message}"),
+            InteractorError::TxFailed {
+                tx_hash,
+                status,
+                message,
+            } => write!(f, "tx {tx_hash} failed with status {status}: {message}"),
+            InteractorError::SimulationFailed { status, message } => {
+                write!(f, "tx simulation failed with status {status}: {message}")
+            },
+            InteractorError::Timeout { tx_hash, timeout } => {
+                write!(f, "tx {tx_hash} still not completed after {timeout:?}")
+            },
+            InteractorError::Decode(message) => write!(f, "decode error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for InteractorError {}
+
+impl From<GatewayError> for InteractorError {
+    fn from(err: GatewayError) -> Self {
+        InteractorError::Gateway(err)
+    }
+}
+
+/// Turns a failed transaction into an error.
+pub(crate) fn check_tx_success(tx: &TransactionOnNetwork) -> Result<(), InteractorError> {
+    let response = TxResponse::from_network_tx(tx.clone());
+    if response.is_success() {
+        Ok(())
+    } else {
+        Err(InteractorError::TxFailed {
+            tx_hash: tx.hash.clone().unwrap_or_default(),
+            status: response.tx_error.status,
+            message: response.tx_error.message,
+        })
+    }
+}
@@ -1,22 +1,34 @@
 use crate::{
-    interactor_multi_sc_process::{update_nonces_and_sign_tx, SenderSet, Txs},
-    Interactor, StepBuffer, TransactionSpec,
+    interactor_multi_sc_process::{SenderSet, Txs},
+    Interactor, InteractorError, StepBuffer, TransactionSpec,
 };
 
 use multiversx_sc_scenario::scenario_model::TxResponse;
 use multiversx_sdk::data::transaction::Transaction;
 
 impl Interactor {
-    pub async fn multi_sc_exec(&mut self, mut buffer: StepBuffer<'_>) {
+    pub async fn multi_sc_exec(&mut self, buffer: StepBuffer<'_>) {
+        self.try_multi_sc_exec(buffer)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"));
+    }
+
+    /// Same as `multi_sc_exec`, but reports errors instead of panicking.
+    ///
+    /// Failed transactions are not reported as errors, their outcome is saved in their steps.
+    pub async fn try_multi_sc_exec(
+        &mut self,
+        mut buffer: StepBuffer<'_>,
+    ) -> Result<(), InteractorError> {
         for step in buffer.refs.iter_mut() {
             step.run_step(&mut self.pre_runners);
         }
 
         let senders = retrieve_senders(buffer.refs.as_slice());
-        self.recall_senders_nonce(senders).await;
+        self.try_recall_senders_nonce(senders).await?;
 
         let txs = self.retrieve_txs(&mut buffer);
-        let results = self.process_txs(txs).await;
+        let results = self.process_txs(txs).await?;
 
         for (i, sc_call_step) in buffer.refs.iter_mut().enumerate() {
             sc_call_step.set_response(TxResponse::from_network_tx(results.get(i).unwrap().clone()));
@@ -25,21 +37,15 @@ impl Interactor {
         for step in buffer.refs.iter_mut() {
             step.run_step(&mut self.post_runners);
         }
+        Ok(())
     }
 
+    /// Unsigned transactions, they only get signed when their batch is sent.
     fn retrieve_txs(&mut self, buffer: &mut StepBuffer<'_>) -> Vec<Transaction> {
         let mut txs = Txs::new();
 
         for sc_call_step in &mut buffer.refs {
-            let mut transaction = sc_call_step.to_transaction(self);
-            let sender_address = &sc_call_step.to_address().value;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            update_nonces_and_sign_tx(&mut transaction, sender);
-            txs.push(transaction);
+            txs.push(sc_call_step.to_transaction(self));
         }
         txs
     }
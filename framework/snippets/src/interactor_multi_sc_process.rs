@@ -1,47 +1,113 @@
-use crate::{multiversx_sc::types::Address, Interactor, Sender};
+use crate::{
+    erdrs_address_to_h256, interactor_retrieve::sleep, interactor_sender::is_nonce_error,
+    multiversx_sc::types::Address, Interactor, InteractorError,
+};
 use futures::future::join_all;
+use log::info;
 use multiversx_sdk::data::transaction::{Transaction, TransactionOnNetwork};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) type Txs = Vec<Transaction>;
 pub(crate) type SenderSet = HashSet<Address>;
 
 impl Interactor {
-    pub(crate) async fn recall_senders_nonce(&mut self, senders: HashSet<Address>) {
+    pub(crate) async fn try_recall_senders_nonce(
+        &mut self,
+        senders: HashSet<Address>,
+    ) -> Result<(), InteractorError> {
         for sender_address in &senders {
-            let nonce = self.recall_nonce(sender_address).await;
-            let sender = self
-                .sender_map
-                .get_mut(sender_address)
-                .expect("sender not registered");
-
-            sender.current_nonce = Some(nonce);
-            println!("sender's recalled nonce: {nonce}");
+            self.ensure_nonce(sender_address).await?;
         }
+        Ok(())
     }
 
-    pub(crate) async fn process_txs(&mut self, txs: Vec<Transaction>) -> Vec<TransactionOnNetwork> {
-        let mut futures = Vec::new();
+    /// Signs and sends transactions in batches, then waits for all of them.
+    ///
+    /// Each batch is signed right before it is sent, so that it continues from the nonces accepted so far.
+    /// A transaction missing from the accepted ones is sent again on its own, to find out why.
+    /// If its nonce was rejected, it is signed again with a nonce that follows both the network nonce
+    /// and the highest nonce already accepted from its sender, since those are still pending.
+    /// Any other rejection is returned.
+    pub(crate) async fn process_txs(
+        &mut self,
+        txs: Vec<Transaction>,
+    ) -> Result<Vec<TransactionOnNetwork>, InteractorError> {
+        let mut tx_hashes = Vec::with_capacity(txs.len());
+        let mut highest_accepted_nonces = HashMap::<Address, u64>::new();
+        let mut waiting_time_ms = 0;
 
-        for tx in &txs {
-            let tx_hash = self
-                .proxy
-                .send_transaction(tx)
-                .await
-                .expect("failed to send transaction");
+        for (batch_index, batch) in txs.chunks(self.config.batch_size.get()).enumerate() {
+            if batch_index > 0 && !self.config.batch_interval.is_zero() {
+                sleep(&mut waiting_time_ms, self.config.batch_interval).await;
+            }
 
-            println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(self.retrieve_tx_on_network(tx_hash.clone()));
+            let mut batch = batch.to_vec();
+            for tx in &mut batch {
+                let sender_address = erdrs_address_to_h256(tx.sender.clone());
+                self.sender_mut(&sender_address)?.set_nonce_and_sign_tx(tx);
+            }
+            let accepted = self.proxy.send_transactions_indexed(&batch).await?;
+            for &index in accepted.keys() {
+                record_accepted_nonce(&mut highest_accepted_nonces, &batch[index]);
+            }
+
+            for (index, mut tx) in batch.into_iter().enumerate() {
+                let sender_address = erdrs_address_to_h256(tx.sender.clone());
+                let tx_hash = match accepted.get(&index) {
+                    Some(tx_hash) => tx_hash.clone(),
+                    None => {
+                        let tx_hash = self
+                            .resend_rejected_tx(&sender_address, &mut tx, &highest_accepted_nonces)
+                            .await?;
+                        record_accepted_nonce(&mut highest_accepted_nonces, &tx);
+                        tx_hash
+                    },
+                };
+
+                println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
+                tx_hashes.push(tx_hash);
+            }
         }
 
-        join_all(futures).await
+        join_all(
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| self.try_retrieve_tx_on_network(tx_hash)),
+        )
+        .await
+        .into_iter()
+        .collect()
     }
-}
 
-pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &mut Sender) {
-    transaction.nonce = sender.current_nonce.unwrap();
-    sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
+    async fn resend_rejected_tx(
+        &mut self,
+        sender_address: &Address,
+        tx: &mut Transaction,
+        highest_accepted_nonces: &HashMap<Address, u64>,
+    ) -> Result<String, InteractorError> {
+        match self.proxy.send_transaction(tx).await {
+            Ok(tx_hash) => Ok(tx_hash),
+            Err(err) if is_nonce_error(&err) => {
+                info!("nonce {} rejected: {err}", tx.nonce);
+                let network_nonce = self.try_recall_nonce(sender_address).await?;
+                let next_nonce = highest_accepted_nonces
+                    .get(sender_address)
+                    .map_or(network_nonce, |&highest_nonce| {
+                        network_nonce.max(highest_nonce + 1)
+                    });
+                let sender = self.sender_mut(sender_address)?;
+                sender.current_nonce = Some(next_nonce);
+                sender.set_nonce_and_sign_tx(tx);
+                Ok(self.proxy.send_transaction(tx).await?)
+            },
+            Err(err) => Err(err.into()),
+        }
+    }
+}
 
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
+fn record_accepted_nonce(highest_accepted_nonces: &mut HashMap<Address, u64>, tx: &Transaction) {
+    let highest_nonce = highest_accepted_nonces
+        .entry(erdrs_address_to_h256(tx.sender.clone()))
+        .or_insert(tx.nonce);
+    *highest_nonce = (*highest_nonce).max(tx.nonce);
 }
@@ -1,59 +1,87 @@
-use crate::Interactor;
+use crate::{Interactor, InteractorError};
 use log::info;
 use multiversx_sdk::data::transaction::TransactionOnNetwork;
-use std::time::Duration;
-
-const TX_GET_RESULTS_NUM_RETRIES: usize = 8;
-const EXTRA_WAITING_TIME_MS: Duration = Duration::from_millis(8000);
-const WAITING_TIME_MS: Duration = Duration::from_secs(25);
-const WAIT: u64 = 1000;
+use std::time::{Duration, Instant};
 
 impl Interactor {
-    /// Retrieves a transaction from the network.
+    /// Waits for a transaction to complete, then retrieves it from the network, with results.
+    ///
+    /// Panics if the transaction does not complete in time.
     pub(crate) async fn retrieve_tx_on_network(&self, tx_hash: String) -> TransactionOnNetwork {
+        self.try_retrieve_tx_on_network(tx_hash)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Waits for a transaction to complete, then retrieves it from the network, with results.
+    ///
+    /// Polls the transaction status, then waits for the transaction to be notarized in a hyperblock,
+    /// plus the configured number of confirmation blocks.
+    pub async fn try_retrieve_tx_on_network(
+        &self,
+        tx_hash: String,
+    ) -> Result<TransactionOnNetwork, InteractorError> {
+        let start = Instant::now();
         let mut waiting_time_ms = 0;
-        let mut break_outer = false;
-        sleep(&mut waiting_time_ms, WAITING_TIME_MS).await;
 
-        let tx = 'outer: loop {
-            let mut retries = TX_GET_RESULTS_NUM_RETRIES;
-            let mut wait = WAIT;
-            loop {
-                let tx_info_result = self.proxy.get_transaction_info_with_results(&tx_hash).await;
-                match tx_info_result {
-                    Ok(tx) => {
-                        if break_outer {
-                            break 'outer tx;
-                        }
+        let tx = loop {
+            if let Some(tx) = self.poll_completed_tx(&tx_hash).await {
+                break tx;
+            }
 
-                        // reset waiting time
-                        waiting_time_ms = WAITING_TIME_MS.as_millis() as u64;
+            if start.elapsed() >= self.config.tx_timeout {
+                return Err(InteractorError::Timeout {
+                    tx_hash,
+                    timeout: self.config.tx_timeout,
+                });
+            }
+            sleep(&mut waiting_time_ms, self.config.polling_interval).await;
+        };
 
-                        tokio::time::sleep(EXTRA_WAITING_TIME_MS).await;
-                        break_outer = true;
+        info!("tx {tx_hash} completed after {waiting_time_ms} ms");
+        info!("tx with results: {:#?}", tx);
+        Ok(tx)
+    }
 
-                        break;
-                    },
-                    Err(err) => {
-                        assert!(
-                            retries > 0,
-                            "still no answer after {TX_GET_RESULTS_NUM_RETRIES} retries"
-                        );
+    /// Yields the transaction once it is final and confirmed, `None` while still waiting.
+    async fn poll_completed_tx(&self, tx_hash: &str) -> Option<TransactionOnNetwork> {
+        let status = match self.proxy.get_transaction_status(tx_hash).await {
+            Ok(status) => status,
+            Err(err) => {
+                // freshly sent transactions can take a while to become visible
+                info!("tx {tx_hash} status fetch error: {err}");
+                return None;
+            },
+        };
+        if status.is_pending() {
+            return None;
+        }
 
-                        info!(
-                            "tx result fetch error after {} ms: {}",
-                            self.waiting_time_ms, err
-                        );
-                        retries -= 1;
-                        sleep(&mut waiting_time_ms, Duration::from_millis(wait)).await;
-                        wait *= 2;
-                    },
-                }
-            }
+        let tx = match self.proxy.get_transaction_info_with_results(tx_hash).await {
+            Ok(tx) => tx,
+            Err(err) => {
+                info!("tx {tx_hash} fetch error: {err}");
+                return None;
+            },
         };
+        if tx.hyperblock_nonce == 0 {
+            return None;
+        }
 
-        info!("tx with results: {:#?}", tx);
-        tx
+        if self.config.confirmation_blocks > 0 {
+            let latest_nonce = self.proxy.get_latest_hyper_block_nonce(false).await.ok()?;
+            if latest_nonce < tx.hyperblock_nonce + self.config.confirmation_blocks {
+                return None;
+            }
+            // results produced in the meantime are only visible on a fresh fetch
+            return self
+                .proxy
+                .get_transaction_info_with_results(tx_hash)
+                .await
+                .ok();
+        }
+
+        Some(tx)
     }
 }
 
@@ -1,4 +1,7 @@
-use crate::{address_h256_to_erdrs, mandos_to_erdrs_address, Interactor};
+use crate::{
+    address_h256_to_erdrs, interactor_error::check_tx_success, mandos_to_erdrs_address, Interactor,
+    InteractorError,
+};
 use log::info;
 use multiversx_sc_scenario::{
     api::StaticApi,
@@ -6,7 +9,7 @@ use multiversx_sc_scenario::{
     scenario::ScenarioRunner,
     scenario_model::{ScCallStep, SetStateStep, TxCall, TxResponse},
 };
-use multiversx_sdk::data::transaction::Transaction;
+use multiversx_sdk::data::transaction::{Transaction, TransactionOnNetwork};
 
 impl Interactor {
     pub async fn sc_call<S>(&mut self, mut sc_call_step: S)
@@ -14,9 +17,49 @@ impl Interactor {
         S: AsMut<ScCallStep>,
     {
         let sc_call_step = sc_call_step.as_mut();
-        let tx_hash = self.launch_sc_call(sc_call_step).await;
-        let tx = self.retrieve_tx_on_network(tx_hash.clone()).await;
+        let tx = self
+            .send_sc_call(sc_call_step)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"));
+        self.process_sc_call_response(sc_call_step, tx);
+    }
+
+    /// Same as `sc_call`, but reports errors instead of panicking.
+    ///
+    /// Failed transactions are also reported as errors. Their response is still saved in the step
+    /// and recorded by the post-runners, with the expectation built from the failure instead of
+    /// being checked against it.
+    pub async fn try_sc_call<S>(&mut self, mut sc_call_step: S) -> Result<(), InteractorError>
+    where
+        S: AsMut<ScCallStep>,
+    {
+        let sc_call_step = sc_call_step.as_mut();
+        let tx = self.send_sc_call(sc_call_step).await?;
+        let tx_status = check_tx_success(&tx);
+        if tx_status.is_err() {
+            if let Some(expect) = &mut sc_call_step.expect {
+                if expect.build_from_response {
+                    *expect = TxResponse::from_network_tx(tx.clone()).to_expect();
+                }
+            }
+        }
+        self.process_sc_call_response(sc_call_step, tx);
+        tx_status
+    }
+
+    async fn send_sc_call(
+        &mut self,
+        sc_call_step: &mut ScCallStep,
+    ) -> Result<TransactionOnNetwork, InteractorError> {
+        let tx_hash = self.launch_sc_call(sc_call_step).await?;
+        self.try_retrieve_tx_on_network(tx_hash).await
+    }
 
+    fn process_sc_call_response(
+        &mut self,
+        sc_call_step: &mut ScCallStep,
+        tx: TransactionOnNetwork,
+    ) {
         sc_call_step.save_response(TxResponse::from_network_tx(tx));
 
         if let Some(token_identifier) = sc_call_step.response().new_issued_token_identifier.clone()
@@ -31,18 +74,21 @@ impl Interactor {
         self.post_runners.run_sc_call_step(sc_call_step);
     }
 
-    async fn launch_sc_call(&mut self, sc_call_step: &mut ScCallStep) -> String {
+    async fn launch_sc_call(
+        &mut self,
+        sc_call_step: &mut ScCallStep,
+    ) -> Result<String, InteractorError> {
         self.pre_runners.run_sc_call_step(sc_call_step);
 
         let sender_address = &sc_call_step.tx.from.value;
         let mut transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self.proxy.send_transaction(&transaction).await.unwrap();
+        let tx_hash = self
+            .sign_and_send_tx(sender_address, &mut transaction)
+            .await?;
         println!("sc call tx hash: {tx_hash}");
         info!("sc call tx hash: {}", tx_hash);
 
-        tx_hash
+        Ok(tx_hash)
     }
 
     pub(crate) fn tx_call_to_blockchain_tx(&self, tx_call: &TxCall) -> Transaction {
@@ -56,7 +102,7 @@ impl Interactor {
 
         Transaction {
             nonce: 0,
-            value: contract_call.egld_payment.to_alloc().to_string(),
+            value: contract_call.egld_payment.to_alloc(),
             sender: mandos_to_erdrs_address(&tx_call.from),
             receiver: address_h256_to_erdrs(&contract_call.basic.to.to_address()),
             gas_price: self.network_config.min_gas_price,
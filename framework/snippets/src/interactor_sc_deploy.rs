@@ -1,11 +1,16 @@
-use crate::{mandos_to_erdrs_address, Interactor};
+use crate::{
+    interactor_error::check_tx_success, mandos_to_erdrs_address, Interactor, InteractorError,
+};
 use log::info;
 use multiversx_sc_scenario::{
     bech32,
     mandos_system::ScenarioRunner,
     scenario_model::{ScDeployStep, SetStateStep, TxResponse},
 };
-use multiversx_sdk::data::{address::Address as ErdrsAddress, transaction::Transaction};
+use multiversx_sdk::data::{
+    address::Address as ErdrsAddress,
+    transaction::{Transaction, TransactionOnNetwork},
+};
 
 const DEPLOY_RECEIVER: [u8; 32] = [0u8; 32];
 
@@ -13,7 +18,7 @@ impl Interactor {
     pub(crate) fn sc_deploy_to_blockchain_tx(&self, sc_deploy_step: &ScDeployStep) -> Transaction {
         Transaction {
             nonce: 0,
-            value: sc_deploy_step.tx.egld_value.value.to_string(),
+            value: sc_deploy_step.tx.egld_value.value.clone(),
             sender: mandos_to_erdrs_address(&sc_deploy_step.tx.from),
             receiver: ErdrsAddress::from_bytes(DEPLOY_RECEIVER),
             gas_price: self.network_config.min_gas_price,
@@ -27,21 +32,26 @@ impl Interactor {
     }
 
     pub async fn launch_sc_deploy(&mut self, sc_deploy_step: &mut ScDeployStep) -> String {
+        self.try_launch_sc_deploy(sc_deploy_step)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub async fn try_launch_sc_deploy(
+        &mut self,
+        sc_deploy_step: &mut ScDeployStep,
+    ) -> Result<String, InteractorError> {
         self.pre_runners.run_sc_deploy_step(sc_deploy_step);
 
         let sender_address = &sc_deploy_step.tx.from.value;
         let mut transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
         let tx_hash = self
-            .proxy
-            .send_transaction(&transaction)
-            .await
-            .expect("error sending tx (possible API failure)");
+            .sign_and_send_tx(sender_address, &mut transaction)
+            .await?;
         println!("sc deploy tx hash: {tx_hash}");
         info!("sc deploy tx hash: {}", tx_hash);
 
-        tx_hash
+        Ok(tx_hash)
     }
 
     pub async fn sc_deploy<S>(&mut self, mut sc_deploy_step: S)
@@ -49,9 +59,34 @@ impl Interactor {
         S: AsMut<ScDeployStep>,
     {
         let sc_deploy_step = sc_deploy_step.as_mut();
-        let tx_hash = self.launch_sc_deploy(sc_deploy_step).await;
-        let tx = self.retrieve_tx_on_network(tx_hash.clone()).await;
+        let tx_hash = self
+            .try_launch_sc_deploy(sc_deploy_step)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"));
+        let tx = self.retrieve_tx_on_network(tx_hash).await;
+        self.process_sc_deploy_response(sc_deploy_step, tx);
+    }
+
+    /// Same as `sc_deploy`, but reports errors instead of panicking.
+    ///
+    /// Failed deploys are also reported as errors, without checking the step expectations.
+    pub async fn try_sc_deploy<S>(&mut self, mut sc_deploy_step: S) -> Result<(), InteractorError>
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        let sc_deploy_step = sc_deploy_step.as_mut();
+        let tx_hash = self.try_launch_sc_deploy(sc_deploy_step).await?;
+        let tx = self.try_retrieve_tx_on_network(tx_hash).await?;
+        check_tx_success(&tx)?;
+        self.process_sc_deploy_response(sc_deploy_step, tx);
+        Ok(())
+    }
 
+    fn process_sc_deploy_response(
+        &mut self,
+        sc_deploy_step: &mut ScDeployStep,
+        tx: TransactionOnNetwork,
+    ) {
         let addr = sc_deploy_step.tx.from.clone();
         let nonce = tx.nonce;
         sc_deploy_step.save_response(TxResponse::from_network_tx(tx));
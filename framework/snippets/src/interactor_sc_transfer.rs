@@ -1,23 +1,45 @@
-use crate::Interactor;
+use crate::{interactor_error::check_tx_success, Interactor, InteractorError};
 use log::info;
 use multiversx_sc_scenario::{scenario::ScenarioRunner, scenario_model::TransferStep};
+use multiversx_sdk::data::transaction::TransactionOnNetwork;
 
 impl Interactor {
     pub async fn transfer(&mut self, transfer_step: TransferStep) -> String {
+        let (tx_hash, _) = self
+            .perform_transfer(transfer_step)
+            .await
+            .unwrap_or_else(|err| panic!("{err}"));
+        tx_hash
+    }
+
+    /// Same as `transfer`, but reports errors, including failed transfers, instead of panicking.
+    pub async fn try_transfer(
+        &mut self,
+        transfer_step: TransferStep,
+    ) -> Result<String, InteractorError> {
+        let (tx_hash, tx) = self.perform_transfer(transfer_step).await?;
+        check_tx_success(&tx)?;
+        Ok(tx_hash)
+    }
+
+    async fn perform_transfer(
+        &mut self,
+        transfer_step: TransferStep,
+    ) -> Result<(String, TransactionOnNetwork), InteractorError> {
         self.pre_runners.run_transfer_step(&transfer_step);
 
         let sender_address = &transfer_step.tx.from.value;
         let mut transaction = self.tx_call_to_blockchain_tx(&transfer_step.tx.to_tx_call());
-        self.set_nonce_and_sign_tx(sender_address, &mut transaction)
-            .await;
-        let tx_hash = self.proxy.send_transaction(&transaction).await.unwrap();
+        let tx_hash = self
+            .sign_and_send_tx(sender_address, &mut transaction)
+            .await?;
         println!("transfer tx hash: {tx_hash}");
         info!("transfer tx hash: {}", tx_hash);
 
-        self.retrieve_tx_on_network(tx_hash.clone()).await;
+        let tx = self.try_retrieve_tx_on_network(tx_hash.clone()).await?;
 
         self.post_runners.run_transfer_step(&transfer_step);
 
-        tx_hash
+        Ok((tx_hash, tx))
     }
 }
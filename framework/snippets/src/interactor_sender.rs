@@ -1,56 +1,132 @@
-use log::debug;
+use log::{debug, info};
 use multiversx_sc_scenario::multiversx_sc::types::Address;
-use multiversx_sdk::{data::transaction::Transaction, wallet::Wallet};
+use multiversx_sdk::{data::transaction::Transaction, gateway::GatewayError, wallet::Wallet};
 
-use crate::{address_h256_to_erdrs, Interactor};
+use crate::{address_h256_to_erdrs, Interactor, InteractorError};
 
 /// A user account that can sign transactions (a pem is present).
 pub struct Sender {
     pub address: Address,
     pub wallet: Wallet,
+    /// Nonce of the next transaction, tracked locally.
+    ///
+    /// `None` until the nonce is first recalled from the network.
     pub current_nonce: Option<u64>,
 }
 
+impl Sender {
+    /// Assigns the next nonce to the transaction and signs it.
+    pub(crate) fn set_nonce_and_sign_tx(&mut self, transaction: &mut Transaction) {
+        let nonce = self
+            .current_nonce
+            .expect("sender nonce not recalled from the network");
+        transaction.nonce = nonce;
+        self.current_nonce = Some(nonce + 1);
+
+        let signature = self.wallet.sign_tx(transaction);
+        transaction.signature = Some(hex::encode(signature));
+        debug!("transaction {:#?}", transaction);
+    }
+}
+
+/// Markers of a rejected nonce, as reported by the network when a transaction is submitted.
+const NONCE_ERROR_MARKERS: &[&str] = &[
+    "lowerNonceInTx: true",
+    "veryHighNonceInTx: true",
+    "lower nonce in transaction",
+    "higher nonce in transaction",
+];
+
+/// Whether the network rejected the transaction because of its nonce, and nothing else.
+pub(crate) fn is_nonce_error(err: &GatewayError) -> bool {
+    match err {
+        GatewayError::Api { message, .. } => NONCE_ERROR_MARKERS
+            .iter()
+            .any(|marker| message.contains(marker)),
+        _ => false,
+    }
+}
+
 impl Interactor {
     pub async fn recall_nonce(&self, address: &Address) -> u64 {
+        self.try_recall_nonce(address)
+            .await
+            .unwrap_or_else(|err| panic!("failed to retrieve account nonce: {err}"))
+    }
+
+    pub async fn try_recall_nonce(&self, address: &Address) -> Result<u64, InteractorError> {
         let erdrs_address = address_h256_to_erdrs(address);
-        let account = self
-            .proxy
-            .get_account(&erdrs_address)
+        let account = self.proxy.get_account(&erdrs_address).await?;
+        Ok(account.nonce)
+    }
+
+    /// Replaces the locally tracked nonce with the one on the network.
+    ///
+    /// Only needed when the account is also used outside this interactor,
+    /// since rejected nonces trigger a resync automatically.
+    pub async fn resync_nonce(&mut self, address: &Address) -> u64 {
+        self.try_resync_nonce(address)
             .await
-            .expect("failed to retrieve account nonce");
-        account.nonce
+            .unwrap_or_else(|err| panic!("failed to resync nonce: {err}"))
+    }
+
+    pub async fn try_resync_nonce(&mut self, address: &Address) -> Result<u64, InteractorError> {
+        let nonce = self.try_recall_nonce(address).await?;
+        println!("sender's recalled nonce: {nonce}");
+        self.sender_mut(address)?.current_nonce = Some(nonce);
+        Ok(nonce)
+    }
+
+    /// Recalls the nonce from the network, unless it is already tracked.
+    pub(crate) async fn ensure_nonce(&mut self, address: &Address) -> Result<(), InteractorError> {
+        if self.sender_mut(address)?.current_nonce.is_none() {
+            self.try_resync_nonce(address).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sender_mut(&mut self, address: &Address) -> Result<&mut Sender, InteractorError> {
+        self.sender_map
+            .get_mut(address)
+            .ok_or_else(|| InteractorError::unknown_sender(address))
     }
 
     pub(crate) async fn set_nonce_and_sign_tx(
         &mut self,
         sender_address: &Address,
         transaction: &mut Transaction,
-    ) {
-        // read
-        let sender = self
-            .sender_map
-            .get(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
-
-        // recall
-        let nonce = self.recall_nonce(&sender.address).await;
-        println!("sender's recalled nonce: {nonce}");
-
-        // set tx nonce
-        transaction.nonce = nonce;
+    ) -> Result<(), InteractorError> {
+        self.ensure_nonce(sender_address).await?;
+        self.sender_mut(sender_address)?
+            .set_nonce_and_sign_tx(transaction);
         println!("-- tx nonce: {}", transaction.nonce);
+        Ok(())
+    }
 
-        // update
-        let sender = self
-            .sender_map
-            .get_mut(sender_address)
-            .expect("the wallet that was supposed to sign is not registered");
-        sender.current_nonce = Some(nonce + 1);
+    /// Signs and sends a transaction, using the locally tracked nonce.
+    ///
+    /// Gas estimation and simulation are applied first, if configured.
+    /// If the network rejects the nonce, it is recalled and the transaction is signed and sent again.
+    pub(crate) async fn sign_and_send_tx(
+        &mut self,
+        sender_address: &Address,
+        transaction: &mut Transaction,
+    ) -> Result<String, InteractorError> {
+        self.prepare_tx(sender_address, transaction).await?;
 
-        // sign
-        let signature = sender.wallet.sign_tx(transaction);
-        transaction.signature = Some(hex::encode(signature));
-        debug!("transaction {:#?}", transaction);
+        let mut resyncs = 0;
+        loop {
+            self.set_nonce_and_sign_tx(sender_address, transaction)
+                .await?;
+            match self.proxy.send_transaction(transaction).await {
+                Ok(tx_hash) => return Ok(tx_hash),
+                Err(err) if is_nonce_error(&err) && resyncs < self.config.max_nonce_resyncs => {
+                    info!("nonce {} rejected: {err}", transaction.nonce);
+                    self.try_resync_nonce(sender_address).await?;
+                    resyncs += 1;
+                },
+                Err(err) => return Err(err.into()),
+            }
+        }
     }
 }
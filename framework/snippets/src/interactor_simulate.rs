@@ -0,0 +1,125 @@
+use crate::{Interactor, InteractorError};
+use log::info;
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::vm_err_msg::RETURN_CODE_USER_ERROR,
+    multiversx_sc::types::Address,
+    scenario_model::{ScCallStep, ScDeployStep, TxResponse, TxResponseStatus},
+};
+use multiversx_sdk::data::transaction::Transaction;
+
+impl Interactor {
+    /// Asks the network how much gas the transaction needs, without the safety margin.
+    pub async fn estimate_gas(&self, transaction: &Transaction) -> Result<u64, InteractorError> {
+        let cost = self.proxy.request_transaction_cost(transaction).await?;
+        if cost.tx_gas_units == 0 {
+            return Err(InteractorError::SimulationFailed {
+                status: RETURN_CODE_USER_ERROR,
+                message: cost.return_message,
+            });
+        }
+        Ok(cost.tx_gas_units)
+    }
+
+    /// Simulates the transaction on the network. Simulation failures are reported in the response.
+    pub async fn simulate_tx(
+        &self,
+        transaction: &Transaction,
+    ) -> Result<TxResponse, InteractorError> {
+        let result = self.proxy.simulate_transaction(transaction).await?;
+        info!("tx simulation result: {:#?}", result);
+        Ok(TxResponse::from_simulation_result(result))
+    }
+
+    /// Simulates a call as the next transaction of its sender, without sending it.
+    pub async fn simulate_sc_call<S>(
+        &mut self,
+        mut sc_call_step: S,
+    ) -> Result<TxResponse, InteractorError>
+    where
+        S: AsMut<ScCallStep>,
+    {
+        let sc_call_step = sc_call_step.as_mut();
+        let transaction = self.tx_call_to_blockchain_tx(&sc_call_step.tx);
+        self.simulate_as_next_tx(&sc_call_step.tx.from.value, transaction)
+            .await
+    }
+
+    /// Simulates a deploy as the next transaction of its sender, without sending it.
+    pub async fn simulate_sc_deploy<S>(
+        &mut self,
+        mut sc_deploy_step: S,
+    ) -> Result<TxResponse, InteractorError>
+    where
+        S: AsMut<ScDeployStep>,
+    {
+        let sc_deploy_step = sc_deploy_step.as_mut();
+        let transaction = self.sc_deploy_to_blockchain_tx(sc_deploy_step);
+        self.simulate_as_next_tx(&sc_deploy_step.tx.from.value, transaction)
+            .await
+    }
+
+    /// Failed gas estimations are reported as failed simulations, since they also come from executing the transaction.
+    async fn simulate_as_next_tx(
+        &mut self,
+        sender_address: &Address,
+        mut transaction: Transaction,
+    ) -> Result<TxResponse, InteractorError> {
+        match self
+            .prepare_simulation(sender_address, &mut transaction)
+            .await
+        {
+            Ok(()) => self.simulate_tx(&transaction).await,
+            Err(InteractorError::SimulationFailed { status, message }) => Ok(TxResponse {
+                tx_error: TxResponseStatus { status, message },
+                ..Default::default()
+            }),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn prepare_simulation(
+        &mut self,
+        sender_address: &Address,
+        transaction: &mut Transaction,
+    ) -> Result<(), InteractorError> {
+        self.ensure_nonce(sender_address).await?;
+        transaction.nonce = self.sender_mut(sender_address)?.current_nonce.unwrap();
+        self.apply_gas_estimation(transaction).await
+    }
+
+    async fn apply_gas_estimation(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<(), InteractorError> {
+        if let Some(margin_percent) = self.config.gas_estimation_margin_percent {
+            let estimated_gas = self.estimate_gas(transaction).await?;
+            transaction.gas_limit =
+                estimated_gas.saturating_add(estimated_gas.saturating_mul(margin_percent) / 100);
+            println!("-- estimated gas limit: {}", transaction.gas_limit);
+        }
+        Ok(())
+    }
+
+    /// Applies gas estimation and simulation, as configured, to a transaction about to be signed.
+    pub(crate) async fn prepare_tx(
+        &mut self,
+        sender_address: &Address,
+        transaction: &mut Transaction,
+    ) -> Result<(), InteractorError> {
+        if self.config.gas_estimation_margin_percent.is_none() && !self.config.simulate {
+            return Ok(());
+        }
+
+        self.prepare_simulation(sender_address, transaction).await?;
+        if self.config.simulate {
+            let response = self.simulate_tx(transaction).await?;
+            if !response.is_success() {
+                return Err(InteractorError::SimulationFailed {
+                    status: response.tx_error.status,
+                    message: response.tx_error.message,
+                });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_sc::types::{ManagedAddress, Tx, TxBaseWithEnv, TxEnv},
+    scenario_format::interpret_trait::InterpreterContext,
+    world_tx::{ScenarioTxEnv, ScenarioTxEnvData},
+};
+
+use crate::Interactor;
+
+/// Default gas limit for interactor transactions, when none is specified.
+const DEFAULT_GAS: u64 = 5_000_000;
+
+/// Sends transactions to the blockchain, via the interactor.
+pub struct InteractorEnvExec<'w> {
+    pub interactor: &'w mut Interactor,
+    pub data: ScenarioTxEnvData,
+}
+
+impl<'w> TxEnv for InteractorEnvExec<'w> {
+    type Api = StaticApi;
+
+    fn resolve_sender_address(&self) -> ManagedAddress<StaticApi> {
+        panic!(
+            "interactor transactions need an explicit sender, please specify it using `.from(...)`"
+        )
+    }
+
+    fn default_gas(&self) -> u64 {
+        DEFAULT_GAS
+    }
+}
+
+impl<'w> ScenarioTxEnv for InteractorEnvExec<'w> {
+    fn env_data(&self) -> &ScenarioTxEnvData {
+        &self.data
+    }
+
+    fn env_data_mut(&mut self) -> &mut ScenarioTxEnvData {
+        &mut self.data
+    }
+}
+
+/// Performs VM queries on the blockchain, via the interactor.
+pub struct InteractorEnvQuery<'w> {
+    pub interactor: &'w mut Interactor,
+    pub data: ScenarioTxEnvData,
+}
+
+impl<'w> TxEnv for InteractorEnvQuery<'w> {
+    type Api = StaticApi;
+
+    fn resolve_sender_address(&self) -> ManagedAddress<StaticApi> {
+        panic!("queries do not have a sender")
+    }
+
+    fn default_gas(&self) -> u64 {
+        DEFAULT_GAS
+    }
+}
+
+impl<'w> ScenarioTxEnv for InteractorEnvQuery<'w> {
+    fn env_data(&self) -> &ScenarioTxEnvData {
+        &self.data
+    }
+
+    fn env_data_mut(&mut self) -> &mut ScenarioTxEnvData {
+        &mut self.data
+    }
+}
+
+fn new_env_data() -> ScenarioTxEnvData {
+    let current_dir = std::env::current_dir().unwrap();
+    ScenarioTxEnvData::with_interpreter_context(
+        InterpreterContext::default()
+            .with_dir(current_dir)
+            .with_allowed_missing_files(),
+    )
+}
+
+impl Interactor {
+    /// Starts building a transaction, using the same builder as contracts and tests.
+    ///
+    /// It gets sent by calling `prepare_async().run().await`.
+    pub fn tx(&mut self) -> TxBaseWithEnv<InteractorEnvExec<'_>> {
+        Tx::new_with_env(InteractorEnvExec {
+            interactor: self,
+            data: new_env_data(),
+        })
+    }
+
+    /// Starts building a VM query, using the same builder as contracts and tests.
+    pub fn query(&mut self) -> TxBaseWithEnv<InteractorEnvQuery<'_>> {
+        Tx::new_with_env(InteractorEnvQuery {
+            interactor: self,
+            data: new_env_data(),
+        })
+    }
+}
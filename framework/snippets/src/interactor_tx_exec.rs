@@ -0,0 +1,172 @@
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    multiversx_sc::types::{
+        Code, DeployCall, FunctionCall, Tx, TxFrom, TxGas, TxPayment, TxPaymentEgldOnly,
+        TxRunnableResultHandler, TxToSpecified,
+    },
+    scenario_model::{ScCallStep, ScDeployStep, ScQueryStep, TransferStep, TxResponse},
+    world_tx::{
+        tx_to_sc_call_step, tx_to_sc_deploy_step, tx_to_sc_query_step, tx_to_transfer_step,
+        TxCodeValue,
+    },
+};
+
+use crate::{Interactor, InteractorEnvExec, InteractorEnvQuery};
+
+/// Interactor transactions are sent asynchronously, so they are first converted into a step that can be awaited.
+pub trait InteractorPrepareAsync {
+    type Exec;
+
+    fn prepare_async(self) -> Self::Exec;
+}
+
+/// A call, ready to be sent.
+pub struct InteractorCallStep<'w, RH> {
+    interactor: &'w mut Interactor,
+    step: ScCallStep,
+    result_handler: RH,
+}
+
+impl<'w, RH> InteractorCallStep<'w, RH>
+where
+    RH: TxRunnableResultHandler<TxResponse, InteractorEnvExec<'w>>,
+{
+    pub async fn run(mut self) -> RH::Returns {
+        self.interactor.sc_call(&mut self.step).await;
+        self.result_handler.process_result(self.step.response())
+    }
+}
+
+impl<'w, From, To, Payment, Gas, RH> InteractorPrepareAsync
+    for Tx<InteractorEnvExec<'w>, From, To, Payment, Gas, FunctionCall<StaticApi>, RH>
+where
+    From: TxFrom<InteractorEnvExec<'w>>,
+    To: TxToSpecified<InteractorEnvExec<'w>>,
+    Payment: TxPayment<InteractorEnvExec<'w>>,
+    Gas: TxGas<InteractorEnvExec<'w>>,
+    RH: TxRunnableResultHandler<TxResponse, InteractorEnvExec<'w>>,
+{
+    type Exec = InteractorCallStep<'w, RH>;
+
+    fn prepare_async(self) -> Self::Exec {
+        let (env, step, result_handler) = tx_to_sc_call_step(self);
+        InteractorCallStep {
+            interactor: env.interactor,
+            step,
+            result_handler,
+        }
+    }
+}
+
+/// A deploy, ready to be sent.
+pub struct InteractorDeployStep<'w, RH> {
+    interactor: &'w mut Interactor,
+    step: ScDeployStep,
+    result_handler: RH,
+}
+
+impl<'w, RH> InteractorDeployStep<'w, RH>
+where
+    RH: TxRunnableResultHandler<TxResponse, InteractorEnvExec<'w>>,
+{
+    pub async fn run(mut self) -> RH::Returns {
+        self.interactor.sc_deploy(&mut self.step).await;
+        self.result_handler.process_result(self.step.response())
+    }
+}
+
+impl<'w, From, Payment, Gas, CodeValue, RH> InteractorPrepareAsync
+    for Tx<
+        InteractorEnvExec<'w>,
+        From,
+        (),
+        Payment,
+        Gas,
+        DeployCall<InteractorEnvExec<'w>, Code<CodeValue>>,
+        RH,
+    >
+where
+    From: TxFrom<InteractorEnvExec<'w>>,
+    Payment: TxPaymentEgldOnly<InteractorEnvExec<'w>>,
+    Gas: TxGas<InteractorEnvExec<'w>>,
+    CodeValue: TxCodeValue,
+    RH: TxRunnableResultHandler<TxResponse, InteractorEnvExec<'w>>,
+{
+    type Exec = InteractorDeployStep<'w, RH>;
+
+    fn prepare_async(self) -> Self::Exec {
+        let (env, step, result_handler) = tx_to_sc_deploy_step(self);
+        InteractorDeployStep {
+            interactor: env.interactor,
+            step,
+            result_handler,
+        }
+    }
+}
+
+/// A simple transfer, ready to be sent.
+pub struct InteractorTransferStep<'w> {
+    interactor: &'w mut Interactor,
+    step: TransferStep,
+}
+
+impl<'w> InteractorTransferStep<'w> {
+    /// Sends the transfer and returns the transaction hash.
+    pub async fn run(self) -> String {
+        self.interactor.transfer(self.step).await
+    }
+}
+
+impl<'w, From, To, Payment, Gas> InteractorPrepareAsync
+    for Tx<InteractorEnvExec<'w>, From, To, Payment, Gas, (), ()>
+where
+    From: TxFrom<InteractorEnvExec<'w>>,
+    To: TxToSpecified<InteractorEnvExec<'w>>,
+    Payment: TxPayment<InteractorEnvExec<'w>>,
+    Gas: TxGas<InteractorEnvExec<'w>>,
+{
+    type Exec = InteractorTransferStep<'w>;
+
+    fn prepare_async(self) -> Self::Exec {
+        let (env, step) = tx_to_transfer_step(self);
+        InteractorTransferStep {
+            interactor: env.interactor,
+            step,
+        }
+    }
+}
+
+/// A VM query, ready to be performed.
+pub struct InteractorQueryStep<'w, RH> {
+    interactor: &'w mut Interactor,
+    step: ScQueryStep,
+    result_handler: RH,
+}
+
+impl<'w, RH> InteractorQueryStep<'w, RH>
+where
+    RH: TxRunnableResultHandler<TxResponse, InteractorEnvQuery<'w>>,
+{
+    pub async fn run(mut self) -> RH::Returns {
+        self.interactor.perform_sc_query(&mut self.step).await;
+        self.result_handler.process_result(self.step.response())
+    }
+}
+
+impl<'w, To, RH> InteractorPrepareAsync
+    for Tx<InteractorEnvQuery<'w>, (), To, (), (), FunctionCall<StaticApi>, RH>
+where
+    To: TxToSpecified<InteractorEnvQuery<'w>>,
+    RH: TxRunnableResultHandler<TxResponse, InteractorEnvQuery<'w>>,
+{
+    type Exec = InteractorQueryStep<'w, RH>;
+
+    fn prepare_async(self) -> Self::Exec {
+        let (env, step, result_handler) = tx_to_sc_query_step(self);
+        InteractorQueryStep {
+            interactor: env.interactor,
+            step,
+            result_handler,
+        }
+    }
+}
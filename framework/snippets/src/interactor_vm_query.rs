@@ -1,4 +1,4 @@
-use crate::{address_h256_to_erdrs, Interactor};
+use crate::{address_h256_to_erdrs, Interactor, InteractorError};
 use log::info;
 use multiversx_sc_scenario::{
     api::StaticApi,
@@ -18,6 +18,23 @@ impl Interactor {
     }
 
     pub async fn perform_sc_query(&mut self, step: &mut ScQueryStep) {
+        self.try_perform_sc_query(step)
+            .await
+            .unwrap_or_else(|err| panic!("error executing VM query: {err}"));
+    }
+
+    /// Same as `sc_query`, but reports errors instead of panicking.
+    pub async fn try_sc_query<S>(&mut self, mut step: S) -> Result<(), InteractorError>
+    where
+        S: AsMut<ScQueryStep>,
+    {
+        self.try_perform_sc_query(step.as_mut()).await
+    }
+
+    async fn try_perform_sc_query(
+        &mut self,
+        step: &mut ScQueryStep,
+    ) -> Result<(), InteractorError> {
         let sc_address = address_h256_to_erdrs(&step.tx.to.to_address());
         let req = VmValueRequest {
             sc_address: sc_address.clone(),
@@ -31,24 +48,25 @@ impl Interactor {
             caller: sc_address,
             value: "0".to_string(),
         };
-        let result = self
-            .proxy
-            .execute_vmquery(&req)
-            .await
-            .expect("error executing VM query");
+        let result = self.proxy.execute_vmquery(&req).await?;
 
         info!("{:#?}", result);
 
-        let raw_results: Vec<Vec<u8>> = result
+        let raw_results = result
             .data
             .return_data
             .iter()
-            .map(|result| base64::decode(result).expect("query result base64 decode error"))
-            .collect();
+            .map(|result| {
+                base64::decode(result).map_err(|err| {
+                    InteractorError::Decode(format!("query result base64 decode error: {err}"))
+                })
+            })
+            .collect::<Result<Vec<Vec<u8>>, InteractorError>>()?;
         step.save_response(TxResponse::from_raw_results(raw_results));
 
         self.pre_runners.run_sc_query_step(step);
         self.post_runners.run_sc_query_step(step);
+        Ok(())
     }
 
     #[deprecated(since = "0.42.0", note = "Was renamed to `quick_query`.")]
@@ -1,5 +1,8 @@
+mod event_decoder;
 mod interactor;
+mod interactor_config;
 mod interactor_dns;
+mod interactor_error;
 mod interactor_multi_sc_exec;
 mod interactor_multi_sc_process;
 mod interactor_retrieve;
@@ -8,15 +11,24 @@ mod interactor_sc_deploy;
 mod interactor_sc_extra;
 mod interactor_sc_transfer;
 mod interactor_sender;
+mod interactor_simulate;
+mod interactor_tx_env;
+mod interactor_tx_exec;
 mod interactor_tx_spec;
 mod interactor_vm_query;
+pub mod local_chain;
 mod step_buffer;
 
 pub use env_logger;
+pub use event_decoder::*;
 pub use hex;
 pub use interactor::*;
+pub use interactor_config::*;
 pub use interactor_dns::*;
+pub use interactor_error::InteractorError;
 pub use interactor_sender::*;
+pub use interactor_tx_env::*;
+pub use interactor_tx_exec::*;
 pub use interactor_tx_spec::*;
 pub use log;
 pub use multiversx_sc_scenario::{self, multiversx_sc};
@@ -0,0 +1,16 @@
+//! In-process stand-in for a MultiversX gateway.
+//!
+//! Wraps the Rust VM behind the same REST endpoints that [`CommunicationProxy`](crate::sdk::blockchain::CommunicationProxy)
+//! calls, so interactor scripts can be pointed at `localhost` and run offline.
+//! [`LocalChainTransport`] skips HTTP altogether and plugs the chain directly into the proxy.
+
+mod local_chain_config;
+mod local_chain_exec;
+mod local_chain_server;
+mod local_chain_state;
+mod local_chain_transport;
+
+pub use local_chain_config::LocalChainConfig;
+pub use local_chain_server::{LocalChainServer, LocalChainServerHandle};
+pub use local_chain_state::{LocalChain, LocalChainError};
+pub use local_chain_transport::LocalChainTransport;
@@ -0,0 +1,66 @@
+use multiversx_sdk::data::network_config::NetworkConfig;
+
+pub const LOCAL_CHAIN_ID: &str = "local-testnet";
+
+/// Network parameters reported by the local chain on `/network/config`.
+#[derive(Debug, Clone)]
+pub struct LocalChainConfig {
+    pub chain_id: String,
+    pub min_gas_price: u64,
+    pub min_gas_limit: u64,
+    pub gas_per_data_byte: u64,
+    pub min_transaction_version: u32,
+    /// Round duration, in milliseconds.
+    pub round_duration: u64,
+    /// Timestamp of the genesis block, in seconds.
+    pub start_time: u64,
+    pub rounds_per_epoch: u64,
+    /// Execution gas reported by `/transaction/cost` for contract calls and deploys,
+    /// on top of the data cost. The Rust VM does not meter gas, so this is a flat allowance.
+    pub sc_execution_gas: u64,
+}
+
+impl Default for LocalChainConfig {
+    fn default() -> Self {
+        LocalChainConfig {
+            chain_id: LOCAL_CHAIN_ID.to_string(),
+            min_gas_price: 1_000_000_000,
+            min_gas_limit: 50_000,
+            gas_per_data_byte: 1_500,
+            min_transaction_version: 1,
+            round_duration: 6_000,
+            start_time: 0,
+            rounds_per_epoch: 2_400,
+            sc_execution_gas: 5_000_000,
+        }
+    }
+}
+
+impl LocalChainConfig {
+    /// Checks the parameters the chain relies on, e.g. the ones it divides by.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.rounds_per_epoch == 0 {
+            return Err("rounds_per_epoch must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn to_network_config(&self) -> NetworkConfig {
+        NetworkConfig {
+            chain_id: self.chain_id.clone(),
+            denomination: 18,
+            gas_per_data_byte: self.gas_per_data_byte,
+            latest_tag_software_version: "local".to_string(),
+            meta_consensus_group_size: 1,
+            min_gas_limit: self.min_gas_limit,
+            min_gas_price: self.min_gas_price,
+            min_transaction_version: self.min_transaction_version,
+            num_metachain_nodes: 1,
+            num_nodes_in_shard: 1,
+            num_shards_without_meta: 1,
+            round_duration: self.round_duration as i64,
+            shard_consensus_group_size: 1,
+            start_time: self.start_time as i64,
+        }
+    }
+}
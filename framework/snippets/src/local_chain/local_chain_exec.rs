@@ -0,0 +1,492 @@
+use std::{
+    collections::HashMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::{
+        crypto_functions::keccak256,
+        tx_execution::execute_current_tx_context_input,
+        tx_mock::{TxFunctionName, TxInput, TxLog, TxResult},
+        types::{VMAddress, VMCodeMetadata, H256},
+    },
+    num_bigint::BigUint,
+};
+use multiversx_sdk::data::{
+    address::Address as ErdrsAddress,
+    hyperblock::HyperBlock,
+    transaction::{
+        ApiLogs, ApiSmartContractResult, Events, Transaction, TransactionOnNetwork,
+        TxCostResponseData, TxSimulationResult,
+    },
+    tx_status::TxStatus,
+    vm::{CallType, VMOutputApi, VmValueRequest},
+};
+
+use super::{local_chain_state::transaction_hash, LocalChain, LocalChainError};
+
+const LOG_IDENTIFIER_SC_DEPLOY: &str = "SCDeploy";
+const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+const VM_TYPE_WASM: [u8; 2] = [5, 0];
+const RETURN_CODE_OK_HEX: &str = "6f6b";
+
+/// A transaction that was accepted, but not yet included in a block.
+pub(super) fn pending_tx_on_network(hash: &str, tx: &Transaction) -> TransactionOnNetwork {
+    TransactionOnNetwork {
+        kind: "normal".to_string(),
+        hash: Some(hash.to_string()),
+        nonce: tx.nonce,
+        round: 0,
+        epoch: 0,
+        value: tx.value.clone(),
+        receiver: tx.receiver.clone(),
+        sender: tx.sender.clone(),
+        gas_price: tx.gas_price,
+        gas_limit: tx.gas_limit,
+        signature: tx.signature.clone().unwrap_or_default(),
+        source_shard: 0,
+        destination_shard: 0,
+        block_nonce: 0,
+        block_hash: String::new(),
+        notarized_at_source_in_meta_nonce: 0,
+        notarized_at_source_in_meta_hash: String::new(),
+        notarized_at_destination_in_meta_nonce: 0,
+        notarized_at_destination_in_meta_hash: String::new(),
+        miniblock_type: "TxBlock".to_string(),
+        miniblock_hash: String::new(),
+        timestamp: 0,
+        data: tx.data.clone(),
+        status: TxStatus::Pending,
+        hyperblock_nonce: 0,
+        hyperblock_hash: String::new(),
+        smart_contract_results: None,
+        logs: None,
+    }
+}
+
+/// Mirrors the address derivation of the protocol:
+/// 8 zero bytes, the VM type, 20 bytes of `keccak256(creator | nonce)`, then the creator shard bytes.
+pub(super) fn compute_new_address(creator: &VMAddress, creator_nonce: u64) -> VMAddress {
+    let mut hash_input = creator.to_vec();
+    hash_input.extend_from_slice(&creator_nonce.to_le_bytes());
+    let hash = keccak256(&hash_input);
+
+    let mut address = [0u8; 32];
+    address[8..10].copy_from_slice(&VM_TYPE_WASM);
+    address[10..30].copy_from_slice(&hash[10..30]);
+    address[30..].copy_from_slice(&creator.as_array()[30..]);
+    address.into()
+}
+
+/// Decoded form of the transaction `data` field.
+enum TxDataKind {
+    Deploy {
+        code: Vec<u8>,
+        code_metadata: VMCodeMetadata,
+        args: Vec<Vec<u8>>,
+    },
+    Call {
+        func_name: TxFunctionName,
+        args: Vec<Vec<u8>>,
+    },
+}
+
+fn decode_tx_data(tx: &Transaction) -> Result<TxDataKind, String> {
+    let data = match &tx.data {
+        Some(data) => {
+            let decoded = base64::decode(data).map_err(|_| "data is not base64".to_string())?;
+            String::from_utf8(decoded).map_err(|_| "data is not valid UTF-8".to_string())?
+        },
+        None => String::new(),
+    };
+
+    let mut parts = data.split('@');
+    let first = parts.next().unwrap_or_default();
+    let args = parts
+        .map(hex::decode)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| "arguments are not hex-encoded".to_string())?;
+
+    if tx.receiver.to_bytes() == [0u8; 32] {
+        let code = hex::decode(first).map_err(|_| "code is not hex-encoded".to_string())?;
+        // the VM type and code metadata precede the constructor arguments
+        if args.len() < 2 {
+            return Err("missing VM type or code metadata".to_string());
+        }
+        let code_metadata = <[u8; 2]>::try_from(args[1].as_slice())
+            .map_err(|_| "code metadata must be 2 bytes".to_string())?;
+        Ok(TxDataKind::Deploy {
+            code,
+            code_metadata: VMCodeMetadata::from(code_metadata),
+            args: args[2..].to_vec(),
+        })
+    } else {
+        Ok(TxDataKind::Call {
+            func_name: first.to_string().into(),
+            args,
+        })
+    }
+}
+
+fn return_code_message(status: u64) -> &'static str {
+    match status {
+        0 => "ok",
+        1 => "function not found",
+        2 => "wrong signature",
+        3 => "contract not found",
+        4 => "user error",
+        5 => "out of gas",
+        6 => "account collision",
+        7 => "out of funds",
+        8 => "call stack overflow",
+        9 => "contract invalid",
+        10 => "execution failed",
+        _ => "unknown error",
+    }
+}
+
+fn erdrs_address(address: &VMAddress) -> ErdrsAddress {
+    ErdrsAddress::from_bytes(*address.as_array())
+}
+
+fn log_to_event(log: &TxLog) -> Events {
+    Events {
+        address: erdrs_address(&log.address),
+        identifier: log.endpoint.as_str().to_string(),
+        topics: Some(log.topics.iter().map(base64::encode).collect()),
+        data: Some(base64::encode(log.data.concat())),
+    }
+}
+
+impl LocalChain {
+    /// Runs a transaction from the mempool and records its outcome in the given block.
+    pub(super) fn execute_transaction(
+        &mut self,
+        hash: &str,
+        tx: &Transaction,
+        block: &HyperBlock,
+    ) -> TransactionOnNetwork {
+        let mut tx_on_network = pending_tx_on_network(hash, tx);
+        tx_on_network.round = block.round;
+        tx_on_network.epoch = block.epoch;
+        tx_on_network.block_nonce = block.nonce;
+        tx_on_network.block_hash = block.hash.clone();
+        tx_on_network.notarized_at_source_in_meta_nonce = block.nonce;
+        tx_on_network.notarized_at_source_in_meta_hash = block.hash.clone();
+        tx_on_network.notarized_at_destination_in_meta_nonce = block.nonce;
+        tx_on_network.notarized_at_destination_in_meta_hash = block.hash.clone();
+        tx_on_network.hyperblock_nonce = block.nonce;
+        tx_on_network.hyperblock_hash = block.hash.clone();
+        tx_on_network.timestamp = block.timestamp;
+
+        let sender = VMAddress::from(tx.sender.to_bytes());
+        let tx_input = TxInput {
+            from: sender.clone(),
+            to: VMAddress::from(tx.receiver.to_bytes()),
+            egld_value: tx.value.clone(),
+            gas_limit: tx.gas_limit,
+            gas_price: tx.gas_price,
+            tx_hash: H256::from_slice(&hex::decode(hash).unwrap()),
+            ..Default::default()
+        };
+
+        let outcome = match decode_tx_data(tx) {
+            Ok(data_kind) => {
+                catch_unwind(AssertUnwindSafe(|| self.run_tx_input(tx_input, data_kind)))
+                    .unwrap_or_else(|panic_obj| {
+                        let message = panic_obj
+                            .downcast_ref::<String>()
+                            .cloned()
+                            .or_else(|| panic_obj.downcast_ref::<&str>().map(|s| s.to_string()))
+                            .unwrap_or_else(|| "execution failed".to_string());
+                        (None, TxResult::from_panic_string(&message))
+                    })
+            },
+            Err(message) => {
+                // malformed data still consumes the nonce
+                self.vm_runner
+                    .blockchain_mock
+                    .state
+                    .increase_account_nonce(&sender);
+                (None, TxResult::from_panic_string(&message))
+            },
+        };
+
+        let (new_address, tx_result) = outcome;
+        let contract_address = new_address
+            .clone()
+            .unwrap_or_else(|| VMAddress::from(tx.receiver.to_bytes()));
+        let mut events = Vec::new();
+        let mut scrs = Vec::new();
+
+        if tx_result.result_status == 0 {
+            tx_on_network.status = TxStatus::Success;
+            if let Some(new_address) = &new_address {
+                events.push(Events {
+                    address: erdrs_address(new_address),
+                    identifier: LOG_IDENTIFIER_SC_DEPLOY.to_string(),
+                    topics: Some(vec![
+                        base64::encode(new_address.as_bytes()),
+                        base64::encode(sender.as_bytes()),
+                    ]),
+                    data: None,
+                });
+            }
+            events.extend(tx_result.result_logs.iter().map(log_to_event));
+
+            if new_address.is_some() || tx.data.is_some() {
+                let mut scr_data = format!("@{RETURN_CODE_OK_HEX}");
+                for value in &tx_result.result_values {
+                    scr_data.push('@');
+                    scr_data.push_str(&hex::encode(value));
+                }
+                scrs.push(ApiSmartContractResult {
+                    hash: hex::encode(keccak256(format!("{hash}-scr-0").as_bytes())),
+                    nonce: tx.nonce + 1,
+                    value: BigUint::default(),
+                    receiver: tx.sender.clone(),
+                    sender: erdrs_address(&contract_address),
+                    data: scr_data,
+                    prev_tx_hash: hash.to_string(),
+                    original_tx_hash: hash.to_string(),
+                    gas_limit: 0,
+                    gas_price: tx.gas_price,
+                    call_type: CallType::DirectCall,
+                    relayer_address: None,
+                    relayed_value: None,
+                    code: None,
+                    code_metadata: None,
+                    return_message: None,
+                    original_sender: None,
+                    logs: None,
+                });
+            }
+        } else {
+            tx_on_network.status = TxStatus::Fail;
+            events.push(Events {
+                address: tx.sender.clone(),
+                identifier: LOG_IDENTIFIER_SIGNAL_ERROR.to_string(),
+                topics: Some(vec![
+                    base64::encode(sender.as_bytes()),
+                    base64::encode(tx_result.result_message.as_bytes()),
+                ]),
+                data: Some(base64::encode(format!(
+                    "@{}",
+                    hex::encode(return_code_message(tx_result.result_status))
+                ))),
+            });
+        }
+
+        if !scrs.is_empty() {
+            tx_on_network.smart_contract_results = Some(scrs);
+        }
+        if !events.is_empty() {
+            tx_on_network.logs = Some(ApiLogs {
+                address: erdrs_address(&contract_address),
+                events,
+            });
+        }
+
+        tx_on_network
+    }
+
+    fn run_tx_input(
+        &mut self,
+        mut tx_input: TxInput,
+        data_kind: TxDataKind,
+    ) -> (Option<VMAddress>, TxResult) {
+        let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+        match data_kind {
+            TxDataKind::Deploy {
+                code,
+                code_metadata,
+                args,
+            } => {
+                let creator_nonce = blockchain_mock.state.accounts[&tx_input.from].nonce;
+                let new_address = compute_new_address(&tx_input.from, creator_nonce);
+                blockchain_mock.state.put_new_address(
+                    tx_input.from.clone(),
+                    creator_nonce,
+                    new_address.clone(),
+                );
+
+                tx_input.to = VMAddress::zero();
+                tx_input.func_name = TxFunctionName::INIT;
+                tx_input.args = args;
+                let (new_address, tx_result) = blockchain_mock.vm.sc_create(
+                    tx_input,
+                    &code,
+                    code_metadata,
+                    &mut blockchain_mock.state,
+                    execute_current_tx_context_input,
+                );
+                let new_address = (tx_result.result_status == 0).then_some(new_address);
+                (new_address, tx_result)
+            },
+            TxDataKind::Call { func_name, args } => {
+                tx_input.func_name = func_name;
+                tx_input.args = args;
+
+                // nonce gets increased irrespective of whether the tx fails or not
+                blockchain_mock.state.increase_account_nonce(&tx_input.from);
+                let tx_result = blockchain_mock.vm.sc_call_with_async_and_callback(
+                    tx_input,
+                    &mut blockchain_mock.state,
+                    execute_current_tx_context_input,
+                );
+                (None, tx_result)
+            },
+        }
+    }
+
+    /// Executes a view function without committing any state changes, as `/vm-values/query` does.
+    pub fn vm_query(&mut self, request: &VmValueRequest) -> Result<VMOutputApi, LocalChainError> {
+        let sc_address = VMAddress::from(request.sc_address.to_bytes());
+        if !self.state().accounts.contains_key(&sc_address) {
+            return Err(LocalChainError::NotFound(format!(
+                "contract not found: {}",
+                request.sc_address.to_string()
+            )));
+        }
+
+        let args = request
+            .args
+            .iter()
+            .map(hex::decode)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                LocalChainError::BadRequest("arguments are not hex-encoded".to_string())
+            })?;
+        let egld_value = if request.value.is_empty() {
+            BigUint::default()
+        } else {
+            request
+                .value
+                .parse::<BigUint>()
+                .map_err(|_| LocalChainError::BadRequest("invalid query value".to_string()))?
+        };
+        let tx_input = TxInput {
+            from: VMAddress::from(request.caller.to_bytes()),
+            to: sc_address,
+            egld_value,
+            func_name: request.func_name.clone().into(),
+            args,
+            gas_limit: u64::MAX,
+            gas_price: 0,
+            ..Default::default()
+        };
+
+        let blockchain_mock = &mut self.vm_runner.blockchain_mock;
+        let tx_result = catch_unwind(AssertUnwindSafe(|| {
+            blockchain_mock.vm.execute_sc_query_lambda(
+                tx_input,
+                &mut blockchain_mock.state,
+                execute_current_tx_context_input,
+            )
+        }))
+        .map_err(|_| LocalChainError::BadRequest("query execution failed".to_string()))?;
+
+        Ok(VMOutputApi {
+            return_data: tx_result.result_values.iter().map(base64::encode).collect(),
+            return_code: return_code_message(tx_result.result_status).to_string(),
+            return_message: tx_result.result_message,
+            gas_remaining: 0,
+            gas_refund: 0,
+            output_accounts: HashMap::new(),
+            deleted_accounts: None,
+            touched_accounts: None,
+            logs: None,
+        })
+    }
+
+    /// Executes a transaction against the current state, then rolls everything back,
+    /// as `/transaction/simulate` does. The signature is not checked.
+    pub fn simulate_transaction(
+        &mut self,
+        tx: &Transaction,
+    ) -> Result<TxSimulationResult, LocalChainError> {
+        self.validate_transaction(tx, false)?;
+        Ok(self.simulate_unchecked(tx))
+    }
+
+    /// Estimates the gas needed by a transaction, as `/transaction/cost` does.
+    ///
+    /// Contract calls and deploys are simulated, and cost the data gas plus
+    /// [`sc_execution_gas`](super::LocalChainConfig::sc_execution_gas).
+    /// Failed simulations are reported in the return message, with no gas units.
+    pub fn transaction_cost(
+        &mut self,
+        tx: &Transaction,
+    ) -> Result<TxCostResponseData, LocalChainError> {
+        let sender = VMAddress::from(tx.sender.to_bytes());
+        if !self.state().accounts.contains_key(&sender) {
+            return Err(LocalChainError::BadRequest(
+                "sender account does not exist".to_string(),
+            ));
+        }
+
+        let data_gas = self.data_gas(tx);
+        let receiver = VMAddress::from(tx.receiver.to_bytes());
+        let is_deploy = tx.receiver.to_bytes() == [0u8; 32];
+        let is_sc_call = self
+            .state()
+            .accounts
+            .get(&receiver)
+            .is_some_and(|account| account.contract_path.is_some());
+        if !is_deploy && !is_sc_call {
+            return Ok(TxCostResponseData {
+                tx_gas_units: data_gas,
+                return_message: String::new(),
+            });
+        }
+
+        let mut sim_tx = tx.clone();
+        sim_tx.gas_limit = data_gas + self.config.sc_execution_gas;
+        let result = self.simulate_unchecked(&sim_tx);
+        if result.status.is_success() {
+            Ok(TxCostResponseData {
+                tx_gas_units: sim_tx.gas_limit,
+                return_message: String::new(),
+            })
+        } else {
+            Ok(TxCostResponseData {
+                tx_gas_units: 0,
+                return_message: result.fail_reason,
+            })
+        }
+    }
+
+    fn simulate_unchecked(&mut self, tx: &Transaction) -> TxSimulationResult {
+        let hash = transaction_hash(tx);
+        let block = self.blocks.last().unwrap().clone();
+        let snapshot = (*self.vm_runner.blockchain_mock.state).clone();
+        let tx_on_network = self.execute_transaction(&hash, tx, &block);
+        *self.vm_runner.blockchain_mock.state = snapshot;
+
+        let fail_reason = tx_on_network
+            .logs
+            .as_ref()
+            .and_then(|logs| {
+                logs.events
+                    .iter()
+                    .find(|event| event.identifier == LOG_IDENTIFIER_SIGNAL_ERROR)
+            })
+            .and_then(|event| event.topics.as_ref()?.get(1).cloned())
+            .and_then(|topic| base64::decode(topic).ok())
+            .map(|message| String::from_utf8_lossy(&message).into_owned())
+            .unwrap_or_default();
+
+        TxSimulationResult {
+            status: tx_on_network.status,
+            fail_reason,
+            hash,
+            sc_results: tx_on_network
+                .smart_contract_results
+                .unwrap_or_default()
+                .into_iter()
+                .map(|scr| (scr.hash.clone(), scr))
+                .collect(),
+            logs: tx_on_network.logs,
+        }
+    }
+}
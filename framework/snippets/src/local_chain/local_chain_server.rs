@@ -0,0 +1,262 @@
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::SocketAddr,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use bech32::FromBase32;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use multiversx_sdk::data::{
+    address::Address as ErdrsAddress, transaction::Transaction, vm::VmValueRequest,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
+
+use super::{LocalChain, LocalChainError};
+
+const CODE_SUCCESSFUL: &str = "successful";
+const CODE_BAD_REQUEST: &str = "bad_request";
+const CODE_NOT_FOUND: &str = "not_found";
+
+/// Serves a [`LocalChain`] over HTTP, using the gateway REST API.
+///
+/// Besides the gateway endpoints, it accepts `POST /simulator/generate-blocks/{count}`,
+/// which is how tests drive block production when automatic block generation is off.
+pub struct LocalChainServer {
+    chain: Arc<Mutex<LocalChain>>,
+}
+
+/// A running [`LocalChainServer`]. The server stops when the handle is dropped.
+pub struct LocalChainServerHandle {
+    local_addr: SocketAddr,
+    chain: Arc<Mutex<LocalChain>>,
+    join_handle: JoinHandle<()>,
+}
+
+impl LocalChainServer {
+    pub fn new(chain: LocalChain) -> Self {
+        LocalChainServer {
+            chain: Arc::new(Mutex::new(chain)),
+        }
+    }
+
+    /// Shared access to the chain, for setting up state or generating blocks while serving.
+    pub fn chain(&self) -> Arc<Mutex<LocalChain>> {
+        self.chain.clone()
+    }
+
+    /// Binds to the given address and serves requests on the current tokio runtime.
+    ///
+    /// Use port 0 to get an OS-assigned port; the actual address is available on the handle.
+    pub fn start(self, addr: SocketAddr) -> Result<LocalChainServerHandle, hyper::Error> {
+        let chain = self.chain.clone();
+        let make_service = make_service_fn(move |_conn| {
+            let chain = chain.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle_request(chain.clone(), request)
+                }))
+            }
+        });
+
+        let server = Server::try_bind(&addr)?.serve(make_service);
+        let local_addr = server.local_addr();
+        let join_handle = tokio::spawn(async move {
+            if let Err(err) = server.await {
+                log::error!("local chain server error: {err}");
+            }
+        });
+
+        Ok(LocalChainServerHandle {
+            local_addr,
+            chain: self.chain,
+            join_handle,
+        })
+    }
+}
+
+impl LocalChainServerHandle {
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Gateway URL to pass to the `Interactor` or `CommunicationProxy`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.local_addr)
+    }
+
+    pub fn chain(&self) -> Arc<Mutex<LocalChain>> {
+        self.chain.clone()
+    }
+
+    pub fn generate_blocks(&self, num_blocks: u64) -> u64 {
+        self.chain
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generate_blocks(num_blocks)
+    }
+}
+
+impl Drop for LocalChainServerHandle {
+    fn drop(&mut self) {
+        self.join_handle.abort();
+    }
+}
+
+async fn handle_request(
+    chain: Arc<Mutex<LocalChain>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let (status, body) = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => process_request(&chain, &method, &path, &body),
+        Err(err) => error_body(LocalChainError::BadRequest(err.to_string())),
+    };
+    Ok(json_response(status, body))
+}
+
+/// Dispatches a gateway request to the chain, returning the status and the response envelope.
+///
+/// Shared by the HTTP server and the in-process transport.
+pub(super) fn process_request(
+    chain: &Mutex<LocalChain>,
+    method: &Method,
+    path: &str,
+    body: &[u8],
+) -> (StatusCode, Value) {
+    let path = path.trim_matches('/');
+    let segments: Vec<&str> = path.split('/').collect();
+    let mut chain = chain.lock().unwrap_or_else(PoisonError::into_inner);
+    // malformed addresses in request bodies make the SDK deserializer panic
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        route(&mut chain, method, segments.as_slice(), body)
+    }))
+    .unwrap_or_else(|_| {
+        Err(LocalChainError::BadRequest(
+            "request could not be processed".to_string(),
+        ))
+    });
+    match result {
+        Ok(data) => (
+            StatusCode::OK,
+            json!({ "data": data, "error": "", "code": CODE_SUCCESSFUL }),
+        ),
+        Err(err) => error_body(err),
+    }
+}
+
+fn route(
+    chain: &mut LocalChain,
+    method: &Method,
+    segments: &[&str],
+    body: &[u8],
+) -> Result<Value, LocalChainError> {
+    match (method, segments) {
+        (&Method::GET, ["network", "config"]) => Ok(json!({ "config": chain.network_config() })),
+        (&Method::GET, ["network", "status", ..]) => {
+            Ok(json!({ "status": chain.network_status() }))
+        },
+        (&Method::GET, ["address", address]) => {
+            Ok(json!({ "account": chain.account(&parse_address(address)?) }))
+        },
+        (&Method::GET, ["address", address, "keys"]) => {
+            Ok(json!({ "pairs": chain.account_storage(&parse_address(address)?) }))
+        },
+        (&Method::GET, ["address", address, "esdt"]) => {
+            Ok(json!({ "esdts": chain.account_esdt(&parse_address(address)?) }))
+        },
+        (&Method::POST, ["transaction", "send"]) => {
+            let tx: Transaction = parse_body(body)?;
+            let tx_hash = chain.send_transaction(tx)?;
+            Ok(json!({ "txHash": tx_hash }))
+        },
+        (&Method::POST, ["transaction", "cost"]) => {
+            let tx: Transaction = parse_body(body)?;
+            Ok(json!(chain.transaction_cost(&tx)?))
+        },
+        (&Method::POST, ["transaction", "simulate"]) => {
+            let tx: Transaction = parse_body(body)?;
+            Ok(json!({ "result": chain.simulate_transaction(&tx)? }))
+        },
+        (&Method::POST, ["transaction", "send-multiple"]) => {
+            let txs: Vec<Transaction> = parse_body(body)?;
+            // rejected transactions are left out, the rest are keyed by their index in the batch
+            let tx_hashes: HashMap<usize, String> = chain
+                .send_transactions(txs)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, result)| Some((index, result.ok()?)))
+                .collect();
+            Ok(json!({ "numOfSentTxs": tx_hashes.len(), "txsHashes": tx_hashes }))
+        },
+        (&Method::GET, ["transaction", hash]) => {
+            Ok(json!({ "transaction": chain.transaction(hash)? }))
+        },
+        (&Method::GET, ["transaction", hash, "status"]) => {
+            Ok(json!({ "status": chain.transaction_status(hash)? }))
+        },
+        (&Method::POST, ["vm-values", "query"]) => {
+            let request: VmValueRequest = parse_body(body)?;
+            Ok(json!({ "data": chain.vm_query(&request)? }))
+        },
+        (&Method::GET, ["hyperblock", "by-nonce", nonce]) => {
+            let nonce = nonce
+                .parse::<u64>()
+                .map_err(|_| LocalChainError::BadRequest(format!("invalid nonce: {nonce}")))?;
+            Ok(json!({ "hyperblock": chain.hyperblock_by_nonce(nonce)? }))
+        },
+        (&Method::GET, ["hyperblock", "by-hash", hash]) => {
+            Ok(json!({ "hyperblock": chain.hyperblock_by_hash(hash)? }))
+        },
+        (&Method::POST, ["simulator", "generate-blocks", count]) => {
+            let count = count.parse::<u64>().map_err(|_| {
+                LocalChainError::BadRequest(format!("invalid block count: {count}"))
+            })?;
+            Ok(json!({ "nonce": chain.generate_blocks(count) }))
+        },
+        _ => Err(LocalChainError::NotFound(format!(
+            "unknown endpoint: {method} /{}",
+            segments.join("/")
+        ))),
+    }
+}
+
+/// The SDK address parser panics on wrong lengths, so the address is decoded here instead.
+fn parse_address(bech32_address: &str) -> Result<ErdrsAddress, LocalChainError> {
+    let invalid = || LocalChainError::BadRequest(format!("invalid address: {bech32_address}"));
+    let (_, data, _) = bech32::decode(bech32_address).map_err(|_| invalid())?;
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|_| invalid())?;
+    let bytes: [u8; 32] = bytes.try_into().map_err(|_| invalid())?;
+    Ok(ErdrsAddress::from_bytes(bytes))
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, LocalChainError> {
+    serde_json::from_slice(body)
+        .map_err(|err| LocalChainError::BadRequest(format!("invalid request body: {err}")))
+}
+
+fn error_body(err: LocalChainError) -> (StatusCode, Value) {
+    let (status, code) = match &err {
+        LocalChainError::BadRequest(_) => (StatusCode::BAD_REQUEST, CODE_BAD_REQUEST),
+        LocalChainError::NotFound(_) => (StatusCode::NOT_FOUND, CODE_NOT_FOUND),
+    };
+    (
+        status,
+        json!({ "data": null, "error": err.to_string(), "code": code }),
+    )
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: T) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap()
+}
@@ -0,0 +1,557 @@
+use std::{collections::HashMap, fmt, path::PathBuf};
+
+use multiversx_sc_scenario::{
+    api::DebugApi,
+    debug_executor::ContractContainer,
+    multiversx_chain_vm::{
+        crypto_functions::{keccak256, verify_ed25519},
+        types::VMAddress,
+        world_mock::{AccountData, BlockInfo, BlockchainState, EsdtInstance},
+    },
+    multiversx_sc::contract_base::CallableContractBuilder,
+    num_bigint::BigUint,
+    scenario::{run_vm::ScenarioVMRunner, ScenarioRunner},
+    scenario_format::{
+        interpret_trait::{InterpretableFrom, InterpreterContext},
+        value_interpreter::interpret_string,
+    },
+    scenario_model::{BytesValue, SetStateStep},
+};
+use multiversx_sdk::data::{
+    account::Account,
+    address::Address as ErdrsAddress,
+    esdt::EsdtBalance,
+    hyperblock::{HyperBlock, ShardBlocks},
+    network_config::NetworkConfig,
+    network_status::NetworkStatus,
+    transaction::{Transaction, TransactionOnNetwork},
+    tx_status::TxStatus,
+};
+
+use super::{local_chain_exec::pending_tx_on_network, LocalChainConfig};
+
+const LOCAL_SHARD_ID: u32 = 0;
+
+/// Error reported back to gateway clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalChainError {
+    /// The request was malformed, or the transaction was rejected before entering the mempool.
+    BadRequest(String),
+    /// The requested transaction, block or contract does not exist.
+    NotFound(String),
+}
+
+impl fmt::Display for LocalChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalChainError::BadRequest(message) => write!(f, "{message}"),
+            LocalChainError::NotFound(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for LocalChainError {}
+
+pub(super) struct PendingTx {
+    pub hash: String,
+    pub tx: Transaction,
+}
+
+/// A single-shard chain that executes transactions with the Rust VM.
+///
+/// Contracts need to be registered upfront, exactly as in blackbox tests,
+/// since the VM runs the Rust contract implementations instead of WASM.
+///
+/// By default every transaction gets included in a new block as soon as it is sent.
+/// Call `set_auto_generate_blocks(false)` to keep transactions in the mempool
+/// until `generate_blocks` is called explicitly.
+pub struct LocalChain {
+    pub(super) config: LocalChainConfig,
+    current_dir: PathBuf,
+    pub(super) vm_runner: ScenarioVMRunner,
+    auto_generate_blocks: bool,
+    pub(super) mempool: Vec<PendingTx>,
+    pub(super) transactions: HashMap<String, TransactionOnNetwork>,
+    pub(super) blocks: Vec<HyperBlock>,
+}
+
+impl Default for LocalChain {
+    fn default() -> Self {
+        Self::new(LocalChainConfig::default())
+    }
+}
+
+impl LocalChain {
+    /// Panics if the configuration is invalid.
+    pub fn new(config: LocalChainConfig) -> Self {
+        Self::try_new(config).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as `new`, but reports an invalid configuration instead of panicking.
+    pub fn try_new(config: LocalChainConfig) -> Result<Self, String> {
+        config
+            .validate()
+            .map_err(|message| format!("invalid local chain config: {message}"))?;
+
+        let genesis = HyperBlock {
+            nonce: 0,
+            round: 0,
+            hash: hex::encode(keccak256(b"genesis")),
+            prev_block_hash: String::new(),
+            epoch: 0,
+            num_txs: 0,
+            shard_blocks: Vec::new(),
+            timestamp: config.start_time,
+            accumulated_fees: "0".to_string(),
+            developer_fees: "0".to_string(),
+            accumulated_fees_in_epoch: "0".to_string(),
+            developer_fees_in_epoch: "0".to_string(),
+            transactions: Vec::new(),
+        };
+        Ok(LocalChain {
+            config,
+            current_dir: std::env::current_dir().unwrap(),
+            vm_runner: ScenarioVMRunner::new(),
+            auto_generate_blocks: true,
+            mempool: Vec::new(),
+            transactions: HashMap::new(),
+            blocks: vec![genesis],
+        })
+    }
+
+    pub fn config(&self) -> &LocalChainConfig {
+        &self.config
+    }
+
+    pub fn set_current_dir(&mut self, current_dir: PathBuf) -> &mut Self {
+        self.current_dir = current_dir;
+        self
+    }
+
+    pub fn interpreter_context(&self) -> InterpreterContext {
+        InterpreterContext::default()
+            .with_dir(self.current_dir.clone())
+            .with_allowed_missing_files()
+    }
+
+    /// Resolves a code expression the same way deploy transactions are matched to registered contracts.
+    pub fn code_expression(&self, path: &str) -> BytesValue {
+        BytesValue::interpret_from(path, &self.interpreter_context())
+    }
+
+    /// Links a contract path to a contract implementation.
+    ///
+    /// Deploy transactions whose code matches the interpreted expression will run this contract.
+    pub fn register_contract<B: CallableContractBuilder>(
+        &mut self,
+        expression: &str,
+        contract_builder: B,
+    ) -> &mut Self {
+        let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.vm_runner.contract_map_ref.lock().register_contract(
+            contract_bytes,
+            ContractContainer::new(contract_builder.new_contract_obj::<DebugApi>(), None, false),
+        );
+        self
+    }
+
+    /// Sets up accounts, balances and storage directly, bypassing transactions.
+    pub fn set_state_step(&mut self, step: SetStateStep) -> &mut Self {
+        self.vm_runner.run_set_state_step(&step);
+        self
+    }
+
+    pub fn state(&self) -> &BlockchainState {
+        &self.vm_runner.blockchain_mock.state
+    }
+
+    pub fn auto_generate_blocks(&self) -> bool {
+        self.auto_generate_blocks
+    }
+
+    pub fn set_auto_generate_blocks(&mut self, auto_generate_blocks: bool) -> &mut Self {
+        self.auto_generate_blocks = auto_generate_blocks;
+        self
+    }
+
+    /// Nonce of the latest generated block.
+    pub fn current_block_nonce(&self) -> u64 {
+        self.blocks.last().unwrap().nonce
+    }
+
+    pub fn network_config(&self) -> NetworkConfig {
+        self.config.to_network_config()
+    }
+
+    pub fn network_status(&self) -> NetworkStatus {
+        let nonce = self.current_block_nonce();
+        let rounds_per_epoch = self.config.rounds_per_epoch;
+        let epoch_start = nonce - nonce % rounds_per_epoch;
+        NetworkStatus {
+            current_round: nonce,
+            epoch_number: nonce / rounds_per_epoch,
+            nonce,
+            nonce_at_epoch_start: epoch_start,
+            nonces_passed_in_current_epoch: nonce - epoch_start,
+            round_at_epoch_start: epoch_start,
+            rounds_passed_in_current_epoch: nonce - epoch_start,
+            rounds_per_epoch: rounds_per_epoch as u32,
+        }
+    }
+
+    /// Account view, as returned by `/address/{address}`.
+    ///
+    /// Unknown addresses are reported as empty accounts, like on a real network.
+    pub fn account(&self, address: &ErdrsAddress) -> Account {
+        let vm_address = VMAddress::from(address.to_bytes());
+        let empty = AccountData::new_empty(vm_address.clone());
+        let account = self.state().accounts.get(&vm_address).unwrap_or(&empty);
+        Account {
+            address: address.clone(),
+            nonce: account.nonce,
+            balance: account.egld_balance.clone(),
+            username: String::from_utf8_lossy(&account.username).into_owned(),
+            code: account
+                .contract_path
+                .as_ref()
+                .map(hex::encode)
+                .unwrap_or_default(),
+            code_hash: account
+                .contract_path
+                .as_ref()
+                .map(|code| base64::encode(keccak256(code))),
+            root_hash: None,
+            code_metadata: None,
+            developer_reward: Some(account.developer_rewards.to_string()),
+            owner_address: account
+                .contract_owner
+                .as_ref()
+                .map(|owner| ErdrsAddress::from_bytes(*owner.as_array()).to_string()),
+        }
+    }
+
+    /// Hex-encoded storage of an account, as returned by `/address/{address}/keys`.
+    pub fn account_storage(&self, address: &ErdrsAddress) -> HashMap<String, String> {
+        let vm_address = VMAddress::from(address.to_bytes());
+        self.state()
+            .accounts
+            .get(&vm_address)
+            .map(|account| {
+                account
+                    .storage
+                    .iter()
+                    .filter(|(_, value)| !value.is_empty())
+                    .map(|(key, value)| (hex::encode(key), hex::encode(value)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ESDT balances of an account, as returned by `/address/{address}/esdt`.
+    ///
+    /// Non-fungible instances are keyed by `<token identifier>-<nonce hex>`.
+    pub fn account_esdt(&self, address: &ErdrsAddress) -> HashMap<String, EsdtBalance> {
+        let vm_address = VMAddress::from(address.to_bytes());
+        let mut result = HashMap::new();
+        let Some(account) = self.state().accounts.get(&vm_address) else {
+            return result;
+        };
+        for (token_identifier, esdt_data) in account.esdt.iter() {
+            let token_identifier = String::from_utf8_lossy(token_identifier).into_owned();
+            for (nonce, instance) in esdt_data.instances.get_instances() {
+                if instance.balance == BigUint::default() {
+                    continue;
+                }
+                let key = if *nonce == 0 {
+                    token_identifier.clone()
+                } else {
+                    format!("{token_identifier}-{}", nonce_hex(*nonce))
+                };
+                result.insert(key.clone(), esdt_balance(key, *nonce, instance));
+            }
+        }
+        result
+    }
+
+    /// Validates a signed transaction and adds it to the mempool.
+    ///
+    /// Returns the transaction hash.
+    pub fn send_transaction(&mut self, tx: Transaction) -> Result<String, LocalChainError> {
+        self.validate_transaction(&tx, true)?;
+
+        let hash = transaction_hash(&tx);
+        if self.transactions.contains_key(&hash) {
+            return Err(LocalChainError::BadRequest(format!(
+                "transaction already exists: {hash}"
+            )));
+        }
+
+        self.transactions
+            .insert(hash.clone(), pending_tx_on_network(&hash, &tx));
+        self.mempool.push(PendingTx {
+            hash: hash.clone(),
+            tx,
+        });
+
+        if self.auto_generate_blocks {
+            self.generate_blocks(1);
+        }
+
+        Ok(hash)
+    }
+
+    /// Sends several transactions, rejecting them individually.
+    ///
+    /// Returns the outcome of each transaction, in the order they were given:
+    /// either its hash or the reason it was rejected.
+    pub fn send_transactions(
+        &mut self,
+        txs: Vec<Transaction>,
+    ) -> Vec<Result<String, LocalChainError>> {
+        let auto_generate_blocks = self.auto_generate_blocks;
+        self.auto_generate_blocks = false;
+        let results: Vec<_> = txs
+            .into_iter()
+            .map(|tx| self.send_transaction(tx))
+            .collect();
+        self.auto_generate_blocks = auto_generate_blocks;
+
+        if auto_generate_blocks && results.iter().any(Result::is_ok) {
+            self.generate_blocks(1);
+        }
+
+        results
+    }
+
+    /// Gas charged for moving the transaction, before any execution: the minimum plus the data cost.
+    pub(super) fn data_gas(&self, tx: &Transaction) -> u64 {
+        let data_len = tx.data.as_ref().map_or(0, |data| {
+            base64::decode(data).map_or(data.len(), |decoded| decoded.len())
+        }) as u64;
+        self.config.min_gas_limit + data_len * self.config.gas_per_data_byte
+    }
+
+    pub(super) fn validate_transaction(
+        &self,
+        tx: &Transaction,
+        check_signature: bool,
+    ) -> Result<(), LocalChainError> {
+        if tx.chain_id != self.config.chain_id {
+            return Err(LocalChainError::BadRequest(format!(
+                "invalid chain ID: expected {}, got {}",
+                self.config.chain_id, tx.chain_id
+            )));
+        }
+        if tx.version < self.config.min_transaction_version {
+            return Err(LocalChainError::BadRequest(
+                "invalid transaction version".to_string(),
+            ));
+        }
+        if tx.gas_price < self.config.min_gas_price {
+            return Err(LocalChainError::BadRequest(
+                "insufficient gas price in tx".to_string(),
+            ));
+        }
+
+        let min_gas_limit = self.data_gas(tx);
+        if tx.gas_limit < min_gas_limit {
+            return Err(LocalChainError::BadRequest(format!(
+                "insufficient gas limit in tx: minimum is {min_gas_limit}"
+            )));
+        }
+
+        if check_signature && !verify_signature(tx) {
+            return Err(LocalChainError::BadRequest(
+                "invalid transaction signature".to_string(),
+            ));
+        }
+
+        let sender = VMAddress::from(tx.sender.to_bytes());
+        let Some(sender_account) = self.state().accounts.get(&sender) else {
+            return Err(LocalChainError::BadRequest(
+                "insufficient funds: sender account does not exist".to_string(),
+            ));
+        };
+        if tx.nonce < sender_account.nonce {
+            return Err(LocalChainError::BadRequest(
+                "invalid transaction nonce: lowerNonceInTx: true, veryHighNonceInTx: false"
+                    .to_string(),
+            ));
+        }
+
+        let value = tx.value.clone();
+        let max_fee = BigUint::from(tx.gas_limit) * tx.gas_price;
+        if sender_account.egld_balance < value + max_fee {
+            return Err(LocalChainError::BadRequest(
+                "insufficient funds".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Produces the given number of blocks.
+    ///
+    /// Pending transactions are included in the first one, in nonce order for each sender.
+    /// Transactions with a nonce gap stay in the mempool.
+    ///
+    /// Returns the nonce of the latest block.
+    pub fn generate_blocks(&mut self, num_blocks: u64) -> u64 {
+        for _ in 0..num_blocks {
+            self.generate_block();
+        }
+        self.current_block_nonce()
+    }
+
+    fn generate_block(&mut self) {
+        let prev_block = self.blocks.last().unwrap();
+        let nonce = prev_block.nonce + 1;
+        let timestamp = self.config.start_time + nonce * self.config.round_duration / 1000;
+        let epoch = nonce / self.config.rounds_per_epoch;
+        let mut hash_input = nonce.to_be_bytes().to_vec();
+        hash_input.extend_from_slice(prev_block.hash.as_bytes());
+        let hash = hex::encode(keccak256(&hash_input));
+        let prev_block_hash = prev_block.hash.clone();
+
+        let state = &mut self.vm_runner.blockchain_mock.state;
+        state.previous_block_info = state.current_block_info.clone();
+        state.current_block_info = BlockInfo {
+            block_timestamp: timestamp,
+            block_nonce: nonce,
+            block_round: nonce,
+            block_epoch: epoch,
+            block_random_seed: Box::new(random_seed(&hash)),
+        };
+
+        let mut block = HyperBlock {
+            nonce,
+            round: nonce,
+            hash: hash.clone(),
+            prev_block_hash,
+            epoch,
+            num_txs: 0,
+            shard_blocks: vec![ShardBlocks {
+                hash,
+                nonce,
+                shard: LOCAL_SHARD_ID,
+            }],
+            timestamp,
+            accumulated_fees: "0".to_string(),
+            developer_fees: "0".to_string(),
+            accumulated_fees_in_epoch: "0".to_string(),
+            developer_fees_in_epoch: "0".to_string(),
+            transactions: Vec::new(),
+        };
+
+        while let Some(index) = self.next_executable_tx() {
+            let pending = self.mempool.remove(index);
+            let tx_on_network = self.execute_transaction(&pending.hash, &pending.tx, &block);
+            self.transactions
+                .insert(pending.hash, tx_on_network.clone());
+            block.transactions.push(tx_on_network);
+            block.num_txs += 1;
+        }
+
+        self.blocks.push(block);
+    }
+
+    fn next_executable_tx(&self) -> Option<usize> {
+        self.mempool.iter().position(|pending| {
+            let sender = VMAddress::from(pending.tx.sender.to_bytes());
+            self.state()
+                .accounts
+                .get(&sender)
+                .is_some_and(|account| account.nonce == pending.tx.nonce)
+        })
+    }
+
+    pub fn transaction(&self, hash: &str) -> Result<TransactionOnNetwork, LocalChainError> {
+        self.transactions
+            .get(hash)
+            .cloned()
+            .ok_or_else(|| LocalChainError::NotFound(format!("transaction not found: {hash}")))
+    }
+
+    pub fn transaction_status(&self, hash: &str) -> Result<TxStatus, LocalChainError> {
+        self.transaction(hash).map(|tx| tx.status)
+    }
+
+    pub fn hyperblock_by_nonce(&self, nonce: u64) -> Result<HyperBlock, LocalChainError> {
+        self.blocks
+            .get(nonce as usize)
+            .cloned()
+            .ok_or_else(|| LocalChainError::NotFound(format!("block not found: {nonce}")))
+    }
+
+    pub fn hyperblock_by_hash(&self, hash: &str) -> Result<HyperBlock, LocalChainError> {
+        self.blocks
+            .iter()
+            .find(|block| block.hash == hash)
+            .cloned()
+            .ok_or_else(|| LocalChainError::NotFound(format!("block not found: {hash}")))
+    }
+}
+
+fn esdt_balance(token_identifier: String, nonce: u64, instance: &EsdtInstance) -> EsdtBalance {
+    let metadata = &instance.metadata;
+    EsdtBalance {
+        token_identifier,
+        balance: instance.balance.clone(),
+        nonce,
+        name: (nonce != 0).then(|| String::from_utf8_lossy(&metadata.name).into_owned()),
+        creator: metadata
+            .creator
+            .as_ref()
+            .map(|creator| ErdrsAddress::from_bytes(*creator.as_array())),
+        royalties: metadata.royalties,
+        hash: metadata.hash.as_ref().map(base64::encode),
+        attributes: metadata.attributes.clone(),
+        uris: metadata.uri.clone(),
+    }
+}
+
+fn nonce_hex(nonce: u64) -> String {
+    let hex_nonce = format!("{nonce:x}");
+    if hex_nonce.len() % 2 == 1 {
+        format!("0{hex_nonce}")
+    } else {
+        hex_nonce
+    }
+}
+
+fn random_seed(block_hash: &str) -> [u8; 48] {
+    let mut seed = [0u8; 48];
+    let first = keccak256(block_hash.as_bytes());
+    let second = keccak256(&first);
+    seed[..32].copy_from_slice(&first);
+    seed[32..].copy_from_slice(&second[..16]);
+    seed
+}
+
+/// Serializes the transaction the same way the SDK wallet does before signing.
+fn signing_payload(tx: &Transaction) -> Vec<u8> {
+    let mut unsigned_tx = tx.clone();
+    unsigned_tx.signature = None;
+    let tx_bytes = serde_json::json!(unsigned_tx).to_string().into_bytes();
+
+    let should_sign_on_tx_hash = unsigned_tx.version >= 2 && unsigned_tx.options & 1 > 0;
+    if should_sign_on_tx_hash {
+        keccak256(&tx_bytes).to_vec()
+    } else {
+        tx_bytes
+    }
+}
+
+fn verify_signature(tx: &Transaction) -> bool {
+    let Some(signature) = tx.signature.as_ref().and_then(|sig| hex::decode(sig).ok()) else {
+        return false;
+    };
+    verify_ed25519(&tx.sender.to_bytes(), &signing_payload(tx), &signature)
+}
+
+/// The local chain identifies transactions by the Keccak-256 hash of their signed JSON form.
+///
+/// This is not the hash a real network would produce, but it is equally unique.
+pub(super) fn transaction_hash(tx: &Transaction) -> String {
+    hex::encode(keccak256(serde_json::json!(tx).to_string().as_bytes()))
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use hyper::Method;
+use multiversx_sdk::gateway::{
+    GatewayFuture, GatewayMethod, GatewayRawResponse, GatewayRequest, GatewayTransport,
+};
+
+use super::{local_chain_server::process_request, LocalChain};
+
+/// Serves gateway requests straight from a [`LocalChain`], without going through HTTP.
+///
+/// Plug it into a `CommunicationProxy` with `CommunicationProxy::with_transport`.
+#[derive(Clone)]
+pub struct LocalChainTransport {
+    chain: Arc<Mutex<LocalChain>>,
+}
+
+impl LocalChainTransport {
+    pub fn new(chain: LocalChain) -> Self {
+        Self::from_shared(Arc::new(Mutex::new(chain)))
+    }
+
+    /// Shares the chain with a server, or with the test setting up state.
+    pub fn from_shared(chain: Arc<Mutex<LocalChain>>) -> Self {
+        LocalChainTransport { chain }
+    }
+
+    pub fn chain(&self) -> Arc<Mutex<LocalChain>> {
+        self.chain.clone()
+    }
+}
+
+impl GatewayTransport for LocalChainTransport {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_> {
+        let method = match request.method {
+            GatewayMethod::Get => Method::GET,
+            GatewayMethod::Post => Method::POST,
+        };
+        // query parameters such as `withResults` do not change local chain responses
+        let path = request.path.split('?').next().unwrap_or_default();
+        let body = request.body.unwrap_or_default();
+        let (status, body) = process_request(&self.chain, &method, path, body.as_bytes());
+        let response = GatewayRawResponse {
+            status: status.as_u16(),
+            body: body.to_string(),
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
@@ -0,0 +1,59 @@
+use multiversx_sc_snippets::{
+    multiversx_sc::{
+        abi::EventAbi,
+        types::{Address as H256Address, BigUint},
+    },
+    multiversx_sc_scenario::{api::StaticApi, test_wallets},
+    sdk::{data::address::Address, indexer::IndexedEvent},
+    DecodedEvent, EventDecodeError, EventDecoder, FromDecodedEvent,
+};
+
+struct AddEvent {
+    caller: H256Address,
+    value: BigUint<StaticApi>,
+}
+
+impl FromDecodedEvent for AddEvent {
+    fn from_decoded_event(event: &DecodedEvent) -> Result<Self, EventDecodeError> {
+        Ok(AddEvent {
+            caller: event.field("caller")?,
+            value: event.field("value")?,
+        })
+    }
+}
+
+#[test]
+fn event_decoder_test() {
+    let mut event_abi = EventAbi::new(&[], "add");
+    event_abi.add_input::<H256Address>("caller", true);
+    event_abi.add_input::<BigUint<StaticApi>>("value", false);
+    let decoder = EventDecoder::default().with_event(event_abi);
+
+    let caller = test_wallets::mike().address();
+    let event = IndexedEvent {
+        tx_hash: "hash".to_string(),
+        address: Address::from_bytes([0u8; 32]),
+        identifier: "add".to_string(),
+        topics: vec![b"add".to_vec(), caller.to_bytes().to_vec()],
+        data: vec![0x01, 0x00],
+    };
+
+    let add_event: AddEvent = decoder.decode_as(&event).unwrap();
+    assert_eq!(add_event.caller.to_vec(), caller.to_bytes().to_vec());
+    assert_eq!(add_event.value, BigUint::from(256u32));
+
+    let mut other_event = event.clone();
+    other_event.topics[0] = b"other".to_vec();
+    assert_eq!(
+        decoder.decode(&other_event).unwrap_err(),
+        EventDecodeError::UnknownEvent("other".to_string())
+    );
+    other_event.topics = vec![b"add".to_vec()];
+    assert_eq!(
+        decoder.decode(&other_event).unwrap_err(),
+        EventDecodeError::TopicCount {
+            expected: 1,
+            found: 0
+        }
+    );
+}
@@ -0,0 +1,293 @@
+mod local_chain_utils;
+
+use local_chain_utils::{adder_chain, query_sum, send_signed, ADDER_PATH_EXPR, GAS_LIMIT};
+use multiversx_sc_snippets::{
+    local_chain::LocalChainTransport,
+    multiversx_sc::types::Address as H256Address,
+    multiversx_sc_scenario::{
+        scenario_model::{ScCallStep, ScDeployStep},
+        test_wallets,
+    },
+    sdk::{
+        blockchain::CommunicationProxy,
+        data::{address::Address, transaction::Transaction},
+        gateway::{GatewayFuture, GatewayMethod, GatewayRequest, GatewayTransport},
+    },
+    tokio, Interactor, InteractorConfig, InteractorError, StepBuffer,
+};
+use std::time::Duration;
+
+/// Produces a block whenever a transaction status is polled, and only then,
+/// so that transactions stay pending while they are being sent.
+struct BlockOnStatusTransport(LocalChainTransport);
+
+impl GatewayTransport for BlockOnStatusTransport {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_> {
+        if request.method == GatewayMethod::Get && request.path.ends_with("/status") {
+            self.0.chain().lock().unwrap().generate_blocks(1);
+        }
+        self.0.send(request)
+    }
+}
+
+#[tokio::test]
+async fn interactor_local_chain_test() {
+    let owner = test_wallets::mike();
+    let (chain, _) = adder_chain(&owner);
+    let code = chain.code_expression(ADDER_PATH_EXPR);
+    let proxy = CommunicationProxy::with_transport(LocalChainTransport::new(chain));
+    let mut interactor = Interactor::with_proxy(proxy.clone()).await.with_config(
+        InteractorConfig::default()
+            .polling_interval(Duration::from_millis(10))
+            .tx_timeout(Duration::from_secs(5))
+            .batch_size(2),
+    );
+    let owner_address = interactor.register_wallet(owner);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(code)
+        .argument("5")
+        .gas_limit(GAS_LIMIT);
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("missing deployed address");
+    let adder_erdrs_address = Address::from_bytes(*adder_address.as_array());
+
+    let add_step = |value: &str| {
+        ScCallStep::new()
+            .from(&owner_address)
+            .to(&adder_address)
+            .function("add")
+            .argument(value)
+            .gas_limit(GAS_LIMIT)
+    };
+    interactor.sc_call(add_step("1")).await;
+    assert_eq!(query_sum(&proxy, &adder_erdrs_address).await, vec![6]);
+
+    // the account is used outside the interactor, so the tracked nonce becomes stale
+    send_signed(
+        &proxy,
+        &owner,
+        2,
+        adder_erdrs_address.clone(),
+        "add@01".into(),
+    )
+    .await;
+    interactor.sc_call(add_step("1")).await;
+    assert_eq!(query_sum(&proxy, &adder_erdrs_address).await, vec![8]);
+
+    // batches of 2
+    let mut steps = vec![add_step("1"), add_step("2"), add_step("3")];
+    interactor
+        .multi_sc_exec(StepBuffer::from_sc_call_vec(&mut steps))
+        .await;
+    assert!(steps.iter().all(|step| step.response().is_success()));
+    assert_eq!(query_sum(&proxy, &adder_erdrs_address).await, vec![14]);
+
+    let account = proxy.get_account(&owner.address()).await.unwrap();
+    assert_eq!(account.nonce, 7);
+}
+
+#[tokio::test]
+async fn interactor_try_api_test() {
+    let owner = test_wallets::mike();
+    let (chain, _) = adder_chain(&owner);
+    let code = chain.code_expression(ADDER_PATH_EXPR);
+    let proxy = CommunicationProxy::with_transport(LocalChainTransport::new(chain));
+    let mut interactor = Interactor::with_proxy(proxy).await.with_config(
+        InteractorConfig::default()
+            .polling_interval(Duration::from_millis(10))
+            .tx_timeout(Duration::from_millis(50)),
+    );
+    let owner_address = interactor.register_wallet(owner);
+    assert_eq!(interactor.try_recall_nonce(&owner_address).await, Ok(0));
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(code)
+        .argument("5")
+        .gas_limit(GAS_LIMIT);
+    interactor.try_sc_deploy(&mut deploy_step).await.unwrap();
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("missing deployed address");
+
+    // failed transaction, the response is still saved
+    let mut failed_step = ScCallStep::new()
+        .from(&owner_address)
+        .to(&adder_address)
+        .function("add")
+        .gas_limit(GAS_LIMIT);
+    let err = interactor.try_sc_call(&mut failed_step).await.unwrap_err();
+    assert!(matches!(
+        &err,
+        InteractorError::TxFailed { message, .. } if message == "wrong number of arguments"
+    ));
+    assert!(!failed_step.response().is_success());
+
+    // unregistered sender
+    let stranger = H256Address::from([1u8; 32]);
+    let err = interactor
+        .try_sc_call(
+            ScCallStep::new()
+                .from(&stranger)
+                .to(&adder_address)
+                .function("add")
+                .argument("1")
+                .gas_limit(GAS_LIMIT),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, InteractorError::Signing(_)));
+
+    // unknown transaction
+    let err = interactor
+        .try_retrieve_tx_on_network("unknown".to_string())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, InteractorError::Timeout { .. }));
+    assert!(err.is_transient());
+}
+
+#[tokio::test]
+async fn interactor_gas_estimation_test() {
+    let owner = test_wallets::mike();
+    let (chain, _) = adder_chain(&owner);
+    let code = chain.code_expression(ADDER_PATH_EXPR);
+    let proxy = CommunicationProxy::with_transport(LocalChainTransport::new(chain));
+    let mut interactor = Interactor::with_proxy(proxy.clone()).await.with_config(
+        InteractorConfig::default()
+            .polling_interval(Duration::from_millis(10))
+            .estimate_gas(10)
+            .simulate(true),
+    );
+    let owner_address = interactor.register_wallet(owner);
+
+    // no gas limit in the steps, it gets estimated
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(code)
+        .argument("5");
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("missing deployed address");
+    let adder_erdrs_address = Address::from_bytes(*adder_address.as_array());
+
+    let add_step = || {
+        ScCallStep::new()
+            .from(&owner_address)
+            .to(&adder_address)
+            .function("add")
+    };
+
+    let response = interactor
+        .simulate_sc_call(add_step().argument("2"))
+        .await
+        .unwrap();
+    assert!(response.is_success());
+    assert_eq!(query_sum(&proxy, &adder_erdrs_address).await, vec![5]);
+
+    let response = interactor.simulate_sc_call(add_step()).await.unwrap();
+    assert_eq!(response.tx_error.message, "wrong number of arguments");
+
+    // failed simulations are not sent
+    let err = interactor.try_sc_call(add_step()).await.unwrap_err();
+    assert!(matches!(
+        &err,
+        InteractorError::SimulationFailed { message, .. } if message == "wrong number of arguments"
+    ));
+    assert_eq!(interactor.try_recall_nonce(&owner_address).await, Ok(1));
+
+    interactor.sc_call(add_step().argument("2")).await;
+    assert_eq!(query_sum(&proxy, &adder_erdrs_address).await, vec![7]);
+
+    let network_config = proxy.get_network_config().await.unwrap();
+    let transfer = Transaction {
+        nonce: 2,
+        value: 1u32.into(),
+        receiver: test_wallets::mike().address(),
+        sender: test_wallets::mike().address(),
+        gas_price: network_config.min_gas_price,
+        gas_limit: 0,
+        data: None,
+        signature: None,
+        chain_id: network_config.chain_id,
+        version: network_config.min_transaction_version,
+        options: 0,
+    };
+    assert_eq!(
+        interactor.estimate_gas(&transfer).await.unwrap(),
+        network_config.min_gas_limit
+    );
+}
+
+#[tokio::test]
+async fn interactor_pending_nonce_test() {
+    let owner = test_wallets::mike();
+    let (mut chain, _) = adder_chain(&owner);
+    chain.set_auto_generate_blocks(false);
+    let code = chain.code_expression(ADDER_PATH_EXPR);
+    let transport = LocalChainTransport::new(chain);
+    let chain = transport.chain();
+    let proxy = CommunicationProxy::with_transport(BlockOnStatusTransport(transport));
+    let mut interactor = Interactor::with_proxy(proxy.clone()).await.with_config(
+        InteractorConfig::default()
+            .polling_interval(Duration::from_millis(10))
+            .tx_timeout(Duration::from_secs(5))
+            .batch_size(2),
+    );
+    let owner_address = interactor.register_wallet(owner);
+
+    let mut deploy_step = ScDeployStep::new()
+        .from(&owner_address)
+        .code(code)
+        .argument("5")
+        .gas_limit(GAS_LIMIT);
+    interactor.sc_deploy(&mut deploy_step).await;
+    let adder_address = deploy_step
+        .response()
+        .new_deployed_address
+        .clone()
+        .expect("missing deployed address");
+    let adder_erdrs_address = Address::from_bytes(*adder_address.as_array());
+
+    // nonce 1 is used outside the interactor
+    send_signed(
+        &proxy,
+        &owner,
+        1,
+        adder_erdrs_address.clone(),
+        "add@01".into(),
+    )
+    .await;
+    chain.lock().unwrap().generate_blocks(1);
+
+    // nonce 1 is rejected, nonce 2 is accepted but still pending,
+    // so the rejected call must be resent with nonce 3, not with the network nonce
+    let add_step = |value: &str| {
+        ScCallStep::new()
+            .from(&owner_address)
+            .to(&adder_address)
+            .function("add")
+            .argument(value)
+            .gas_limit(GAS_LIMIT)
+    };
+    let mut steps = vec![add_step("1"), add_step("2")];
+    interactor
+        .multi_sc_exec(StepBuffer::from_sc_call_vec(&mut steps))
+        .await;
+    assert!(steps.iter().all(|step| step.response().is_success()));
+    assert_eq!(query_sum(&proxy, &adder_erdrs_address).await, vec![9]);
+
+    let account = proxy.get_account(&owner.address()).await.unwrap();
+    assert_eq!(account.nonce, 4);
+}
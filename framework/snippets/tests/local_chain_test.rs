@@ -0,0 +1,257 @@
+mod local_chain_utils;
+
+use local_chain_utils::{
+    adder_chain, deployed_address, query_sum, send_signed, signed_tx, DEPLOY_RECEIVER,
+};
+use multiversx_sc_snippets::{
+    local_chain::{
+        LocalChain, LocalChainConfig, LocalChainError, LocalChainServer, LocalChainTransport,
+    },
+    multiversx_sc_scenario::{scenario_model::TxResponse, test_wallets},
+    sdk::{
+        blockchain::CommunicationProxy,
+        data::{address::Address, tx_status::TxStatus},
+        gateway::GatewayError,
+        indexer::{FileCheckpointStore, HyperBlockIndexer, IndexerFilter},
+    },
+    tokio,
+};
+
+#[tokio::test]
+async fn local_chain_server_test() {
+    let owner = test_wallets::mike();
+    let (chain, deploy_data) = adder_chain(&owner);
+
+    let server = LocalChainServer::new(chain)
+        .start(([127, 0, 0, 1], 0).into())
+        .unwrap();
+    let proxy = CommunicationProxy::new(server.url());
+
+    // deploy
+    let tx_hash = send_signed(
+        &proxy,
+        &owner,
+        0,
+        Address::from_bytes(DEPLOY_RECEIVER),
+        deploy_data,
+    )
+    .await;
+    let tx = proxy
+        .get_transaction_info_with_results(&tx_hash)
+        .await
+        .unwrap();
+    assert_eq!(tx.status, TxStatus::Success);
+    let adder_address = deployed_address(tx);
+    assert_eq!(query_sum(&proxy, &adder_address).await, vec![5]);
+
+    // call, with automatic block generation
+    let tx_hash = send_signed(&proxy, &owner, 1, adder_address.clone(), "add@03".into()).await;
+    assert_eq!(
+        proxy.get_transaction_status(&tx_hash).await.unwrap(),
+        TxStatus::Success
+    );
+    assert_eq!(query_sum(&proxy, &adder_address).await, vec![8]);
+
+    // stale nonces are rejected
+    let stale_tx = signed_tx(&proxy, &owner, 1, adder_address.clone(), "add@03".into()).await;
+    assert!(proxy.send_transaction(&stale_tx).await.is_err());
+
+    // call, with manual block generation
+    server
+        .chain()
+        .lock()
+        .unwrap()
+        .set_auto_generate_blocks(false);
+    let tx_hash = send_signed(&proxy, &owner, 2, adder_address.clone(), "add@02".into()).await;
+    assert_eq!(
+        proxy.get_transaction_status(&tx_hash).await.unwrap(),
+        TxStatus::Pending
+    );
+    assert_eq!(query_sum(&proxy, &adder_address).await, vec![8]);
+
+    let block_nonce = server.generate_blocks(1);
+    assert_eq!(
+        proxy.get_transaction_status(&tx_hash).await.unwrap(),
+        TxStatus::Success
+    );
+    assert_eq!(query_sum(&proxy, &adder_address).await, vec![10]);
+    let hyperblock = proxy.get_hyper_block_by_nonce(block_nonce).await.unwrap();
+    assert_eq!(hyperblock.num_txs, 1);
+    assert_eq!(
+        proxy.get_latest_hyper_block_nonce(false).await.unwrap(),
+        block_nonce
+    );
+
+    // failed call
+    server
+        .chain()
+        .lock()
+        .unwrap()
+        .set_auto_generate_blocks(true);
+    let tx_hash = send_signed(&proxy, &owner, 3, adder_address.clone(), "add".into()).await;
+    let tx = proxy
+        .get_transaction_info_with_results(&tx_hash)
+        .await
+        .unwrap();
+    assert_eq!(tx.status, TxStatus::Fail);
+    let response = TxResponse::from_network_tx(tx);
+    assert!(!response.is_success());
+    assert_eq!(response.tx_error.message, "wrong number of arguments");
+
+    let account = proxy.get_account(&owner.address()).await.unwrap();
+    assert_eq!(account.nonce, 4);
+}
+
+#[tokio::test]
+async fn local_chain_transport_test() {
+    let owner = test_wallets::mike();
+    let (chain, deploy_data) = adder_chain(&owner);
+    let proxy = CommunicationProxy::with_transport(LocalChainTransport::new(chain));
+
+    let tx_hash = send_signed(
+        &proxy,
+        &owner,
+        0,
+        Address::from_bytes(DEPLOY_RECEIVER),
+        deploy_data,
+    )
+    .await;
+    let tx = proxy
+        .get_transaction_info_with_results(&tx_hash)
+        .await
+        .unwrap();
+    let adder_address = deployed_address(tx);
+
+    send_signed(&proxy, &owner, 1, adder_address.clone(), "add@07".into()).await;
+    assert_eq!(query_sum(&proxy, &adder_address).await, vec![12]);
+
+    assert!(matches!(
+        proxy.get_transaction_status("unknown").await,
+        Err(GatewayError::Api { code, .. }) if code == "not_found"
+    ));
+}
+
+#[tokio::test]
+async fn local_chain_indexer_test() {
+    let owner = test_wallets::mike();
+    let (chain, deploy_data) = adder_chain(&owner);
+    let transport = LocalChainTransport::new(chain);
+    let shared_chain = transport.chain();
+    let proxy = CommunicationProxy::with_transport(transport);
+
+    let deploy_hash = send_signed(
+        &proxy,
+        &owner,
+        0,
+        Address::from_bytes(DEPLOY_RECEIVER),
+        deploy_data,
+    )
+    .await;
+    let tx = proxy
+        .get_transaction_info_with_results(&deploy_hash)
+        .await
+        .unwrap();
+    let adder_address = deployed_address(tx);
+    let add_hash = send_signed(&proxy, &owner, 1, adder_address.clone(), "add@07".into()).await;
+
+    let checkpoint_path =
+        std::env::temp_dir().join(format!("local-chain-indexer-{}", std::process::id()));
+    let _ = std::fs::remove_file(&checkpoint_path);
+    let new_indexer = || {
+        let transport = LocalChainTransport::from_shared(shared_chain.clone());
+        HyperBlockIndexer::new(CommunicationProxy::with_transport(transport))
+            .with_checkpoint(FileCheckpointStore::new(&checkpoint_path))
+            .with_filter(IndexerFilter::new().address(&adder_address))
+            .with_start_nonce(1)
+    };
+
+    // the deploy is matched through the SCDeploy event emitted by the new contract
+    let mut indexer = new_indexer();
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(block.nonce, 1);
+    assert_eq!(block.transactions.len(), 1);
+    assert_eq!(
+        block.transactions[0].hash.as_deref(),
+        Some(deploy_hash.as_str())
+    );
+    assert_eq!(block.events[0].identifier, "SCDeploy");
+    indexer.commit(&block).unwrap();
+
+    // not committed, so delivered again after a restart
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(block.nonce, 2);
+    drop(indexer);
+
+    let mut indexer = new_indexer();
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(block.nonce, 2);
+    assert_eq!(
+        block.transactions[0].hash.as_deref(),
+        Some(add_hash.as_str())
+    );
+    assert!(block.events.is_empty());
+    indexer.commit(&block).unwrap();
+    assert!(indexer.next_block().await.unwrap().is_none());
+
+    let _ = std::fs::remove_file(&checkpoint_path);
+}
+
+#[test]
+#[should_panic(expected = "invalid local chain config: rounds_per_epoch must be greater than 0")]
+fn local_chain_zero_rounds_per_epoch_test() {
+    LocalChain::new(LocalChainConfig {
+        rounds_per_epoch: 0,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn local_chain_try_new_invalid_config_test() {
+    let result = LocalChain::try_new(LocalChainConfig {
+        rounds_per_epoch: 0,
+        ..Default::default()
+    });
+    assert_eq!(
+        result.err(),
+        Some("invalid local chain config: rounds_per_epoch must be greater than 0".to_string())
+    );
+}
+
+#[tokio::test]
+async fn local_chain_send_transactions_test() {
+    let owner = test_wallets::mike();
+    let (chain, deploy_data) = adder_chain(&owner);
+    let server = LocalChainServer::new(chain)
+        .start(([127, 0, 0, 1], 0).into())
+        .unwrap();
+    let proxy = CommunicationProxy::new(server.url());
+
+    let deploy_tx = signed_tx(
+        &proxy,
+        &owner,
+        0,
+        Address::from_bytes(DEPLOY_RECEIVER),
+        deploy_data,
+    )
+    .await;
+    let mut unsigned_tx = deploy_tx.clone();
+    unsigned_tx.nonce = 1;
+
+    let results = server
+        .chain()
+        .lock()
+        .unwrap()
+        .send_transactions(vec![deploy_tx, unsigned_tx]);
+    assert_eq!(results.len(), 2);
+    let tx_hash = results[0].clone().unwrap();
+    assert_eq!(
+        results[1],
+        Err(LocalChainError::BadRequest(
+            "invalid transaction signature".to_string()
+        ))
+    );
+    assert_eq!(
+        proxy.get_transaction_status(&tx_hash).await.unwrap(),
+        TxStatus::Success
+    );
+}
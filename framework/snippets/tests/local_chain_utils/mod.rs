@@ -0,0 +1,100 @@
+#![allow(dead_code)] // not all tests use all helpers
+
+use multiversx_sc_snippets::{
+    local_chain::LocalChain,
+    multiversx_sc_scenario::scenario_model::{Account, ScDeployStep, SetStateStep, TxResponse},
+    sdk::{
+        blockchain::CommunicationProxy,
+        data::{
+            address::Address,
+            transaction::{Transaction, TransactionOnNetwork},
+            vm::VmValueRequest,
+        },
+        wallet::Wallet,
+    },
+};
+
+pub const ADDER_PATH_EXPR: &str = "file:../../contracts/examples/adder/output/adder.wasm";
+pub const DEPLOY_RECEIVER: [u8; 32] = [0u8; 32];
+pub const GAS_LIMIT: u64 = 10_000_000;
+
+/// Signed transaction with the minimum gas price, built without the interactor.
+pub async fn signed_tx(
+    proxy: &CommunicationProxy,
+    wallet: &Wallet,
+    nonce: u64,
+    receiver: Address,
+    data: String,
+) -> Transaction {
+    let network_config = proxy.get_network_config().await.unwrap();
+    let mut tx = Transaction {
+        nonce,
+        value: 0u32.into(),
+        receiver,
+        sender: wallet.address(),
+        gas_price: network_config.min_gas_price,
+        gas_limit: GAS_LIMIT,
+        data: Some(base64::encode(data)),
+        signature: None,
+        chain_id: network_config.chain_id,
+        version: network_config.min_transaction_version,
+        options: 0,
+    };
+    tx.signature = Some(hex::encode(wallet.sign_tx(&tx)));
+    tx
+}
+
+pub async fn send_signed(
+    proxy: &CommunicationProxy,
+    wallet: &Wallet,
+    nonce: u64,
+    receiver: Address,
+    data: String,
+) -> String {
+    let tx = signed_tx(proxy, wallet, nonce, receiver, data).await;
+    proxy.send_transaction(&tx).await.unwrap()
+}
+
+pub async fn query_sum(proxy: &CommunicationProxy, adder_address: &Address) -> Vec<u8> {
+    let result = proxy
+        .execute_vmquery(&VmValueRequest {
+            sc_address: adder_address.clone(),
+            func_name: "getSum".to_string(),
+            caller: adder_address.clone(),
+            value: "0".to_string(),
+            args: Vec::new(),
+        })
+        .await
+        .unwrap();
+    base64::decode(&result.data.return_data[0]).unwrap()
+}
+
+/// Chain with a funded owner and the adder contract registered, plus the adder deploy tx data.
+pub fn adder_chain(owner: &Wallet) -> (LocalChain, String) {
+    let owner_expr = format!("bech32:{}", owner.address().to_bech32_string().unwrap());
+
+    let mut chain = LocalChain::default();
+    chain
+        .register_contract(ADDER_PATH_EXPR, adder::ContractBuilder)
+        .set_state_step(SetStateStep::new().put_account(
+            owner_expr.as_str(),
+            Account::new().nonce(0).balance("1,000,000,000,000,000,000"),
+        ));
+    let deploy_data = ScDeployStep::new()
+        .from(owner_expr.as_str())
+        .code(chain.code_expression(ADDER_PATH_EXPR))
+        .argument("5")
+        .tx
+        .to_tx_data();
+    (chain, deploy_data)
+}
+
+pub fn deployed_address(tx: TransactionOnNetwork) -> Address {
+    let response = TxResponse::from_network_tx(tx);
+    Address::from_bytes(
+        *response
+            .new_deployed_address
+            .expect("missing deployed address")
+            .as_array(),
+    )
+}
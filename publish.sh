@@ -79,6 +79,10 @@ cd data/codec
 cargo publish || return 1
 cd ../..
 
+cd framework/derive-model
+cargo publish || return 1
+cd ../..
+
 cd framework/derive
 cargo publish || return 1
 cd ../..
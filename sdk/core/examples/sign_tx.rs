@@ -1,6 +1,7 @@
 use multiversx_sdk::{
     blockchain::{CommunicationProxy, DEVNET_GATEWAY},
     data::transaction::Transaction,
+    num_bigint::BigUint,
     wallet::Wallet,
 };
 
@@ -21,7 +22,7 @@ async fn main() {
 
     let mut unsign_tx = Transaction {
         nonce: arg.nonce,
-        value: "0".to_string(),
+        value: BigUint::default(),
         receiver: addr.clone(),
         sender: addr.clone(),
         gas_price: arg.gas_price,
@@ -1,13 +1,14 @@
 use multiversx_sdk::{
     blockchain::{CommunicationProxy, DEVNET_GATEWAY},
     data::{address::Address, transaction::Transaction},
+    num_bigint::BigUint,
 };
 
 #[tokio::main]
 async fn main() {
     let tx = Transaction {
         nonce: 1,
-        value: "50".to_string(),
+        value: BigUint::from(50u32),
         receiver: Address::from_bech32_string(
             "erd1rh5ws22jxm9pe7dtvhfy6j3uttuupkepferdwtmslms5fydtrh5sx3xr8r",
         )
@@ -1,23 +1,34 @@
-use std::collections::HashMap;
-
-use crate::data::{
-    account::{Account, AccountResponse},
-    account_storage::AccountStorageResponse,
-    address::Address,
-    esdt::{EsdtBalance, EsdtBalanceResponse, EsdtRolesResponse},
-    hyperblock::{HyperBlock, HyperBlockResponse},
-    network_config::{NetworkConfig, NetworkConfigResponse},
-    network_economics::{NetworkEconomics, NetworkEconomicsResponse},
-    network_status::NetworkStatusResponse,
-    transaction::{
-        ArgCreateTransaction, ResponseTxCost, SendTransactionResponse, SendTransactionsResponse,
-        Transaction, TransactionInfo, TransactionOnNetwork, TransactionStatus, TxCostResponseData,
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::Arc,
+};
+
+use crate::{
+    data::{
+        account::{Account, AccountData},
+        account_storage::AccountStorage,
+        address::Address,
+        esdt::{EsdtBalance, EsdtBalanceData, EsdtRolesData},
+        hyperblock::{HyperBlock, HyperBlockData},
+        network_config::{NetworkConfig, NetworkConfigData},
+        network_economics::{NetworkEconomics, NetworkEconomicsData},
+        network_status::NetworkStatusData,
+        transaction::{
+            ArgCreateTransaction, SendTransactionData, SendTransactionsResponseData,
+            SimulateTransactionData, Transaction, TransactionInfoData, TransactionOnNetwork,
+            TransactionStatusData, TxCostResponseData, TxSimulationResult,
+        },
+        tx_status::TxStatus,
+        vm::{VmValueRequest, VmValuesResponseData},
+    },
+    gateway::{
+        GatewayError, GatewayRequest, GatewayResponse, GatewayResult, GatewayTransport,
+        HttpGatewayTransport,
     },
-    vm::{ResponseVmValue, VmValueRequest, VmValuesResponseData},
 };
-use anyhow::{anyhow, Result};
-use itertools::Itertools;
-use reqwest::Client;
+use num_bigint::BigUint;
+use serde::{de::DeserializeOwned, Serialize};
 
 pub const MAINNET_GATEWAY: &str = "https://gateway.multiversx.com";
 pub const TESTNET_GATEWAY: &str = "https://testnet-gateway.multiversx.com";
@@ -31,6 +42,7 @@ const NETWORK_ECONOMICS_ENDPOINT: &str = "network/economics";
 const ACCOUNT_ENDPOINT: &str = "address/";
 const KEYS_ENDPOINT: &str = "/keys/";
 const COST_TRANSACTION_ENDPOINT: &str = "transaction/cost";
+const SIMULATE_TRANSACTION_ENDPOINT: &str = "transaction/simulate";
 const SEND_TRANSACTION_ENDPOINT: &str = "transaction/send";
 const SEND_MULTIPLE_TRANSACTIONS_ENDPOINT: &str = "transaction/send-multiple";
 const GET_TRANSACTION_INFO_ENDPOINT: &str = "transaction/";
@@ -38,255 +50,179 @@ const GET_HYPER_BLOCK_BY_NONCE_ENDPOINT: &str = "hyperblock/by-nonce/";
 const GET_HYPER_BLOCK_BY_HASH_ENDPOINT: &str = "hyperblock/by-hash/";
 const GET_NETWORK_STATUS_ENDPOINT: &str = "network/status";
 const WITH_RESULTS_QUERY_PARAM: &str = "?withResults=true";
+const SKIP_SIGNATURE_CHECK_QUERY_PARAM: &str = "?checkSignature=false";
 const VM_VALUES_ENDPOINT: &str = "vm-values/query";
 
-#[derive(Clone, Debug)]
+/// Client for the gateway REST API.
+///
+/// By default requests go over HTTP, but any [`GatewayTransport`] can be plugged in,
+/// e.g. a configured [`HttpGatewayTransport`], recorded fixtures or an in-process chain.
+#[derive(Clone)]
 pub struct CommunicationProxy {
-    proxy_url: String,
-    client: Client,
+    transport: Arc<dyn GatewayTransport>,
+}
+
+impl fmt::Debug for CommunicationProxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommunicationProxy").finish_non_exhaustive()
+    }
 }
 
 impl CommunicationProxy {
     pub fn new(proxy_url: String) -> Self {
+        Self::with_transport(HttpGatewayTransport::new(proxy_url))
+    }
+
+    pub fn with_transport<T: GatewayTransport + 'static>(transport: T) -> Self {
         Self {
-            proxy_url,
-            client: Client::new(),
+            transport: Arc::new(transport),
         }
     }
 
-    fn get_endpoint(&self, endpoint: &str) -> String {
-        format!("{}/{}", self.proxy_url, endpoint)
+    pub fn transport(&self) -> &dyn GatewayTransport {
+        self.transport.as_ref()
     }
 
-    // get_network_config retrieves the network configuration from the proxy
-    pub async fn get_network_config(&self) -> Result<NetworkConfig> {
-        let endpoint = self.get_endpoint(NETWORK_CONFIG_ENDPOINT);
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<NetworkConfigResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.config),
-        }
+    async fn get<T: DeserializeOwned>(&self, endpoint: &str) -> GatewayResult<T> {
+        let raw = self.transport.send(GatewayRequest::get(endpoint)).await?;
+        GatewayResponse::decode(raw)
     }
 
-    // get_network_economics retrieves the network economics from the proxy
-    pub async fn get_network_economics(&self) -> Result<NetworkEconomics> {
-        let endpoint = self.get_endpoint(NETWORK_ECONOMICS_ENDPOINT);
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<NetworkEconomicsResponse>()
+    async fn post<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &B,
+    ) -> GatewayResult<T> {
+        let body = serde_json::to_string(body)
+            .map_err(|err| GatewayError::InvalidRequest(err.to_string()))?;
+        let raw = self
+            .transport
+            .send(GatewayRequest::post(endpoint, body))
             .await?;
+        GatewayResponse::decode(raw)
+    }
 
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.metrics),
+    fn account_endpoint(address: &Address, suffix: &str) -> GatewayResult<String> {
+        if !address.is_valid() {
+            return Err(GatewayError::InvalidRequest("invalid address".to_string()));
         }
+
+        Ok(ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + suffix)
     }
 
-    async fn get_hyper_block(&self, endpoint: &str) -> Result<HyperBlock> {
-        let endpoint = self.get_endpoint(endpoint);
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<HyperBlockResponse>()
-            .await?;
+    // get_network_config retrieves the network configuration from the proxy
+    pub async fn get_network_config(&self) -> GatewayResult<NetworkConfig> {
+        let data: NetworkConfigData = self.get(NETWORK_CONFIG_ENDPOINT).await?;
+        Ok(data.config)
+    }
 
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.hyperblock),
-        }
+    // get_network_economics retrieves the network economics from the proxy
+    pub async fn get_network_economics(&self) -> GatewayResult<NetworkEconomics> {
+        let data: NetworkEconomicsData = self.get(NETWORK_ECONOMICS_ENDPOINT).await?;
+        Ok(data.metrics)
+    }
+
+    async fn get_hyper_block(&self, endpoint: &str) -> GatewayResult<HyperBlock> {
+        let data: HyperBlockData = self.get(endpoint).await?;
+        Ok(data.hyperblock)
     }
 
     // get_hyper_block_by_hash retrieves a hyper block's info by hash from the network
-    pub async fn get_hyper_block_by_hash(&self, hash: &str) -> Result<HyperBlock> {
+    pub async fn get_hyper_block_by_hash(&self, hash: &str) -> GatewayResult<HyperBlock> {
         let endpoint = GET_HYPER_BLOCK_BY_HASH_ENDPOINT.to_string() + hash;
         self.get_hyper_block(endpoint.as_str()).await
     }
 
     // get_hyper_block_by_nonce retrieves a hyper block's info by nonce from the network
-    pub async fn get_hyper_block_by_nonce(&self, nonce: u64) -> Result<HyperBlock> {
+    pub async fn get_hyper_block_by_nonce(&self, nonce: u64) -> GatewayResult<HyperBlock> {
         let endpoint = GET_HYPER_BLOCK_BY_NONCE_ENDPOINT.to_string() + nonce.to_string().as_str();
         self.get_hyper_block(endpoint.as_str()).await
     }
 
     // get_latest_hyper_block_nonce retrieves the latest hyper block (metachain) nonce from the network
-    pub async fn get_latest_hyper_block_nonce(&self, with_metachain: bool) -> Result<u64> {
+    pub async fn get_latest_hyper_block_nonce(&self, with_metachain: bool) -> GatewayResult<u64> {
         let mut endpoint = GET_NETWORK_STATUS_ENDPOINT.to_string();
 
         if with_metachain {
             endpoint = format!("{GET_NETWORK_STATUS_ENDPOINT}/{METACHAIN_SHARD_ID}");
         }
 
-        let endpoint = self.get_endpoint(endpoint.as_str());
-
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<NetworkStatusResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.status.nonce),
-        }
+        let data: NetworkStatusData = self.get(endpoint.as_str()).await?;
+        Ok(data.status.nonce)
     }
 
     // request_transaction_cost retrieves how many gas a transaction will consume
-    pub async fn request_transaction_cost(&self, tx: &Transaction) -> Result<TxCostResponseData> {
-        let endpoint = self.get_endpoint(COST_TRANSACTION_ENDPOINT);
-        let resp = self
-            .client
-            .post(endpoint)
-            .json(tx)
-            .send()
-            .await?
-            .json::<ResponseTxCost>()
-            .await?;
+    pub async fn request_transaction_cost(
+        &self,
+        tx: &Transaction,
+    ) -> GatewayResult<TxCostResponseData> {
+        self.post(COST_TRANSACTION_ENDPOINT, tx).await
+    }
 
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b),
-        }
+    // simulate_transaction executes a transaction without broadcasting it; the signature is not checked, so it can be simulated before signing
+    pub async fn simulate_transaction(
+        &self,
+        tx: &Transaction,
+    ) -> GatewayResult<TxSimulationResult> {
+        let endpoint = SIMULATE_TRANSACTION_ENDPOINT.to_string() + SKIP_SIGNATURE_CHECK_QUERY_PARAM;
+        let data: SimulateTransactionData = self.post(endpoint.as_str(), tx).await?;
+        Ok(data.result)
     }
 
     // get_account retrieves an account info from the network (nonce, balance)
-    pub async fn get_account(&self, address: &Address) -> Result<Account> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
-        }
-
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str();
-        let endpoint = self.get_endpoint(endpoint.as_str());
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<AccountResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.account),
-        }
+    pub async fn get_account(&self, address: &Address) -> GatewayResult<Account> {
+        let endpoint = Self::account_endpoint(address, "")?;
+        let data: AccountData = self.get(endpoint.as_str()).await?;
+        Ok(data.account)
     }
 
     // get_account_esdt_roles retrieves an all esdt roles of an account from the network
     pub async fn get_account_esdt_roles(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, Vec<String>>> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
-        }
-
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/esdts/roles";
-        let endpoint = self.get_endpoint(endpoint.as_str());
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<EsdtRolesResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.roles),
-        }
+    ) -> GatewayResult<HashMap<String, Vec<String>>> {
+        let endpoint = Self::account_endpoint(address, "/esdts/roles")?;
+        let data: EsdtRolesData = self.get(endpoint.as_str()).await?;
+        Ok(data.roles)
     }
 
     // get_account_esdt_tokens retrieves an all esdt token of an account from the network
     pub async fn get_account_esdt_tokens(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, EsdtBalance>> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
-        }
-
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/esdt";
-        let endpoint = self.get_endpoint(endpoint.as_str());
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<EsdtBalanceResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.esdts),
-        }
+    ) -> GatewayResult<HashMap<String, EsdtBalance>> {
+        let endpoint = Self::account_endpoint(address, "/esdt")?;
+        let data: EsdtBalanceData = self.get(endpoint.as_str()).await?;
+        Ok(data.esdts)
     }
 
     // get_account_esdt_tokens retrieves an all esdt token of an account from the network
     pub async fn get_account_storage_keys(
         &self,
         address: &Address,
-    ) -> Result<HashMap<String, String>> {
-        if !address.is_valid() {
-            return Err(anyhow!("invalid address"));
-        }
-
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + KEYS_ENDPOINT;
-        let endpoint = self.get_endpoint(endpoint.as_str());
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<AccountStorageResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.pairs),
-        }
+    ) -> GatewayResult<HashMap<String, String>> {
+        let endpoint = Self::account_endpoint(address, KEYS_ENDPOINT)?;
+        let data: AccountStorage = self.get(endpoint.as_str()).await?;
+        Ok(data.pairs)
     }
 
     async fn get_transaction_info_internal(
         &self,
         hash: &str,
         with_results: bool,
-    ) -> Result<TransactionOnNetwork> {
+    ) -> GatewayResult<TransactionOnNetwork> {
         let mut endpoint = GET_TRANSACTION_INFO_ENDPOINT.to_string() + hash;
 
         if with_results {
             endpoint += WITH_RESULTS_QUERY_PARAM
         }
 
-        let endpoint = self.get_endpoint(endpoint.as_str());
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<TransactionInfo>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.transaction),
-        }
+        let data: TransactionInfoData = self.get(endpoint.as_str()).await?;
+        Ok(data.transaction)
     }
 
     // get_transaction_info retrieves a transaction's details from the network
-    pub async fn get_transaction_info(&self, hash: &str) -> Result<TransactionOnNetwork> {
+    pub async fn get_transaction_info(&self, hash: &str) -> GatewayResult<TransactionOnNetwork> {
         self.get_transaction_info_internal(hash, false).await
     }
 
@@ -294,27 +230,15 @@ impl CommunicationProxy {
     pub async fn get_transaction_info_with_results(
         &self,
         hash: &str,
-    ) -> Result<TransactionOnNetwork> {
+    ) -> GatewayResult<TransactionOnNetwork> {
         self.get_transaction_info_internal(hash, true).await
     }
 
     // get_transaction_status retrieves a transaction's status from the network
-    pub async fn get_transaction_status(&self, hash: &str) -> Result<String> {
+    pub async fn get_transaction_status(&self, hash: &str) -> GatewayResult<TxStatus> {
         let endpoint = format!("transaction/{hash}/status");
-        let endpoint = self.get_endpoint(endpoint.as_str());
-
-        let resp = self
-            .client
-            .get(endpoint)
-            .send()
-            .await?
-            .json::<TransactionStatus>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.status),
-        }
+        let data: TransactionStatusData = self.get(endpoint.as_str()).await?;
+        Ok(data.status)
     }
 
     // get_default_transaction_arguments will prepare the transaction creation argument by querying the account's info
@@ -322,12 +246,12 @@ impl CommunicationProxy {
         &self,
         address: &Address,
         network_configs: &NetworkConfig,
-    ) -> Result<ArgCreateTransaction> {
+    ) -> GatewayResult<ArgCreateTransaction> {
         let account = self.get_account(address).await?;
 
         Ok(ArgCreateTransaction {
             nonce: account.nonce,
-            value: "".to_string(),
+            value: BigUint::default(),
             rcv_addr: address.clone(),
             snd_addr: address.clone(),
             gas_price: network_configs.min_gas_price,
@@ -341,65 +265,36 @@ impl CommunicationProxy {
         })
     }
 
-    pub async fn send_transaction(&self, tx: &Transaction) -> Result<String> {
-        let endpoint = self.get_endpoint(SEND_TRANSACTION_ENDPOINT);
-        let resp = self
-            .client
-            .post(endpoint)
-            .json(tx)
-            .send()
-            .await?
-            .json::<SendTransactionResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b.tx_hash),
-        }
+    pub async fn send_transaction(&self, tx: &Transaction) -> GatewayResult<String> {
+        let data: SendTransactionData = self.post(SEND_TRANSACTION_ENDPOINT, tx).await?;
+        Ok(data.tx_hash)
     }
 
-    pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
-        let endpoint = self.get_endpoint(SEND_MULTIPLE_TRANSACTIONS_ENDPOINT);
-        let resp = self
-            .client
-            .post(endpoint)
-            .json(txs)
-            .send()
-            .await?
-            .json::<SendTransactionsResponse>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => {
-                let mut tx_hashs: Vec<String> = vec![];
-                for key in b.txs_hashes.keys().sorted() {
-                    tx_hashs.push(b.txs_hashes[key].clone());
-                }
+    pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> GatewayResult<Vec<String>> {
+        let tx_hashes = self.send_transactions_indexed(txs).await?;
+        Ok(tx_hashes.into_values().collect())
+    }
 
-                Ok(tx_hashs)
-            },
-        }
+    // send_transactions_indexed sends a batch of transactions and returns the hashes of the accepted ones, keyed by their index in the batch
+    pub async fn send_transactions_indexed(
+        &self,
+        txs: &Vec<Transaction>,
+    ) -> GatewayResult<BTreeMap<usize, String>> {
+        let data: SendTransactionsResponseData =
+            self.post(SEND_MULTIPLE_TRANSACTIONS_ENDPOINT, txs).await?;
+
+        Ok(data
+            .txs_hashes
+            .into_iter()
+            .map(|(index, tx_hash)| (index as usize, tx_hash))
+            .collect())
     }
 
     // execute_vmquery retrieves data from existing SC trie through the use of a VM
     pub async fn execute_vmquery(
         &self,
         vm_request: &VmValueRequest,
-    ) -> Result<VmValuesResponseData> {
-        let endpoint = self.get_endpoint(VM_VALUES_ENDPOINT);
-        let resp = self
-            .client
-            .post(endpoint)
-            .json(vm_request)
-            .send()
-            .await?
-            .json::<ResponseVmValue>()
-            .await?;
-
-        match resp.data {
-            None => Err(anyhow!("{}", resp.error)),
-            Some(b) => Ok(b),
-        }
+    ) -> GatewayResult<VmValuesResponseData> {
+        self.post(VM_VALUES_ENDPOINT, vm_request).await
     }
 }
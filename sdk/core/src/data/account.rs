@@ -1,4 +1,5 @@
-use super::address::Address;
+use super::{address::Address, serde_utils::biguint};
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
 // Account holds an Account's information
@@ -7,7 +8,8 @@ use serde::{Deserialize, Serialize};
 pub struct Account {
     pub address: Address,
     pub nonce: u64,
-    pub balance: String,
+    #[serde(with = "biguint")]
+    pub balance: BigUint,
     pub username: String,
     pub code: String,
     pub code_hash: Option<String>,
@@ -1,12 +1,62 @@
+use super::{
+    address::Address,
+    serde_utils::{base64_bytes, base64_bytes_vec, biguint, u64_lenient},
+};
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // EsdtBalance  holds information about the esdt balance
+// The metadata fields are only present for non-fungible tokens
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EsdtBalance {
     pub token_identifier: String,
-    pub balance: String,
+    #[serde(with = "biguint")]
+    pub balance: BigUint,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Address>,
+    /// Royalties in basis points, out of 10000.
+    #[serde(default, with = "u64_lenient")]
+    pub royalties: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(default, with = "base64_bytes")]
+    pub attributes: Vec<u8>,
+    #[serde(default, with = "base64_bytes_vec")]
+    pub uris: Vec<Vec<u8>>,
+}
+
+impl EsdtBalance {
+    pub fn is_fungible(&self) -> bool {
+        self.nonce == 0
+    }
+
+    /// Parses attributes following the `key1:value1;key2:value2` convention, e.g. `tags:a,b;metadata:cid`.
+    ///
+    /// Returns `None` if the attributes are not UTF-8 or not in this format.
+    pub fn attribute_pairs(&self) -> Option<BTreeMap<String, String>> {
+        let attributes = std::str::from_utf8(&self.attributes).ok()?;
+        attributes
+            .split(';')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once(':')?;
+                Some((key.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+
+    pub fn uri_strings(&self) -> Vec<String> {
+        self.uris
+            .iter()
+            .map(|uri| String::from_utf8_lossy(uri).into_owned())
+            .collect()
+    }
 }
 
 // EsdtBalanceDataholds the esdt balance data
@@ -36,3 +86,46 @@ pub struct EsdtRolesResponse {
     pub error: String,
     pub code: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_nft_balance() {
+        let balance: EsdtBalance = serde_json::from_str(
+            r#"{
+                "attributes": "dGFnczpjYXQsYXJ0O21ldGFkYXRhOlFtYWJj",
+                "balance": "1",
+                "creator": "erd1qqqqqqqqqqqqqpgqyfjjn43spw7teklwtpz4x5waygq2mluyj9ts0mdwn6",
+                "name": "Cat #1",
+                "nonce": 1,
+                "royalties": "750",
+                "tokenIdentifier": "CAT-123456-01",
+                "uris": ["aHR0cHM6Ly9jYXQ="]
+            }"#,
+        )
+        .unwrap();
+        assert!(!balance.is_fungible());
+        assert_eq!(balance.balance, BigUint::from(1u32));
+        assert_eq!(balance.royalties, 750);
+        assert_eq!(balance.uri_strings(), vec!["https://cat".to_string()]);
+        let pairs = balance.attribute_pairs().unwrap();
+        assert_eq!(pairs["tags"], "cat,art");
+        assert_eq!(pairs["metadata"], "Qmabc");
+    }
+
+    #[test]
+    fn test_decode_fungible_balance() {
+        let balance: EsdtBalance = serde_json::from_str(
+            r#"{"tokenIdentifier": "WEGLD-abcdef", "balance": "123456789012345678901234567890"}"#,
+        )
+        .unwrap();
+        assert!(balance.is_fungible());
+        assert_eq!(
+            balance.balance.to_string(),
+            "123456789012345678901234567890"
+        );
+        assert!(balance.attributes.is_empty());
+    }
+}
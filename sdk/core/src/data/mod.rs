@@ -6,5 +6,7 @@ pub mod hyperblock;
 pub mod network_config;
 pub mod network_economics;
 pub mod network_status;
+pub mod serde_utils;
 pub mod transaction;
+pub mod tx_status;
 pub mod vm;
@@ -0,0 +1,91 @@
+//! Serde adapters for the gateway encodings of amounts and binary fields.
+
+use num_bigint::BigUint;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    String(String),
+    Number(u64),
+}
+
+/// Amounts are decimal strings on the wire, except in smart contract results, where they are numbers.
+/// Empty strings are read as zero.
+pub mod biguint {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &BigUint, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigUint, D::Error> {
+        match StringOrNumber::deserialize(deserializer)? {
+            StringOrNumber::String(value) if value.is_empty() => Ok(BigUint::default()),
+            StringOrNumber::String(value) => value
+                .parse()
+                .map_err(|_| de::Error::custom(format!("invalid amount: {value}"))),
+            StringOrNumber::Number(value) => Ok(BigUint::from(value)),
+        }
+    }
+}
+
+/// Small integers the gateway sometimes sends as strings, such as NFT royalties.
+pub mod u64_lenient {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        match StringOrNumber::deserialize(deserializer)? {
+            StringOrNumber::String(value) if value.is_empty() => Ok(0),
+            StringOrNumber::String(value) => value
+                .parse()
+                .map_err(|_| de::Error::custom(format!("invalid number: {value}"))),
+            StringOrNumber::Number(value) => Ok(value),
+        }
+    }
+}
+
+/// Binary values, base64-encoded. Missing and null values are read as empty.
+pub mod base64_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::encode(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+        base64::decode(&value).map_err(|_| de::Error::custom(format!("invalid base64: {value}")))
+    }
+}
+
+/// Lists of base64-encoded binary values, such as NFT URIs.
+pub mod base64_bytes_vec {
+    use super::*;
+    use serde::ser::SerializeSeq;
+
+    pub fn serialize<S: Serializer>(values: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+        for value in values {
+            seq.serialize_element(&base64::encode(value))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Option::<Vec<String>>::deserialize(deserializer)?
+            .unwrap_or_default()
+            .iter()
+            .map(|value| {
+                base64::decode(value)
+                    .map_err(|_| de::Error::custom(format!("invalid base64: {value}")))
+            })
+            .collect()
+    }
+}
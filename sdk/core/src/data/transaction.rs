@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use super::{address::Address, vm::CallType};
+use super::{address::Address, serde_utils::biguint, tx_status::TxStatus, vm::CallType};
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
 // Transaction holds the fields of a transaction to be broadcasted to the network
@@ -8,7 +9,8 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
     pub nonce: u64,
-    pub value: String,
+    #[serde(with = "biguint")]
+    pub value: BigUint,
     pub receiver: Address,
     pub sender: Address,
     pub gas_price: u64,
@@ -20,7 +22,7 @@ pub struct Transaction {
     #[serde(rename = "chainID")]
     pub chain_id: String,
     pub version: u32,
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub options: u32,
 }
 
@@ -57,7 +59,8 @@ pub struct TransactionOnNetwork {
     pub nonce: u64,
     pub round: u64,
     pub epoch: u64,
-    pub value: String,
+    #[serde(with = "biguint")]
+    pub value: BigUint,
     pub receiver: Address,
     pub sender: Address,
     pub gas_price: u64,
@@ -76,7 +79,7 @@ pub struct TransactionOnNetwork {
     pub miniblock_hash: String,
     pub timestamp: u64,
     pub data: Option<String>,
-    pub status: String,
+    pub status: TxStatus,
     pub hyperblock_nonce: u64,
     pub hyperblock_hash: String,
     pub smart_contract_results: Option<Vec<ApiSmartContractResult>>,
@@ -93,6 +96,20 @@ pub struct Events {
     pub data: Option<String>,
 }
 
+impl Events {
+    /// The topics, decoded from base64.
+    ///
+    /// Fails if any topic is not valid base64, since skipping it would shift the positions of the others.
+    pub fn topics_bytes(&self) -> Result<Vec<Vec<u8>>, base64::DecodeError> {
+        self.topics.iter().flatten().map(base64::decode).collect()
+    }
+
+    /// The data, decoded from base64. Empty if missing.
+    pub fn data_bytes(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        self.data.as_deref().map_or(Ok(Vec::new()), base64::decode)
+    }
+}
+
 // ApiLogs represents logs with changed fields' types in order to make it friendly for API's json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -106,7 +123,8 @@ pub struct ApiLogs {
 pub struct ApiSmartContractResult {
     pub hash: String,
     pub nonce: u64,
-    pub value: u64,
+    #[serde(with = "biguint")]
+    pub value: BigUint,
     pub receiver: Address,
     pub sender: Address,
     pub data: String,
@@ -121,6 +139,55 @@ pub struct ApiSmartContractResult {
     pub code_metadata: Option<String>,
     pub return_message: Option<String>,
     pub original_sender: Option<String>,
+    #[serde(default)]
+    pub logs: Option<ApiLogs>,
+}
+
+impl ApiSmartContractResult {
+    /// Splits the data into the called function and its arguments.
+    ///
+    /// Returns `None` if an argument is not valid hex.
+    pub fn decoded_data(&self) -> Option<ScrData> {
+        ScrData::parse(&self.data)
+    }
+}
+
+// ScrData is the `function@arg1@arg2` data of a smart contract result, with the arguments hex-decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrData {
+    /// Empty for results returned to the caller, which look like `@<return code>@<value>...`.
+    pub function: String,
+    pub args: Vec<Vec<u8>>,
+}
+
+impl ScrData {
+    pub fn parse(data: &str) -> Option<Self> {
+        let mut split = data.split('@');
+        let function = split.next().unwrap_or_default().to_string();
+        let args = split
+            .map(|arg| hex::decode(arg).ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some(ScrData { function, args })
+    }
+
+    /// The return code (e.g. `ok`) of a result returned to the caller.
+    pub fn return_code(&self) -> Option<String> {
+        if !self.function.is_empty() {
+            return None;
+        }
+
+        let return_code = self.args.first()?;
+        Some(String::from_utf8_lossy(return_code).into_owned())
+    }
+
+    /// The values returned to the caller, after the return code.
+    pub fn return_values(&self) -> &[Vec<u8>] {
+        if self.function.is_empty() && !self.args.is_empty() {
+            &self.args[1..]
+        } else {
+            &[]
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,7 +205,7 @@ pub struct TransactionInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionStatusData {
-    pub status: String,
+    pub status: TxStatus,
 }
 
 // TransactionStatus holds a transaction's status response from the network
@@ -153,7 +220,8 @@ pub struct TransactionStatus {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArgCreateTransaction {
     pub nonce: u64,
-    pub value: String,
+    #[serde(with = "biguint")]
+    pub value: BigUint,
     pub rcv_addr: Address,
     pub snd_addr: Address,
     pub gas_price: u64,
@@ -163,7 +231,8 @@ pub struct ArgCreateTransaction {
     pub chain_id: String,
     pub version: u32,
     pub options: u32,
-    pub available_balance: String,
+    #[serde(with = "biguint")]
+    pub available_balance: BigUint,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -194,3 +263,22 @@ pub struct SendTransactionsResponse {
     pub code: String,
     pub data: Option<SendTransactionsResponseData>,
 }
+
+// TxSimulationResult holds the outcome of a transaction simulated by the network, without broadcasting it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxSimulationResult {
+    pub status: TxStatus,
+    #[serde(default)]
+    pub fail_reason: String,
+    #[serde(default)]
+    pub hash: String,
+    #[serde(default)]
+    pub sc_results: HashMap<String, ApiSmartContractResult>,
+    pub logs: Option<ApiLogs>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateTransactionData {
+    pub result: TxSimulationResult,
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// TxStatus is the status of a transaction, as reported by the network
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TxStatus {
+    Pending,
+    Received,
+    PartiallyExecuted,
+    Executed,
+    Success,
+    Fail,
+    Invalid,
+    RewardTx,
+    NotExecuted,
+    /// Any status this SDK version does not know about.
+    Other(String),
+}
+
+impl TxStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TxStatus::Pending => "pending",
+            TxStatus::Received => "received",
+            TxStatus::PartiallyExecuted => "partially-executed",
+            TxStatus::Executed => "executed",
+            TxStatus::Success => "success",
+            TxStatus::Fail => "fail",
+            TxStatus::Invalid => "invalid",
+            TxStatus::RewardTx => "rewardTx",
+            TxStatus::NotExecuted => "notExecuted",
+            TxStatus::Other(status) => status.as_str(),
+        }
+    }
+
+    /// The transaction is not completed yet, its status can still change.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            TxStatus::Pending | TxStatus::Received | TxStatus::PartiallyExecuted
+        )
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, TxStatus::Success | TxStatus::Executed)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(
+            self,
+            TxStatus::Fail | TxStatus::Invalid | TxStatus::NotExecuted
+        )
+    }
+}
+
+impl From<&str> for TxStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "pending" => TxStatus::Pending,
+            "received" => TxStatus::Received,
+            "partially-executed" => TxStatus::PartiallyExecuted,
+            "executed" => TxStatus::Executed,
+            "success" => TxStatus::Success,
+            "fail" => TxStatus::Fail,
+            "invalid" => TxStatus::Invalid,
+            "rewardTx" => TxStatus::RewardTx,
+            "notExecuted" => TxStatus::NotExecuted,
+            _ => TxStatus::Other(status.to_string()),
+        }
+    }
+}
+
+impl From<String> for TxStatus {
+    fn from(status: String) -> Self {
+        TxStatus::from(status.as_str())
+    }
+}
+
+impl From<TxStatus> for String {
+    fn from(status: TxStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
+
+impl fmt::Display for TxStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tx_status_serde() {
+        let status: TxStatus = serde_json::from_str(r#""partially-executed""#).unwrap();
+        assert_eq!(status, TxStatus::PartiallyExecuted);
+        assert!(status.is_pending());
+
+        let status: TxStatus = serde_json::from_str(r#""someNewStatus""#).unwrap();
+        assert_eq!(status, TxStatus::Other("someNewStatus".to_string()));
+        assert_eq!(
+            serde_json::to_string(&status).unwrap(),
+            r#""someNewStatus""#
+        );
+        assert_eq!(
+            serde_json::to_string(&TxStatus::Success).unwrap(),
+            r#""success""#
+        );
+    }
+}
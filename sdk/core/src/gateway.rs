@@ -0,0 +1,13 @@
+mod gateway_error;
+mod gateway_fixture;
+mod gateway_http;
+mod gateway_response;
+mod gateway_transport;
+
+pub use gateway_error::{GatewayError, GatewayResult};
+pub use gateway_fixture::{FixtureGatewayTransport, RecordingGatewayTransport};
+pub use gateway_http::{HttpGatewayTransport, RetryPolicy};
+pub use gateway_response::GatewayResponse;
+pub use gateway_transport::{
+    GatewayFuture, GatewayMethod, GatewayRawResponse, GatewayRequest, GatewayTransport,
+};
@@ -0,0 +1,62 @@
+use std::fmt;
+
+pub type GatewayResult<T> = Result<T, GatewayError>;
+
+/// Everything that can go wrong when talking to a gateway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatewayError {
+    /// The request could not be delivered, or no response was received.
+    Transport(String),
+    /// No response arrived within the configured timeout.
+    Timeout,
+    /// The gateway answered with a non-success HTTP status and no readable error.
+    Http { status: u16, body: String },
+    /// The gateway processed the request, but reported an error.
+    Api { code: String, message: String },
+    /// The response body could not be decoded.
+    Decode(String),
+    /// The request could not be built, e.g. because of an invalid address.
+    InvalidRequest(String),
+    /// A fixture transport has no recorded response for the request.
+    MissingFixture(String),
+    /// Reading or writing a fixture file failed.
+    Io(String),
+}
+
+impl GatewayError {
+    /// Errors worth retrying: the same request might succeed later.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            GatewayError::Transport(_) | GatewayError::Timeout => true,
+            GatewayError::Http { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for GatewayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GatewayError::Transport(message) => write!(f, "transport error: {message}"),
+            GatewayError::Timeout => write!(f, "gateway request timed out"),
+            GatewayError::Http { status, body } => write!(f, "HTTP {status}: {body}"),
+            GatewayError::Api { code, message } => write!(f, "gateway error ({code}): {message}"),
+            GatewayError::Decode(message) => write!(f, "could not decode response: {message}"),
+            GatewayError::InvalidRequest(message) => write!(f, "invalid request: {message}"),
+            GatewayError::MissingFixture(key) => write!(f, "no fixture recorded for {key}"),
+            GatewayError::Io(message) => write!(f, "I/O error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for GatewayError {}
+
+impl From<reqwest::Error> for GatewayError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            GatewayError::Timeout
+        } else {
+            GatewayError::Transport(err.to_string())
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    sync::{Mutex, PoisonError},
+};
+
+use super::{
+    GatewayError, GatewayFuture, GatewayRawResponse, GatewayRequest, GatewayResult,
+    GatewayTransport,
+};
+
+/// Replays recorded responses, for deterministic tests that need no network.
+///
+/// Responses are keyed by [`GatewayRequest::key`], e.g. `GET network/config`.
+/// Request bodies are ignored when matching.
+/// Fixture files are JSON objects mapping these keys to `{ "status": ..., "body": ... }`.
+#[derive(Debug, Default, Clone)]
+pub struct FixtureGatewayTransport {
+    responses: BTreeMap<String, GatewayRawResponse>,
+}
+
+impl FixtureGatewayTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> GatewayResult<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|err| {
+            GatewayError::Io(format!("cannot read fixture {}: {err}", path.display()))
+        })?;
+        let responses = serde_json::from_str(&contents)
+            .map_err(|err| GatewayError::Decode(format!("{}: {err}", path.display())))?;
+        Ok(FixtureGatewayTransport { responses })
+    }
+
+    /// Registers a successful response, with a body serialized from `body`.
+    pub fn with_response(mut self, request: GatewayRequest, body: &serde_json::Value) -> Self {
+        self.insert(request, GatewayRawResponse::ok(body.to_string()));
+        self
+    }
+
+    pub fn insert(&mut self, request: GatewayRequest, response: GatewayRawResponse) {
+        self.responses.insert(request.key(), response);
+    }
+
+    pub fn responses(&self) -> &BTreeMap<String, GatewayRawResponse> {
+        &self.responses
+    }
+}
+
+impl GatewayTransport for FixtureGatewayTransport {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_> {
+        let key = request.key();
+        let result = self
+            .responses
+            .get(&key)
+            .cloned()
+            .ok_or(GatewayError::MissingFixture(key));
+        Box::pin(async move { result })
+    }
+}
+
+/// Forwards requests to another transport and records the responses,
+/// so they can be saved as a fixture and replayed with [`FixtureGatewayTransport`].
+///
+/// When the same request is sent several times, the last response wins.
+pub struct RecordingGatewayTransport<T: GatewayTransport> {
+    inner: T,
+    recorded: Mutex<FixtureGatewayTransport>,
+}
+
+impl<T: GatewayTransport> RecordingGatewayTransport<T> {
+    pub fn new(inner: T) -> Self {
+        RecordingGatewayTransport {
+            inner,
+            recorded: Mutex::new(FixtureGatewayTransport::new()),
+        }
+    }
+
+    pub fn fixture(&self) -> FixtureGatewayTransport {
+        self.recorded
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> GatewayResult<()> {
+        let path = path.as_ref();
+        let fixture = self.fixture();
+        let json = serde_json::to_string_pretty(fixture.responses())
+            .map_err(|err| GatewayError::Decode(err.to_string()))?;
+        fs::write(path, json).map_err(|err| {
+            GatewayError::Io(format!("cannot write fixture {}: {err}", path.display()))
+        })
+    }
+}
+
+impl<T: GatewayTransport> GatewayTransport for RecordingGatewayTransport<T> {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_> {
+        Box::pin(async move {
+            let response = self.inner.send(request.clone()).await?;
+            self.recorded
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(request, response.clone());
+            Ok(response)
+        })
+    }
+}
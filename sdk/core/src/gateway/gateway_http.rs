@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client,
+};
+
+use super::{
+    GatewayError, GatewayFuture, GatewayMethod, GatewayRawResponse, GatewayRequest,
+    GatewayResponse, GatewayResult, GatewayTransport,
+};
+
+/// How often, and how patiently, failed requests are retried.
+///
+/// Only transient failures are retried: transport errors, timeouts and 5xx responses.
+/// Transaction submissions are never retried, see [`GatewayRequest::is_retryable`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Exponential backoff, doubling after each attempt, starting from `initial_backoff`.
+    pub fn exponential(max_retries: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::none()
+    }
+}
+
+/// Sends gateway requests over HTTP, using `reqwest`.
+#[derive(Debug, Clone)]
+pub struct HttpGatewayTransport {
+    proxy_url: String,
+    client: Client,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+}
+
+impl HttpGatewayTransport {
+    pub fn new(proxy_url: impl Into<String>) -> Self {
+        HttpGatewayTransport {
+            proxy_url: proxy_url.into().trim_end_matches('/').to_string(),
+            client: Client::new(),
+            headers: HeaderMap::new(),
+            timeout: None,
+            retry_policy: RetryPolicy::none(),
+        }
+    }
+
+    pub fn proxy_url(&self) -> &str {
+        &self.proxy_url
+    }
+
+    /// Uses a custom client, e.g. one configured with an HTTP proxy or custom TLS settings.
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Adds a header to every request, e.g. an API key or an `Authorization` header.
+    pub fn with_header(mut self, name: &str, value: &str) -> GatewayResult<Self> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|err| GatewayError::InvalidRequest(err.to_string()))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|err| GatewayError::InvalidRequest(err.to_string()))?;
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Timeout for each attempt, retries not included.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    async fn send_once(&self, request: &GatewayRequest) -> GatewayResult<GatewayRawResponse> {
+        let url = format!("{}/{}", self.proxy_url, request.path);
+        let mut builder = match request.method {
+            GatewayMethod::Get => self.client.get(url),
+            GatewayMethod::Post => self.client.post(url),
+        };
+        builder = builder.headers(self.headers.clone());
+        if let Some(body) = &request.body {
+            builder = builder
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.clone());
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let body = response.text().await?;
+        Ok(GatewayRawResponse { status, body })
+    }
+
+    async fn send_with_retries(
+        &self,
+        request: GatewayRequest,
+    ) -> GatewayResult<GatewayRawResponse> {
+        let max_retries = if request.is_retryable() {
+            self.retry_policy.max_retries
+        } else {
+            0
+        };
+        let mut attempt = 0;
+        loop {
+            let result = self.send_once(&request).await;
+            let retry = match &result {
+                Ok(response) => response.status >= 500,
+                Err(err) => err.is_transient(),
+            };
+            if retry && attempt < max_retries {
+                tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                attempt += 1;
+                continue;
+            }
+            return match result {
+                Ok(response) if response.status >= 500 => Err(server_error(response)),
+                result => result,
+            };
+        }
+    }
+}
+
+/// Gateways usually still wrap server errors in the response envelope, whose message is more useful than the raw body.
+fn server_error(response: GatewayRawResponse) -> GatewayError {
+    let status = response.status;
+    let body = response.body.clone();
+    match GatewayResponse::<serde_json::Value>::decode(response) {
+        Err(err) => err,
+        Ok(_) => GatewayError::Http { status, body },
+    }
+}
+
+impl GatewayTransport for HttpGatewayTransport {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_> {
+        Box::pin(self.send_with_retries(request))
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use super::{GatewayError, GatewayRawResponse, GatewayResult};
+
+/// The envelope wrapping every gateway response.
+#[derive(Debug, Deserialize)]
+pub struct GatewayResponse<T> {
+    pub data: Option<T>,
+    #[serde(default)]
+    pub error: String,
+    #[serde(default)]
+    pub code: String,
+}
+
+impl<T> GatewayResponse<T> {
+    /// The payload, or the error reported by the gateway.
+    pub fn into_result(self) -> GatewayResult<T> {
+        match self.data {
+            Some(data) => Ok(data),
+            None => Err(GatewayError::Api {
+                code: self.code,
+                message: self.error,
+            }),
+        }
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> GatewayResponse<T> {
+    /// Decodes a raw response.
+    ///
+    /// Gateways report errors with non-2xx statuses, but still in the usual envelope,
+    /// so the status only matters when the body cannot be decoded.
+    pub fn decode(raw: GatewayRawResponse) -> GatewayResult<T> {
+        match serde_json::from_str::<GatewayResponse<T>>(&raw.body) {
+            Ok(response) => response.into_result(),
+            Err(_) if !raw.is_success() => Err(GatewayError::Http {
+                status: raw.status,
+                body: raw.body,
+            }),
+            Err(err) => Err(GatewayError::Decode(err.to_string())),
+        }
+    }
+}
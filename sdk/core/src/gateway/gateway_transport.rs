@@ -0,0 +1,112 @@
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use super::GatewayResult;
+
+/// POST endpoints that only read chain state, so sending them twice is harmless.
+const READ_ONLY_POST_PATHS: &[&str] = &[
+    "transaction/cost",
+    "transaction/simulate",
+    "vm-values/query",
+];
+
+pub type GatewayFuture<'a> =
+    Pin<Box<dyn Future<Output = GatewayResult<GatewayRawResponse>> + Send + 'a>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GatewayMethod {
+    Get,
+    Post,
+}
+
+impl fmt::Display for GatewayMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GatewayMethod::Get => write!(f, "GET"),
+            GatewayMethod::Post => write!(f, "POST"),
+        }
+    }
+}
+
+/// A request to a gateway endpoint, independent of how it gets delivered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GatewayRequest {
+    pub method: GatewayMethod,
+    /// Endpoint path and query, relative to the gateway root, e.g. `network/config`.
+    pub path: String,
+    /// JSON body, for POST requests.
+    pub body: Option<String>,
+}
+
+impl GatewayRequest {
+    pub fn get(path: impl Into<String>) -> Self {
+        GatewayRequest {
+            method: GatewayMethod::Get,
+            path: path.into(),
+            body: None,
+        }
+    }
+
+    pub fn post(path: impl Into<String>, body: String) -> Self {
+        GatewayRequest {
+            method: GatewayMethod::Post,
+            path: path.into(),
+            body: Some(body),
+        }
+    }
+
+    /// Whether the request can safely be sent again after a failure.
+    ///
+    /// Transaction submissions are excluded: the first attempt might have reached the node,
+    /// so resending could either duplicate them or mask their outcome.
+    pub fn is_retryable(&self) -> bool {
+        match self.method {
+            GatewayMethod::Get => true,
+            GatewayMethod::Post => READ_ONLY_POST_PATHS
+                .iter()
+                .any(|read_only_path| self.path.starts_with(read_only_path)),
+        }
+    }
+
+    /// Identifies the request in fixtures and logs, e.g. `GET network/config`.
+    pub fn key(&self) -> String {
+        format!("{} {}", self.method, self.path)
+    }
+}
+
+/// What came back from the gateway, before decoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GatewayRawResponse {
+    /// HTTP status code. Transports that do not use HTTP report 200 on success.
+    pub status: u16,
+    pub body: String,
+}
+
+impl GatewayRawResponse {
+    pub fn ok(body: impl Into<String>) -> Self {
+        GatewayRawResponse {
+            status: 200,
+            body: body.into(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Delivers gateway requests.
+///
+/// `CommunicationProxy` only deals with endpoints and JSON;
+/// implementations decide how requests reach a gateway (HTTP, fixtures, an in-process chain, ...).
+pub trait GatewayTransport: Send + Sync {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_>;
+}
+
+/// Allows keeping a handle on a transport after handing it to a proxy, e.g. to save recordings.
+impl<T: GatewayTransport + ?Sized> GatewayTransport for Arc<T> {
+    fn send(&self, request: GatewayRequest) -> GatewayFuture<'_> {
+        self.as_ref().send(request)
+    }
+}
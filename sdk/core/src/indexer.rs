@@ -0,0 +1,11 @@
+mod hyperblock_indexer;
+mod indexer_block;
+mod indexer_checkpoint;
+mod indexer_error;
+mod indexer_filter;
+
+pub use hyperblock_indexer::HyperBlockIndexer;
+pub use indexer_block::{IndexedBlock, IndexedEvent};
+pub use indexer_checkpoint::{CheckpointStore, FileCheckpointStore, MemoryCheckpointStore};
+pub use indexer_error::{IndexerError, IndexerResult};
+pub use indexer_filter::IndexerFilter;
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use crate::{blockchain::CommunicationProxy, data::hyperblock::HyperBlock};
+
+use super::{
+    CheckpointStore, IndexedBlock, IndexedEvent, IndexerFilter, IndexerResult,
+    MemoryCheckpointStore,
+};
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(6);
+
+/// Follows the chain hyperblock by hyperblock, in nonce order.
+///
+/// Blocks are delivered at least once: the checkpoint only moves on [`HyperBlockIndexer::commit`],
+/// so a block that was fetched but not committed before a restart is delivered again.
+pub struct HyperBlockIndexer {
+    proxy: CommunicationProxy,
+    checkpoint: Box<dyn CheckpointStore>,
+    filter: IndexerFilter,
+    poll_interval: Duration,
+    start_nonce: u64,
+    fetch_tx_results: bool,
+    next_nonce: Option<u64>,
+}
+
+impl HyperBlockIndexer {
+    pub fn new(proxy: CommunicationProxy) -> Self {
+        HyperBlockIndexer {
+            proxy,
+            checkpoint: Box::new(MemoryCheckpointStore::new()),
+            filter: IndexerFilter::new(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            start_nonce: 0,
+            fetch_tx_results: false,
+            next_nonce: None,
+        }
+    }
+
+    pub fn with_checkpoint<C: CheckpointStore + 'static>(mut self, checkpoint: C) -> Self {
+        self.checkpoint = Box::new(checkpoint);
+        self.next_nonce = None;
+        self
+    }
+
+    pub fn with_filter(mut self, filter: IndexerFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Where to begin when the checkpoint is empty. Defaults to the genesis block.
+    pub fn with_start_nonce(mut self, start_nonce: u64) -> Self {
+        self.start_nonce = start_nonce;
+        self
+    }
+
+    /// Hyperblocks do not always carry logs and smart contract results.
+    /// With this on, every matching transaction is fetched again, with its results.
+    pub fn with_tx_results(mut self, fetch_tx_results: bool) -> Self {
+        self.fetch_tx_results = fetch_tx_results;
+        self
+    }
+
+    pub fn proxy(&self) -> &CommunicationProxy {
+        &self.proxy
+    }
+
+    /// Nonce of the block the next call to [`HyperBlockIndexer::next_block`] will fetch.
+    pub fn next_nonce(&mut self) -> IndexerResult<u64> {
+        if let Some(nonce) = self.next_nonce {
+            return Ok(nonce);
+        }
+
+        let nonce = match self.checkpoint.load()? {
+            Some(committed) => committed + 1,
+            None => self.start_nonce,
+        };
+        self.next_nonce = Some(nonce);
+        Ok(nonce)
+    }
+
+    /// Fetches the next block, or returns `None` if it has not been produced yet.
+    pub async fn next_block(&mut self) -> IndexerResult<Option<IndexedBlock>> {
+        let nonce = self.next_nonce()?;
+        let latest_nonce = self.proxy.get_latest_hyper_block_nonce(true).await?;
+        if nonce > latest_nonce {
+            return Ok(None);
+        }
+
+        let hyperblock = self.proxy.get_hyper_block_by_nonce(nonce).await?;
+        let block = self.index_block(hyperblock).await?;
+        self.next_nonce = Some(nonce + 1);
+        Ok(Some(block))
+    }
+
+    /// Polls until the next block is available.
+    pub async fn wait_next_block(&mut self) -> IndexerResult<IndexedBlock> {
+        loop {
+            if let Some(block) = self.next_block().await? {
+                return Ok(block);
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Marks the block as processed. Indexing resumes after it, even after a restart.
+    pub fn commit(&mut self, block: &IndexedBlock) -> IndexerResult<()> {
+        self.checkpoint.save(block.nonce)
+    }
+
+    async fn index_block(&self, hyperblock: HyperBlock) -> IndexerResult<IndexedBlock> {
+        let mut transactions = Vec::new();
+        let mut events = Vec::new();
+        for tx in hyperblock.transactions {
+            let tx_events = IndexedEvent::from_transaction(&tx)?;
+            if !self.filter.matches_transaction(&tx, &tx_events) {
+                continue;
+            }
+
+            let (tx, tx_events) = match (&tx.hash, self.fetch_tx_results) {
+                (Some(hash), true) => {
+                    let tx = self.proxy.get_transaction_info_with_results(hash).await?;
+                    let tx_events = IndexedEvent::from_transaction(&tx)?;
+                    (tx, tx_events)
+                },
+                _ => (tx, tx_events),
+            };
+            events.extend(
+                tx_events
+                    .into_iter()
+                    .filter(|event| self.filter.matches_event(event)),
+            );
+            transactions.push(tx);
+        }
+
+        Ok(IndexedBlock {
+            nonce: hyperblock.nonce,
+            hash: hyperblock.hash,
+            timestamp: hyperblock.timestamp,
+            transactions,
+            events,
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use crate::data::{
+    address::Address,
+    transaction::{Events, TransactionOnNetwork},
+};
+
+use super::{IndexerError, IndexerResult};
+
+/// A hyperblock, reduced to the transactions and events that passed the indexer filter.
+#[derive(Debug, Clone)]
+pub struct IndexedBlock {
+    pub nonce: u64,
+    pub hash: String,
+    pub timestamp: u64,
+    pub transactions: Vec<TransactionOnNetwork>,
+    pub events: Vec<IndexedEvent>,
+}
+
+/// A log event with its topics and data decoded from base64.
+#[derive(Debug, Clone)]
+pub struct IndexedEvent {
+    pub tx_hash: String,
+    pub address: Address,
+    pub identifier: String,
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+impl IndexedEvent {
+    /// Fails if the topics or the data are not valid base64.
+    pub fn from_event(tx_hash: &str, event: &Events) -> IndexerResult<Self> {
+        let decode_error = |err: base64::DecodeError| {
+            IndexerError::Decode(format!(
+                "`{}` event of transaction {tx_hash}: {err}",
+                event.identifier
+            ))
+        };
+        Ok(IndexedEvent {
+            tx_hash: tx_hash.to_string(),
+            address: event.address.clone(),
+            identifier: event.identifier.clone(),
+            topics: event.topics_bytes().map_err(decode_error)?,
+            data: event.data_bytes().map_err(decode_error)?,
+        })
+    }
+
+    /// Contract events carry their name as the first topic; the identifier is the endpoint that emitted them.
+    pub fn name(&self) -> Option<&[u8]> {
+        self.topics.first().map(Vec::as_slice)
+    }
+
+    /// All events of a transaction, in emission order.
+    pub fn from_transaction(tx: &TransactionOnNetwork) -> IndexerResult<Vec<Self>> {
+        let tx_hash = tx.hash.as_deref().unwrap_or_default();
+        tx.logs
+            .iter()
+            .flat_map(|logs| logs.events.iter())
+            .map(|event| IndexedEvent::from_event(tx_hash, event))
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::{IndexerError, IndexerResult};
+
+/// Persists the nonce of the last fully processed hyperblock, so indexing can resume after a restart.
+pub trait CheckpointStore: Send {
+    /// The last committed nonce, or `None` if nothing was committed yet.
+    fn load(&self) -> IndexerResult<Option<u64>>;
+
+    fn save(&mut self, nonce: u64) -> IndexerResult<()>;
+}
+
+/// Keeps the checkpoint in memory only. Useful in tests, or when every run starts from scratch.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCheckpointStore {
+    nonce: Option<u64>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_nonce(nonce: u64) -> Self {
+        MemoryCheckpointStore { nonce: Some(nonce) }
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self) -> IndexerResult<Option<u64>> {
+        Ok(self.nonce)
+    }
+
+    fn save(&mut self, nonce: u64) -> IndexerResult<()> {
+        self.nonce = Some(nonce);
+        Ok(())
+    }
+}
+
+/// Stores the checkpoint as a plain decimal nonce in a file.
+///
+/// Saving writes a temporary file first and renames it, so a crash never leaves a truncated checkpoint.
+#[derive(Debug, Clone)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+}
+
+impl FileCheckpointStore {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        FileCheckpointStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self) -> IndexerResult<Option<u64>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|err| IndexerError::Checkpoint(format!("{}: {err}", self.path.display())))?;
+        contents.trim().parse::<u64>().map(Some).map_err(|_| {
+            IndexerError::Checkpoint(format!(
+                "{}: invalid nonce {:?}",
+                self.path.display(),
+                contents.trim()
+            ))
+        })
+    }
+
+    fn save(&mut self, nonce: u64) -> IndexerResult<()> {
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, nonce.to_string())
+            .and_then(|_| fs::rename(&tmp_path, &self.path))
+            .map_err(|err| IndexerError::Checkpoint(format!("{}: {err}", self.path.display())))
+    }
+}
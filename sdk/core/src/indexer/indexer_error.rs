@@ -0,0 +1,44 @@
+use std::fmt;
+
+use crate::gateway::GatewayError;
+
+pub type IndexerResult<T> = Result<T, IndexerError>;
+
+/// Everything that can go wrong while indexing hyperblocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexerError {
+    /// Fetching blocks or transactions from the gateway failed.
+    Gateway(GatewayError),
+    /// The checkpoint could not be loaded or saved.
+    Checkpoint(String),
+    /// A transaction event could not be decoded.
+    Decode(String),
+}
+
+impl IndexerError {
+    /// Errors worth retrying: polling again might succeed.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            IndexerError::Gateway(err) => err.is_transient(),
+            IndexerError::Checkpoint(_) | IndexerError::Decode(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Gateway(err) => write!(f, "{err}"),
+            IndexerError::Checkpoint(message) => write!(f, "checkpoint error: {message}"),
+            IndexerError::Decode(message) => write!(f, "could not decode event: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {}
+
+impl From<GatewayError> for IndexerError {
+    fn from(err: GatewayError) -> Self {
+        IndexerError::Gateway(err)
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use crate::data::{address::Address, transaction::TransactionOnNetwork};
+
+use super::IndexedEvent;
+
+/// Selects the transactions and events an indexer reports.
+///
+/// An empty address or identifier set matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct IndexerFilter {
+    addresses: HashSet<[u8; 32]>,
+    identifiers: HashSet<String>,
+}
+
+impl IndexerFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps transactions sent by, sent to, or emitting events from this address.
+    pub fn address(mut self, address: &Address) -> Self {
+        self.addresses.insert(address.to_bytes());
+        self
+    }
+
+    /// Keeps events whose identifier or name (first topic) is the given one,
+    /// and the transactions that emitted them.
+    pub fn event_identifier(mut self, identifier: &str) -> Self {
+        self.identifiers.insert(identifier.to_string());
+        self
+    }
+
+    fn matches_address(&self, address: &Address) -> bool {
+        self.addresses.contains(&address.to_bytes())
+    }
+
+    pub fn matches_event(&self, event: &IndexedEvent) -> bool {
+        let address_ok = self.addresses.is_empty() || self.matches_address(&event.address);
+        let identifier_ok = self.identifiers.is_empty()
+            || self.identifiers.contains(&event.identifier)
+            || event
+                .name()
+                .and_then(|name| std::str::from_utf8(name).ok())
+                .is_some_and(|name| self.identifiers.contains(name));
+        address_ok && identifier_ok
+    }
+
+    /// `events` are the transaction's own events, as decoded by [`IndexedEvent::from_transaction`].
+    pub fn matches_transaction(&self, tx: &TransactionOnNetwork, events: &[IndexedEvent]) -> bool {
+        if !self.identifiers.is_empty() {
+            return events.iter().any(|event| self.matches_event(event));
+        }
+
+        self.addresses.is_empty()
+            || self.matches_address(&tx.sender)
+            || self.matches_address(&tx.receiver)
+            || events
+                .iter()
+                .any(|event| self.matches_address(&event.address))
+    }
+}
@@ -1,4 +1,9 @@
 pub mod blockchain;
 pub mod crypto;
 pub mod data;
+pub mod gateway;
+pub mod indexer;
+pub mod tx_outcome;
 pub mod wallet;
+
+pub use num_bigint;
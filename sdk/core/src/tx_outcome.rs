@@ -0,0 +1,8 @@
+mod tx_outcome_async;
+mod tx_outcome_decode;
+mod tx_outcome_esdt;
+mod tx_outcome_model;
+
+pub use tx_outcome_async::{AsyncCallOutcome, AsyncCallbackOutcome};
+pub use tx_outcome_esdt::{EsdtTokenTransfer, EsdtTransfer};
+pub use tx_outcome_model::{TxErrorSource, TxOutcome, TxOutcomeError};
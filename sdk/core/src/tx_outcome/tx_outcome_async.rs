@@ -0,0 +1,97 @@
+use crate::data::{
+    address::Address,
+    transaction::{ApiSmartContractResult, ScrData},
+    vm::CallType,
+};
+
+use super::tx_outcome_esdt::u64_from_be_bytes;
+
+/// An asynchronous call made by a contract, as seen in the smart contract results.
+#[derive(Debug, Clone)]
+pub struct AsyncCallOutcome {
+    pub scr_hash: String,
+    pub caller: Address,
+    pub callee: Address,
+    pub function: String,
+    pub args: Vec<Vec<u8>>,
+    /// `None` while the callback has not been executed yet, or if the call did not return.
+    pub callback: Option<AsyncCallbackOutcome>,
+}
+
+/// The result of an asynchronous call, returned to the caller's callback.
+#[derive(Debug, Clone)]
+pub struct AsyncCallbackOutcome {
+    pub scr_hash: String,
+    /// Zero if the asynchronous call succeeded.
+    pub return_code: u64,
+    pub return_values: Vec<Vec<u8>>,
+}
+
+impl AsyncCallOutcome {
+    /// Pairs every asynchronous call with its callback.
+    ///
+    /// `scrs` are expected in execution order, as given by [`order_scrs`].
+    pub(super) fn from_scrs(scrs: &[&ApiSmartContractResult]) -> Vec<Self> {
+        scrs.iter()
+            .filter(|scr| scr.call_type == CallType::AsynchronousCall)
+            .filter_map(|scr| {
+                let data = scr.decoded_data()?;
+                let callback = scrs
+                    .iter()
+                    .find(|callback| {
+                        callback.call_type == CallType::AsynchronousCallBack
+                            && callback.prev_tx_hash == scr.hash
+                    })
+                    .and_then(|callback| AsyncCallbackOutcome::from_scr(callback));
+                Some(AsyncCallOutcome {
+                    scr_hash: scr.hash.clone(),
+                    caller: scr.sender.clone(),
+                    callee: scr.receiver.clone(),
+                    function: data.function,
+                    args: data.args,
+                    callback,
+                })
+            })
+            .collect()
+    }
+}
+
+impl AsyncCallbackOutcome {
+    fn from_scr(scr: &ApiSmartContractResult) -> Option<Self> {
+        let ScrData { args, .. } = scr.decoded_data()?;
+        let (return_code, return_values) = args.split_first()?;
+        Some(AsyncCallbackOutcome {
+            scr_hash: scr.hash.clone(),
+            return_code: u64_from_be_bytes(return_code)?,
+            return_values: return_values.to_vec(),
+        })
+    }
+}
+
+/// Orders smart contract results breadth-first, starting from the transaction,
+/// so that every result comes after the one that caused it.
+///
+/// Results that cannot be linked to the transaction are kept at the end, in their original order.
+pub(super) fn order_scrs<'a>(
+    tx_hash: &str,
+    scrs: &'a [ApiSmartContractResult],
+) -> Vec<&'a ApiSmartContractResult> {
+    let mut ordered: Vec<&ApiSmartContractResult> = Vec::with_capacity(scrs.len());
+    let mut parents = vec![tx_hash.to_string()];
+    while !parents.is_empty() {
+        let children: Vec<&ApiSmartContractResult> = scrs
+            .iter()
+            .filter(|scr| parents.contains(&scr.prev_tx_hash))
+            .filter(|scr| !ordered.iter().any(|seen| seen.hash == scr.hash))
+            .collect();
+        parents = children.iter().map(|scr| scr.hash.clone()).collect();
+        ordered.extend(children);
+    }
+
+    for scr in scrs {
+        if !ordered.iter().any(|seen| seen.hash == scr.hash) {
+            ordered.push(scr);
+        }
+    }
+    ordered
+}
@@ -0,0 +1,180 @@
+use crate::data::{
+    address::Address,
+    transaction::{
+        ApiLogs, ApiSmartContractResult, Events, ScrData, TransactionOnNetwork, TxSimulationResult,
+    },
+    tx_status::TxStatus,
+};
+
+use super::{
+    tx_outcome_async::order_scrs, AsyncCallOutcome, EsdtTransfer, TxErrorSource, TxOutcome,
+    TxOutcomeError,
+};
+
+const LOG_IDENTIFIER_SC_DEPLOY: &str = "SCDeploy";
+const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
+const LOG_IDENTIFIER_INTERNAL_VM_ERRORS: &str = "internalVMErrors";
+const LOG_IDENTIFIER_WRITE_LOG: &str = "writeLog";
+
+const ESDT_SYSTEM_SC_BECH32: &str =
+    "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+const ISSUE_RESULT_PREFIX: &str = "@00@";
+
+impl TxOutcome {
+    /// Decodes a transaction retrieved from the network.
+    ///
+    /// Error events take precedence. A failed transaction without any error event
+    /// still gets an error, with [`TxErrorSource::Status`] and the status as message.
+    pub fn from_network_tx(tx: &TransactionOnNetwork) -> Self {
+        let tx_hash = tx.hash.clone().unwrap_or_default();
+        let scrs = tx.smart_contract_results.as_deref().unwrap_or_default();
+        let fail_reason = tx.status.is_failed().then(|| tx.status.to_string());
+        Self::decode(
+            &tx_hash,
+            tx.status.clone(),
+            fail_reason,
+            scrs,
+            tx.logs.as_ref(),
+        )
+    }
+
+    pub fn from_simulation_result(result: &TxSimulationResult) -> Self {
+        let scrs: Vec<ApiSmartContractResult> = result.sc_results.values().cloned().collect();
+        let fail_reason = (!result.status.is_success()).then(|| result.fail_reason.clone());
+        Self::decode(
+            &result.hash,
+            result.status.clone(),
+            fail_reason,
+            &scrs,
+            result.logs.as_ref(),
+        )
+    }
+
+    fn decode(
+        tx_hash: &str,
+        status: TxStatus,
+        fail_reason: Option<String>,
+        scrs: &[ApiSmartContractResult],
+        logs: Option<&ApiLogs>,
+    ) -> Self {
+        let ordered_scrs = order_scrs(tx_hash, scrs);
+        let tx_events: &[Events] = logs.map(|logs| logs.events.as_slice()).unwrap_or_default();
+        let events: Vec<Events> = tx_events
+            .iter()
+            .chain(
+                ordered_scrs
+                    .iter()
+                    .filter_map(|scr| scr.logs.as_ref())
+                    .flat_map(|logs| logs.events.iter()),
+            )
+            .cloned()
+            .collect();
+
+        let mut outcome = TxOutcome {
+            status,
+            return_code: None,
+            return_data: Vec::new(),
+            error: decode_error(&events).or_else(|| {
+                fail_reason.map(|message| TxOutcomeError {
+                    source: TxErrorSource::Status,
+                    address: None,
+                    message,
+                })
+            }),
+            deployed_address: None,
+            issued_token_identifier: None,
+            esdt_transfers: events.iter().filter_map(EsdtTransfer::from_event).collect(),
+            async_calls: AsyncCallOutcome::from_scrs(&ordered_scrs),
+            events,
+        };
+        if outcome.error.is_some() {
+            return outcome;
+        }
+
+        if let Some(return_data) = decode_return_data(scrs, tx_events) {
+            outcome.return_code = return_data.return_code();
+            outcome.return_data = return_data.return_values().to_vec();
+        }
+        outcome.deployed_address = decode_deployed_address(tx_events);
+        outcome.issued_token_identifier = decode_issued_token_identifier(scrs);
+        outcome
+    }
+}
+
+fn find_event<'a>(events: &'a [Events], identifier: &str) -> Option<&'a Events> {
+    events.iter().find(|event| event.identifier == identifier)
+}
+
+/// Looks through all events, in execution order: cross-shard calls fail in a smart contract result,
+/// so the error event is then only in the logs of that result.
+///
+/// `signalError` topics are the address that raised the error and the error message.
+/// `internalVMErrors` carry the message in the data.
+fn decode_error(events: &[Events]) -> Option<TxOutcomeError> {
+    if let Some(event) = find_event(events, LOG_IDENTIFIER_SIGNAL_ERROR) {
+        let message = match event.topics_bytes() {
+            Ok(topics) if topics.len() == 2 => String::from_utf8_lossy(&topics[1]).into_owned(),
+            Ok(topics) => format!("expected to have 2 topics, found {} instead", topics.len()),
+            Err(err) => format!("invalid topics: {err}"),
+        };
+        return Some(TxOutcomeError {
+            source: TxErrorSource::SignalError,
+            address: Some(event.address.clone()),
+            message,
+        });
+    }
+
+    find_event(events, LOG_IDENTIFIER_INTERNAL_VM_ERRORS).map(|event| TxOutcomeError {
+        source: TxErrorSource::InternalVmError,
+        address: Some(event.address.clone()),
+        message: match event.data_bytes() {
+            Ok(data) => String::from_utf8_lossy(&data).trim().to_string(),
+            Err(err) => format!("invalid data: {err}"),
+        },
+    })
+}
+
+/// Results are returned in a smart contract result, or in a `writeLog` event
+/// when the transaction is executed in a single shard.
+fn decode_return_data(scrs: &[ApiSmartContractResult], events: &[Events]) -> Option<ScrData> {
+    if let Some(out_scr) = scrs
+        .iter()
+        .find(|scr| scr.nonce != 0 && scr.data.starts_with('@'))
+    {
+        return out_scr.decoded_data();
+    }
+
+    events.iter().rev().find_map(|event| {
+        if event.identifier != LOG_IDENTIFIER_WRITE_LOG {
+            return None;
+        }
+
+        let data = String::from_utf8(event.data_bytes().ok()?).ok()?;
+        if data.starts_with('@') {
+            ScrData::parse(&data)
+        } else {
+            None
+        }
+    })
+}
+
+/// The first topic of the `SCDeploy` event is the new contract address.
+fn decode_deployed_address(events: &[Events]) -> Option<Address> {
+    let event = find_event(events, LOG_IDENTIFIER_SC_DEPLOY)?;
+    let topics = event.topics_bytes().ok()?;
+    if topics.len() != 2 {
+        return None;
+    }
+
+    let address: [u8; 32] = topics[0].as_slice().try_into().ok()?;
+    Some(Address::from_bytes(address))
+}
+
+/// Issuing a token yields a result from the system smart contract: `@00@<token identifier>`.
+fn decode_issued_token_identifier(scrs: &[ApiSmartContractResult]) -> Option<String> {
+    let issue_scr = scrs.iter().find(|scr| {
+        scr.sender.to_string() == ESDT_SYSTEM_SC_BECH32 && scr.data.starts_with(ISSUE_RESULT_PREFIX)
+    })?;
+    let token_identifier = issue_scr.decoded_data()?.args.get(1)?.clone();
+    String::from_utf8(token_identifier).ok()
+}
@@ -0,0 +1,75 @@
+use num_bigint::BigUint;
+
+use crate::data::{address::Address, transaction::Events};
+
+const ESDT_TRANSFER: &str = "ESDTTransfer";
+const ESDT_NFT_TRANSFER: &str = "ESDTNFTTransfer";
+const MULTI_ESDT_NFT_TRANSFER: &str = "MultiESDTNFTTransfer";
+
+/// An `ESDTTransfer`, `ESDTNFTTransfer` or `MultiESDTNFTTransfer` event.
+#[derive(Debug, Clone)]
+pub struct EsdtTransfer {
+    pub identifier: String,
+    pub sender: Address,
+    pub receiver: Address,
+    pub tokens: Vec<EsdtTokenTransfer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EsdtTokenTransfer {
+    pub token_identifier: String,
+    /// Zero for fungible tokens.
+    pub nonce: u64,
+    pub amount: BigUint,
+}
+
+impl EsdtTransfer {
+    /// Decodes an ESDT transfer event. Returns `None` for other events, or malformed ones.
+    ///
+    /// The topics are `token, nonce, amount` for every token, followed by the receiver.
+    pub fn from_event(event: &Events) -> Option<Self> {
+        if !matches!(
+            event.identifier.as_str(),
+            ESDT_TRANSFER | ESDT_NFT_TRANSFER | MULTI_ESDT_NFT_TRANSFER
+        ) {
+            return None;
+        }
+
+        let topics = event.topics_bytes().ok()?;
+        let (receiver, token_topics) = topics.split_last()?;
+        if token_topics.is_empty() || token_topics.len() % 3 != 0 {
+            return None;
+        }
+
+        let receiver: [u8; 32] = receiver.as_slice().try_into().ok()?;
+        let tokens = token_topics
+            .chunks(3)
+            .map(|chunk| {
+                Some(EsdtTokenTransfer {
+                    token_identifier: String::from_utf8(chunk[0].clone()).ok()?,
+                    nonce: u64_from_be_bytes(&chunk[1])?,
+                    amount: BigUint::from_bytes_be(&chunk[2]),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(EsdtTransfer {
+            identifier: event.identifier.clone(),
+            sender: event.address.clone(),
+            receiver: Address::from_bytes(receiver),
+            tokens,
+        })
+    }
+}
+
+pub(super) fn u64_from_be_bytes(bytes: &[u8]) -> Option<u64> {
+    if bytes.len() > 8 {
+        return None;
+    }
+
+    Some(
+        bytes
+            .iter()
+            .fold(0u64, |acc, byte| (acc << 8) | *byte as u64),
+    )
+}
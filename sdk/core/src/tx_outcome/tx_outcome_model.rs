@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::data::{address::Address, transaction::Events, tx_status::TxStatus};
+
+use super::{AsyncCallOutcome, EsdtTransfer};
+
+/// What a transaction did, decoded from its smart contract results and logs.
+#[derive(Debug, Clone)]
+pub struct TxOutcome {
+    pub status: TxStatus,
+    /// The return code of the results sent back to the caller, `ok` for successful calls.
+    pub return_code: Option<String>,
+    /// The values returned to the caller.
+    pub return_data: Vec<Vec<u8>>,
+    pub error: Option<TxOutcomeError>,
+    pub deployed_address: Option<Address>,
+    pub issued_token_identifier: Option<String>,
+    /// All ESDT transfers, including those performed by contracts.
+    pub esdt_transfers: Vec<EsdtTransfer>,
+    /// Asynchronous calls, in the order they were made, with their callbacks.
+    pub async_calls: Vec<AsyncCallOutcome>,
+    /// All events, those of the transaction first, then those of its smart contract results.
+    pub events: Vec<Events>,
+}
+
+impl TxOutcome {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The first event with the given identifier.
+    pub fn find_event(&self, identifier: &str) -> Option<&Events> {
+        self.events
+            .iter()
+            .find(|event| event.identifier == identifier)
+    }
+}
+
+/// Where a transaction error was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxErrorSource {
+    /// A `signalError` event, raised by a contract or the protocol.
+    SignalError,
+    /// An `internalVMErrors` event, raised by the VM.
+    InternalVmError,
+    /// The transaction status, when no error event explains the failure.
+    Status,
+}
+
+#[derive(Debug, Clone)]
+pub struct TxOutcomeError {
+    pub source: TxErrorSource,
+    /// The account that raised the error, if known.
+    pub address: Option<Address>,
+    pub message: String,
+}
+
+impl fmt::Display for TxOutcomeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
@@ -1,5 +1,9 @@
 extern crate rand;
 
+mod wallet_keystore;
+
+pub use wallet_keystore::*;
+
 use anyhow::Result;
 use bip39::{Language, Mnemonic};
 use hmac::{Hmac, Mac};
@@ -111,6 +115,18 @@ impl Wallet {
         Ok(Self { priv_key: pri_key })
     }
 
+    /// Loads a wallet from a JSON keystore file, as produced by the web wallet or `mxpy`.
+    pub fn from_keystore_file(file_path: &str, password: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(file_path)?;
+        Self::from_keystore_file_contents(&contents, password)
+    }
+
+    pub fn from_keystore_file_contents(contents: &str, password: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(contents)?;
+        let priv_key = keystore.decrypt_private_key(password)?;
+        Ok(Self { priv_key })
+    }
+
     pub fn address(&self) -> Address {
         let public_key = PublicKey::from(&self.priv_key);
         Address::from(&public_key)
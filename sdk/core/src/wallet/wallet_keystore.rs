@@ -0,0 +1,124 @@
+use aes::{
+    cipher::{KeyIvInit, StreamCipher},
+    Aes128,
+};
+use anyhow::{anyhow, Result};
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+use crate::crypto::private_key::PrivateKey;
+
+use super::Wallet;
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+const KEYSTORE_CIPHER: &str = "aes-128-ctr";
+const KEYSTORE_KDF: &str = "scrypt";
+const KIND_SECRET_KEY: &str = "secretKey";
+const KIND_MNEMONIC: &str = "mnemonic";
+
+/// The JSON keystore format used by MultiversX wallets.
+///
+/// Only the fields needed for decryption are modelled.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Keystore {
+    pub version: u32,
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub bech32: Option<String>,
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeystoreCrypto {
+    pub ciphertext: String,
+    pub cipherparams: KeystoreCipherParams,
+    pub cipher: String,
+    pub kdf: String,
+    pub kdfparams: KeystoreKdfParams,
+    pub mac: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeystoreCipherParams {
+    pub iv: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeystoreKdfParams {
+    pub dklen: usize,
+    pub salt: String,
+    pub n: u64,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Keystore {
+    /// Decrypts the keystore contents, checking the MAC first.
+    ///
+    /// Mnemonic keystores yield the key of the first account.
+    pub fn decrypt_private_key(&self, password: &str) -> Result<PrivateKey> {
+        let crypto = &self.crypto;
+        if crypto.cipher != KEYSTORE_CIPHER {
+            return Err(anyhow!("unsupported keystore cipher: {}", crypto.cipher));
+        }
+        if crypto.kdf != KEYSTORE_KDF {
+            return Err(anyhow!("unsupported keystore kdf: {}", crypto.kdf));
+        }
+
+        let mut derived_key = crypto.kdfparams.derive_key(password)?;
+        let ciphertext = hex::decode(&crypto.ciphertext)?;
+        let iv = hex::decode(&crypto.cipherparams.iv)?;
+        if iv.len() != 16 {
+            return Err(anyhow!("invalid keystore iv length"));
+        }
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&derived_key[16..32])
+            .expect("HMAC can take key of any size");
+        mac.update(&ciphertext);
+        let expected_mac = hex::decode(&crypto.mac)?;
+        if mac.verify_slice(&expected_mac).is_err() {
+            derived_key.zeroize();
+            return Err(anyhow!("wrong keystore password"));
+        }
+
+        let mut plaintext = ciphertext;
+        let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into());
+        cipher.apply_keystream(&mut plaintext);
+        derived_key.zeroize();
+
+        let result = match self.kind.as_deref().unwrap_or(KIND_SECRET_KEY) {
+            KIND_SECRET_KEY => PrivateKey::from_bytes(&plaintext),
+            KIND_MNEMONIC => std::str::from_utf8(&plaintext)
+                .map_err(anyhow::Error::from)
+                .and_then(|phrase| Mnemonic::parse_normalized(phrase).map_err(Into::into))
+                .map(|mnemonic| Wallet::get_private_key_from_mnemonic(mnemonic, 0, 0)),
+            other => Err(anyhow!("unsupported keystore kind: {other}")),
+        };
+        plaintext.zeroize();
+        result
+    }
+}
+
+impl KeystoreKdfParams {
+    fn derive_key(&self, password: &str) -> Result<Vec<u8>> {
+        if self.dklen != 32 {
+            return Err(anyhow!("unsupported keystore dklen: {}", self.dklen));
+        }
+        if !self.n.is_power_of_two() {
+            return Err(anyhow!("keystore scrypt n must be a power of two"));
+        }
+        let log_n = self.n.trailing_zeros() as u8;
+        let params = scrypt::Params::new(log_n, self.r, self.p, self.dklen)
+            .map_err(|err| anyhow!("invalid keystore scrypt params: {err}"))?;
+        let salt = hex::decode(&self.salt)?;
+        let mut derived_key = vec![0u8; self.dklen];
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut derived_key)
+            .map_err(|err| anyhow!("keystore key derivation failed: {err}"))?;
+        Ok(derived_key)
+    }
+}
@@ -0,0 +1,186 @@
+use multiversx_sdk::{
+    blockchain::CommunicationProxy,
+    data::address::Address,
+    gateway::{
+        FixtureGatewayTransport, GatewayError, GatewayRawResponse, GatewayRequest,
+        HttpGatewayTransport, RecordingGatewayTransport,
+    },
+};
+use serde_json::json;
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+const ALICE: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+fn network_config_json() -> serde_json::Value {
+    json!({
+        "data": {
+            "config": {
+                "erd_chain_id": "D",
+                "erd_denomination": 18,
+                "erd_gas_per_data_byte": 1500,
+                "erd_latest_tag_software_version": "v1.6.0",
+                "erd_meta_consensus_group_size": 58,
+                "erd_min_gas_limit": 50000,
+                "erd_min_gas_price": 1000000000,
+                "erd_min_transaction_version": 1,
+                "erd_num_metachain_nodes": 58,
+                "erd_num_nodes_in_shard": 58,
+                "erd_num_shards_without_meta": 3,
+                "erd_round_duration": 6000,
+                "erd_shard_consensus_group_size": 21,
+                "erd_start_time": 1694000000
+            }
+        },
+        "error": "",
+        "code": "successful"
+    })
+}
+
+#[tokio::test]
+async fn test_fixture_network_config() {
+    let transport = FixtureGatewayTransport::new().with_response(
+        GatewayRequest::get("network/config"),
+        &network_config_json(),
+    );
+    let proxy = CommunicationProxy::with_transport(transport);
+
+    let network_config = proxy.get_network_config().await.unwrap();
+    assert_eq!(network_config.chain_id, "D");
+    assert_eq!(network_config.min_gas_limit, 50_000);
+}
+
+#[tokio::test]
+async fn test_fixture_api_error() {
+    let mut transport = FixtureGatewayTransport::new();
+    transport.insert(
+        GatewayRequest::get("transaction/abc/status"),
+        GatewayRawResponse {
+            status: 404,
+            body: json!({ "data": null, "error": "transaction not found", "code": "not_found" })
+                .to_string(),
+        },
+    );
+    let proxy = CommunicationProxy::with_transport(transport);
+
+    assert_eq!(
+        proxy.get_transaction_status("abc").await,
+        Err(GatewayError::Api {
+            code: "not_found".to_string(),
+            message: "transaction not found".to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_fixture_errors() {
+    let mut transport = FixtureGatewayTransport::new();
+    transport.insert(
+        GatewayRequest::get("network/economics"),
+        GatewayRawResponse {
+            status: 502,
+            body: "Bad Gateway".to_string(),
+        },
+    );
+    transport.insert(
+        GatewayRequest::get("network/status"),
+        GatewayRawResponse::ok("not json"),
+    );
+    let proxy = CommunicationProxy::with_transport(transport);
+
+    let err = proxy.get_network_economics().await.unwrap_err();
+    assert!(matches!(err, GatewayError::Http { status: 502, .. }));
+    assert!(err.is_transient());
+
+    let err = proxy.get_latest_hyper_block_nonce(false).await.unwrap_err();
+    assert!(matches!(err, GatewayError::Decode(_)));
+    assert!(!err.is_transient());
+
+    let address = Address::from_bech32_string(ALICE).unwrap();
+    let err = proxy.get_account(&address).await.unwrap_err();
+    assert_eq!(
+        err,
+        GatewayError::MissingFixture(format!("GET address/{ALICE}"))
+    );
+}
+
+#[tokio::test]
+async fn test_record_and_replay() {
+    let source = FixtureGatewayTransport::new().with_response(
+        GatewayRequest::get("network/config"),
+        &network_config_json(),
+    );
+    let recording = Arc::new(RecordingGatewayTransport::new(source));
+    let proxy = CommunicationProxy::with_transport(recording.clone());
+    proxy.get_network_config().await.unwrap();
+
+    let path = std::env::temp_dir().join("multiversx_sdk_gateway_fixture_test.json");
+    recording.save(&path).unwrap();
+    let replay = FixtureGatewayTransport::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let proxy = CommunicationProxy::with_transport(replay);
+    let network_config = proxy.get_network_config().await.unwrap();
+    assert_eq!(network_config.chain_id, "D");
+    assert!(proxy.get_network_economics().await.is_err());
+}
+
+#[test]
+fn test_only_reads_are_retryable() {
+    assert!(GatewayRequest::get("network/config").is_retryable());
+    assert!(GatewayRequest::post("transaction/simulate", "{}".to_string()).is_retryable());
+    assert!(GatewayRequest::post("transaction/cost", "{}".to_string()).is_retryable());
+    assert!(GatewayRequest::post("vm-values/query", "{}".to_string()).is_retryable());
+    assert!(!GatewayRequest::post("transaction/send", "{}".to_string()).is_retryable());
+    assert!(!GatewayRequest::post("transaction/send-multiple", "[]".to_string()).is_retryable());
+}
+
+/// Answers every connection with the same HTTP response, returns the server URL.
+async fn serve_http_response(status_line: &'static str, body: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request).await;
+            let response = format!(
+                "HTTP/1.1 {status_line}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+    url
+}
+
+#[tokio::test]
+async fn test_http_server_error_envelope() {
+    let body = json!({ "data": null, "error": "node is syncing", "code": "internal_issue" });
+    let url = serve_http_response("500 Internal Server Error", body.to_string()).await;
+    let proxy = CommunicationProxy::with_transport(HttpGatewayTransport::new(url));
+
+    assert_eq!(
+        proxy.get_network_config().await.unwrap_err(),
+        GatewayError::Api {
+            code: "internal_issue".to_string(),
+            message: "node is syncing".to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_http_server_error_raw_body() {
+    let url = serve_http_response("503 Service Unavailable", "unavailable".to_string()).await;
+    let proxy = CommunicationProxy::with_transport(HttpGatewayTransport::new(url));
+
+    assert_eq!(
+        proxy.get_network_config().await.unwrap_err(),
+        GatewayError::Http {
+            status: 503,
+            body: "unavailable".to_string(),
+        }
+    );
+}
@@ -0,0 +1,245 @@
+use multiversx_sdk::{
+    blockchain::CommunicationProxy,
+    data::{address::Address, transaction::Events},
+    gateway::{FixtureGatewayTransport, GatewayRequest},
+    indexer::{
+        CheckpointStore, FileCheckpointStore, HyperBlockIndexer, IndexedEvent, IndexerError,
+        IndexerFilter, MemoryCheckpointStore,
+    },
+};
+use serde_json::{json, Value};
+use std::fs;
+
+const USER: &str = "erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g";
+const CONTRACT_A: &str = "erd1qqqqqqqqqqqqqpgqllqglpjdrz5kn3m0k9uf9hdqjmg3xdhk6r7se3wvlk";
+const CONTRACT_B: &str = "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d";
+
+fn response(data: Value) -> Value {
+    json!({ "data": data, "error": "", "code": "successful" })
+}
+
+fn network_status_json(nonce: u64) -> Value {
+    response(json!({
+        "status": {
+            "erd_current_round": nonce,
+            "erd_epoch_number": 0,
+            "erd_nonce": nonce,
+            "erd_nonce_at_epoch_start": 0,
+            "erd_nonces_passed_in_current_epoch": nonce,
+            "erd_round_at_epoch_start": 0,
+            "erd_rounds_passed_in_current_epoch": nonce,
+            "erd_rounds_per_epoch": 14400,
+        }
+    }))
+}
+
+fn tx_json(hash: &str, receiver: &str, events: Value) -> Value {
+    json!({
+        "type": "normal",
+        "hash": hash,
+        "nonce": 0,
+        "round": 1,
+        "epoch": 0,
+        "value": "0",
+        "receiver": receiver,
+        "sender": USER,
+        "gasPrice": 1000000000,
+        "gasLimit": 10000000,
+        "signature": "",
+        "sourceShard": 0,
+        "destinationShard": 0,
+        "blockNonce": 1,
+        "blockHash": "",
+        "notarizedAtSourceInMetaNonce": 1,
+        "NotarizedAtSourceInMetaHash": "",
+        "notarizedAtDestinationInMetaNonce": 1,
+        "notarizedAtDestinationInMetaHash": "",
+        "miniblockType": "TxBlock",
+        "miniblockHash": "",
+        "timestamp": 1694437098,
+        "data": null,
+        "status": "success",
+        "hyperblockNonce": 1,
+        "hyperblockHash": "",
+        "smartContractResults": null,
+        "logs": { "address": receiver, "events": events },
+    })
+}
+
+fn event_json(address: &str, identifier: &str, name: &str) -> Value {
+    json!({
+        "address": address,
+        "identifier": identifier,
+        "topics": [base64::encode(name)],
+        "data": null,
+    })
+}
+
+fn hyperblock_json(nonce: u64, txs: Value) -> Value {
+    response(json!({
+        "hyperblock": {
+            "nonce": nonce,
+            "round": nonce,
+            "hash": format!("block-{nonce}"),
+            "prevBlockHash": "",
+            "epoch": 0,
+            "numTxs": txs.as_array().unwrap().len(),
+            "shardBlocks": [],
+            "timestamp": 1694437098 + nonce * 6,
+            "accumulatedFees": "0",
+            "developerFees": "0",
+            "accumulatedFeesInEpoch": "0",
+            "developerFeesInEpoch": "0",
+            "transactions": txs,
+        }
+    }))
+}
+
+/// Two blocks: a call to contract A, an unrelated call, and a call to B that makes A emit an event.
+fn chain_fixture() -> FixtureGatewayTransport {
+    FixtureGatewayTransport::new()
+        .with_response(
+            GatewayRequest::get("network/status/4294967295"),
+            &network_status_json(2),
+        )
+        .with_response(
+            GatewayRequest::get("hyperblock/by-nonce/1"),
+            &hyperblock_json(
+                1,
+                json!([
+                    tx_json("tx-a", CONTRACT_A, json!([])),
+                    tx_json("tx-other", USER, json!([])),
+                ]),
+            ),
+        )
+        .with_response(
+            GatewayRequest::get("hyperblock/by-nonce/2"),
+            &hyperblock_json(
+                2,
+                json!([tx_json(
+                    "tx-b",
+                    CONTRACT_B,
+                    json!([
+                        event_json(CONTRACT_B, "forward", "forwarded"),
+                        event_json(CONTRACT_A, "deposit", "deposit"),
+                    ])
+                )]),
+            ),
+        )
+}
+
+fn tx_hashes(block: &multiversx_sdk::indexer::IndexedBlock) -> Vec<&str> {
+    block
+        .transactions
+        .iter()
+        .map(|tx| tx.hash.as_deref().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_memory_checkpoint() {
+    let mut checkpoint = MemoryCheckpointStore::new();
+    assert_eq!(checkpoint.load(), Ok(None));
+    checkpoint.save(7).unwrap();
+    assert_eq!(checkpoint.load(), Ok(Some(7)));
+}
+
+#[test]
+fn test_file_checkpoint() {
+    let path = std::env::temp_dir().join(format!("indexer-checkpoint-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut checkpoint = FileCheckpointStore::new(&path);
+    assert_eq!(checkpoint.load(), Ok(None));
+    checkpoint.save(42).unwrap();
+    assert_eq!(FileCheckpointStore::new(&path).load(), Ok(Some(42)));
+
+    fs::write(&path, "not a nonce").unwrap();
+    assert!(matches!(
+        checkpoint.load(),
+        Err(IndexerError::Checkpoint(_))
+    ));
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_index_by_address() {
+    let contract_a = Address::from_bech32_string(CONTRACT_A).unwrap();
+    let checkpoint_path =
+        std::env::temp_dir().join(format!("indexer-by-address-{}", std::process::id()));
+    let _ = fs::remove_file(&checkpoint_path);
+    let new_indexer = || {
+        HyperBlockIndexer::new(CommunicationProxy::with_transport(chain_fixture()))
+            .with_checkpoint(FileCheckpointStore::new(&checkpoint_path))
+            .with_filter(IndexerFilter::new().address(&contract_a))
+            .with_start_nonce(1)
+    };
+
+    let mut indexer = new_indexer();
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(block.nonce, 1);
+    assert_eq!(block.hash, "block-1");
+    assert_eq!(tx_hashes(&block), vec!["tx-a"]);
+    assert!(block.events.is_empty());
+    indexer.commit(&block).unwrap();
+
+    // not committed, so delivered again after a restart
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(block.nonce, 2);
+    drop(indexer);
+
+    let mut indexer = new_indexer();
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(block.nonce, 2);
+    // matched through the event emitted by contract A, only that event is reported
+    assert_eq!(tx_hashes(&block), vec!["tx-b"]);
+    assert_eq!(block.events.len(), 1);
+    assert_eq!(block.events[0].identifier, "deposit");
+    assert_eq!(block.events[0].tx_hash, "tx-b");
+    indexer.commit(&block).unwrap();
+
+    // block 3 not produced yet
+    assert!(indexer.next_block().await.unwrap().is_none());
+    assert_eq!(indexer.next_nonce().unwrap(), 3);
+
+    fs::remove_file(&checkpoint_path).unwrap();
+}
+
+#[tokio::test]
+async fn test_index_by_event_name() {
+    let mut indexer = HyperBlockIndexer::new(CommunicationProxy::with_transport(chain_fixture()))
+        .with_filter(IndexerFilter::new().event_identifier("forwarded"))
+        .with_start_nonce(1);
+
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert!(block.transactions.is_empty());
+
+    let block = indexer.next_block().await.unwrap().unwrap();
+    assert_eq!(tx_hashes(&block), vec!["tx-b"]);
+    assert_eq!(block.events.len(), 1);
+    assert_eq!(block.events[0].name(), Some(&b"forwarded"[..]));
+}
+
+#[test]
+fn test_invalid_base64_event() {
+    let event = Events {
+        address: Address::from_bech32_string(CONTRACT_A).unwrap(),
+        identifier: "deposit".to_string(),
+        topics: Some(vec![base64::encode("deposit"), "not base64!".to_string()]),
+        data: None,
+    };
+    assert!(event.topics_bytes().is_err());
+    assert_eq!(event.data_bytes(), Ok(Vec::new()));
+    assert!(matches!(
+        IndexedEvent::from_event("tx", &event),
+        Err(IndexerError::Decode(_))
+    ));
+
+    let event = Events {
+        topics: Some(vec![base64::encode("deposit")]),
+        data: Some("not base64!".to_string()),
+        ..event
+    };
+    assert_eq!(event.topics_bytes(), Ok(vec![b"deposit".to_vec()]));
+    assert!(event.data_bytes().is_err());
+}
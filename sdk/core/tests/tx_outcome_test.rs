@@ -0,0 +1,212 @@
+use multiversx_sdk::{
+    data::{address::Address, transaction::TransactionOnNetwork, tx_status::TxStatus},
+    num_bigint::BigUint,
+    tx_outcome::{TxErrorSource, TxOutcome},
+};
+use serde_json::{json, Value};
+
+const USER: &str = "erd1uh67c2lkhyj4vh73akv7jky9sfgvus8awwcj64uju69mmfne5u7q299t7g";
+const CONTRACT_A: &str = "erd1qqqqqqqqqqqqqpgqllqglpjdrz5kn3m0k9uf9hdqjmg3xdhk6r7se3wvlk";
+const CONTRACT_B: &str = "erd1qqqqqqqqqqqqqpgq4nlkk7jwhqgp4r08lal46tqt70jdv0685u7qrr3l2d";
+const TX_HASH: &str = "f34e136ca81c0e32f6fb532b753612715675073f3718b5db009bb275d246fd7a";
+
+fn tx_json(status: &str, scrs: Value, events: Value) -> TransactionOnNetwork {
+    serde_json::from_value(json!({
+        "type": "normal",
+        "hash": TX_HASH,
+        "nonce": 53,
+        "round": 1,
+        "epoch": 1,
+        "value": "0",
+        "receiver": CONTRACT_A,
+        "sender": USER,
+        "gasPrice": 1000000000,
+        "gasLimit": 600000000,
+        "signature": "",
+        "sourceShard": 0,
+        "destinationShard": 1,
+        "blockNonce": 1,
+        "blockHash": "",
+        "notarizedAtSourceInMetaNonce": 1,
+        "NotarizedAtSourceInMetaHash": "",
+        "notarizedAtDestinationInMetaNonce": 1,
+        "notarizedAtDestinationInMetaHash": "",
+        "miniblockType": "TxBlock",
+        "miniblockHash": "",
+        "timestamp": 1694437098,
+        "data": null,
+        "status": status,
+        "hyperblockNonce": 1,
+        "hyperblockHash": "",
+        "smartContractResults": scrs,
+        "logs": { "address": CONTRACT_A, "events": events },
+    }))
+    .unwrap()
+}
+
+fn scr_json(
+    hash: &str,
+    prev_tx_hash: &str,
+    sender: &str,
+    receiver: &str,
+    data: &str,
+    call_type: u8,
+) -> Value {
+    json!({
+        "hash": hash,
+        "nonce": 0,
+        "value": 0,
+        "receiver": receiver,
+        "sender": sender,
+        "data": data,
+        "prevTxHash": prev_tx_hash,
+        "originalTxHash": TX_HASH,
+        "gasLimit": 0,
+        "gasPrice": 1000000000,
+        "callType": call_type,
+    })
+}
+
+fn address_topic(bech32: &str) -> String {
+    base64::encode(Address::from_bech32_string(bech32).unwrap().to_bytes())
+}
+
+#[test]
+fn test_async_call_with_callback() {
+    // listed out of order on purpose: the callback comes before the call it answers
+    let scrs = json!([
+        scr_json("bb", "aa", CONTRACT_B, CONTRACT_A, "@00@0a@0218711a00", 2),
+        scr_json(
+            "aa",
+            TX_HASH,
+            CONTRACT_A,
+            CONTRACT_B,
+            "returnTwoU64@4f3c60",
+            1
+        ),
+    ]);
+    let events = json!([{
+        "address": CONTRACT_A,
+        "identifier": "writeLog",
+        "topics": [address_topic(USER)],
+        "data": base64::encode("@6f6b@07"),
+    }]);
+    let outcome = TxOutcome::from_network_tx(&tx_json("success", scrs, events));
+
+    assert!(outcome.is_success());
+    assert_eq!(outcome.status, TxStatus::Success);
+    assert_eq!(outcome.return_code.as_deref(), Some("ok"));
+    assert_eq!(outcome.return_data, vec![vec![7u8]]);
+
+    assert_eq!(outcome.async_calls.len(), 1);
+    let async_call = &outcome.async_calls[0];
+    assert_eq!(async_call.function, "returnTwoU64");
+    assert_eq!(async_call.callee.to_string(), CONTRACT_B);
+    let callback = async_call.callback.as_ref().unwrap();
+    assert_eq!(callback.scr_hash, "bb");
+    assert_eq!(callback.return_code, 0);
+    assert_eq!(
+        callback.return_values,
+        vec![vec![0x0a], hex::decode("0218711a00").unwrap()]
+    );
+}
+
+#[test]
+fn test_esdt_transfers() {
+    let events = json!([
+        {
+            "address": USER,
+            "identifier": "ESDTTransfer",
+            "topics": [
+                base64::encode("WEGLD-abcdef"),
+                "",
+                base64::encode(hex::decode("0de0b6b3a7640000").unwrap()),
+                address_topic(CONTRACT_A),
+            ],
+            "data": null,
+        },
+        {
+            "address": CONTRACT_A,
+            "identifier": "MultiESDTNFTTransfer",
+            "topics": [
+                base64::encode("NFT-123456"),
+                base64::encode([0x05]),
+                base64::encode([0x01]),
+                base64::encode("MEX-abcdef"),
+                "",
+                base64::encode([0x64]),
+                address_topic(CONTRACT_B),
+            ],
+            "data": null,
+        },
+    ]);
+    let outcome = TxOutcome::from_network_tx(&tx_json("success", json!([]), events));
+
+    assert_eq!(outcome.esdt_transfers.len(), 2);
+    let transfer = &outcome.esdt_transfers[0];
+    assert_eq!(transfer.sender.to_string(), USER);
+    assert_eq!(transfer.receiver.to_string(), CONTRACT_A);
+    assert_eq!(transfer.tokens[0].token_identifier, "WEGLD-abcdef");
+    assert_eq!(transfer.tokens[0].nonce, 0);
+    assert_eq!(transfer.tokens[0].amount, BigUint::from(10u64.pow(18)));
+
+    let multi_transfer = &outcome.esdt_transfers[1];
+    assert_eq!(multi_transfer.receiver.to_string(), CONTRACT_B);
+    assert_eq!(multi_transfer.tokens.len(), 2);
+    assert_eq!(multi_transfer.tokens[0].nonce, 5);
+    assert_eq!(multi_transfer.tokens[1].amount, BigUint::from(100u32));
+}
+
+#[test]
+fn test_errors() {
+    let events = json!([
+        {
+            "address": CONTRACT_A,
+            "identifier": "signalError",
+            "topics": [address_topic(USER), base64::encode("wrong amount")],
+            "data": null,
+        },
+        {
+            "address": CONTRACT_A,
+            "identifier": "internalVMErrors",
+            "topics": [address_topic(CONTRACT_A), base64::encode("add")],
+            "data": base64::encode("\n\truntime.go:856 [error signalled by smartcontract] [add]"),
+        },
+    ]);
+    let outcome = TxOutcome::from_network_tx(&tx_json("fail", json!([]), events.clone()));
+    let error = outcome.error.unwrap();
+    assert_eq!(error.source, TxErrorSource::SignalError);
+    assert_eq!(error.message, "wrong amount");
+
+    let vm_error_only = json!([events[1]]);
+    let outcome = TxOutcome::from_network_tx(&tx_json("fail", json!([]), vm_error_only));
+    let error = outcome.error.unwrap();
+    assert_eq!(error.source, TxErrorSource::InternalVmError);
+    assert!(error.message.starts_with("runtime.go:856"));
+
+    let outcome = TxOutcome::from_network_tx(&tx_json("invalid", json!([]), json!([])));
+    let error = outcome.error.unwrap();
+    assert_eq!(error.source, TxErrorSource::Status);
+    assert_eq!(error.message, "invalid");
+}
+
+#[test]
+fn test_error_in_scr_logs() {
+    // cross-shard call: the transaction itself only moved to the destination shard
+    let mut scr = scr_json("aa", TX_HASH, USER, CONTRACT_A, "add@05", 0);
+    scr["logs"] = json!({
+        "address": CONTRACT_A,
+        "events": [{
+            "address": CONTRACT_A,
+            "identifier": "signalError",
+            "topics": [address_topic(USER), base64::encode("wrong amount")],
+            "data": null,
+        }],
+    });
+    let outcome = TxOutcome::from_network_tx(&tx_json("fail", json!([scr]), json!([])));
+
+    let error = outcome.error.unwrap();
+    assert_eq!(error.source, TxErrorSource::SignalError);
+    assert_eq!(error.message, "wrong amount");
+    assert_eq!(error.address.unwrap().to_string(), CONTRACT_A);
+}
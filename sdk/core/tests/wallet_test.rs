@@ -48,3 +48,19 @@ fn test_load_from_pem() {
         "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
     );
 }
+
+#[test]
+fn test_load_from_keystore() {
+    let wallet = Wallet::from_keystore_file("tests/alice.json", "password").unwrap();
+    let addr = wallet.address();
+    assert_eq!(
+        addr.to_bech32_string().unwrap(),
+        "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"
+    );
+}
+
+#[test]
+fn test_load_from_keystore_wrong_password() {
+    let result = Wallet::from_keystore_file("tests/alice.json", "not the password");
+    assert!(result.is_err());
+}
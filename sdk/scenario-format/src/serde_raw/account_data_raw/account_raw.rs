@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use crate::serde_raw::{EsdtRaw, ValueSubTree};
+use crate::serde_raw::{EsdtRaw, GuardiansRaw, ValueSubTree};
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -41,4 +41,8 @@ pub struct AccountRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub developer_rewards: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardians: Option<GuardiansRaw>,
 }
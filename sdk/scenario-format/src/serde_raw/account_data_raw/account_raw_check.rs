@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::serde_raw::{CheckBytesValueRaw, CheckEsdtMapRaw, CheckStorageRaw};
+use crate::serde_raw::{CheckBytesValueRaw, CheckEsdtMapRaw, CheckGuardiansRaw, CheckStorageRaw};
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -43,4 +43,8 @@ pub struct CheckAccountRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub async_call_data: CheckBytesValueRaw,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckGuardiansRaw::is_unspecified")]
+    pub guardians: CheckGuardiansRaw,
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serde_raw::ValueSubTree;
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GuardiansRaw {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<GuardianRaw>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<GuardianRaw>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guarded: Option<ValueSubTree>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuardianRaw {
+    pub address: ValueSubTree,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_epoch: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_uid: Option<ValueSubTree>,
+}
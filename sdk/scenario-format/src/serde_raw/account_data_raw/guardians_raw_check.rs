@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::serde_raw::CheckBytesValueRaw;
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckGuardiansRaw {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<CheckGuardianRaw>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<CheckGuardianRaw>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub guarded: CheckBytesValueRaw,
+}
+
+impl CheckGuardiansRaw {
+    pub fn is_unspecified(&self) -> bool {
+        self.active.is_none() && self.pending.is_none() && self.guarded.is_unspecified()
+    }
+}
+
+/// An empty address checks that there is no such guardian.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckGuardianRaw {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub address: CheckBytesValueRaw,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub activation_epoch: CheckBytesValueRaw,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub service_uid: CheckBytesValueRaw,
+}
@@ -1,7 +1,11 @@
 mod account_raw;
 mod account_raw_check;
 mod accounts_raw_check;
+mod guardians_raw;
+mod guardians_raw_check;
 
 pub use account_raw::*;
 pub use account_raw_check::*;
 pub use accounts_raw_check::*;
+pub use guardians_raw::*;
+pub use guardians_raw_check::*;
@@ -40,7 +40,8 @@ impl Serialize for CheckLogsRaw {
             CheckLogsRaw::Unspecified => serializer.serialize_str(""),
             CheckLogsRaw::Star => serializer.serialize_str("*"),
             CheckLogsRaw::List(l) => {
-                let mut seq = serializer.serialize_seq(Some(l.list.len()))?;
+                let len = l.list.len() + l.more_allowed_at_end as usize;
+                let mut seq = serializer.serialize_seq(Some(len))?;
                 for item in &l.list {
                     seq.serialize_element(item)?;
                 }
@@ -32,4 +32,8 @@ pub struct TxCallRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_price: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<ValueSubTree>,
 }
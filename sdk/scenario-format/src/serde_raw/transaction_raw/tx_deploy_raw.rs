@@ -25,4 +25,8 @@ pub struct TxDeployRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_price: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<ValueSubTree>,
 }
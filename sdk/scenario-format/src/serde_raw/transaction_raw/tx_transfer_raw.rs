@@ -28,4 +28,8 @@ pub struct TxTransferRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_price: Option<ValueSubTree>,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guardian: Option<ValueSubTree>,
 }
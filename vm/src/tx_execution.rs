@@ -4,6 +4,7 @@ mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
 mod exec_general_tx;
+mod exec_guarded_tx;
 mod system_sc;
 
 pub use blockchain_vm::{BlockchainVM, BlockchainVMRef};
@@ -12,4 +13,5 @@ pub use exec_call::*;
 pub use exec_contract_endpoint::*;
 pub use exec_create::*;
 pub(crate) use exec_general_tx::*;
+pub use exec_guarded_tx::*;
 pub use system_sc::*;
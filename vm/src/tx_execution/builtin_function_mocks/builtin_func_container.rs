@@ -1,11 +1,20 @@
 use super::{
     builtin_func_trait::BuiltinFunction,
     builtin_function_names::*,
+    esdt_management::{
+        ESDTFreeze, ESDTNftCreateRoleTransfer, ESDTSetBurnRoleForAll, ESDTSetLimitedTransfer,
+        ESDTSetRole, ESDTUnFreeze, ESDTUnSetBurnRoleForAll, ESDTUnSetLimitedTransfer,
+        ESDTUnSetRole, ESDTWipe,
+    },
     esdt_nft::{
-        ESDTLocalBurn, ESDTLocalMint, ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn,
-        ESDTNftCreate, ESDTNftUpdateAttributes,
+        ESDTLocalBurn, ESDTLocalMint, ESDTMetaDataRecreate, ESDTModifyCreator, ESDTModifyRoyalties,
+        ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn, ESDTNftCreate, ESDTNftUpdateAttributes,
+        ESDTSetNewUris,
+    },
+    general::{
+        ChangeOwner, ClaimDeveloperRewards, DeleteUsername, GuardAccount, SaveKeyValue,
+        SetGuardian, SetUsername, UnGuardAccount, UpgradeContract,
     },
-    general::{ChangeOwner, ClaimDeveloperRewards, DeleteUsername, SetUsername, UpgradeContract},
     transfer::{ESDTMultiTransfer, ESDTNftTransfer, ESDTTransfer},
     BuiltinFunctionEsdtTransferInfo,
 };
@@ -13,6 +22,7 @@ use crate::{
     tx_execution::BlockchainVMRef,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::EsdtLocalRole,
+    vm_err_msg,
 };
 
 /// Container for builtin function logic.
@@ -103,6 +113,31 @@ impl<'a> BuiltinFunctionCall<'a> {
                 ESDTNftUpdateAttributes,
                 f,
             ),
+            ESDT_MODIFY_ROYALTIES_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::ModifyRoyalties, ESDTModifyRoyalties, f)
+            },
+            ESDT_MODIFY_CREATOR_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::ModifyCreator, ESDTModifyCreator, f)
+            },
+            ESDT_SET_NEW_URIS_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::SetNewUri, ESDTSetNewUris, f)
+            },
+            ESDT_METADATA_RECREATE_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::NftRecreate, ESDTMetaDataRecreate, f)
+            },
+
+            ESDT_SET_ROLE_FUNC_NAME => self.execute_bf(ESDTSetRole, f),
+            ESDT_UNSET_ROLE_FUNC_NAME => self.execute_bf(ESDTUnSetRole, f),
+            ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME => {
+                self.execute_bf(ESDTNftCreateRoleTransfer, f)
+            },
+            ESDT_FREEZE_FUNC_NAME => self.execute_bf(ESDTFreeze, f),
+            ESDT_UNFREEZE_FUNC_NAME => self.execute_bf(ESDTUnFreeze, f),
+            ESDT_WIPE_FUNC_NAME => self.execute_bf(ESDTWipe, f),
+            ESDT_SET_LIMITED_TRANSFER_FUNC_NAME => self.execute_bf(ESDTSetLimitedTransfer, f),
+            ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME => self.execute_bf(ESDTUnSetLimitedTransfer, f),
+            ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME => self.execute_bf(ESDTSetBurnRoleForAll, f),
+            ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME => self.execute_bf(ESDTUnSetBurnRoleForAll, f),
 
             ESDT_MULTI_TRANSFER_FUNC_NAME => self.execute_bf(ESDTMultiTransfer, f),
             ESDT_NFT_TRANSFER_FUNC_NAME => self.execute_bf(ESDTNftTransfer, f),
@@ -111,6 +146,10 @@ impl<'a> BuiltinFunctionCall<'a> {
             CLAIM_DEVELOPER_REWARDS_FUNC_NAME => self.execute_bf(ClaimDeveloperRewards, f),
             SET_USERNAME_FUNC_NAME => self.execute_bf(SetUsername, f),
             DELETE_USERNAME_FUNC_NAME => self.execute_bf(DeleteUsername, f),
+            SAVE_KEY_VALUE_FUNC_NAME => self.execute_bf(SaveKeyValue, f),
+            SET_GUARDIAN_FUNC_NAME => self.execute_bf(SetGuardian, f),
+            GUARD_ACCOUNT_FUNC_NAME => self.execute_bf(GuardAccount, f),
+            UN_GUARD_ACCOUNT_FUNC_NAME => self.execute_bf(UnGuardAccount, f),
             UPGRADE_CONTRACT_FUNC_NAME => self.execute_bf(UpgradeContract, f),
             MIGRATE_USERNAME_FUNC_NAME => {
                 panic!("builtin function {MIGRATE_USERNAME_FUNC_NAME} was dropped")
@@ -141,7 +180,7 @@ impl<'a> BuiltinFunctionCall<'a> {
             self.execute_bf(builtin_func, f)
         } else {
             (
-                TxResult::from_vm_error("action is not allowed"),
+                TxResult::from_vm_error(vm_err_msg::ACTION_NOT_ALLOWED),
                 BlockchainUpdate::empty(),
             )
         }
@@ -150,6 +189,14 @@ impl<'a> BuiltinFunctionCall<'a> {
 
 fn check_allowed_to_execute(role: EsdtLocalRole, tx_input: &TxInput, tx_cache: &TxCache) -> bool {
     let token_identifier = tx_input.args[0].clone();
+    if is_burn_role(role)
+        && tx_cache
+            .get_esdt_global_settings(&token_identifier)
+            .burn_role_for_all
+    {
+        return true;
+    }
+
     let available_roles = tx_cache.with_account_mut(&tx_input.to, |account| {
         account.esdt.get_roles(&token_identifier)
     });
@@ -157,3 +204,7 @@ fn check_allowed_to_execute(role: EsdtLocalRole, tx_input: &TxInput, tx_cache: &
         .iter()
         .any(|available_role| available_role.as_slice() == role.name().as_bytes())
 }
+
+fn is_burn_role(role: EsdtLocalRole) -> bool {
+    matches!(role, EsdtLocalRole::Burn | EsdtLocalRole::NftBurn)
+}
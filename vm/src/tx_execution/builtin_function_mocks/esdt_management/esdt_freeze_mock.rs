@@ -0,0 +1,82 @@
+use crate::{
+    tx_execution::{
+        builtin_function_names::{ESDT_FREEZE_FUNC_NAME, ESDT_UNFREEZE_FUNC_NAME},
+        BlockchainVMRef,
+    },
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_management_common::{check_called_by_esdt_system_sc, error_output, result_with_log},
+};
+
+/// Blocks all transfers of a token from and to an account.
+pub struct ESDTFreeze;
+
+impl BuiltinFunction for ESDTFreeze {
+    fn name(&self) -> &str {
+        ESDT_FREEZE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_set_frozen(tx_input, tx_cache, ESDT_FREEZE_FUNC_NAME, true)
+    }
+}
+
+/// Reverts an `ESDTFreeze`.
+pub struct ESDTUnFreeze;
+
+impl BuiltinFunction for ESDTUnFreeze {
+    fn name(&self) -> &str {
+        ESDT_UNFREEZE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_set_frozen(tx_input, tx_cache, ESDT_UNFREEZE_FUNC_NAME, false)
+    }
+}
+
+fn execute_set_frozen(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    func_name: &str,
+    frozen: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err) = check_called_by_esdt_system_sc(&tx_input, 1) {
+        return error_output(err);
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        account.esdt.set_frozen(token_identifier, frozen);
+    });
+
+    let topics = vec![
+        token_identifier.to_vec(),
+        Vec::new(), // nonce = 0
+        Vec::new(), // value = 0
+        tx_input.to.to_vec(),
+    ];
+    let tx_result = result_with_log(&tx_input.from, func_name, topics);
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
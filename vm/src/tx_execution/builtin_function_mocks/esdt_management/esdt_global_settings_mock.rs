@@ -0,0 +1,150 @@
+use crate::{
+    tx_execution::{
+        builtin_function_names::{
+            ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME, ESDT_SET_LIMITED_TRANSFER_FUNC_NAME,
+            ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME, ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME,
+        },
+        BlockchainVMRef,
+    },
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    vm_err_msg,
+    world_mock::{AccountData, EsdtGlobalSettings, SYSTEM_ACCOUNT_ADDRESS},
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_management_common::{check_called_by_esdt_system_sc, error_output, result_with_log},
+};
+
+/// Restricts transfers to accounts that have the transfer role.
+pub struct ESDTSetLimitedTransfer;
+
+impl BuiltinFunction for ESDTSetLimitedTransfer {
+    fn name(&self) -> &str {
+        ESDT_SET_LIMITED_TRANSFER_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_change_global_settings(tx_input, tx_cache, self.name(), |settings| {
+            settings.limited_transfer = true
+        })
+    }
+}
+
+pub struct ESDTUnSetLimitedTransfer;
+
+impl BuiltinFunction for ESDTUnSetLimitedTransfer {
+    fn name(&self) -> &str {
+        ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_change_global_settings(tx_input, tx_cache, self.name(), |settings| {
+            settings.limited_transfer = false
+        })
+    }
+}
+
+/// Allows anyone to burn the token, without the burn role.
+pub struct ESDTSetBurnRoleForAll;
+
+impl BuiltinFunction for ESDTSetBurnRoleForAll {
+    fn name(&self) -> &str {
+        ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_change_global_settings(tx_input, tx_cache, self.name(), |settings| {
+            settings.burn_role_for_all = true
+        })
+    }
+}
+
+pub struct ESDTUnSetBurnRoleForAll;
+
+impl BuiltinFunction for ESDTUnSetBurnRoleForAll {
+    fn name(&self) -> &str {
+        ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_change_global_settings(tx_input, tx_cache, self.name(), |settings| {
+            settings.burn_role_for_all = false
+        })
+    }
+}
+
+/// Global settings live in the system account, which gets created on first use.
+fn execute_change_global_settings<M>(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    func_name: &str,
+    modify: M,
+) -> (TxResult, BlockchainUpdate)
+where
+    M: FnOnce(&mut EsdtGlobalSettings),
+{
+    if let Err(err) = check_called_by_esdt_system_sc(&tx_input, 1) {
+        return error_output(err);
+    }
+    if tx_input.to != SYSTEM_ACCOUNT_ADDRESS {
+        return error_output(TxPanic::vm_error(vm_err_msg::ONLY_SYSTEM_ACCOUNT_ACCEPTED));
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let system_account_exists =
+        tx_cache.with_account_or_else(&SYSTEM_ACCOUNT_ADDRESS, |_| true, || false);
+    if !system_account_exists {
+        tx_cache.insert_account(AccountData::new_empty(SYSTEM_ACCOUNT_ADDRESS));
+    }
+    tx_cache.with_account_mut(&SYSTEM_ACCOUNT_ADDRESS, |account| {
+        let mut settings = EsdtGlobalSettings::load(&account.storage, token_identifier);
+        modify(&mut settings);
+        settings.save(&mut account.storage, token_identifier);
+    });
+
+    let topics = vec![
+        token_identifier.to_vec(),
+        Vec::new(), // nonce = 0
+        Vec::new(), // value = 0
+    ];
+    let tx_result = result_with_log(&tx_input.to, func_name, topics);
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -0,0 +1,50 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_execution::is_esdt_system_sc_address,
+    tx_mock::{BlockchainUpdate, TxInput, TxLog, TxPanic, TxResult},
+    types::VMAddress,
+    vm_err_msg,
+};
+
+/// These builtin functions can only be invoked by the ESDT system SC, without any payment.
+pub(super) fn check_called_by_esdt_system_sc(
+    tx_input: &TxInput,
+    min_num_args: usize,
+) -> Result<(), TxPanic> {
+    if !is_esdt_system_sc_address(&tx_input.from) {
+        return Err(TxPanic::vm_error(vm_err_msg::ADDRESS_IS_NOT_ESDT_SYSTEM_SC));
+    }
+    if !tx_input.egld_value.is_zero() {
+        return Err(TxPanic::vm_error(
+            vm_err_msg::BUILTIN_FUNCTION_CALLED_WITH_VALUE,
+        ));
+    }
+    if tx_input.args.len() < min_num_args {
+        return Err(TxPanic::vm_error(
+            vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS,
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn error_output(err: TxPanic) -> (TxResult, BlockchainUpdate) {
+    (TxResult::from_panic_obj(&err), BlockchainUpdate::empty())
+}
+
+pub(super) fn result_with_log(
+    address: &VMAddress,
+    endpoint: &str,
+    topics: Vec<Vec<u8>>,
+) -> TxResult {
+    TxResult {
+        result_status: 0,
+        result_logs: vec![TxLog {
+            address: address.clone(),
+            endpoint: endpoint.into(),
+            topics,
+            data: vec![],
+        }],
+        ..Default::default()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    tx_execution::{
+        builtin_function_names::ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME, BlockchainVMRef,
+    },
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{top_decode_u64, EsdtLocalRole, VMAddress},
+    vm_err_msg,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_management_common::{check_called_by_esdt_system_sc, error_output, result_with_log},
+};
+
+/// Moves the NFT create role from one account to another, called by the ESDT system SC.
+///
+/// On the real chain this happens in two steps:
+/// - `ESDTNFTCreateRoleTransfer@token@destination` removes the role from the current holder;
+/// - `ESDTNFTCreateRoleTransfer@token@last_nonce` sets it on the destination, along with the last nonce.
+///
+/// The mock performs both steps at once when given a destination, but also accepts the second form.
+pub struct ESDTNftCreateRoleTransfer;
+
+impl BuiltinFunction for ESDTNftCreateRoleTransfer {
+    fn name(&self) -> &str {
+        ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_called_by_esdt_system_sc(&tx_input, 2) {
+            return error_output(err);
+        }
+        if tx_input.args.len() != 2 {
+            return error_output(TxPanic::vm_error(
+                vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS,
+            ));
+        }
+
+        let token_identifier = tx_input.args[0].as_slice();
+        let nft_create_role = [EsdtLocalRole::NftCreate.name().as_bytes().to_vec()];
+        let second_arg = tx_input.args[1].as_slice();
+
+        let destination = if second_arg.len() == 32 {
+            let destination = VMAddress::from_slice(second_arg);
+            let last_nonce = tx_cache.with_account_mut(&tx_input.to, |account| {
+                account
+                    .esdt
+                    .remove_roles(token_identifier, &nft_create_role);
+                account.esdt.get_last_nonce(token_identifier)
+            });
+            tx_cache.with_account_mut(&destination, |account| {
+                account.esdt.add_roles(token_identifier, &nft_create_role);
+                account.esdt.set_last_nonce(token_identifier, last_nonce);
+            });
+            destination
+        } else {
+            let last_nonce = top_decode_u64(second_arg);
+            tx_cache.with_account_mut(&tx_input.to, |account| {
+                account.esdt.add_roles(token_identifier, &nft_create_role);
+                account.esdt.set_last_nonce(token_identifier, last_nonce);
+            });
+            tx_input.to.clone()
+        };
+
+        let topics = vec![
+            token_identifier.to_vec(),
+            Vec::new(), // nonce = 0
+            Vec::new(), // value = 0
+            destination.to_vec(),
+        ];
+        let tx_result = result_with_log(
+            &tx_input.to,
+            ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME,
+            topics,
+        );
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
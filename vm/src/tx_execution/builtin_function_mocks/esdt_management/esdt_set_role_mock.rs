@@ -0,0 +1,98 @@
+use crate::{
+    tx_execution::{
+        builtin_function_names::{ESDT_SET_ROLE_FUNC_NAME, ESDT_UNSET_ROLE_FUNC_NAME},
+        BlockchainVMRef,
+    },
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::EsdtLocalRole,
+    vm_err_msg,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_management_common::{check_called_by_esdt_system_sc, error_output, result_with_log},
+};
+
+/// Gives local roles to an account. Called by the ESDT system SC on `setSpecialRole`.
+pub struct ESDTSetRole;
+
+impl BuiltinFunction for ESDTSetRole {
+    fn name(&self) -> &str {
+        ESDT_SET_ROLE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_change_roles(tx_input, tx_cache, ESDT_SET_ROLE_FUNC_NAME, true)
+    }
+}
+
+/// Takes local roles away from an account. Called by the ESDT system SC on `unSetSpecialRole`.
+pub struct ESDTUnSetRole;
+
+impl BuiltinFunction for ESDTUnSetRole {
+    fn name(&self) -> &str {
+        ESDT_UNSET_ROLE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        execute_change_roles(tx_input, tx_cache, ESDT_UNSET_ROLE_FUNC_NAME, false)
+    }
+}
+
+fn execute_change_roles(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    func_name: &str,
+    set: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(err) = check_called_by_esdt_system_sc(&tx_input, 2) {
+        return error_output(err);
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let roles = &tx_input.args[1..];
+    if roles
+        .iter()
+        .any(|role| EsdtLocalRole::from(role.as_slice()) == EsdtLocalRole::None)
+    {
+        return error_output(TxPanic::vm_error(
+            vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS,
+        ));
+    }
+
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        if set {
+            account.esdt.add_roles(token_identifier, roles);
+        } else {
+            account.esdt.remove_roles(token_identifier, roles);
+        }
+    });
+
+    let mut topics = vec![
+        token_identifier.to_vec(),
+        Vec::new(), // nonce = 0
+        Vec::new(), // value = 0
+    ];
+    topics.extend_from_slice(roles);
+    let tx_result = result_with_log(&tx_input.to, func_name, topics);
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
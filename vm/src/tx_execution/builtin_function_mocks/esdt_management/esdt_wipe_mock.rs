@@ -0,0 +1,72 @@
+use crate::{
+    tx_execution::{builtin_function_names::ESDT_WIPE_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_management_common::{check_called_by_esdt_system_sc, error_output, result_with_log},
+};
+
+/// Destroys the balance of a frozen account.
+///
+/// An optional nonce argument restricts the wipe to a single NFT/SFT instance.
+pub struct ESDTWipe;
+
+impl BuiltinFunction for ESDTWipe {
+    fn name(&self) -> &str {
+        ESDT_WIPE_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_called_by_esdt_system_sc(&tx_input, 1) {
+            return error_output(err);
+        }
+        if tx_input.args.len() > 2 {
+            return error_output(TxPanic::vm_error(
+                vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS,
+            ));
+        }
+
+        let token_identifier = tx_input.args[0].as_slice();
+        let nonce = tx_input
+            .args
+            .get(1)
+            .map(|nonce_bytes| top_decode_u64(nonce_bytes));
+
+        let wiped = tx_cache.with_account_mut(&tx_input.to, |account| {
+            if account.esdt.is_frozen(token_identifier) {
+                Ok(account.esdt.wipe(token_identifier, nonce))
+            } else {
+                Err(TxPanic::vm_error(
+                    vm_err_msg::CANNOT_WIPE_ACCOUNT_NOT_FROZEN,
+                ))
+            }
+        });
+        let wiped = match wiped {
+            Ok(wiped) => wiped,
+            Err(err) => return error_output(err),
+        };
+
+        let topics = vec![
+            token_identifier.to_vec(),
+            top_encode_u64(nonce.unwrap_or_default()),
+            wiped.to_bytes_be(),
+            tx_input.to.to_vec(),
+        ];
+        let tx_result = result_with_log(&tx_input.from, ESDT_WIPE_FUNC_NAME, topics);
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
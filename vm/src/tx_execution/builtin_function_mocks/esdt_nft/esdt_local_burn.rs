@@ -3,6 +3,7 @@ use num_bigint::BigUint;
 use crate::{
     tx_execution::{builtin_function_names::ESDT_LOCAL_BURN_FUNC_NAME, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -25,7 +26,8 @@ impl BuiltinFunction for ESDTLocalBurn {
         F: FnOnce(),
     {
         if tx_input.args.len() != 2 {
-            let err_result = TxResult::from_vm_error("ESDTLocalBurn expects 2 arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
 
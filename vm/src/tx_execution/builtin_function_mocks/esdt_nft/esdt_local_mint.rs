@@ -4,6 +4,7 @@ use crate::tx_execution::{builtin_function_names::ESDT_LOCAL_MINT_FUNC_NAME, Blo
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    vm_err_msg,
     world_mock::EsdtInstanceMetadata,
 };
 
@@ -27,7 +28,8 @@ impl BuiltinFunction for ESDTLocalMint {
         F: FnOnce(),
     {
         if tx_input.args.len() != 2 {
-            let err_result = TxResult::from_vm_error("ESDTLocalMint expects 2 arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
 
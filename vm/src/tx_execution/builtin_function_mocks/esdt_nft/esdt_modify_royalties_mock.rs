@@ -0,0 +1,75 @@
+use crate::{
+    tx_execution::{builtin_function_names::ESDT_MODIFY_ROYALTIES_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
+    world_mock::ROYALTIES_MAX,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+pub struct ESDTModifyRoyalties;
+
+impl BuiltinFunction for ESDTModifyRoyalties {
+    fn name(&self) -> &str {
+        ESDT_MODIFY_ROYALTIES_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if tx_input.args.len() != 3 {
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].as_slice();
+        let nonce = top_decode_u64(tx_input.args[1].as_slice());
+        let royalties = top_decode_u64(tx_input.args[2].as_slice());
+        if royalties > ROYALTIES_MAX {
+            let err_result = TxResult::from_vm_error(vm_err_msg::INVALID_ROYALTIES);
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            if let Some(instance) = account.esdt.get_instance_mut(token_identifier, nonce) {
+                instance.metadata.royalties = royalties;
+                true
+            } else {
+                false
+            }
+        });
+        if !found {
+            let err_result = TxResult::from_vm_error(vm_err_msg::NEW_NFT_DATA_ON_SENDER);
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let esdt_modify_royalties_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_MODIFY_ROYALTIES_FUNC_NAME.into(),
+            topics: vec![
+                token_identifier.to_vec(),
+                top_encode_u64(nonce),
+                Vec::new(), // value = 0
+                top_encode_u64(royalties),
+            ],
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: 0,
+            result_logs: vec![esdt_modify_royalties_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
@@ -3,6 +3,7 @@ use num_bigint::BigUint;
 use crate::{
     tx_execution::BlockchainVMRef,
     types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
 };
 
 use crate::{
@@ -31,7 +32,8 @@ impl BuiltinFunction for ESDTNftAddQuantity {
         F: FnOnce(),
     {
         if tx_input.args.len() != 3 {
-            let err_result = TxResult::from_vm_error("ESDTNFTAddQuantity expects 3 arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
 
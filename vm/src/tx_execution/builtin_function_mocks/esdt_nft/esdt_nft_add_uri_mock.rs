@@ -2,6 +2,7 @@ use crate::{
     tx_execution::{builtin_function_names::ESDT_NFT_ADD_URI_FUNC_NAME, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
     types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -24,7 +25,8 @@ impl BuiltinFunction for ESDTNftAddUri {
         F: FnOnce(),
     {
         if tx_input.args.len() < 3 {
-            let err_result = TxResult::from_vm_error("ESDTNFTAddURI expects at least 3 arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
 
@@ -32,11 +34,23 @@ impl BuiltinFunction for ESDTNftAddUri {
         let nonce = top_decode_u64(tx_input.args[1].as_slice());
         let mut new_uris = tx_input.args[2..].to_vec();
 
-        tx_cache.with_account_mut(&tx_input.from, |account| {
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            if account
+                .esdt
+                .get_instance_mut(&token_identifier, nonce)
+                .is_none()
+            {
+                return false;
+            }
             account
                 .esdt
                 .add_uris(token_identifier.as_slice(), nonce, new_uris.clone());
+            true
         });
+        if !found {
+            let err_result = TxResult::from_vm_error(vm_err_msg::NEW_NFT_DATA_ON_SENDER);
+            return (err_result, BlockchainUpdate::empty());
+        }
 
         let mut topics = vec![
             token_identifier.to_vec(),
@@ -4,6 +4,7 @@ use crate::{
     tx_execution::{builtin_function_names::ESDT_NFT_BURN_FUNC_NAME, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
     types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -26,7 +27,8 @@ impl BuiltinFunction for ESDTNftBurn {
         F: FnOnce(),
     {
         if tx_input.args.len() != 3 {
-            let err_result = TxResult::from_vm_error("ESDTNFTBurn expects 3 arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
 
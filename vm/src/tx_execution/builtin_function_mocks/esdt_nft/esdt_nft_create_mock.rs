@@ -4,6 +4,7 @@ use crate::{
     tx_execution::{builtin_function_names::ESDT_NFT_CREATE_FUNC_NAME, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
     types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
     world_mock::{EsdtInstance, EsdtInstanceMetadata},
 };
 
@@ -27,7 +28,8 @@ impl BuiltinFunction for ESDTNftCreate {
         F: FnOnce(),
     {
         if tx_input.args.len() < 7 {
-            let err_result = TxResult::from_vm_error("ESDTNFTCreate too few arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
         assert!(
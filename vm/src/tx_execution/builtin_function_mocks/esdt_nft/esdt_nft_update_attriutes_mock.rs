@@ -2,6 +2,7 @@ use crate::{
     tx_execution::{builtin_function_names::ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, BlockchainVMRef},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
     types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -24,7 +25,8 @@ impl BuiltinFunction for ESDTNftUpdateAttributes {
         F: FnOnce(),
     {
         if tx_input.args.len() != 3 {
-            let err_result = TxResult::from_vm_error("ESDTNFTUpdateAttributes expects 3 arguments");
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
             return (err_result, BlockchainUpdate::empty());
         }
 
@@ -32,11 +34,23 @@ impl BuiltinFunction for ESDTNftUpdateAttributes {
         let nonce = top_decode_u64(tx_input.args[1].as_slice());
         let attributes_bytes = tx_input.args[2].as_slice();
 
-        tx_cache.with_account_mut(&tx_input.from, |account| {
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            if account
+                .esdt
+                .get_instance_mut(token_identifier, nonce)
+                .is_none()
+            {
+                return false;
+            }
             account
                 .esdt
                 .update_attributes(token_identifier, nonce, attributes_bytes.to_vec());
+            true
         });
+        if !found {
+            let err_result = TxResult::from_vm_error(vm_err_msg::NEW_NFT_DATA_ON_SENDER);
+            return (err_result, BlockchainUpdate::empty());
+        }
 
         let esdt_nft_create_log = TxLog {
             address: tx_input.from,
@@ -0,0 +1,72 @@
+use crate::{
+    tx_execution::{builtin_function_names::ESDT_SET_NEW_URIS_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Unlike `ESDTNFTAddURI`, it replaces all existing URIs.
+pub struct ESDTSetNewUris;
+
+impl BuiltinFunction for ESDTSetNewUris {
+    fn name(&self) -> &str {
+        ESDT_SET_NEW_URIS_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if tx_input.args.len() < 3 {
+            let err_result =
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].as_slice();
+        let nonce = top_decode_u64(tx_input.args[1].as_slice());
+        let new_uris = tx_input.args[2..].to_vec();
+
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            if let Some(instance) = account.esdt.get_instance_mut(token_identifier, nonce) {
+                instance.metadata.uri = new_uris.clone();
+                true
+            } else {
+                false
+            }
+        });
+        if !found {
+            let err_result = TxResult::from_vm_error(vm_err_msg::NEW_NFT_DATA_ON_SENDER);
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let mut topics = vec![
+            token_identifier.to_vec(),
+            top_encode_u64(nonce),
+            Vec::new(), // value = 0
+        ];
+        topics.extend(new_uris);
+        let esdt_set_new_uris_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_SET_NEW_URIS_FUNC_NAME.into(),
+            topics,
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: 0,
+            result_logs: vec![esdt_set_new_uris_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
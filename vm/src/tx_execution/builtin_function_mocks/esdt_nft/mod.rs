@@ -1,15 +1,23 @@
 mod esdt_local_burn;
 mod esdt_local_mint;
+mod esdt_metadata_recreate_mock;
+mod esdt_modify_creator_mock;
+mod esdt_modify_royalties_mock;
 mod esdt_nft_add_quantity_mock;
 mod esdt_nft_add_uri_mock;
 mod esdt_nft_burn_mock;
 mod esdt_nft_create_mock;
 mod esdt_nft_update_attriutes_mock;
+mod esdt_set_new_uris_mock;
 
 pub use esdt_local_burn::*;
 pub use esdt_local_mint::*;
+pub use esdt_metadata_recreate_mock::*;
+pub use esdt_modify_creator_mock::*;
+pub use esdt_modify_royalties_mock::*;
 pub use esdt_nft_add_quantity_mock::*;
 pub use esdt_nft_add_uri_mock::*;
 pub use esdt_nft_burn_mock::*;
 pub use esdt_nft_create_mock::*;
 pub use esdt_nft_update_attriutes_mock::*;
+pub use esdt_set_new_uris_mock::*;
@@ -1,12 +1,22 @@
 mod change_owner_mock;
 mod claim_developer_rewards_mock;
 mod delete_username_mock;
+mod dns_address;
+mod guard_account_mock;
+mod guardian_common;
 mod migrate_username_mock;
+mod save_key_value_mock;
+mod set_guardian_mock;
 mod set_username_mock;
+mod un_guard_account_mock;
 mod upgrade_contract;
 
 pub use change_owner_mock::*;
 pub use claim_developer_rewards_mock::*;
 pub use delete_username_mock::DeleteUsername;
+pub use guard_account_mock::GuardAccount;
+pub use save_key_value_mock::*;
+pub use set_guardian_mock::SetGuardian;
 pub use set_username_mock::*;
+pub use un_guard_account_mock::UnGuardAccount;
 pub use upgrade_contract::*;
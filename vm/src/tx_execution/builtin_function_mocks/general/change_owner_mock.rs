@@ -5,6 +5,7 @@ use crate::tx_execution::{
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
+    vm_err_msg,
 };
 
 use super::super::builtin_func_trait::BuiltinFunction;
@@ -28,7 +29,7 @@ impl BuiltinFunction for ChangeOwner {
     {
         if tx_input.args.len() != 1 {
             return (
-                TxResult::from_vm_error("ChangeOwnerAddress expects 1 argument"),
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS),
                 BlockchainUpdate::empty(),
             );
         }
@@ -4,7 +4,10 @@ use crate::tx_execution::{
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    vm_err_msg,
+};
 
 use super::super::builtin_func_trait::BuiltinFunction;
 
@@ -27,7 +30,7 @@ impl BuiltinFunction for ClaimDeveloperRewards {
     {
         if !tx_input.args.is_empty() {
             return (
-                TxResult::from_vm_error("ClaimDeveloperRewards expects no arguments"),
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS),
                 BlockchainUpdate::empty(),
             );
         }
@@ -48,7 +51,7 @@ impl BuiltinFunction for ClaimDeveloperRewards {
             (TxResult::empty(), tx_cache.into_blockchain_updates())
         } else {
             (
-                TxResult::from_vm_error("operation in account not permitted"),
+                TxResult::from_vm_error(vm_err_msg::OPERATION_NOT_PERMITTED),
                 BlockchainUpdate::empty(),
             )
         }
@@ -0,0 +1,20 @@
+use crate::types::VMAddress;
+
+/// Number of zero bytes at the start of every smart contract address, followed by the VM type.
+const SC_ADDRESS_PREFIX_LENGTH: usize = 10;
+
+const DNS_ADDRESS_NAME: &[u8] = b"dns";
+
+/// The username builtin functions can only be called by the DNS contracts.
+///
+/// There is one DNS contract per possible value of the last byte of the username hash,
+/// at the `sc:dns#XX` addresses, i.e. the SC prefix, `dns`, `_` padding and the hash byte last.
+pub(super) fn is_dns_address(address: &VMAddress) -> bool {
+    if !address.is_smart_contract_address() {
+        return false;
+    }
+
+    let name_and_padding = &address.as_bytes()[SC_ADDRESS_PREFIX_LENGTH..31];
+    let (name, padding) = name_and_padding.split_at(DNS_ADDRESS_NAME.len());
+    name == DNS_ADDRESS_NAME && padding.iter().all(|b| *b == b'_')
+}
@@ -0,0 +1,51 @@
+use crate::{
+    tx_execution::{builtin_function_names::GUARD_ACCOUNT_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    vm_err_msg,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    guardian_common::{check_guardian_builtin_call, current_epoch, error_output},
+};
+
+/// Freezes the sender account, from then on all its transactions need to be co-signed by its guardian.
+///
+/// Requires an active guardian.
+pub struct GuardAccount;
+
+impl BuiltinFunction for GuardAccount {
+    fn name(&self) -> &str {
+        GUARD_ACCOUNT_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_guardian_builtin_call(&tx_input, &tx_cache, 0) {
+            return error_output(err);
+        }
+
+        let epoch = current_epoch(&tx_cache);
+        let guarded = tx_cache.with_account_mut(&tx_input.to, |account| {
+            account.guardians.update_active(epoch);
+            if account.guardians.active_guardian(epoch).is_none() {
+                return false;
+            }
+            account.guardians.guarded = true;
+            true
+        });
+        if !guarded {
+            return error_output(TxPanic::vm_error(vm_err_msg::NO_ACTIVE_GUARDIAN));
+        }
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
@@ -0,0 +1,38 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    vm_err_msg,
+};
+
+/// The guardian builtin functions can only be sent by a user account to itself, without any payment.
+pub(super) fn check_guardian_builtin_call(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    num_args: usize,
+) -> Result<(), TxPanic> {
+    if tx_input.args.len() != num_args {
+        return Err(TxPanic::vm_error(
+            vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS,
+        ));
+    }
+    if !tx_input.egld_value.is_zero() {
+        return Err(TxPanic::vm_error(
+            vm_err_msg::BUILTIN_FUNCTION_CALLED_WITH_VALUE,
+        ));
+    }
+    let is_user_account =
+        tx_cache.with_account(&tx_input.to, |account| account.contract_path.is_none());
+    if tx_input.from != tx_input.to || !is_user_account {
+        return Err(TxPanic::vm_error(vm_err_msg::OPERATION_NOT_PERMITTED));
+    }
+    Ok(())
+}
+
+pub(super) fn current_epoch(tx_cache: &TxCache) -> u64 {
+    tx_cache.blockchain_ref().current_block_info.block_epoch
+}
+
+pub(super) fn error_output(err: TxPanic) -> (TxResult, BlockchainUpdate) {
+    (TxResult::from_panic_obj(&err), BlockchainUpdate::empty())
+}
@@ -0,0 +1,61 @@
+use crate::{
+    tx_execution::{builtin_function_names::SAVE_KEY_VALUE_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    vm_err_msg,
+    world_mock::reserved::STORAGE_RESERVED_PREFIX,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Lets user accounts write arbitrary key-value pairs to their own storage.
+///
+/// Arguments come in pairs: key, value, key, value, ...
+pub struct SaveKeyValue;
+
+impl BuiltinFunction for SaveKeyValue {
+    fn name(&self) -> &str {
+        SAVE_KEY_VALUE_FUNC_NAME
+    }
+
+    // `usize::is_multiple_of` is not available on the toolchain pinned in CI
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if tx_input.args.is_empty() || tx_input.args.len() % 2 != 0 || tx_input.from != tx_input.to
+        {
+            return (
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        let is_user_account =
+            tx_cache.with_account(&tx_input.to, |account| account.contract_path.is_none());
+        let writes_reserved_key = tx_input
+            .args
+            .chunks(2)
+            .any(|pair| pair[0].starts_with(STORAGE_RESERVED_PREFIX));
+        if !is_user_account || writes_reserved_key {
+            return (
+                TxResult::from_vm_error(vm_err_msg::OPERATION_NOT_PERMITTED),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        tx_cache.with_account_mut(&tx_input.to, |account| {
+            for pair in tx_input.args.chunks(2) {
+                account.storage.insert(pair[0].clone(), pair[1].clone());
+            }
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
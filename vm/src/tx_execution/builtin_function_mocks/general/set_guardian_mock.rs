@@ -0,0 +1,63 @@
+use crate::{
+    tx_execution::{builtin_function_names::SET_GUARDIAN_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::VMAddress,
+    vm_err_msg,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    guardian_common::{check_guardian_builtin_call, current_epoch, error_output},
+};
+
+/// Registers a guardian for the sender account.
+///
+/// Arguments: guardian address, service UID.
+///
+/// When co-signed by the active guardian, the new guardian replaces it right away.
+/// Otherwise it becomes pending, and only activates after `GUARDIAN_ACTIVATION_EPOCHS_DELAY` epochs.
+pub struct SetGuardian;
+
+impl BuiltinFunction for SetGuardian {
+    fn name(&self) -> &str {
+        SET_GUARDIAN_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_guardian_builtin_call(&tx_input, &tx_cache, 2) {
+            return error_output(err);
+        }
+
+        let guardian_address = VMAddress::from_slice(&tx_input.args[0]);
+        if guardian_address == tx_input.to {
+            return error_output(TxPanic::vm_error(
+                vm_err_msg::CANNOT_SET_OWN_ADDRESS_AS_GUARDIAN,
+            ));
+        }
+
+        let service_uid = tx_input.args[1].clone();
+        let epoch = current_epoch(&tx_cache);
+        tx_cache.with_account_mut(&tx_input.to, |account| {
+            if tx_input.guardian.is_some() {
+                account
+                    .guardians
+                    .set_active(guardian_address, service_uid, epoch);
+            } else {
+                account
+                    .guardians
+                    .set_pending(guardian_address, service_uid, epoch);
+            }
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
@@ -1,8 +1,11 @@
 use crate::tx_execution::{builtin_function_names::SET_USERNAME_FUNC_NAME, BlockchainVMRef};
 
-use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    vm_err_msg,
+};
 
-use super::super::builtin_func_trait::BuiltinFunction;
+use super::{super::builtin_func_trait::BuiltinFunction, dns_address::is_dns_address};
 
 pub struct SetUsername;
 
@@ -21,9 +24,16 @@ impl BuiltinFunction for SetUsername {
     where
         F: FnOnce(),
     {
+        if !is_dns_address(&tx_input.from) {
+            return (
+                TxResult::from_vm_error(vm_err_msg::NOT_A_DNS_ADDRESS),
+                BlockchainUpdate::empty(),
+            );
+        }
+
         if tx_input.args.len() != 1 {
             return (
-                TxResult::from_vm_error("SetUserName expects 1 argument"),
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS),
                 BlockchainUpdate::empty(),
             );
         }
@@ -42,7 +52,7 @@ impl BuiltinFunction for SetUsername {
             (TxResult::empty(), tx_cache.into_blockchain_updates())
         } else {
             (
-                TxResult::from_vm_error("SetUserName expects 1 argument"),
+                TxResult::from_vm_error(vm_err_msg::USERNAME_CHANGE_DISABLED),
                 BlockchainUpdate::empty(),
             )
         }
@@ -0,0 +1,42 @@
+use crate::{
+    tx_execution::{builtin_function_names::UN_GUARD_ACCOUNT_FUNC_NAME, BlockchainVMRef},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    guardian_common::{check_guardian_builtin_call, error_output},
+};
+
+/// Unfreezes the sender account. The guardians are kept.
+///
+/// Since the account is guarded, the transaction needs to be co-signed by the guardian,
+/// this is checked before execution, like for any other transaction.
+pub struct UnGuardAccount;
+
+impl BuiltinFunction for UnGuardAccount {
+    fn name(&self) -> &str {
+        UN_GUARD_ACCOUNT_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _vm: &BlockchainVMRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: FnOnce(),
+    {
+        if let Err(err) = check_guardian_builtin_call(&tx_input, &tx_cache, 0) {
+            return error_output(err);
+        }
+
+        tx_cache.with_account_mut(&tx_input.to, |account| {
+            account.guardians.guarded = false;
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
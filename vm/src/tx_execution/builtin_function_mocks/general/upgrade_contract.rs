@@ -2,7 +2,11 @@ use crate::tx_execution::{
     builtin_function_names::UPGRADE_CONTRACT_FUNC_NAME, create_transfer_value_log, BlockchainVMRef,
 };
 
-use crate::tx_mock::{BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxResult};
+use crate::{
+    tx_mock::{BlockchainUpdate, CallType, TxCache, TxFunctionName, TxInput, TxResult},
+    types::VMCodeMetadata,
+    vm_err_msg,
+};
 
 use super::super::builtin_func_trait::BuiltinFunction;
 
@@ -25,15 +29,29 @@ impl BuiltinFunction for UpgradeContract {
     {
         if tx_input.args.len() < 2 {
             return (
-                TxResult::from_vm_error("upgradeContract expects at least 2 arguments"),
+                TxResult::from_vm_error(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS),
                 BlockchainUpdate::empty(),
             );
         }
 
         let new_code = tx_input.args[0].clone();
+        let Ok(code_metadata_bytes) = <[u8; 2]>::try_from(tx_input.args[1].as_slice()) else {
+            return (
+                TxResult::from_vm_error(vm_err_msg::INVALID_CODE_METADATA),
+                BlockchainUpdate::empty(),
+            );
+        };
+        let new_code_metadata = VMCodeMetadata::from(code_metadata_bytes);
 
-        // tx_input.args[1] is the code metadata, we ignore for now
-        // TODO: model code metadata in Mandos
+        let upgradeable = tx_cache.with_account(&tx_input.to, |account| {
+            account.code_metadata.is_upgradeable()
+        });
+        if !upgradeable {
+            return (
+                TxResult::from_vm_error(vm_err_msg::UPGRADE_NOT_ALLOWED),
+                BlockchainUpdate::empty(),
+            );
+        }
 
         let args = if tx_input.args.len() > 2 {
             tx_input.args[2..].to_vec()
@@ -43,6 +61,7 @@ impl BuiltinFunction for UpgradeContract {
 
         tx_cache.with_account_mut(&tx_input.to, |account| {
             account.contract_path = Some(new_code);
+            account.code_metadata = new_code_metadata;
         });
 
         let transfer_value_log = create_transfer_value_log(&tx_input, CallType::UpgradeFromSource);
@@ -2,6 +2,7 @@ use crate::{
     tx_execution::{builtin_function_names::ESDT_MULTI_TRANSFER_FUNC_NAME, BlockchainVMRef},
     tx_mock::TxLog,
     types::{top_decode_u64, top_encode_u64},
+    vm_err_msg,
 };
 
 use crate::{
@@ -82,11 +83,10 @@ fn build_log(tx_input: &TxInput, call: &ParsedTransferBuiltinFunCall) -> TxLog {
 
 fn try_parse_input(tx_input: &TxInput) -> Result<ParsedTransferBuiltinFunCall, &'static str> {
     if tx_input.args.len() < 2 {
-        return Err("MultiESDTNFTTransfer too few arguments");
+        return Err(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
     }
     if tx_input.to != tx_input.from {
-        // TODO: not sure what the real error message would be, certainly not this
-        return Err("MultiESDTNFTTransfer expects that to == from");
+        return Err(vm_err_msg::INVALID_RECEIVER_ADDRESS);
     }
 
     let mut arg_index = 0;
@@ -97,7 +97,7 @@ fn try_parse_input(tx_input: &TxInput) -> Result<ParsedTransferBuiltinFunCall, &
     arg_index += 1;
 
     if tx_input.args.len() < 2 + num_payments * 3 {
-        return Err("MultiESDTNFTTransfer too few arguments");
+        return Err(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
     }
 
     let mut raw_esdt_transfers = Vec::new();
@@ -5,6 +5,7 @@ use crate::{
     },
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
     types::VMAddress,
+    vm_err_msg,
 };
 
 use super::{
@@ -78,11 +79,10 @@ fn build_log(tx_input: &TxInput, call: &ParsedTransferBuiltinFunCall) -> TxLog {
 
 fn try_parse_input(tx_input: &TxInput) -> Result<ParsedTransferBuiltinFunCall, &'static str> {
     if tx_input.args.len() < 4 {
-        return Err("ESDTNFTTransfer too few arguments");
+        return Err(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
     }
     if tx_input.to != tx_input.from {
-        // TODO: not sure what the real error message would be, certainly not this
-        return Err("ESDTNFTTransfer expects that to == from");
+        return Err(vm_err_msg::INVALID_RECEIVER_ADDRESS);
     }
 
     let token_identifier = tx_input.args[0].clone();
@@ -4,6 +4,7 @@ use crate::{
         BuiltinFunctionEsdtTransferInfo,
     },
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    vm_err_msg,
 };
 
 use super::{
@@ -77,7 +78,7 @@ fn build_log(tx_input: &TxInput, call: &ParsedTransferBuiltinFunCall) -> TxLog {
 
 fn try_parse_input(tx_input: &TxInput) -> Result<ParsedTransferBuiltinFunCall, &'static str> {
     if tx_input.args.len() < 2 {
-        return Err("ESDTTransfer too few arguments");
+        return Err(vm_err_msg::INVALID_BUILTIN_FUNCTION_ARGUMENTS);
     }
 
     let token_identifier = tx_input.args[0].clone();
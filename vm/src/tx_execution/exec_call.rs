@@ -4,14 +4,15 @@ use crate::{
         merge_results, AsyncCallTxData, BlockchainUpdate, CallType, Promise, TxCache, TxContext,
         TxContextStack, TxInput, TxPanic, TxResult, TxResultCalls,
     },
+    types::VMCodeMetadata,
     with_shared::Shareable,
-    world_mock::{AccountData, AccountEsdt, BlockchainState},
+    world_mock::{AccountData, AccountEsdt, AccountGuardians, BlockchainState},
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
 use std::collections::HashMap;
 
-use super::BlockchainVMRef;
+use super::{execute_system_sc, is_system_sc_address, BlockchainVMRef};
 
 /// Executes the SC endpoint, as given by the current TxInput in the current TxContext.
 ///
@@ -25,6 +26,14 @@ pub fn execute_current_tx_context_input() {
     instance.call(func_name.as_str()).expect("execution error");
 }
 
+/// Same as `execute_current_tx_context_input`, but the current TxContext is marked read-only first.
+///
+/// Calls nested in it will also be read-only.
+pub fn execute_current_tx_context_input_read_only() {
+    TxContextStack::static_peek().set_read_only();
+    execute_current_tx_context_input();
+}
+
 impl BlockchainVMRef {
     pub fn execute_sc_query_lambda<F>(
         &self,
@@ -35,6 +44,13 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
+        if is_system_sc_address(&tx_input.to) {
+            return state.with_shared(|state_arc| {
+                let (tx_result, _) = execute_system_sc(tx_input, TxCache::new(state_arc));
+                tx_result
+            });
+        }
+
         let (tx_result, _) = self.execute_in_debugger(tx_input, state, f);
         tx_result
     }
@@ -243,7 +259,9 @@ impl BlockchainVMRef {
                 storage: HashMap::new(),
                 contract_path: None,
                 contract_owner: None,
+                code_metadata: VMCodeMetadata::DEFAULT,
                 developer_rewards: BigUint::zero(),
+                guardians: AccountGuardians::default(),
             });
             Ok(tx_cache.into_blockchain_updates())
         })
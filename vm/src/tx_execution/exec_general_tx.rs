@@ -4,9 +4,10 @@ use crate::{
     tx_execution::execute_system_sc,
     tx_mock::{
         BlockchainUpdate, CallType, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput,
-        TxLog, TxResult,
+        TxLog, TxPanic, TxResult,
     },
-    types::{top_encode_big_uint, VMAddress},
+    types::{top_encode_big_uint, VMAddress, VMCodeMetadata},
+    vm_err_msg,
     with_shared::Shareable,
 };
 
@@ -27,6 +28,48 @@ fn should_execute_sc_call(tx_input: &TxInput) -> bool {
     !tx_input.func_name.is_empty()
 }
 
+/// Plain transfers to a contract (no function called) need the contract to be payable,
+/// or payable by SC, when the sender is itself a contract.
+///
+/// Calls are not checked here, the endpoint decides for itself whether it accepts payments.
+fn check_payable(tx_input: &TxInput, tx_cache: &TxCache) -> Result<(), TxPanic> {
+    if !tx_input.func_name.is_empty() || !tx_input.to.is_smart_contract_address() {
+        return Ok(());
+    }
+
+    let has_value = !tx_input.egld_value.is_zero()
+        || tx_input
+            .esdt_values
+            .iter()
+            .any(|esdt_transfer| !esdt_transfer.value.is_zero());
+    if !has_value {
+        return Ok(());
+    }
+
+    let code_metadata = tx_cache.with_account_or_else(
+        &tx_input.to,
+        |account| {
+            account
+                .contract_path
+                .as_ref()
+                .map(|_| account.code_metadata)
+        },
+        || None,
+    );
+    let Some(code_metadata) = code_metadata else {
+        // ghost accounts and system SCs have no code metadata
+        return Ok(());
+    };
+
+    if code_metadata.is_payable()
+        || (code_metadata.is_payable_by_sc() && tx_input.from.is_smart_contract_address())
+    {
+        Ok(())
+    } else {
+        Err(TxPanic::vm_error(vm_err_msg::NON_PAYABLE_CONTRACT))
+    }
+}
+
 fn should_add_transfer_value_log(tx_input: &TxInput) -> bool {
     if tx_input.call_type == CallType::AsyncCallback
         && !tx_input.callback_payments.esdt_values.is_empty()
@@ -77,6 +120,10 @@ impl BlockchainVMRef {
     where
         F: FnOnce(),
     {
+        if let Err(err) = check_payable(&tx_input, &tx_cache) {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+
         if let Err(err) =
             tx_cache.transfer_egld_balance(&tx_input.from, &tx_input.to, &tx_input.egld_value)
         {
@@ -128,6 +175,7 @@ impl BlockchainVMRef {
         &self,
         mut tx_input: TxInput,
         contract_path: Vec<u8>,
+        code_metadata: VMCodeMetadata,
         tx_cache: TxCache,
         f: F,
     ) -> (TxResult, VMAddress, BlockchainUpdate)
@@ -151,7 +199,12 @@ impl BlockchainVMRef {
                 BlockchainUpdate::empty(),
             );
         }
-        tx_context_sh.create_new_contract(&new_address, contract_path, tx_input_ref.from.clone());
+        tx_context_sh.create_new_contract(
+            &new_address,
+            contract_path,
+            code_metadata,
+            tx_input_ref.from.clone(),
+        );
         tx_context_sh
             .tx_cache
             .increase_egld_balance(&new_address, &tx_input_ref.egld_value);
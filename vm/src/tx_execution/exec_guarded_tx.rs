@@ -0,0 +1,44 @@
+use crate::{
+    tx_mock::{TxInput, TxPanic},
+    vm_err_msg,
+    world_mock::BlockchainState,
+};
+
+use super::builtin_function_names::SET_GUARDIAN_FUNC_NAME;
+
+/// Checks the guardian co-signature of a transaction, before it gets executed.
+///
+/// Transactions from guarded accounts need to be co-signed by the active guardian,
+/// with the exception of `SetGuardian`, which is how a lost guardian gets replaced.
+/// Transactions from unguarded accounts must not be co-signed.
+///
+/// A failed check means the transaction is rejected by the protocol, so the nonce is not consumed.
+pub fn check_guarded_tx(tx_input: &TxInput, state: &BlockchainState) -> Result<(), TxPanic> {
+    let guarded = state
+        .accounts
+        .get(&tx_input.from)
+        .map(|account| account.guardians.guarded)
+        .unwrap_or_default();
+
+    match (&tx_input.guardian, guarded) {
+        (None, false) => Ok(()),
+        (Some(_), false) => Err(TxPanic::vm_error(vm_err_msg::GUARDED_TX_NOT_EXPECTED)),
+        (None, true) => {
+            if tx_input.func_name.as_str() == SET_GUARDIAN_FUNC_NAME {
+                Ok(())
+            } else {
+                Err(TxPanic::vm_error(
+                    vm_err_msg::NOT_ALLOWED_TO_BYPASS_GUARDIAN,
+                ))
+            }
+        },
+        (Some(tx_guardian), true) => {
+            let current_epoch = state.current_block_info.block_epoch;
+            let account = &state.accounts[&tx_input.from];
+            match account.guardians.active_guardian(current_epoch) {
+                Some(active) if &active.address == tx_guardian => Ok(()),
+                _ => Err(TxPanic::vm_error(vm_err_msg::GUARDIAN_MISMATCH)),
+            }
+        },
+    }
+}
@@ -1,26 +1,91 @@
+mod system_sc_delegation;
+mod system_sc_delegation_manager;
 mod system_sc_issue;
 mod system_sc_special_roles;
+mod system_sc_staking_util;
 mod system_sc_unimplemented;
+mod system_sc_validator;
 
 use crate::{
-    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
     types::VMAddress,
+    vm_err_msg,
 };
 use hex_literal::hex;
+use system_sc_delegation::*;
+use system_sc_delegation_manager::*;
 use system_sc_issue::*;
 use system_sc_special_roles::*;
 use system_sc_unimplemented::*;
+use system_sc_validator::*;
 
 /// Address of the system smart contract that manages ESDT.
 /// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u
 pub const ESDT_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] =
     hex!("000000000000000000010000000000000000000000000000000000000002ffff");
 
+/// Address of the system smart contract that handles validator staking.
+/// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqplllst77y4l
+pub const VALIDATOR_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] =
+    hex!("000000000000000000010000000000000000000000000000000000000001ffff");
+
+/// Address of the system smart contract that creates delegation contracts.
+/// Bech32: erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqylllslmq6y6
+pub const DELEGATION_MANAGER_SYSTEM_SC_ADDRESS_ARRAY: [u8; 32] =
+    hex!("000000000000000000010000000000000000000000000000000000000004ffff");
+
+/// Delegation contract addresses share this prefix with the other system SCs,
+/// followed by the contract index and the `ffffff` suffix.
+const DELEGATION_CONTRACT_ADDRESS_PREFIX: [u8; 10] = hex!("00000000000000000001");
+const DELEGATION_CONTRACT_INDEX_START: usize = 21;
+const DELEGATION_CONTRACT_SUFFIX_START: usize = 29;
+
+/// Address of the n-th delegation contract created by the delegation manager, starting from 1.
+pub fn delegation_contract_address(index: u64) -> VMAddress {
+    let mut address = [0u8; 32];
+    address[..DELEGATION_CONTRACT_ADDRESS_PREFIX.len()]
+        .copy_from_slice(&DELEGATION_CONTRACT_ADDRESS_PREFIX);
+    address[DELEGATION_CONTRACT_INDEX_START..DELEGATION_CONTRACT_SUFFIX_START]
+        .copy_from_slice(&index.to_be_bytes());
+    address[DELEGATION_CONTRACT_SUFFIX_START..].copy_from_slice(&[0xff; 3]);
+    VMAddress::new(address)
+}
+
 pub fn is_system_sc_address(address: &VMAddress) -> bool {
+    is_esdt_system_sc_address(address)
+        || address.as_array() == &VALIDATOR_SYSTEM_SC_ADDRESS_ARRAY
+        || address.as_array() == &DELEGATION_MANAGER_SYSTEM_SC_ADDRESS_ARRAY
+        || is_delegation_contract_address(address)
+}
+
+pub fn is_esdt_system_sc_address(address: &VMAddress) -> bool {
     address.as_array() == &ESDT_SYSTEM_SC_ADDRESS_ARRAY
 }
 
+pub fn is_delegation_contract_address(address: &VMAddress) -> bool {
+    let bytes = address.as_array();
+    bytes.starts_with(&DELEGATION_CONTRACT_ADDRESS_PREFIX)
+        && bytes[DELEGATION_CONTRACT_ADDRESS_PREFIX.len()..DELEGATION_CONTRACT_INDEX_START]
+            .iter()
+            .all(|b| *b == 0)
+        && bytes[DELEGATION_CONTRACT_SUFFIX_START..] == [0xff; 3]
+        && bytes[DELEGATION_CONTRACT_INDEX_START..DELEGATION_CONTRACT_SUFFIX_START] != [0u8; 8]
+}
+
 pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let address = tx_input.to.as_array();
+    if address == &VALIDATOR_SYSTEM_SC_ADDRESS_ARRAY {
+        execute_validator(tx_input, tx_cache)
+    } else if address == &DELEGATION_MANAGER_SYSTEM_SC_ADDRESS_ARRAY {
+        execute_delegation_manager(tx_input, tx_cache)
+    } else if is_delegation_contract_address(&tx_input.to) {
+        execute_delegation(tx_input, tx_cache)
+    } else {
+        execute_esdt_system_sc(tx_input, tx_cache)
+    }
+}
+
+fn execute_esdt_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     let func_name = &tx_input.func_name;
     match func_name.as_str() {
         "issue" => issue(tx_input, tx_cache),
@@ -55,6 +120,17 @@ pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         "setBurnRoleGlobally" => set_burn_role_globally(tx_input, tx_cache),
         "unsetBurnRoleGlobally" => unset_burn_role_globally(tx_input, tx_cache),
         "sendAllTransferRoleAddresses" => send_all_transfer_role_addresses(tx_input, tx_cache),
-        invalid_func_name => panic!("invalid system SC function: {invalid_func_name}"),
+        _ => (
+            TxResult::from_panic_obj(&invalid_system_sc_method()),
+            BlockchainUpdate::empty(),
+        ),
     }
 }
+
+/// All system SCs report unknown endpoints the same way.
+fn invalid_system_sc_method() -> TxPanic {
+    TxPanic::new(
+        vm_err_msg::RETURN_CODE_FUNCTION_NOT_FOUND,
+        vm_err_msg::SYSTEM_SC_INVALID_METHOD,
+    )
+}
@@ -0,0 +1,285 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    types::{top_encode_big_uint, top_encode_u64},
+    vm_err_msg,
+    world_mock::{DelegationData, StakeEntry, UnstakedFunds, SERVICE_FEE_MAX},
+};
+
+use super::{
+    invalid_system_sc_method,
+    system_sc_staking_util::{
+        arg_address, arg_big_uint, arg_u64, check_no_call_value, check_num_args, current_epoch,
+        into_system_sc_output, min_delegation_amount, send_egld_to_caller, staking_error,
+        SystemScResult,
+    },
+};
+
+pub fn execute_delegation(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = match tx_input.func_name.as_str() {
+        "delegate" => delegate(&tx_input, &tx_cache),
+        "unDelegate" => undelegate(&tx_input, &tx_cache),
+        "withdraw" => withdraw(&tx_input, &tx_cache),
+        "claimRewards" => claim_rewards(&tx_input, &tx_cache),
+        "reDelegateRewards" => redelegate_rewards(&tx_input, &tx_cache),
+        "changeServiceFee" => change_service_fee(&tx_input, &tx_cache),
+        "modifyTotalDelegationCap" => modify_total_delegation_cap(&tx_input, &tx_cache),
+        "getUserActiveStake" => {
+            get_user_value(&tx_input, &tx_cache, |entry, _| entry.active.clone())
+        },
+        "getUserUnStakedValue" => {
+            get_user_value(&tx_input, &tx_cache, |entry, _| entry.unstaked_value())
+        },
+        "getUserUnBondable" => get_user_value(&tx_input, &tx_cache, |entry, epoch| {
+            entry.unbondable_value(epoch)
+        }),
+        "getClaimableRewards" => get_user_value(&tx_input, &tx_cache, |entry, _| {
+            entry.unclaimed_rewards.clone()
+        }),
+        "getTotalActiveStake" => get_total_active_stake(&tx_input, &tx_cache),
+        "getNumUsers" => get_num_users(&tx_input, &tx_cache),
+        "getServiceFee" => get_service_fee(&tx_input, &tx_cache),
+        _ => Err(invalid_system_sc_method()),
+    };
+    into_system_sc_output(result, tx_cache)
+}
+
+fn load_delegation_data(tx_input: &TxInput, tx_cache: &TxCache) -> Result<DelegationData, TxPanic> {
+    tx_cache
+        .with_account_or_else(
+            &tx_input.to,
+            |account| DelegationData::load(&account.storage),
+            || None,
+        )
+        .ok_or_else(|| staking_error(vm_err_msg::DELEGATION_CONTRACT_NOT_FOUND))
+}
+
+fn save_delegation_data(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    mut delegation_data: DelegationData,
+) {
+    delegation_data.delegators.remove_empty();
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        delegation_data.save(&mut account.storage);
+    });
+}
+
+fn check_total_delegation_cap(
+    delegation_data: &DelegationData,
+    added_stake: &BigUint,
+) -> Result<(), TxPanic> {
+    let cap = &delegation_data.total_delegation_cap;
+    if !cap.is_zero() && &(delegation_data.delegators.total_active() + added_stake) > cap {
+        return Err(staking_error(vm_err_msg::TOTAL_DELEGATION_CAP_REACHED));
+    }
+    Ok(())
+}
+
+fn check_owner(tx_input: &TxInput, delegation_data: &DelegationData) -> Result<(), TxPanic> {
+    if tx_input.from != delegation_data.owner {
+        return Err(staking_error(vm_err_msg::ONLY_OWNER_CAN_CALL_METHOD));
+    }
+    Ok(())
+}
+
+fn delegate(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 0)?;
+    if tx_input.egld_value < min_delegation_amount() {
+        return Err(staking_error(vm_err_msg::DELEGATE_VALUE_BELOW_MINIMUM));
+    }
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    check_total_delegation_cap(&delegation_data, &tx_input.egld_value)?;
+    delegation_data
+        .delegators
+        .get_or_insert_mut(&tx_input.from)
+        .active += &tx_input.egld_value;
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    Ok(TxResult::empty())
+}
+
+/// Moves active stake to the unstaked list, where it waits for the unbonding period.
+fn undelegate(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 1)?;
+    let value = arg_big_uint(tx_input, 0);
+    let epoch = current_epoch(tx_cache);
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    let entry = delegation_data.delegators.get_or_insert_mut(&tx_input.from);
+    if entry.active.is_zero() {
+        return Err(staking_error(vm_err_msg::CALLER_IS_NOT_DELEGATOR));
+    }
+    if value.is_zero() || value > entry.active {
+        return Err(staking_error(vm_err_msg::INVALID_VALUE_TO_UNDELEGATE));
+    }
+    let remaining = &entry.active - &value;
+    if !remaining.is_zero() && remaining < min_delegation_amount() {
+        return Err(staking_error(vm_err_msg::UNDELEGATE_LEAVES_DUST));
+    }
+
+    entry.active = remaining;
+    entry.unstaked.push(UnstakedFunds { value, epoch });
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    Ok(TxResult::empty())
+}
+
+/// Sends back all undelegated funds whose unbonding period has passed.
+fn withdraw(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 0)?;
+    let epoch = current_epoch(tx_cache);
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    if delegation_data.delegators.get(&tx_input.from).is_none() {
+        return Err(staking_error(vm_err_msg::CALLER_IS_NOT_DELEGATOR));
+    }
+    let unbonded = delegation_data
+        .delegators
+        .get_or_insert_mut(&tx_input.from)
+        .withdraw_unbondable(epoch);
+    if unbonded.is_zero() {
+        return Err(staking_error(vm_err_msg::NOTHING_TO_UNBOND));
+    }
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    let mut tx_result = TxResult::empty();
+    send_egld_to_caller(tx_input, tx_cache, &unbonded, &mut tx_result)?;
+    Ok(tx_result)
+}
+
+fn claim_rewards(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 0)?;
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    if delegation_data.delegators.get(&tx_input.from).is_none() {
+        return Err(staking_error(vm_err_msg::CALLER_IS_NOT_DELEGATOR));
+    }
+    let entry = delegation_data.delegators.get_or_insert_mut(&tx_input.from);
+    let rewards = core::mem::take(&mut entry.unclaimed_rewards);
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    let mut tx_result = TxResult::empty();
+    if !rewards.is_zero() {
+        send_egld_to_caller(tx_input, tx_cache, &rewards, &mut tx_result)?;
+    }
+    Ok(tx_result)
+}
+
+/// Adds the unclaimed rewards of the caller to their active stake.
+fn redelegate_rewards(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 0)?;
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    let rewards = delegation_data
+        .delegators
+        .get(&tx_input.from)
+        .map(|entry| entry.unclaimed_rewards.clone())
+        .unwrap_or_default();
+    if rewards.is_zero() {
+        return Err(staking_error(vm_err_msg::NO_REWARDS_TO_REDELEGATE));
+    }
+    check_total_delegation_cap(&delegation_data, &rewards)?;
+
+    let entry = delegation_data.delegators.get_or_insert_mut(&tx_input.from);
+    entry.unclaimed_rewards = BigUint::zero();
+    entry.active += rewards;
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    Ok(TxResult::empty())
+}
+
+fn change_service_fee(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 1)?;
+    let service_fee = arg_u64(tx_input, 0)?;
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    check_owner(tx_input, &delegation_data)?;
+    if service_fee > SERVICE_FEE_MAX {
+        return Err(staking_error(vm_err_msg::NEW_SERVICE_FEE_OUT_OF_BOUNDS));
+    }
+    delegation_data.service_fee = service_fee;
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    Ok(TxResult::empty())
+}
+
+fn modify_total_delegation_cap(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 1)?;
+    let total_delegation_cap = arg_big_uint(tx_input, 0);
+
+    let mut delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    check_owner(tx_input, &delegation_data)?;
+    if !total_delegation_cap.is_zero()
+        && total_delegation_cap < delegation_data.delegators.total_active()
+    {
+        return Err(staking_error(
+            vm_err_msg::DELEGATION_CAP_SMALLER_THAN_ACTIVE,
+        ));
+    }
+    delegation_data.total_delegation_cap = total_delegation_cap;
+    save_delegation_data(tx_input, tx_cache, delegation_data);
+
+    Ok(TxResult::empty())
+}
+
+fn get_user_value<F>(tx_input: &TxInput, tx_cache: &TxCache, f: F) -> SystemScResult
+where
+    F: FnOnce(&StakeEntry, u64) -> BigUint,
+{
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 1)?;
+    let address = arg_address(tx_input, 0)?;
+
+    let delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    let entry = delegation_data
+        .delegators
+        .get(&address)
+        .ok_or_else(|| staking_error(vm_err_msg::VIEW_ONLY_FOR_EXISTING_DELEGATORS))?;
+    let value = f(entry, current_epoch(tx_cache));
+
+    Ok(TxResult {
+        result_values: vec![top_encode_big_uint(&value)],
+        ..Default::default()
+    })
+}
+
+fn get_total_active_stake(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    let delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    Ok(TxResult {
+        result_values: vec![top_encode_big_uint(
+            &delegation_data.delegators.total_active(),
+        )],
+        ..Default::default()
+    })
+}
+
+fn get_num_users(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    let delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    Ok(TxResult {
+        result_values: vec![top_encode_u64(
+            delegation_data.delegators.num_stakers() as u64
+        )],
+        ..Default::default()
+    })
+}
+
+fn get_service_fee(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    let delegation_data = load_delegation_data(tx_input, tx_cache)?;
+    Ok(TxResult {
+        result_values: vec![top_encode_u64(delegation_data.service_fee)],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,122 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::VMAddress,
+    vm_err_msg,
+    world_mock::{
+        reserved::STORAGE_DELEGATION_CONTRACTS_KEY, AccountData, DelegationData, SERVICE_FEE_MAX,
+    },
+};
+
+use super::{
+    delegation_contract_address, invalid_system_sc_method,
+    system_sc_staking_util::{
+        arg_big_uint, arg_u64, check_no_call_value, check_num_args, into_system_sc_output,
+        staking_error, SystemScResult,
+    },
+};
+
+/// 1250 EGLD, the deposit required to create a new delegation contract.
+const DELEGATION_CONTRACT_DEPOSIT: u128 = 1_250_000_000_000_000_000_000;
+
+pub fn execute_delegation_manager(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let result = match tx_input.func_name.as_str() {
+        "createNewDelegationContract" => create_new_delegation_contract(&tx_input, &tx_cache),
+        "getAllContractAddresses" => get_all_contract_addresses(&tx_input, &tx_cache),
+        _ => Err(invalid_system_sc_method()),
+    };
+    into_system_sc_output(result, tx_cache)
+}
+
+/// Creates a new delegation contract, owned by the caller.
+///
+/// The call value is the initial deposit, and it is delegated on behalf of the owner.
+///
+/// Arguments: total delegation cap (0 means unlimited), service fee.
+fn create_new_delegation_contract(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let total_delegation_cap = arg_big_uint(tx_input, 0);
+    let service_fee = arg_u64(tx_input, 1)?;
+
+    if tx_input.egld_value < BigUint::from(DELEGATION_CONTRACT_DEPOSIT) {
+        return Err(staking_error(vm_err_msg::NOT_ENOUGH_CALL_VALUE));
+    }
+    if service_fee > SERVICE_FEE_MAX {
+        return Err(staking_error(vm_err_msg::SERVICE_FEE_OUT_OF_BOUNDS));
+    }
+    if !total_delegation_cap.is_zero() && total_delegation_cap < tx_input.egld_value {
+        return Err(staking_error(vm_err_msg::TOTAL_DELEGATION_CAP_REACHED));
+    }
+
+    let mut contracts = load_contract_addresses(tx_input, tx_cache);
+    let already_deployed = contracts.iter().any(|contract| {
+        tx_cache.with_account_or_else(
+            contract,
+            |account| {
+                DelegationData::load(&account.storage)
+                    .is_some_and(|data| data.owner == tx_input.from)
+            },
+            || false,
+        )
+    });
+    if already_deployed {
+        return Err(staking_error(
+            vm_err_msg::CALLER_ALREADY_DEPLOYED_DELEGATION_SC,
+        ));
+    }
+
+    let new_address = delegation_contract_address(contracts.len() as u64 + 1);
+    let mut delegation_data =
+        DelegationData::new(tx_input.from.clone(), service_fee, total_delegation_cap);
+    delegation_data
+        .delegators
+        .get_or_insert_mut(&tx_input.from)
+        .active = tx_input.egld_value.clone();
+
+    let mut new_account = AccountData::new_empty(new_address.clone());
+    new_account.contract_owner = Some(tx_input.from.clone());
+    delegation_data.save(&mut new_account.storage);
+    tx_cache.insert_account(new_account);
+    tx_cache.transfer_egld_balance(&tx_input.to, &new_address, &tx_input.egld_value)?;
+
+    contracts.push(new_address.clone());
+    save_contract_addresses(tx_input, tx_cache, &contracts);
+
+    Ok(TxResult {
+        result_values: vec![new_address.to_vec()],
+        ..Default::default()
+    })
+}
+
+fn get_all_contract_addresses(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    let contracts = load_contract_addresses(tx_input, tx_cache);
+    Ok(TxResult {
+        result_values: contracts.iter().map(VMAddress::to_vec).collect(),
+        ..Default::default()
+    })
+}
+
+fn load_contract_addresses(tx_input: &TxInput, tx_cache: &TxCache) -> Vec<VMAddress> {
+    tx_cache.with_account(&tx_input.to, |account| {
+        account
+            .storage
+            .get(STORAGE_DELEGATION_CONTRACTS_KEY)
+            .map(|encoded| encoded.chunks(32).map(VMAddress::from_slice).collect())
+            .unwrap_or_default()
+    })
+}
+
+fn save_contract_addresses(tx_input: &TxInput, tx_cache: &TxCache, contracts: &[VMAddress]) {
+    let encoded = contracts.iter().flat_map(VMAddress::to_vec).collect();
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        account
+            .storage
+            .insert(STORAGE_DELEGATION_CONTRACTS_KEY.to_vec(), encoded);
+    });
+}
@@ -4,13 +4,14 @@ use crate::{
     crypto_functions::keccak256,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::{top_decode_u64, VMTokenType},
+    vm_err_msg,
 };
 
 /// Issues a new fungible token.
 #[allow(unused_variables)]
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
-        let tx_result = TxResult::from_vm_error("not enough arguments");
+        let tx_result = TxResult::from_vm_error(vm_err_msg::SYSTEM_SC_NOT_ENOUGH_ARGUMENTS);
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
@@ -25,7 +26,7 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
 #[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
-        let tx_result = TxResult::from_vm_error("not enough arguments");
+        let tx_result = TxResult::from_vm_error(vm_err_msg::SYSTEM_SC_NOT_ENOUGH_ARGUMENTS);
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
@@ -38,7 +39,7 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
 #[allow(unused_variables)]
 pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
-        let tx_result = TxResult::from_vm_error("not enough arguments");
+        let tx_result = TxResult::from_vm_error(vm_err_msg::SYSTEM_SC_NOT_ENOUGH_ARGUMENTS);
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
@@ -54,7 +55,7 @@ pub fn register_and_set_all_roles(
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
-        let tx_result = TxResult::from_vm_error("not enough arguments");
+        let tx_result = TxResult::from_vm_error(vm_err_msg::SYSTEM_SC_NOT_ENOUGH_ARGUMENTS);
         return (tx_result, BlockchainUpdate::empty());
     }
 
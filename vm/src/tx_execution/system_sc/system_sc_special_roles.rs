@@ -1,12 +1,13 @@
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::VMAddress,
+    vm_err_msg,
 };
 
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 3 {
         return (
-            TxResult::from_vm_error("setSpecialRole too few arguments"),
+            TxResult::from_vm_error(vm_err_msg::SYSTEM_SC_NOT_ENOUGH_ARGUMENTS),
             BlockchainUpdate::empty(),
         );
     }
@@ -0,0 +1,94 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{
+        AsyncCallTxData, BlockchainUpdate, TxCache, TxFunctionName, TxInput, TxPanic, TxResult,
+    },
+    types::{top_decode_u64, VMAddress},
+    vm_err_msg,
+};
+
+/// 1 EGLD, the minimum amount that can be delegated or left delegated.
+pub(super) const MIN_DELEGATION_AMOUNT: u128 = 1_000_000_000_000_000_000;
+
+pub(super) type SystemScResult = Result<TxResult, TxPanic>;
+
+/// Staking system SCs signal failures as user errors, same as on the real chain.
+pub(super) fn staking_error(message: &str) -> TxPanic {
+    TxPanic::user_error(message)
+}
+
+pub(super) fn into_system_sc_output(
+    result: SystemScResult,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    match result {
+        Ok(tx_result) => (tx_result, tx_cache.into_blockchain_updates()),
+        Err(err) => (TxResult::from_panic_obj(&err), BlockchainUpdate::empty()),
+    }
+}
+
+pub(super) fn min_delegation_amount() -> BigUint {
+    BigUint::from(MIN_DELEGATION_AMOUNT)
+}
+
+pub(super) fn current_epoch(tx_cache: &TxCache) -> u64 {
+    tx_cache.blockchain_ref().current_block_info.block_epoch
+}
+
+pub(super) fn check_no_call_value(tx_input: &TxInput) -> Result<(), TxPanic> {
+    if tx_input.egld_value.is_zero() && tx_input.esdt_values.is_empty() {
+        Ok(())
+    } else {
+        Err(staking_error(vm_err_msg::CALL_VALUE_MUST_BE_ZERO))
+    }
+}
+
+pub(super) fn check_num_args(tx_input: &TxInput, expected: usize) -> Result<(), TxPanic> {
+    if tx_input.args.len() == expected {
+        Ok(())
+    } else {
+        Err(staking_error(vm_err_msg::INVALID_NUMBER_OF_ARGUMENTS))
+    }
+}
+
+pub(super) fn arg_big_uint(tx_input: &TxInput, index: usize) -> BigUint {
+    BigUint::from_bytes_be(&tx_input.args[index])
+}
+
+pub(super) fn arg_u64(tx_input: &TxInput, index: usize) -> Result<u64, TxPanic> {
+    if tx_input.args[index].len() > 8 {
+        return Err(staking_error(vm_err_msg::INVALID_ARGUMENT));
+    }
+    Ok(top_decode_u64(&tx_input.args[index]))
+}
+
+pub(super) fn arg_address(tx_input: &TxInput, index: usize) -> Result<VMAddress, TxPanic> {
+    if tx_input.args[index].len() != 32 {
+        return Err(staking_error(vm_err_msg::INVALID_ADDRESS_ARGUMENT));
+    }
+    Ok(VMAddress::from_slice(&tx_input.args[index]))
+}
+
+/// Sends EGLD from the system SC back to the caller.
+///
+/// The transfer is also recorded as an outgoing call,
+/// so that callbacks and back-transfers of calling contracts can see it.
+pub(super) fn send_egld_to_caller(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    amount: &BigUint,
+    tx_result: &mut TxResult,
+) -> Result<(), TxPanic> {
+    tx_cache.transfer_egld_balance(&tx_input.to, &tx_input.from, amount)?;
+    tx_result.all_calls.push(AsyncCallTxData {
+        from: tx_input.to.clone(),
+        to: tx_input.from.clone(),
+        call_value: amount.clone(),
+        endpoint_name: TxFunctionName::EMPTY,
+        arguments: Vec::new(),
+        tx_hash: tx_input.tx_hash.clone(),
+    });
+    Ok(())
+}
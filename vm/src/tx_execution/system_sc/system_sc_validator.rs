@@ -0,0 +1,105 @@
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::top_encode_big_uint,
+    vm_err_msg,
+    world_mock::{UnstakedFunds, ValidatorStakeData},
+};
+
+use super::{
+    invalid_system_sc_method,
+    system_sc_staking_util::{
+        arg_big_uint, check_no_call_value, check_num_args, current_epoch, into_system_sc_output,
+        send_egld_to_caller, staking_error, SystemScResult,
+    },
+};
+
+pub fn execute_validator(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let result = match tx_input.func_name.as_str() {
+        "stake" => stake(&tx_input, &tx_cache),
+        "unStakeTokens" => unstake_tokens(&tx_input, &tx_cache),
+        "unBondTokens" => unbond_tokens(&tx_input, &tx_cache),
+        "getTotalStaked" => get_total_staked(&tx_input, &tx_cache),
+        _ => Err(invalid_system_sc_method()),
+    };
+    into_system_sc_output(result, tx_cache)
+}
+
+fn load_stake_data(tx_input: &TxInput, tx_cache: &TxCache) -> ValidatorStakeData {
+    tx_cache.with_account(&tx_input.to, |account| {
+        ValidatorStakeData::load(&account.storage)
+    })
+}
+
+fn save_stake_data(tx_input: &TxInput, tx_cache: &TxCache, mut stake_data: ValidatorStakeData) {
+    stake_data.stakers.remove_empty();
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        stake_data.save(&mut account.storage);
+    });
+}
+
+/// Node keys are not modelled, only the staked amount is recorded.
+fn stake(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    if tx_input.egld_value.is_zero() {
+        return Err(staking_error(vm_err_msg::NOT_ENOUGH_CALL_VALUE));
+    }
+
+    let mut stake_data = load_stake_data(tx_input, tx_cache);
+    stake_data.stakers.get_or_insert_mut(&tx_input.from).active += &tx_input.egld_value;
+    save_stake_data(tx_input, tx_cache, stake_data);
+
+    Ok(TxResult::empty())
+}
+
+fn unstake_tokens(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    check_num_args(tx_input, 1)?;
+    let value = arg_big_uint(tx_input, 0);
+    let epoch = current_epoch(tx_cache);
+
+    let mut stake_data = load_stake_data(tx_input, tx_cache);
+    let entry = stake_data.stakers.get_or_insert_mut(&tx_input.from);
+    if value.is_zero() || value > entry.active {
+        return Err(staking_error(vm_err_msg::INVALID_VALUE_TO_UNSTAKE));
+    }
+    entry.active -= &value;
+    entry.unstaked.push(UnstakedFunds { value, epoch });
+    save_stake_data(tx_input, tx_cache, stake_data);
+
+    Ok(TxResult::empty())
+}
+
+fn unbond_tokens(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    let epoch = current_epoch(tx_cache);
+
+    let mut stake_data = load_stake_data(tx_input, tx_cache);
+    let unbonded = stake_data
+        .stakers
+        .get_or_insert_mut(&tx_input.from)
+        .withdraw_unbondable(epoch);
+    if unbonded.is_zero() {
+        return Err(staking_error(vm_err_msg::NOTHING_TO_UNBOND));
+    }
+    save_stake_data(tx_input, tx_cache, stake_data);
+
+    let mut tx_result = TxResult::empty();
+    send_egld_to_caller(tx_input, tx_cache, &unbonded, &mut tx_result)?;
+    Ok(tx_result)
+}
+
+/// Active stake of the caller.
+fn get_total_staked(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_no_call_value(tx_input)?;
+    let stake_data = load_stake_data(tx_input, tx_cache);
+    let total_staked = stake_data
+        .stakers
+        .get(&tx_input.from)
+        .map(|entry| entry.active.clone())
+        .unwrap_or_default();
+    Ok(TxResult {
+        result_values: vec![top_encode_big_uint(&total_staked)],
+        ..Default::default()
+    })
+}
@@ -1,8 +1,11 @@
 use num_bigint::BigUint;
 
 use crate::{
-    tx_execution::is_system_sc_address, tx_mock::TxPanic, types::VMAddress,
-    world_mock::EsdtInstanceMetadata,
+    tx_execution::is_esdt_system_sc_address,
+    tx_mock::TxPanic,
+    types::{EsdtLocalRole, VMAddress},
+    vm_err_msg,
+    world_mock::{EsdtGlobalSettings, EsdtInstanceMetadata, SYSTEM_ACCOUNT_ADDRESS},
 };
 
 use super::TxCache;
@@ -15,7 +18,9 @@ impl TxCache {
     ) -> Result<(), TxPanic> {
         self.with_account_mut(address, |account| {
             if call_value > &account.egld_balance {
-                return Err(TxPanic::vm_error("failed transfer (insufficient funds)"));
+                return Err(TxPanic::vm_error(
+                    vm_err_msg::FAILED_TRANSFER_INSUFFICIENT_FUNDS,
+                ));
             }
             account.egld_balance -= call_value;
             Ok(())
@@ -92,10 +97,10 @@ impl TxCache {
         to: &VMAddress,
         value: &BigUint,
     ) -> Result<(), TxPanic> {
-        if !is_system_sc_address(from) {
+        if !is_esdt_system_sc_address(from) {
             self.subtract_egld_balance(from, value)?;
         }
-        if !is_system_sc_address(to) {
+        if !is_esdt_system_sc_address(to) {
             self.increase_egld_balance(to, value);
         }
         Ok(())
@@ -109,14 +114,73 @@ impl TxCache {
         nonce: u64,
         value: &BigUint,
     ) -> Result<(), TxPanic> {
-        if !is_system_sc_address(from) && !is_system_sc_address(to) {
+        if !is_esdt_system_sc_address(from) && !is_esdt_system_sc_address(to) {
+            self.check_esdt_transfer_allowed(from, to, esdt_token_identifier)?;
             let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value)?;
             self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
         }
         Ok(())
     }
+
+    /// Token-wide settings, kept in the system account.
+    pub fn get_esdt_global_settings(&self, esdt_token_identifier: &[u8]) -> EsdtGlobalSettings {
+        self.with_account_or_else(
+            &SYSTEM_ACCOUNT_ADDRESS,
+            |account| EsdtGlobalSettings::load(&account.storage, esdt_token_identifier),
+            EsdtGlobalSettings::default,
+        )
+    }
+
+    fn account_has_esdt_role(
+        &self,
+        address: &VMAddress,
+        esdt_token_identifier: &[u8],
+        role: EsdtLocalRole,
+    ) -> bool {
+        self.with_account_or_else(
+            address,
+            |account| {
+                account
+                    .esdt
+                    .has_role(esdt_token_identifier, role.name().as_bytes())
+            },
+            || false,
+        )
+    }
+
+    fn is_esdt_frozen(&self, address: &VMAddress, esdt_token_identifier: &[u8]) -> bool {
+        self.with_account_or_else(
+            address,
+            |account| account.esdt.is_frozen(esdt_token_identifier),
+            || false,
+        )
+    }
+
+    fn check_esdt_transfer_allowed(
+        &self,
+        from: &VMAddress,
+        to: &VMAddress,
+        esdt_token_identifier: &[u8],
+    ) -> Result<(), TxPanic> {
+        if self.is_esdt_frozen(from, esdt_token_identifier)
+            || self.is_esdt_frozen(to, esdt_token_identifier)
+        {
+            return Err(TxPanic::vm_error(vm_err_msg::ESDT_FROZEN));
+        }
+
+        if self
+            .get_esdt_global_settings(esdt_token_identifier)
+            .limited_transfer
+            && !self.account_has_esdt_role(from, esdt_token_identifier, EsdtLocalRole::Transfer)
+            && !self.account_has_esdt_role(to, esdt_token_identifier, EsdtLocalRole::Transfer)
+        {
+            return Err(TxPanic::vm_error(vm_err_msg::ACTION_NOT_ALLOWED));
+        }
+
+        Ok(())
+    }
 }
 
 fn err_insufficient_funds() -> TxPanic {
-    TxPanic::vm_error("insufficient funds")
+    TxPanic::vm_error(vm_err_msg::INSUFFICIENT_FUNDS)
 }
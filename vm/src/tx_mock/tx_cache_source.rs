@@ -13,7 +13,7 @@ pub trait TxCacheSource: Send + Sync {
 
 impl TxCacheSource for TxCache {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData> {
-        Some(self.with_account(address, AccountData::clone))
+        self.with_account_or_else(address, |account| Some(account.clone()), || None)
     }
 
     fn blockchain_ref(&self) -> &BlockchainState {
@@ -1,13 +1,16 @@
 use crate::{
     tx_execution::BlockchainVMRef,
-    types::VMAddress,
-    world_mock::{AccountData, AccountEsdt, BlockchainState, FailingExecutor},
+    types::{VMAddress, VMCodeMetadata},
+    world_mock::{AccountData, AccountEsdt, AccountGuardians, BlockchainState, FailingExecutor},
 };
 use num_bigint::BigUint;
 use num_traits::Zero;
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex, MutexGuard},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
 };
 
 use super::{
@@ -22,6 +25,9 @@ pub struct TxContext {
     pub back_transfers: Mutex<BackTransfers>,
     pub tx_result_cell: Mutex<TxResult>,
     pub b_rng: Mutex<BlockchainRng>,
+
+    /// Set for calls made via `executeReadOnly` and for all calls nested in them.
+    pub read_only: AtomicBool,
 }
 
 impl TxContext {
@@ -35,6 +41,7 @@ impl TxContext {
             back_transfers: Mutex::default(),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            read_only: AtomicBool::new(false),
         }
     }
 
@@ -50,7 +57,9 @@ impl TxContext {
             username: Vec::new(),
             contract_path: None,
             contract_owner: None,
+            code_metadata: VMCodeMetadata::DEFAULT,
             developer_rewards: BigUint::zero(),
+            guardians: AccountGuardians::default(),
         });
 
         let tx_input = TxInput {
@@ -69,6 +78,7 @@ impl TxContext {
             back_transfers: Mutex::default(),
             tx_result_cell: Mutex::new(TxResult::empty()),
             b_rng,
+            read_only: AtomicBool::new(false),
         }
     }
 
@@ -144,10 +154,20 @@ impl TxContext {
         self.b_rng.lock().unwrap()
     }
 
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::Relaxed)
+    }
+
+    /// Storage writes, transfers, deploys and async calls fail from then on.
+    pub fn set_read_only(&self) {
+        self.read_only.store(true, Ordering::Relaxed);
+    }
+
     pub fn create_new_contract(
         &self,
         new_address: &VMAddress,
         contract_path: Vec<u8>,
+        code_metadata: VMCodeMetadata,
         contract_owner: VMAddress,
     ) {
         assert!(
@@ -164,7 +184,9 @@ impl TxContext {
             username: Vec::new(),
             contract_path: Some(contract_path),
             contract_owner: Some(contract_owner),
+            code_metadata,
             developer_rewards: BigUint::zero(),
+            guardians: AccountGuardians::default(),
         });
     }
 
@@ -189,6 +211,7 @@ impl std::fmt::Debug for TxContext {
             .field("managed_types", &self.managed_types)
             .field("tx_result_cell", &self.tx_result_cell)
             .field("b_rng", &self.b_rng)
+            .field("read_only", &self.read_only)
             .finish()
     }
 }
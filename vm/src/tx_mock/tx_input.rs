@@ -23,6 +23,9 @@ pub struct TxInput {
     pub tx_hash: H256,
     pub promise_callback_closure_data: Option<Vec<u8>>,
     pub callback_payments: CallbackPayments,
+
+    /// Set when the transaction is co-signed by a guardian of the sender.
+    pub guardian: Option<VMAddress>,
 }
 
 impl Default for TxInput {
@@ -40,6 +43,7 @@ impl Default for TxInput {
             tx_hash: H256::zero(),
             promise_callback_closure_data: None,
             callback_payments: Default::default(),
+            guardian: None,
         }
     }
 }
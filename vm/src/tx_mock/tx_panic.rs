@@ -1,3 +1,5 @@
+use crate::vm_err_msg;
+
 #[derive(Debug, Clone)]
 pub struct TxPanic {
     pub status: u64,
@@ -13,10 +15,10 @@ impl TxPanic {
     }
 
     pub fn user_error(message: &str) -> TxPanic {
-        TxPanic::new(4, message)
+        TxPanic::new(vm_err_msg::RETURN_CODE_USER_ERROR, message)
     }
 
     pub fn vm_error(message: &str) -> TxPanic {
-        TxPanic::new(10, message)
+        TxPanic::new(vm_err_msg::RETURN_CODE_EXECUTION_FAILED, message)
     }
 }
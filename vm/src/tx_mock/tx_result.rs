@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::vm_err_msg;
+
 use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Debug)]
@@ -55,7 +57,7 @@ impl TxResult {
 
     pub fn from_panic_string(s: &str) -> Self {
         TxResult {
-            result_status: 4,
+            result_status: vm_err_msg::RETURN_CODE_USER_ERROR,
             result_message: s.to_string(),
             result_values: Vec::new(),
             result_logs: Vec::new(),
@@ -72,7 +74,7 @@ impl TxResult {
         S: Into<String>,
     {
         TxResult {
-            result_status: 10,
+            result_status: vm_err_msg::RETURN_CODE_EXECUTION_FAILED,
             result_message: result_message.into(),
             ..Default::default()
         }
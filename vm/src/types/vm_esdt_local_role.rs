@@ -9,6 +9,10 @@ const ESDT_ROLE_NFT_BURN: &str = "ESDTRoleNFTBurn";
 const ESDT_ROLE_NFT_ADD_URI: &str = "ESDTRoleNFTAddURI";
 const ESDT_ROLE_NFT_UPDATE_ATTRIBUTES: &str = "ESDTRoleNFTUpdateAttributes";
 const ESDT_ROLE_TRANSFER: &str = "ESDTTransferRole";
+const ESDT_ROLE_MODIFY_ROYALTIES: &str = "ESDTRoleModifyRoyalties";
+const ESDT_ROLE_MODIFY_CREATOR: &str = "ESDTRoleModifyCreator";
+const ESDT_ROLE_SET_NEW_URI: &str = "ESDTRoleSetNewURI";
+const ESDT_ROLE_NFT_RECREATE: &str = "ESDTRoleNFTRecreate";
 
 /// The VM implementation for EsdtLocalRole, used internally in builtin functions.
 ///
@@ -26,6 +30,10 @@ pub enum EsdtLocalRole {
     NftAddUri,
     NftUpdateAttributes,
     Transfer,
+    ModifyRoyalties,
+    ModifyCreator,
+    SetNewUri,
+    NftRecreate,
 }
 
 impl EsdtLocalRole {
@@ -40,6 +48,10 @@ impl EsdtLocalRole {
             Self::NftAddUri => 6,
             Self::NftUpdateAttributes => 7,
             Self::Transfer => 8,
+            Self::ModifyRoyalties => 9,
+            Self::ModifyCreator => 10,
+            Self::SetNewUri => 11,
+            Self::NftRecreate => 12,
         }
     }
 
@@ -54,6 +66,10 @@ impl EsdtLocalRole {
             Self::NftAddUri => ESDT_ROLE_NFT_ADD_URI,
             Self::NftUpdateAttributes => ESDT_ROLE_NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => ESDT_ROLE_TRANSFER,
+            Self::ModifyRoyalties => ESDT_ROLE_MODIFY_ROYALTIES,
+            Self::ModifyCreator => ESDT_ROLE_MODIFY_CREATOR,
+            Self::SetNewUri => ESDT_ROLE_SET_NEW_URI,
+            Self::NftRecreate => ESDT_ROLE_NFT_RECREATE,
         }
     }
 
@@ -68,13 +84,17 @@ impl EsdtLocalRole {
             Self::NftAddUri => EsdtLocalRoleFlags::NFT_ADD_URI,
             Self::NftUpdateAttributes => EsdtLocalRoleFlags::NFT_UPDATE_ATTRIBUTES,
             Self::Transfer => EsdtLocalRoleFlags::TRANSFER,
+            Self::ModifyRoyalties => EsdtLocalRoleFlags::MODIFY_ROYALTIES,
+            Self::ModifyCreator => EsdtLocalRoleFlags::MODIFY_CREATOR,
+            Self::SetNewUri => EsdtLocalRoleFlags::SET_NEW_URI,
+            Self::NftRecreate => EsdtLocalRoleFlags::NFT_RECREATE,
         }
     }
 }
 
 // TODO: can be done with macros, but I didn't find a public library that does it and is no_std
 // we can implement it, it's easy
-const ALL_ROLES: [EsdtLocalRole; 8] = [
+const ALL_ROLES: [EsdtLocalRole; 12] = [
     EsdtLocalRole::Mint,
     EsdtLocalRole::Burn,
     EsdtLocalRole::NftCreate,
@@ -83,6 +103,10 @@ const ALL_ROLES: [EsdtLocalRole; 8] = [
     EsdtLocalRole::NftAddUri,
     EsdtLocalRole::NftUpdateAttributes,
     EsdtLocalRole::Transfer,
+    EsdtLocalRole::ModifyRoyalties,
+    EsdtLocalRole::ModifyCreator,
+    EsdtLocalRole::SetNewUri,
+    EsdtLocalRole::NftRecreate,
 ];
 
 impl EsdtLocalRole {
@@ -103,6 +127,10 @@ impl From<u16> for EsdtLocalRole {
             6 => Self::NftAddUri,
             7 => Self::NftUpdateAttributes,
             8 => Self::Transfer,
+            9 => Self::ModifyRoyalties,
+            10 => Self::ModifyCreator,
+            11 => Self::SetNewUri,
+            12 => Self::NftRecreate,
             _ => Self::None,
         }
     }
@@ -127,6 +155,14 @@ impl<'a> From<&'a [u8]> for EsdtLocalRole {
             Self::NftUpdateAttributes
         } else if byte_slice == ESDT_ROLE_TRANSFER.as_bytes() {
             Self::Transfer
+        } else if byte_slice == ESDT_ROLE_MODIFY_ROYALTIES.as_bytes() {
+            Self::ModifyRoyalties
+        } else if byte_slice == ESDT_ROLE_MODIFY_CREATOR.as_bytes() {
+            Self::ModifyCreator
+        } else if byte_slice == ESDT_ROLE_SET_NEW_URI.as_bytes() {
+            Self::SetNewUri
+        } else if byte_slice == ESDT_ROLE_NFT_RECREATE.as_bytes() {
+            Self::NftRecreate
         } else {
             Self::None
         }
@@ -10,15 +10,19 @@ bitflags! {
     ///
     /// It might be a good idea to move it to some "common ground" crate, between the framework and the VM.
     pub struct EsdtLocalRoleFlags: u64 {
-        const NONE                  = 0b00000000;
-        const MINT                  = 0b00000001;
-        const BURN                  = 0b00000010;
-        const NFT_CREATE            = 0b00000100;
-        const NFT_ADD_QUANTITY      = 0b00001000;
-        const NFT_BURN              = 0b00010000;
-        const NFT_ADD_URI           = 0b00100000;
-        const NFT_UPDATE_ATTRIBUTES = 0b01000000;
-        const TRANSFER              = 0b10000000;
+        const NONE                  = 0b000000000000;
+        const MINT                  = 0b000000000001;
+        const BURN                  = 0b000000000010;
+        const NFT_CREATE            = 0b000000000100;
+        const NFT_ADD_QUANTITY      = 0b000000001000;
+        const NFT_BURN              = 0b000000010000;
+        const NFT_ADD_URI           = 0b000000100000;
+        const NFT_UPDATE_ATTRIBUTES = 0b000001000000;
+        const TRANSFER              = 0b000010000000;
+        const MODIFY_ROYALTIES      = 0b000100000000;
+        const MODIFY_CREATOR        = 0b001000000000;
+        const SET_NEW_URI           = 0b010000000000;
+        const NFT_RECREATE          = 0b100000000000;
     }
 }
 
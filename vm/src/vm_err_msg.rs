@@ -1,5 +1,33 @@
+//! Return codes and error messages produced by the VM and by the protocol mocks.
+//!
+//! They mirror the Go VM and the Go builtin functions/system SCs exactly,
+//! so that scenarios produce the same results on both backends.
+
+/// Return codes, as in `vmcommon.ReturnCode`.
+pub const RETURN_CODE_OK: u64 = 0;
+pub const RETURN_CODE_FUNCTION_NOT_FOUND: u64 = 1;
+pub const RETURN_CODE_FUNCTION_WRONG_SIGNATURE: u64 = 2;
+pub const RETURN_CODE_CONTRACT_NOT_FOUND: u64 = 3;
+pub const RETURN_CODE_USER_ERROR: u64 = 4;
+pub const RETURN_CODE_OUT_OF_GAS: u64 = 5;
+pub const RETURN_CODE_ACCOUNT_COLLISION: u64 = 6;
+pub const RETURN_CODE_OUT_OF_FUNDS: u64 = 7;
+pub const RETURN_CODE_CALL_STACK_OVERFLOW: u64 = 8;
+pub const RETURN_CODE_CONTRACT_INVALID: u64 = 9;
+pub const RETURN_CODE_EXECUTION_FAILED: u64 = 10;
+pub const RETURN_CODE_UPGRADE_FAILED: u64 = 11;
+pub const RETURN_CODE_SIMULATE_FAILED: u64 = 12;
+
+pub const FUNCTION_NOT_FOUND: &str = "invalid function (not found)";
+
 pub const NON_PAYABLE_FUNC_EGLD: &str = "function does not accept EGLD payment";
 pub const NON_PAYABLE_FUNC_ESDT: &str = "function does not accept ESDT payment";
+pub const NON_PAYABLE_CONTRACT: &str = "sending value to non payable contract";
+
+pub const UPGRADE_NOT_ALLOWED: &str = "upgrade not allowed";
+pub const CONTRACT_NOT_READABLE: &str = "contract is not readable";
+pub const CANNOT_WRITE_ON_READ_ONLY: &str = "cannot write on read only mode";
+pub const INVALID_CALL_ON_READ_ONLY_MODE: &str = "operation not permitted in read only mode";
 
 pub const BIG_INT_BITWISE_OPERATION_NEGATIVE: &str =
     "bitwise operations only allowed on positive integers";
@@ -10,7 +38,76 @@ pub const NUMBER_IS_NOT_NORMAL: &str =
     "number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
 
+pub const ARGUMENT_OUT_OF_RANGE: &str = "argument out of range";
+pub const INVALID_SIGNATURE: &str = "invalid signature";
+pub const STORAGE_WRITE_RESERVED_KEY: &str = "cannot write to storage under reserved key";
+
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
 
 pub const ERROR_NO_CALLBACK_CLOSURE: &str =
     "no callback for closure, cannot call callback directly";
+
+pub const INSUFFICIENT_FUNDS: &str = "insufficient funds";
+pub const FAILED_TRANSFER_INSUFFICIENT_FUNDS: &str = "failed transfer (insufficient funds)";
+
+// Builtin functions.
+
+pub const ACTION_NOT_ALLOWED: &str = "action is not allowed";
+pub const ESDT_FROZEN: &str = "ESDT is frozen for this account";
+pub const ADDRESS_IS_NOT_ESDT_SYSTEM_SC: &str = "address is not ESDT system SC";
+pub const ONLY_SYSTEM_ACCOUNT_ACCEPTED: &str = "only system account is accepted";
+pub const CANNOT_WIPE_ACCOUNT_NOT_FROZEN: &str =
+    "cannot wipe because the account is not frozen for this esdt token";
+pub const BUILTIN_FUNCTION_CALLED_WITH_VALUE: &str =
+    "built in function called with tx value is not allowed";
+pub const INVALID_BUILTIN_FUNCTION_ARGUMENTS: &str =
+    "invalid arguments to process built-in function";
+pub const INVALID_RECEIVER_ADDRESS: &str = "invalid receiver address";
+pub const OPERATION_NOT_PERMITTED: &str = "operation in account not permitted";
+pub const INVALID_ROYALTIES: &str = "invalid royalties value";
+pub const INVALID_TOKEN_NONCE: &str = "invalid token nonce";
+pub const NEW_NFT_DATA_ON_SENDER: &str = "new NFT data on sender";
+pub const INVALID_CODE_METADATA: &str = "invalid code metadata";
+pub const USERNAME_CHANGE_DISABLED: &str = "user name change is disabled";
+pub const NOT_A_DNS_ADDRESS: &str = "not a dns address";
+
+// Guardians.
+
+pub const CANNOT_SET_OWN_ADDRESS_AS_GUARDIAN: &str = "cannot set own address as guardian";
+pub const NO_ACTIVE_GUARDIAN: &str = "no active guardian";
+pub const GUARDED_TX_NOT_EXPECTED: &str = "guarded transaction not expected";
+pub const NOT_ALLOWED_TO_BYPASS_GUARDIAN: &str = "not allowed to bypass guardian";
+pub const GUARDIAN_MISMATCH: &str =
+    "mismatch between transaction guardian and configured account guardian";
+
+// System smart contracts.
+
+pub const SYSTEM_SC_INVALID_METHOD: &str = "invalid method to call";
+pub const SYSTEM_SC_NOT_ENOUGH_ARGUMENTS: &str = "not enough arguments";
+
+// Staking, validator and delegation system smart contracts.
+
+pub const CALL_VALUE_MUST_BE_ZERO: &str = "callValue must be 0";
+pub const INVALID_NUMBER_OF_ARGUMENTS: &str = "invalid number of arguments";
+pub const INVALID_ARGUMENT: &str = "invalid argument";
+pub const INVALID_ADDRESS_ARGUMENT: &str = "invalid address argument";
+pub const NOT_ENOUGH_CALL_VALUE: &str = "not enough call value";
+pub const INVALID_VALUE_TO_UNSTAKE: &str = "invalid value to unstake";
+pub const NOTHING_TO_UNBOND: &str = "nothing to unBond";
+pub const SERVICE_FEE_OUT_OF_BOUNDS: &str = "service fee out of bounds";
+pub const TOTAL_DELEGATION_CAP_REACHED: &str = "total delegation cap reached";
+pub const CALLER_ALREADY_DEPLOYED_DELEGATION_SC: &str = "caller already deployed a delegation sc";
+pub const DELEGATION_CONTRACT_NOT_FOUND: &str = "delegation contract not found";
+pub const ONLY_OWNER_CAN_CALL_METHOD: &str = "only owner can call this method";
+pub const DELEGATE_VALUE_BELOW_MINIMUM: &str =
+    "delegate value must be higher than minDelegationAmount 1000000000000000000";
+pub const CALLER_IS_NOT_DELEGATOR: &str = "caller is not a delegator";
+pub const INVALID_VALUE_TO_UNDELEGATE: &str = "invalid value to undelegate";
+pub const UNDELEGATE_LEAVES_DUST: &str =
+    "invalid value to undelegate - need to undelegate all - do not leave dust behind";
+pub const NO_REWARDS_TO_REDELEGATE: &str = "no rewards to redelegate";
+pub const NEW_SERVICE_FEE_OUT_OF_BOUNDS: &str = "new service fee out of bounds";
+pub const DELEGATION_CAP_SMALLER_THAN_ACTIVE: &str =
+    "cannot make total delegation cap smaller than active";
+pub const VIEW_ONLY_FOR_EXISTING_DELEGATORS: &str =
+    "view function works only for existing delegators";
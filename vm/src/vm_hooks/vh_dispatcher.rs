@@ -848,7 +848,14 @@ impl VMHooks for VMHooksDispatcher {
         arguments_handle: i32,
         result_handle: i32,
     ) -> i32 {
-        panic!("Unavailable: managed_execute_read_only")
+        self.handler.execute_on_dest_context_readonly_raw(
+            gas as u64,
+            address_handle,
+            function_handle,
+            arguments_handle,
+            result_handle,
+        );
+        0
     }
 
     fn managed_execute_on_same_context(
@@ -939,7 +946,8 @@ impl VMHooks for VMHooksDispatcher {
     }
 
     fn managed_get_code_metadata(&self, address_handle: i32, response_handle: i32) {
-        panic!("Unavailable: managed_get_code_metadata")
+        self.handler
+            .managed_get_code_metadata(address_handle, response_handle);
     }
 
     fn managed_is_builtin_function(&self, function_name_handle: i32) -> i32 {
@@ -35,6 +35,16 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         );
     }
 
+    fn managed_get_code_metadata(&self, address_handle: RawHandle, response_handle: RawHandle) {
+        let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
+        let code_metadata = self
+            .account_data(&address)
+            .map(|account| account.code_metadata)
+            .unwrap_or_default();
+        self.m_types_lock()
+            .mb_set(response_handle, code_metadata.to_vec());
+    }
+
     fn get_shard_of_address(&self, address_bytes: &[u8]) -> i32 {
         (address_bytes[address_bytes.len() - 1] % 3).into()
     }
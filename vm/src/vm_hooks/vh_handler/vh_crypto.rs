@@ -1,4 +1,4 @@
-use crate::{crypto_functions, types::RawHandle, vm_hooks::VMHooksHandlerSource};
+use crate::{crypto_functions, types::RawHandle, vm_err_msg, vm_hooks::VMHooksHandlerSource};
 
 pub trait VMHooksCrypto: VMHooksHandlerSource {
     fn sha256_managed(&self, dest: RawHandle, data_handle: RawHandle) {
@@ -27,7 +27,7 @@ pub trait VMHooksCrypto: VMHooksHandlerSource {
         let signature = types.mb_get(signature);
         let sig_valid = crypto_functions::verify_ed25519(key, message, signature);
         if !sig_valid {
-            self.vm_error("invalid signature");
+            self.vm_error(vm_err_msg::INVALID_SIGNATURE);
         }
     }
 }
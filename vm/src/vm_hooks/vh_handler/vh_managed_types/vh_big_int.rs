@@ -22,6 +22,20 @@ macro_rules! binary_op_method {
     };
 }
 
+macro_rules! binary_division_op_method {
+    ($method_name:ident, $rust_op_name:ident) => {
+        fn $method_name(&self, dest: RawHandle, x: RawHandle, y: RawHandle) {
+            let bi_x = self.m_types_lock().bi_get(x);
+            let bi_y = self.m_types_lock().bi_get(y);
+            if bi_y.sign() == num_bigint::Sign::NoSign {
+                self.vm_error(vm_err_msg::DIVISION_BY_0);
+            }
+            let result = bi_x.$rust_op_name(bi_y);
+            self.m_types_lock().bi_overwrite(dest, result);
+        }
+    };
+}
+
 macro_rules! binary_bitwise_op_method {
     ($method_name:ident, $rust_op_name:ident) => {
         fn $method_name(&self, dest: RawHandle, x: RawHandle, y: RawHandle) {
@@ -99,8 +113,8 @@ pub trait VMHooksBigInt: VMHooksHandlerSource + VMHooksError {
     binary_op_method! {bi_add, add}
     binary_op_method! {bi_sub, sub}
     binary_op_method! {bi_mul, mul}
-    binary_op_method! {bi_t_div, div}
-    binary_op_method! {bi_t_mod, rem}
+    binary_division_op_method! {bi_t_div, div}
+    binary_division_op_method! {bi_t_mod, rem}
 
     unary_op_method! {bi_abs, abs}
     unary_op_method! {bi_neg, neg}
@@ -124,11 +138,21 @@ pub trait VMHooksBigInt: VMHooksHandlerSource + VMHooksError {
         }
     }
 
-    unary_op_method! {bi_sqrt, sqrt}
+    fn bi_sqrt(&self, dest: RawHandle, x: RawHandle) {
+        let bi_x = self.m_types_lock().bi_get(x);
+        if bi_x.sign() == num_bigint::Sign::Minus {
+            self.vm_error(vm_err_msg::BAD_BOUNDS_LOWER);
+        }
+        let result = bi_x.sqrt();
+        self.m_types_lock().bi_overwrite(dest, result);
+    }
 
     fn bi_pow(&self, dest: RawHandle, x: RawHandle, y: RawHandle) {
         let bi_x = self.m_types_lock().bi_get(x);
         let bi_y = self.m_types_lock().bi_get(y);
+        if bi_y.sign() == num_bigint::Sign::Minus {
+            self.vm_error(vm_err_msg::BAD_BOUNDS_LOWER);
+        }
         let exp = big_int_to_i64(&bi_y).unwrap().try_into().unwrap();
         let result = pow(bi_x, exp);
         self.m_types_lock().bi_overwrite(dest, result);
@@ -136,6 +160,9 @@ pub trait VMHooksBigInt: VMHooksHandlerSource + VMHooksError {
 
     fn bi_log2(&self, x: RawHandle) -> i32 {
         let bi_x = self.m_types_lock().bi_get(x);
+        if bi_x.sign() == num_bigint::Sign::Minus {
+            self.vm_error(vm_err_msg::BAD_BOUNDS_LOWER);
+        }
         bi_x.bits() as i32 - 1
     }
 
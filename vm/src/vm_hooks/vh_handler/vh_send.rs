@@ -5,6 +5,7 @@ use crate::{
     },
     tx_mock::{AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer},
     types::{top_encode_big_uint, top_encode_u64, RawHandle, VMAddress, VMCodeMetadata},
+    vm_err_msg,
     vm_hooks::VMHooksHandlerSource,
 };
 use num_traits::Zero;
@@ -209,6 +210,8 @@ pub trait VMHooksSend: VMHooksHandlerSource {
         _extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) {
+        self.check_not_read_only();
+
         let contract_address = self.current_address().clone();
         let to = self.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.m_types_lock().bu_get(egld_value_handle);
@@ -369,6 +372,30 @@ pub trait VMHooksSend: VMHooksHandlerSource {
             .mb_set_vec_of_bytes(result_handle, result);
     }
 
+    fn execute_on_dest_context_readonly_raw(
+        &self,
+        _gas: u64,
+        to_handle: RawHandle,
+        endpoint_name_handle: RawHandle,
+        arg_buffer_handle: RawHandle,
+        result_handle: RawHandle,
+    ) {
+        let to = self.m_types_lock().mb_to_address(to_handle);
+        let endpoint_name = self
+            .m_types_lock()
+            .mb_to_function_name(endpoint_name_handle);
+        let arg_buffer = self.m_types_lock().mb_get_vec_of_bytes(arg_buffer_handle);
+
+        if !self.is_readable(&to) {
+            self.vm_error(vm_err_msg::CONTRACT_NOT_READABLE);
+        }
+
+        let result = self.perform_execute_on_dest_context_readonly(to, endpoint_name, arg_buffer);
+
+        self.m_types_lock()
+            .mb_set_vec_of_bytes(result_handle, result);
+    }
+
     fn clean_return_data(&self) {
         let mut tx_result = self.result_lock();
         tx_result.result_values.clear();
@@ -18,7 +18,12 @@ pub trait VMHooksStorageRead: VMHooksHandlerSource {
         dest: RawHandle,
     ) {
         let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
-        let value = self.storage_read_any_address(&address, self.m_types_lock().mb_get(key_handle));
+        // storage of non-readable accounts appears empty, just like in the protocol
+        let value = if self.is_readable(&address) {
+            self.storage_read_any_address(&address, self.m_types_lock().mb_get(key_handle))
+        } else {
+            Vec::new()
+        };
         self.m_types_lock().mb_set(dest, value);
     }
 }
@@ -1,9 +1,10 @@
 use std::sync::{Arc, MutexGuard};
 
 use multiversx_chain_vm_executor::BreakpointValue;
+use num_traits::Zero;
 
 use crate::{
-    tx_execution::execute_current_tx_context_input,
+    tx_execution::{execute_current_tx_context_input, execute_current_tx_context_input_read_only},
     tx_mock::{
         async_call_tx_input, AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType, TxCache,
         TxContext, TxFunctionName, TxInput, TxManagedTypes, TxPanic, TxResult,
@@ -39,7 +40,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     fn halt_with_error(&self, status: u64, message: &str) -> ! {
         *self.0.result_lock() = TxResult::from_panic_obj(&TxPanic::new(status, message));
         let breakpoint = match status {
-            4 => BreakpointValue::SignalError,
+            vm_err_msg::RETURN_CODE_USER_ERROR => BreakpointValue::SignalError,
             _ => BreakpointValue::ExecutionFailed,
         };
         std::panic::panic_any(breakpoint);
@@ -64,6 +65,9 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     }
 
     fn storage_write(&self, key: &[u8], value: &[u8]) {
+        if self.is_read_only() {
+            self.vm_error(vm_err_msg::CANNOT_WRITE_ON_READ_ONLY);
+        }
         self.check_reserved_key(key);
 
         self.0.with_contract_account_mut(|account| {
@@ -95,6 +99,10 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
             .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
     }
 
+    fn is_read_only(&self) -> bool {
+        self.0.is_read_only()
+    }
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -102,6 +110,8 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> ! {
+        self.check_not_read_only();
+
         let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.result_lock();
@@ -118,30 +128,38 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
-        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
-        let tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
-        let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
-        let (tx_result, blockchain_updates) = self.0.vm_ref.execute_builtin_function_or_default(
-            tx_input,
-            tx_cache,
-            execute_current_tx_context_input,
-        );
-
-        if tx_result.result_status == 0 {
-            self.sync_call_post_processing(tx_result, blockchain_updates)
-        } else {
-            // also kill current execution
-            self.halt_with_error(tx_result.result_status, &tx_result.result_message)
+        if !egld_value.is_zero() {
+            self.check_not_read_only();
         }
+
+        let read_only = self.is_read_only();
+        self.execute_on_dest_context_with_mode(to, egld_value, func_name, arguments, read_only)
+    }
+
+    fn perform_execute_on_dest_context_readonly(
+        &self,
+        to: VMAddress,
+        func_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        self.execute_on_dest_context_with_mode(
+            to,
+            num_bigint::BigUint::zero(),
+            func_name,
+            arguments,
+            true,
+        )
     }
 
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
         contract_code: Vec<u8>,
-        _code_metadata: VMCodeMetadata,
+        code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
     ) -> (VMAddress, Vec<Vec<u8>>) {
+        self.check_not_read_only();
+
         let contract_address = self.current_address();
         let tx_hash = self.tx_hash();
         let tx_input = TxInput {
@@ -162,6 +180,7 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         let (tx_result, new_address, blockchain_updates) = self.0.vm_ref.deploy_contract(
             tx_input,
             contract_code,
+            code_metadata,
             tx_cache,
             execute_current_tx_context_input,
         );
@@ -171,7 +190,9 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
                 new_address,
                 self.sync_call_post_processing(tx_result, blockchain_updates),
             ),
-            10 => self.vm_error(&tx_result.result_message), // TODO: not sure it's the right condition, it catches insufficient funds
+            // VM-level failures, such as insufficient funds, keep their message,
+            // errors signalled by the called contract are reported generically
+            vm_err_msg::RETURN_CODE_EXECUTION_FAILED => self.vm_error(&tx_result.result_message),
             _ => self.vm_error(vm_err_msg::ERROR_SIGNALLED_BY_SMARTCONTRACT),
         }
     }
@@ -183,6 +204,8 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) {
+        self.check_not_read_only();
+
         let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
         let mut tx_input = async_call_tx_input(&async_call_data, CallType::TransferExecute);
         if self.is_back_transfer(&tx_input) {
@@ -202,7 +225,9 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
 
                 let _ = self.sync_call_post_processing(tx_result, blockchain_updates);
             },
-            10 => self.vm_error(&tx_result.result_message), // TODO: not sure it's the right condition, it catches insufficient funds
+            // VM-level failures, such as insufficient funds, keep their message,
+            // errors signalled by the called contract are reported generically
+            vm_err_msg::RETURN_CODE_EXECUTION_FAILED => self.vm_error(&tx_result.result_message),
             _ => self.vm_error(vm_err_msg::ERROR_SIGNALLED_BY_SMARTCONTRACT),
         }
     }
@@ -228,6 +253,40 @@ impl DebugApiVMHooksHandler {
         }
     }
 
+    /// Calls nested in a read-only call are also read-only.
+    fn execute_on_dest_context_with_mode(
+        &self,
+        to: VMAddress,
+        egld_value: num_bigint::BigUint,
+        func_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+        read_only: bool,
+    ) -> Vec<Vec<u8>> {
+        let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
+        let tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        let tx_cache = TxCache::new(self.0.blockchain_cache_arc());
+        let (tx_result, blockchain_updates) = if read_only {
+            self.0.vm_ref.execute_builtin_function_or_default(
+                tx_input,
+                tx_cache,
+                execute_current_tx_context_input_read_only,
+            )
+        } else {
+            self.0.vm_ref.execute_builtin_function_or_default(
+                tx_input,
+                tx_cache,
+                execute_current_tx_context_input,
+            )
+        };
+
+        if tx_result.result_status == 0 {
+            self.sync_call_post_processing(tx_result, blockchain_updates)
+        } else {
+            // also kill current execution
+            self.halt_with_error(tx_result.result_status, &tx_result.result_message)
+        }
+    }
+
     fn sync_call_post_processing(
         &self,
         tx_result: TxResult,
@@ -249,7 +308,7 @@ impl DebugApiVMHooksHandler {
 
     fn check_reserved_key(&self, key: &[u8]) {
         if key.starts_with(STORAGE_RESERVED_PREFIX) {
-            self.vm_error("cannot write to storage under reserved key");
+            self.vm_error(vm_err_msg::STORAGE_WRITE_RESERVED_KEY);
         }
     }
 
@@ -105,6 +105,10 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         vec![]
     }
 
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -125,6 +129,15 @@ impl VMHooksHandlerSource for SingleTxApiVMHooksHandler {
         panic!("cannot launch contract calls in the SingleTxApi")
     }
 
+    fn perform_execute_on_dest_context_readonly(
+        &self,
+        _to: VMAddress,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the SingleTxApi")
+    }
+
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
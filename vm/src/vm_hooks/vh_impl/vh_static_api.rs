@@ -80,6 +80,10 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot access account data in the StaticApi")
     }
 
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
     fn perform_async_call(
         &self,
         _to: VMAddress,
@@ -100,6 +104,15 @@ impl VMHooksHandlerSource for StaticApiVMHooksHandler {
         panic!("cannot launch contract calls in the StaticApi")
     }
 
+    fn perform_execute_on_dest_context_readonly(
+        &self,
+        _to: VMAddress,
+        _func_name: TxFunctionName,
+        _args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        panic!("cannot launch contract calls in the StaticApi")
+    }
+
     fn perform_deploy(
         &self,
         _egld_value: num_bigint::BigUint,
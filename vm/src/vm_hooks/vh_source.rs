@@ -3,6 +3,7 @@ use std::{fmt::Debug, sync::MutexGuard};
 use crate::{
     tx_mock::{BackTransfers, TxFunctionName, TxInput, TxLog, TxManagedTypes, TxResult},
     types::{VMAddress, VMCodeMetadata, H256},
+    vm_err_msg,
     world_mock::{AccountData, BlockInfo},
 };
 
@@ -13,7 +14,7 @@ pub trait VMHooksHandlerSource: Debug {
     fn halt_with_error(&self, status: u64, message: &str) -> !;
 
     fn vm_error(&self, message: &str) -> ! {
-        self.halt_with_error(10, message)
+        self.halt_with_error(vm_err_msg::RETURN_CODE_EXECUTION_FAILED, message)
     }
 
     fn input_ref(&self) -> &TxInput;
@@ -62,8 +63,26 @@ pub trait VMHooksHandlerSource: Debug {
             .expect("missing current account")
     }
 
+    /// Contracts can always read their own state, other accounts only if they are flagged as readable.
+    fn is_readable(&self, address: &VMAddress) -> bool {
+        address == self.current_address()
+            || self
+                .account_data(address)
+                .is_some_and(|account| account.code_metadata.is_readable())
+    }
+
     fn account_code(&self, address: &VMAddress) -> Vec<u8>;
 
+    /// Set while executing a read-only call, or any call nested in it.
+    fn is_read_only(&self) -> bool;
+
+    /// Transfers, deploys and async calls are not allowed in read-only mode.
+    fn check_not_read_only(&self) {
+        if self.is_read_only() {
+            self.vm_error(vm_err_msg::INVALID_CALL_ON_READ_ONLY_MODE);
+        }
+    }
+
     fn perform_async_call(
         &self,
         to: VMAddress,
@@ -80,6 +99,15 @@ pub trait VMHooksHandlerSource: Debug {
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>>;
 
+    /// Same as `perform_execute_on_dest_context`, but without value,
+    /// and the called contract cannot modify state.
+    fn perform_execute_on_dest_context_readonly(
+        &self,
+        to: VMAddress,
+        func_name: TxFunctionName,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>>;
+
     fn perform_deploy(
         &self,
         egld_value: num_bigint::BigUint,
@@ -4,20 +4,26 @@ mod blockchain_mock;
 mod blockchain_mock_account_util;
 mod blockchain_state;
 mod esdt_data;
+mod esdt_global_settings;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
 mod failing_executor;
+mod guardian_data;
 pub mod reserved;
+mod staking_data;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_state::BlockchainState;
 pub use esdt_data::*;
+pub use esdt_global_settings::*;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
 pub use failing_executor::FailingExecutor;
+pub use guardian_data::*;
+pub use staking_data::*;
@@ -1,8 +1,11 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use super::AccountEsdt;
-use crate::{display_util::key_hex, types::VMAddress};
+use super::{AccountEsdt, AccountGuardians};
+use crate::{
+    display_util::key_hex,
+    types::{VMAddress, VMCodeMetadata},
+};
 use std::{collections::HashMap, fmt, fmt::Write};
 
 pub type AccountStorage = HashMap<Vec<u8>, Vec<u8>>;
@@ -17,7 +20,9 @@ pub struct AccountData {
     pub username: Vec<u8>,
     pub contract_path: Option<Vec<u8>>,
     pub contract_owner: Option<VMAddress>,
+    pub code_metadata: VMCodeMetadata,
     pub developer_rewards: BigUint,
+    pub guardians: AccountGuardians,
 }
 
 impl AccountData {
@@ -31,7 +36,9 @@ impl AccountData {
             username: vec![],
             contract_path: None,
             contract_owner: None,
+            code_metadata: VMCodeMetadata::DEFAULT,
             developer_rewards: BigUint::zero(),
+            guardians: AccountGuardians::default(),
         }
     }
 }
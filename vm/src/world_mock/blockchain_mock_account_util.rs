@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt::Write};
 
-use crate::{display_util::address_hex, types::VMAddress};
+use crate::{display_util::address_hex, tx_execution::is_system_sc_address, types::VMAddress};
 
 use super::{AccountData, BlockchainState};
 
@@ -51,6 +51,11 @@ impl BlockchainState {
         let is_sc = account.address.is_smart_contract_address();
         let has_code = self.check_account_has_code(account);
 
+        // system SCs are built into the VM, they have no code of their own
+        if is_system_sc_address(&account.address) {
+            return;
+        }
+
         assert!(
             !is_sc || has_code,
             "Account has a smart contract address but no code"
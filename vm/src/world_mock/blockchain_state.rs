@@ -5,7 +5,7 @@ use num_traits::Zero;
 
 use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo, DelegationData};
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
@@ -68,6 +68,12 @@ impl BlockchainState {
         account
             .storage
             .insert(STORAGE_REWARD_KEY.to_vec(), storage_v_rew.to_bytes_be());
+
+        // rewards sent to delegation contracts become claimable by the delegators
+        if let Some(mut delegation_data) = DelegationData::load(&account.storage) {
+            delegation_data.distribute_rewards(amount);
+            delegation_data.save(&mut account.storage);
+        }
     }
 
     pub fn put_new_token_identifier(&mut self, token_identifier: String) {
@@ -1,13 +1,13 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::{display_util::key_hex, types::VMTokenType};
+use crate::{display_util::key_hex, types::VMTokenType, vm_err_msg};
 use std::{
     collections::{hash_map::Iter, HashMap},
     fmt::{self, Write},
 };
 
-use super::{EsdtInstanceMetadata, EsdtInstances, EsdtRoles};
+use super::{EsdtInstance, EsdtInstanceMetadata, EsdtInstances, EsdtRoles};
 
 #[derive(Clone, Default, Debug)]
 pub struct EsdtData {
@@ -124,7 +124,7 @@ impl AccountEsdt {
             .unwrap_or_else(|| panic!("invalid token"))
             .instances
             .get_mut_by_nonce(nonce)
-            .unwrap_or_else(|| panic!("invalid token nonce"))
+            .unwrap_or_else(|| panic!("{}", vm_err_msg::INVALID_TOKEN_NONCE))
             .metadata
             .uri
             .append(&mut new_uris);
@@ -141,15 +141,85 @@ impl AccountEsdt {
             .unwrap_or_else(|| panic!("invalid token"))
             .instances
             .get_mut_by_nonce(nonce)
-            .unwrap_or_else(|| panic!("invalid token nonce"))
+            .unwrap_or_else(|| panic!("{}", vm_err_msg::INVALID_TOKEN_NONCE))
             .metadata
             .attributes = new_attribute_bytes;
     }
 
+    pub fn get_instance_mut(
+        &mut self,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> Option<&mut EsdtInstance> {
+        self.get_mut_by_identifier(token_identifier)?
+            .instances
+            .get_mut_by_nonce(nonce)
+    }
+
     pub fn iter(&self) -> Iter<Vec<u8>, EsdtData> {
         self.0.iter()
     }
 
+    fn get_mut_or_insert(&mut self, token_identifier: &[u8]) -> &mut EsdtData {
+        self.0.entry(token_identifier.to_vec()).or_default()
+    }
+
+    pub fn add_roles(&mut self, token_identifier: &[u8], roles: &[Vec<u8>]) {
+        let esdt_data = self.get_mut_or_insert(token_identifier);
+        let mut new_roles = esdt_data.roles.get();
+        for role in roles {
+            if !new_roles.contains(role) {
+                new_roles.push(role.clone());
+            }
+        }
+        esdt_data.roles = EsdtRoles::new(new_roles);
+    }
+
+    pub fn remove_roles(&mut self, token_identifier: &[u8], roles: &[Vec<u8>]) {
+        if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
+            let mut new_roles = esdt_data.roles.get();
+            new_roles.retain(|role| !roles.contains(role));
+            esdt_data.roles = EsdtRoles::new(new_roles);
+        }
+    }
+
+    pub fn has_role(&self, token_identifier: &[u8], role: &[u8]) -> bool {
+        self.get_by_identifier(token_identifier)
+            .map(|esdt_data| esdt_data.roles.get().iter().any(|r| r.as_slice() == role))
+            .unwrap_or(false)
+    }
+
+    pub fn get_last_nonce(&self, token_identifier: &[u8]) -> u64 {
+        self.get_by_identifier(token_identifier)
+            .map(|esdt_data| esdt_data.last_nonce)
+            .unwrap_or_default()
+    }
+
+    pub fn set_last_nonce(&mut self, token_identifier: &[u8], last_nonce: u64) {
+        self.get_mut_or_insert(token_identifier).last_nonce = last_nonce;
+    }
+
+    pub fn is_frozen(&self, token_identifier: &[u8]) -> bool {
+        self.get_by_identifier(token_identifier)
+            .map(|esdt_data| esdt_data.frozen)
+            .unwrap_or(false)
+    }
+
+    pub fn set_frozen(&mut self, token_identifier: &[u8], frozen: bool) {
+        self.get_mut_or_insert(token_identifier).frozen = frozen;
+    }
+
+    /// Removes the balance of a single token nonce, or of all nonces if none is given.
+    ///
+    /// Returns the wiped amount.
+    pub fn wipe(&mut self, token_identifier: &[u8], nonce: Option<u64>) -> BigUint {
+        if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
+            esdt_data.instances.wipe(nonce)
+        } else {
+            BigUint::zero()
+        }
+    }
+
     pub fn set_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
         if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
             let roles = esdt_data.roles.get();
@@ -0,0 +1,52 @@
+use crate::types::VMAddress;
+
+use super::{reserved::STORAGE_ESDT_PREFIX, AccountStorage};
+
+/// The protocol keeps token-wide ESDT settings in this account, on every shard.
+pub const SYSTEM_ACCOUNT_ADDRESS: VMAddress = VMAddress::new([0xff; 32]);
+
+const LIMITED_TRANSFER_FLAG: u8 = 0b0000_0010;
+const BURN_ROLE_FOR_ALL_FLAG: u8 = 0b0000_0100;
+
+/// Token-wide ESDT settings, as configured by the ESDT system SC.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct EsdtGlobalSettings {
+    /// Only transfers from or to accounts with the transfer role are allowed.
+    pub limited_transfer: bool,
+
+    /// Anyone can burn the token, regardless of roles.
+    pub burn_role_for_all: bool,
+}
+
+impl EsdtGlobalSettings {
+    fn storage_key(token_identifier: &[u8]) -> Vec<u8> {
+        [STORAGE_ESDT_PREFIX, token_identifier].concat()
+    }
+
+    pub fn load(storage: &AccountStorage, token_identifier: &[u8]) -> Self {
+        let flags = storage
+            .get(&Self::storage_key(token_identifier))
+            .and_then(|encoded| encoded.first().copied())
+            .unwrap_or_default();
+        EsdtGlobalSettings {
+            limited_transfer: flags & LIMITED_TRANSFER_FLAG != 0,
+            burn_role_for_all: flags & BURN_ROLE_FOR_ALL_FLAG != 0,
+        }
+    }
+
+    pub fn save(&self, storage: &mut AccountStorage, token_identifier: &[u8]) {
+        let mut flags = 0u8;
+        if self.limited_transfer {
+            flags |= LIMITED_TRANSFER_FLAG;
+        }
+        if self.burn_role_for_all {
+            flags |= BURN_ROLE_FOR_ALL_FLAG;
+        }
+        let key = Self::storage_key(token_identifier);
+        if flags == 0 {
+            storage.remove(&key);
+        } else {
+            storage.insert(key, vec![flags, 0]);
+        }
+    }
+}
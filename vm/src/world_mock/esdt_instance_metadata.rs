@@ -1,5 +1,8 @@
 use crate::types::VMAddress;
 
+/// Royalties are expressed in hundredths of a percent, so 10000 means 100%.
+pub const ROYALTIES_MAX: u64 = 10_000;
+
 /// Holds the data for a MultiversX standard digital token transaction
 #[derive(Clone, Default, Debug)]
 pub struct EsdtInstanceMetadata {
@@ -99,6 +99,22 @@ impl EsdtInstances {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Removes the instance with the given nonce, or all instances if no nonce is given.
+    ///
+    /// Returns the total balance removed.
+    pub fn wipe(&mut self, nonce: Option<u64>) -> BigUint {
+        let mut wiped = BigUint::zero();
+        self.0.retain(|instance_nonce, instance| {
+            if nonce.is_none() || nonce == Some(*instance_nonce) {
+                wiped += &instance.balance;
+                false
+            } else {
+                true
+            }
+        });
+        wiped
+    }
 }
 
 impl fmt::Display for EsdtInstances {
@@ -0,0 +1,68 @@
+use crate::types::VMAddress;
+
+/// Number of epochs before a guardian set without the current guardian's co-signature becomes active.
+pub const GUARDIAN_ACTIVATION_EPOCHS_DELAY: u64 = 20;
+
+/// A guardian, as registered on a user account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guardian {
+    pub address: VMAddress,
+    pub activation_epoch: u64,
+    pub service_uid: Vec<u8>,
+}
+
+/// The guardians of an account, and whether or not the account is guarded (a.k.a. frozen).
+///
+/// A guarded account can only send transactions co-signed by its active guardian,
+/// except for `SetGuardian`, which is how a lost guardian gets replaced.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountGuardians {
+    pub active: Option<Guardian>,
+    pub pending: Option<Guardian>,
+    pub guarded: bool,
+}
+
+impl AccountGuardians {
+    /// The guardian that can co-sign transactions in the given epoch, if any.
+    ///
+    /// A pending guardian takes over as soon as its activation epoch is reached.
+    pub fn active_guardian(&self, current_epoch: u64) -> Option<&Guardian> {
+        if let Some(pending) = &self.pending {
+            if pending.activation_epoch <= current_epoch {
+                return Some(pending);
+            }
+        }
+        self.active
+            .as_ref()
+            .filter(|active| active.activation_epoch <= current_epoch)
+    }
+
+    /// Promotes the pending guardian, if its activation epoch was reached.
+    pub fn update_active(&mut self, current_epoch: u64) {
+        if let Some(pending) = &self.pending {
+            if pending.activation_epoch <= current_epoch {
+                self.active = self.pending.take();
+            }
+        }
+    }
+
+    /// Without the active guardian's co-signature, the new guardian only activates after a delay.
+    pub fn set_pending(&mut self, address: VMAddress, service_uid: Vec<u8>, current_epoch: u64) {
+        self.update_active(current_epoch);
+        self.pending = Some(Guardian {
+            address,
+            activation_epoch: current_epoch + GUARDIAN_ACTIVATION_EPOCHS_DELAY,
+            service_uid,
+        });
+    }
+
+    /// Co-signed by the active guardian, the new guardian replaces it immediately.
+    pub fn set_active(&mut self, address: VMAddress, service_uid: Vec<u8>, current_epoch: u64) {
+        self.pending = None;
+        self.active = Some(Guardian {
+            address,
+            activation_epoch: current_epoch,
+            service_uid,
+        });
+    }
+}
@@ -1,3 +1,11 @@
 pub const STORAGE_RESERVED_PREFIX: &[u8] = b"ELROND";
 
 pub const STORAGE_REWARD_KEY: &[u8] = b"ELRONDreward";
+
+pub const STORAGE_DELEGATION_KEY: &[u8] = b"ELRONDdelegation";
+
+pub const STORAGE_DELEGATION_CONTRACTS_KEY: &[u8] = b"ELRONDdelegationContracts";
+
+pub const STORAGE_VALIDATOR_STAKE_KEY: &[u8] = b"ELRONDvalidatorStake";
+
+pub const STORAGE_ESDT_PREFIX: &[u8] = b"ELRONDesdt";
@@ -0,0 +1,352 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::types::VMAddress;
+
+use super::{
+    reserved::{STORAGE_DELEGATION_KEY, STORAGE_VALIDATOR_STAKE_KEY},
+    AccountStorage,
+};
+
+/// Number of epochs that unstaked funds stay locked before they can be withdrawn.
+pub const UNBOND_PERIOD_EPOCHS: u64 = 10;
+
+/// Service fees are expressed in hundredths of a percent, so 10000 means 100%.
+pub const SERVICE_FEE_MAX: u64 = 10_000;
+
+/// Funds that were unstaked in a given epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnstakedFunds {
+    pub value: BigUint,
+    pub epoch: u64,
+}
+
+/// Everything the staking system SCs know about one staker/delegator.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StakeEntry {
+    pub active: BigUint,
+    pub unstaked: Vec<UnstakedFunds>,
+    pub unclaimed_rewards: BigUint,
+}
+
+impl StakeEntry {
+    pub fn is_empty(&self) -> bool {
+        self.active.is_zero() && self.unstaked.is_empty() && self.unclaimed_rewards.is_zero()
+    }
+
+    pub fn unstaked_value(&self) -> BigUint {
+        self.unstaked.iter().map(|funds| &funds.value).sum()
+    }
+
+    pub fn unbondable_value(&self, current_epoch: u64) -> BigUint {
+        self.unstaked
+            .iter()
+            .filter(|funds| is_unbondable(funds, current_epoch))
+            .map(|funds| &funds.value)
+            .sum()
+    }
+
+    /// Removes all unstaked funds whose unbonding period has passed, returns their sum.
+    pub fn withdraw_unbondable(&mut self, current_epoch: u64) -> BigUint {
+        let unbondable = self.unbondable_value(current_epoch);
+        self.unstaked
+            .retain(|funds| !is_unbondable(funds, current_epoch));
+        unbondable
+    }
+}
+
+fn is_unbondable(funds: &UnstakedFunds, current_epoch: u64) -> bool {
+    funds.epoch + UNBOND_PERIOD_EPOCHS <= current_epoch
+}
+
+/// Stake entries, in the order in which the stakers first joined.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StakeLedger(Vec<(VMAddress, StakeEntry)>);
+
+impl StakeLedger {
+    pub fn get(&self, address: &VMAddress) -> Option<&StakeEntry> {
+        self.0
+            .iter()
+            .find(|(entry_address, _)| entry_address == address)
+            .map(|(_, entry)| entry)
+    }
+
+    pub fn get_or_insert_mut(&mut self, address: &VMAddress) -> &mut StakeEntry {
+        let index = if let Some(index) = self.position(address) {
+            index
+        } else {
+            self.0.push((address.clone(), StakeEntry::default()));
+            self.0.len() - 1
+        };
+        &mut self.0[index].1
+    }
+
+    /// Forgets stakers that have nothing left in the contract.
+    pub fn remove_empty(&mut self) {
+        self.0.retain(|(_, entry)| !entry.is_empty());
+    }
+
+    pub fn num_stakers(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn total_active(&self) -> BigUint {
+        self.0.iter().map(|(_, entry)| &entry.active).sum()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut (VMAddress, StakeEntry)> {
+        self.0.iter_mut()
+    }
+
+    fn position(&self, address: &VMAddress) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|(entry_address, _)| entry_address == address)
+    }
+
+    fn encode(&self, encoder: &mut StakingEncoder) {
+        encoder.push_u64(self.0.len() as u64);
+        for (address, entry) in &self.0 {
+            encoder.push_bytes(address.as_bytes());
+            encoder.push_big_uint(&entry.active);
+            encoder.push_big_uint(&entry.unclaimed_rewards);
+            encoder.push_u64(entry.unstaked.len() as u64);
+            for funds in &entry.unstaked {
+                encoder.push_big_uint(&funds.value);
+                encoder.push_u64(funds.epoch);
+            }
+        }
+    }
+
+    fn decode(decoder: &mut StakingDecoder) -> Self {
+        let num_entries = decoder.next_u64();
+        let mut entries = Vec::new();
+        for _ in 0..num_entries {
+            let address = VMAddress::from_slice(decoder.next_bytes());
+            let active = decoder.next_big_uint();
+            let unclaimed_rewards = decoder.next_big_uint();
+            let num_unstaked = decoder.next_u64();
+            let mut unstaked = Vec::new();
+            for _ in 0..num_unstaked {
+                let value = decoder.next_big_uint();
+                let epoch = decoder.next_u64();
+                unstaked.push(UnstakedFunds { value, epoch });
+            }
+            entries.push((
+                address,
+                StakeEntry {
+                    active,
+                    unstaked,
+                    unclaimed_rewards,
+                },
+            ));
+        }
+        StakeLedger(entries)
+    }
+}
+
+/// State of a delegation contract, as created by the delegation manager system SC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DelegationData {
+    pub owner: VMAddress,
+    pub service_fee: u64,
+    /// Zero means the contract accepts unlimited delegation.
+    pub total_delegation_cap: BigUint,
+    pub delegators: StakeLedger,
+}
+
+impl DelegationData {
+    pub fn new(owner: VMAddress, service_fee: u64, total_delegation_cap: BigUint) -> Self {
+        DelegationData {
+            owner,
+            service_fee,
+            total_delegation_cap,
+            delegators: StakeLedger::default(),
+        }
+    }
+
+    pub fn load(storage: &AccountStorage) -> Option<Self> {
+        let encoded = storage.get(STORAGE_DELEGATION_KEY)?;
+        let mut decoder = StakingDecoder::new(encoded);
+        let owner = VMAddress::from_slice(decoder.next_bytes());
+        let service_fee = decoder.next_u64();
+        let total_delegation_cap = decoder.next_big_uint();
+        let delegators = StakeLedger::decode(&mut decoder);
+        Some(DelegationData {
+            owner,
+            service_fee,
+            total_delegation_cap,
+            delegators,
+        })
+    }
+
+    pub fn save(&self, storage: &mut AccountStorage) {
+        let mut encoder = StakingEncoder::default();
+        encoder.push_bytes(self.owner.as_bytes());
+        encoder.push_u64(self.service_fee);
+        encoder.push_big_uint(&self.total_delegation_cap);
+        self.delegators.encode(&mut encoder);
+        storage.insert(STORAGE_DELEGATION_KEY.to_vec(), encoder.0);
+    }
+
+    /// Splits validator rewards between the owner (the service fee)
+    /// and the delegators, proportionally to their active stake.
+    pub fn distribute_rewards(&mut self, rewards: &BigUint) {
+        let total_active = self.delegators.total_active();
+        if total_active.is_zero() {
+            self.delegators
+                .get_or_insert_mut(&self.owner)
+                .unclaimed_rewards += rewards;
+            return;
+        }
+
+        let service_fee = rewards * self.service_fee / SERVICE_FEE_MAX;
+        let delegator_rewards = rewards - &service_fee;
+        let mut distributed = BigUint::zero();
+        for (_, entry) in self.delegators.iter_mut() {
+            let share = &delegator_rewards * &entry.active / &total_active;
+            distributed += &share;
+            entry.unclaimed_rewards += share;
+        }
+
+        // the owner also gets the rounding leftovers
+        let owner_rewards = rewards - distributed;
+        self.delegators
+            .get_or_insert_mut(&self.owner)
+            .unclaimed_rewards += owner_rewards;
+    }
+}
+
+/// State of the validator (staking) system SC.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidatorStakeData {
+    pub stakers: StakeLedger,
+}
+
+impl ValidatorStakeData {
+    pub fn load(storage: &AccountStorage) -> Self {
+        if let Some(encoded) = storage.get(STORAGE_VALIDATOR_STAKE_KEY) {
+            let mut decoder = StakingDecoder::new(encoded);
+            ValidatorStakeData {
+                stakers: StakeLedger::decode(&mut decoder),
+            }
+        } else {
+            ValidatorStakeData::default()
+        }
+    }
+
+    pub fn save(&self, storage: &mut AccountStorage) {
+        let mut encoder = StakingEncoder::default();
+        self.stakers.encode(&mut encoder);
+        storage.insert(STORAGE_VALIDATOR_STAKE_KEY.to_vec(), encoder.0);
+    }
+}
+
+#[derive(Default)]
+struct StakingEncoder(Vec<u8>);
+
+impl StakingEncoder {
+    fn push_u64(&mut self, value: u64) {
+        self.0.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        self.push_u64(bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn push_big_uint(&mut self, value: &BigUint) {
+        self.push_bytes(&value.to_bytes_be());
+    }
+}
+
+struct StakingDecoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> StakingDecoder<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        StakingDecoder { bytes }
+    }
+
+    fn next_slice(&mut self, len: usize) -> &'a [u8] {
+        assert!(self.bytes.len() >= len, "invalid staking data encoding");
+        let (slice, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        slice
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut arr = [0u8; 8];
+        arr.copy_from_slice(self.next_slice(8));
+        u64::from_be_bytes(arr)
+    }
+
+    fn next_bytes(&mut self) -> &'a [u8] {
+        let len = self.next_u64() as usize;
+        self.next_slice(len)
+    }
+
+    fn next_big_uint(&mut self) -> BigUint {
+        BigUint::from_bytes_be(self.next_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> VMAddress {
+        VMAddress::new([byte; 32])
+    }
+
+    #[test]
+    fn delegation_data_encode_decode() {
+        let mut data = DelegationData::new(address(1), 1000, BigUint::from(5000u32));
+        let entry = data.delegators.get_or_insert_mut(&address(2));
+        entry.active = BigUint::from(300u32);
+        entry.unclaimed_rewards = BigUint::from(7u32);
+        entry.unstaked.push(UnstakedFunds {
+            value: BigUint::from(100u32),
+            epoch: 3,
+        });
+
+        let mut storage = AccountStorage::default();
+        data.save(&mut storage);
+        assert_eq!(DelegationData::load(&storage), Some(data));
+    }
+
+    #[test]
+    fn delegation_rewards_distribution() {
+        let mut data = DelegationData::new(address(1), 1000, BigUint::zero());
+        data.delegators.get_or_insert_mut(&address(1)).active = BigUint::from(100u32);
+        data.delegators.get_or_insert_mut(&address(2)).active = BigUint::from(300u32);
+
+        data.distribute_rewards(&BigUint::from(1003u32));
+
+        // 10% service fee: 100, the remaining 903 split 1:3, rounding leftover to the owner
+        let owner_rewards = &data.delegators.get(&address(1)).unwrap().unclaimed_rewards;
+        let delegator_rewards = &data.delegators.get(&address(2)).unwrap().unclaimed_rewards;
+        assert_eq!(delegator_rewards, &BigUint::from(677u32));
+        assert_eq!(owner_rewards, &BigUint::from(326u32));
+    }
+
+    #[test]
+    fn unbonding_period() {
+        let mut entry = StakeEntry::default();
+        entry.unstaked.push(UnstakedFunds {
+            value: BigUint::from(10u32),
+            epoch: 2,
+        });
+        entry.unstaked.push(UnstakedFunds {
+            value: BigUint::from(20u32),
+            epoch: 5,
+        });
+
+        assert_eq!(entry.unbondable_value(11), BigUint::zero());
+        assert_eq!(entry.withdraw_unbondable(12), BigUint::from(10u32));
+        assert_eq!(entry.unstaked_value(), BigUint::from(20u32));
+        assert_eq!(entry.withdraw_unbondable(15), BigUint::from(20u32));
+        assert!(entry.is_empty());
+    }
+}